@@ -48,7 +48,8 @@ where
 
 				stream_len -= u64::from(header.size);
 
-				let id3v2 = parse_id3v2(reader, header, parse_mode)?;
+				let frames_offset = reader.stream_position()?;
+				let id3v2 = parse_id3v2(reader, header, parse_options, Some(frames_offset))?;
 				if let Some(existing_tag) = &mut file.id3v2_tag {
 					log::warn!("Duplicate ID3v2 tag found, appending frames to previous tag");
 
@@ -42,7 +42,7 @@ where
 
 		let reader = &mut &*content;
 
-		let id3v2 = parse_id3v2(reader, header, parse_options.parsing_mode)?;
+		let id3v2 = parse_id3v2(reader, header, parse_options, None)?;
 		id3v2_tag = Some(id3v2);
 	}
 
@@ -82,7 +82,7 @@ where
 				let ape_header = read_ape_header(data, false)?;
 				stream_len -= u64::from(ape_header.size);
 
-				let ape = read_ape_tag_with_header(data, ape_header)?;
+				let ape = read_ape_tag_with_header(data, ape_header, parse_options)?;
 				ape_tag = Some(ape);
 			},
 			_ => {
@@ -117,7 +117,7 @@ where
 	// Strongly recommended to be at the end of the file
 	data.seek(SeekFrom::Current(-32))?;
 
-	if let Some((tag, header)) = read_ape_tag(data, true)? {
+	if let Some((tag, header)) = read_ape_tag(data, true, parse_options)? {
 		stream_len -= u64::from(header.size);
 		ape_tag = Some(tag);
 	}
@@ -3,9 +3,11 @@ pub(crate) mod read;
 mod write;
 
 use crate::ape::tag::item::{ApeItem, ApeItemRef};
-use crate::config::WriteOptions;
+use crate::config::{ParseOptions, WriteOptions};
 use crate::error::{LoftyError, Result};
+use crate::file::write_verified;
 use crate::id3::v2::util::pairs::{format_number_pair, set_number, NUMBER_PAIR_KEYS};
+use crate::macros::err;
 use crate::tag::item::ItemValueRef;
 use crate::tag::{
 	try_parse_year, Accessor, ItemKey, ItemValue, MergeTag, SplitTag, Tag, TagExt, TagItem, TagType,
@@ -14,7 +16,7 @@ use crate::util::flag_item;
 use crate::util::io::{FileLike, Truncate};
 
 use std::borrow::Cow;
-use std::io::Write;
+use std::io::{Read, Seek, Write};
 use std::ops::Deref;
 
 use lofty_attr::tag;
@@ -75,7 +77,7 @@ macro_rules! impl_accessor {
 #[derive(Default, Debug, PartialEq, Eq, Clone)]
 #[tag(
 	description = "An `APE` tag",
-	supported_formats(Ape, Mpeg, Mpc, WavPack)
+	supported_formats(Ape, Mpeg, Mpc, OptimFrog, WavPack)
 )]
 pub struct ApeTag {
 	/// Whether or not to mark the tag as read only
@@ -99,6 +101,42 @@ impl ApeTag {
 		Self::default()
 	}
 
+	/// Read a tag previously written with [`TagExt::dump_to`]
+	///
+	/// # Errors
+	///
+	/// * `reader` does not start with an APE header
+	/// * The tag is otherwise malformed
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use lofty::ape::ApeTag;
+	/// use lofty::config::WriteOptions;
+	/// use lofty::tag::{Accessor, TagExt};
+	/// use std::io::Cursor;
+	///
+	/// # fn main() -> lofty::error::Result<()> {
+	/// let mut tag = ApeTag::new();
+	/// tag.set_title(String::from("Foo title"));
+	///
+	/// let mut dump = Vec::new();
+	/// tag.dump_to(&mut dump, WriteOptions::default())?;
+	///
+	/// let re_read = ApeTag::read_from_dump(&mut Cursor::new(dump))?;
+	/// assert_eq!(re_read.title().as_deref(), Some("Foo title"));
+	/// # Ok(()) }
+	/// ```
+	pub fn read_from_dump<R>(reader: &mut R) -> Result<Self>
+	where
+		R: Read + Seek,
+	{
+		match read::read_ape_tag(reader, false, ParseOptions::new())? {
+			Some((tag, _)) => Ok(tag),
+			None => err!(FakeTag),
+		}
+	}
+
 	/// Get an [`ApeItem`] by key
 	///
 	/// NOTE: While `APE` items are supposed to be case-sensitive,
@@ -228,11 +266,21 @@ impl<'a> IntoIterator for &'a ApeTag {
 
 impl Accessor for ApeTag {
 	impl_accessor!(
-		artist  => "Artist";
-		title   => "Title";
-		album   => "Album";
-		genre   => "GENRE";
-		comment => "Comment";
+		artist            => "Artist";
+		title             => "Title";
+		album             => "Album";
+		genre             => "GENRE";
+		comment           => "Comment";
+		lyrics            => "Lyrics";
+		title_sort        => "TITLESORT";
+		artist_sort       => "ARTISTSORT";
+		album_title_sort  => "ALBUMSORT";
+		album_artist_sort => "ALBUMARTISTSORT";
+		isrc              => "ISRC";
+		barcode           => "Barcode";
+		catalog_number    => "CatalogNumber";
+		label             => "Label";
+		encoder           => "Encoder";
 	);
 
 	fn track(&self) -> Option<u32> {
@@ -470,6 +518,11 @@ impl MergeTag for SplitTagRemainder {
 		}
 
 		for pic in tag.pictures {
+			if !pic.is_loaded() {
+				log::warn!("Discarding a picture that was not loaded, see `Picture::load`");
+				continue;
+			}
+
 			if let Some(key) = pic.pic_type.as_ape_key() {
 				if let Ok(item) =
 					ApeItem::new(key.to_string(), ItemValue::Binary(pic.as_ape_bytes()))
@@ -512,7 +565,9 @@ where
 		F: FileLike,
 		LoftyError: From<<F as Truncate>::Error>,
 	{
-		write::write_to(file, self, write_options)
+		write_verified(file, write_options, |file, write_options| {
+			write::write_to(file, self, write_options)
+		})
 	}
 
 	pub(crate) fn dump_to<W: Write>(
@@ -564,7 +619,7 @@ pub(crate) fn tagitems_into_ape(tag: &Tag) -> impl Iterator<Item = ApeItemRef<'_
 #[cfg(test)]
 mod tests {
 	use crate::ape::{ApeItem, ApeTag};
-	use crate::config::WriteOptions;
+	use crate::config::{ParseOptions, WriteOptions};
 	use crate::id3::v2::util::pairs::DEFAULT_NUMBER_IN_PAIR;
 	use crate::prelude::*;
 	use crate::tag::{ItemValue, Tag, TagItem, TagType};
@@ -622,9 +677,10 @@ mod tests {
 		let tag = crate::tag::utils::test_utils::read_path("tests/tags/assets/test.apev2");
 		let mut reader = Cursor::new(tag);
 
-		let (parsed_tag, _) = crate::ape::tag::read::read_ape_tag(&mut reader, false)
-			.unwrap()
-			.unwrap();
+		let (parsed_tag, _) =
+			crate::ape::tag::read::read_ape_tag(&mut reader, false, ParseOptions::new())
+				.unwrap()
+				.unwrap();
 
 		assert_eq!(expected_tag.len(), parsed_tag.len());
 
@@ -638,9 +694,10 @@ mod tests {
 		let tag_bytes = crate::tag::utils::test_utils::read_path("tests/tags/assets/test.apev2");
 		let mut reader = Cursor::new(tag_bytes);
 
-		let (parsed_tag, _) = crate::ape::tag::read::read_ape_tag(&mut reader, false)
-			.unwrap()
-			.unwrap();
+		let (parsed_tag, _) =
+			crate::ape::tag::read::read_ape_tag(&mut reader, false, ParseOptions::new())
+				.unwrap()
+				.unwrap();
 
 		let mut writer = Vec::new();
 		parsed_tag
@@ -649,9 +706,10 @@ mod tests {
 
 		let mut temp_reader = Cursor::new(writer);
 
-		let (temp_parsed_tag, _) = crate::ape::tag::read::read_ape_tag(&mut temp_reader, false)
-			.unwrap()
-			.unwrap();
+		let (temp_parsed_tag, _) =
+			crate::ape::tag::read::read_ape_tag(&mut temp_reader, false, ParseOptions::new())
+				.unwrap()
+				.unwrap();
 
 		assert_eq!(parsed_tag, temp_parsed_tag);
 	}
@@ -661,7 +719,7 @@ mod tests {
 		let tag_bytes = crate::tag::utils::test_utils::read_path("tests/tags/assets/test.apev2");
 		let mut reader = Cursor::new(tag_bytes);
 
-		let (ape, _) = crate::ape::tag::read::read_ape_tag(&mut reader, false)
+		let (ape, _) = crate::ape::tag::read::read_ape_tag(&mut reader, false, ParseOptions::new())
 			.unwrap()
 			.unwrap();
 
@@ -670,6 +728,38 @@ mod tests {
 		crate::tag::utils::test_utils::verify_tag(&tag, true, true);
 	}
 
+	#[test]
+	fn skip_pictures_keeps_ape_pictures() {
+		let mut tag = ApeTag::default();
+		tag.set_title(String::from("Foo title"));
+		tag.insert(
+			ApeItem::new(
+				String::from("Cover Art (Front)"),
+				ItemValue::Binary(vec![1; 64]),
+			)
+			.unwrap(),
+		);
+
+		let mut writer = Vec::new();
+		tag.dump_to(&mut writer, WriteOptions::default()).unwrap();
+
+		let (parsed_tag, _) = crate::ape::tag::read::read_ape_tag(
+			&mut Cursor::new(writer),
+			false,
+			ParseOptions::new().read_pictures(false),
+		)
+		.unwrap()
+		.unwrap();
+
+		// APE pictures are stored as items, which can't be skipped without losing them when writing
+		assert_eq!(parsed_tag.len(), 2);
+		assert_eq!(parsed_tag.title().as_deref(), Some("Foo title"));
+		assert_eq!(
+			parsed_tag.get("Cover Art (Front)").map(ApeItem::value),
+			Some(&ItemValue::Binary(vec![1; 64]))
+		);
+	}
+
 	#[test]
 	fn tag_to_ape() {
 		fn verify_key(tag: &ApeTag, key: &str, expected_val: &str) {
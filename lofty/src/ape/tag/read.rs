@@ -2,7 +2,10 @@ use super::item::ApeItem;
 use super::ApeTag;
 use crate::ape::constants::{APE_PREAMBLE, INVALID_KEYS};
 use crate::ape::header::{self, ApeHeader};
+use crate::config::ParseOptions;
 use crate::error::Result;
+use crate::id3::v1::Id3v1Tag;
+use crate::id3::{find_id3v1, find_lyrics3v2, ID3FindResults};
 use crate::macros::{decode_err, err, try_vec};
 use crate::tag::ItemValue;
 use crate::util::text::utf8_decode;
@@ -11,10 +14,16 @@ use std::io::{Read, Seek, SeekFrom};
 
 use byteorder::{LittleEndian, ReadBytesExt};
 
-pub(crate) fn read_ape_tag_with_header<R>(data: &mut R, header: ApeHeader) -> Result<ApeTag>
+pub(crate) fn read_ape_tag_with_header<R>(
+	data: &mut R,
+	header: ApeHeader,
+	parse_options: ParseOptions,
+) -> Result<ApeTag>
 where
 	R: Read + Seek,
 {
+	parse_options.check_tag_size(u64::from(header.size))?;
+
 	let mut tag = ApeTag::default();
 	let mut remaining_size = header.size;
 
@@ -86,6 +95,7 @@ where
 pub(crate) fn read_ape_tag<R: Read + Seek>(
 	reader: &mut R,
 	footer: bool,
+	parse_options: ParseOptions,
 ) -> Result<Option<(ApeTag, ApeHeader)>> {
 	let mut ape_preamble = [0; 8];
 	reader.read_exact(&mut ape_preamble)?;
@@ -93,9 +103,55 @@ pub(crate) fn read_ape_tag<R: Read + Seek>(
 	if &ape_preamble == APE_PREAMBLE {
 		let ape_header = header::read_ape_header(reader, footer)?;
 
-		let ape = read_ape_tag_with_header(reader, ape_header)?;
+		let ape = read_ape_tag_with_header(reader, ape_header, parse_options)?;
 		return Ok(Some((ape, ape_header)));
 	}
 
 	Ok(None)
 }
+
+/// Read the tags at the end of a file, as found in WavPack and OptimFROG files
+///
+/// Starting from the end of the file, these are an ID3v1 tag, a Lyrics3v2 tag (which is skipped), and
+/// an APE tag, all of which are optional. The total size of the tags is returned along with them, so the
+/// length of the audio stream can be calculated.
+pub(crate) fn read_trailing_tags<R>(
+	reader: &mut R,
+	parse_options: ParseOptions,
+) -> Result<(Option<Id3v1Tag>, Option<ApeTag>, u64)>
+where
+	R: Read + Seek,
+{
+	let mut tags_size = 0;
+
+	let mut id3v1_tag = None;
+	let mut ape_tag = None;
+
+	let ID3FindResults(id3v1_header, id3v1) = find_id3v1(reader, true)?;
+
+	if id3v1_header.is_some() {
+		tags_size += 128;
+		id3v1_tag = id3v1;
+	}
+
+	// Next, check for a Lyrics3v2 tag, and skip over it, as it's no use to us
+	let ID3FindResults(lyrics3_header, lyrics3v2_size) = find_lyrics3v2(reader)?;
+
+	if lyrics3_header.is_some() {
+		tags_size += u64::from(lyrics3v2_size);
+	}
+
+	// Next, search for an APE tag footer
+	//
+	// Starts with ['A', 'P', 'E', 'T', 'A', 'G', 'E', 'X']
+	// Exactly 32 bytes long
+	// Strongly recommended to be at the end of the file
+	reader.seek(SeekFrom::Current(-32))?;
+
+	if let Some((tag, header)) = read_ape_tag(reader, true, parse_options)? {
+		tags_size += u64::from(header.size);
+		ape_tag = Some(tag);
+	}
+
+	Ok((id3v1_tag, ape_tag, tags_size))
+}
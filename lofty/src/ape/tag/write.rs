@@ -2,7 +2,7 @@ use super::item::ApeItemRef;
 use super::ApeTagRef;
 use crate::ape::constants::APE_PREAMBLE;
 use crate::ape::tag::read;
-use crate::config::WriteOptions;
+use crate::config::{ParseOptions, WriteOptions};
 use crate::error::{LoftyError, Result};
 use crate::id3::{find_id3v1, find_id3v2, find_lyrics3v2, FindId3v2Config};
 use crate::macros::{decode_err, err};
@@ -14,6 +14,8 @@ use std::io::{Cursor, Seek, SeekFrom, Write};
 
 use byteorder::{LittleEndian, WriteBytesExt};
 
+const ENCODER_KEY: &str = "Encoder";
+
 #[allow(clippy::shadow_unrelated)]
 pub(crate) fn write_to<'a, F, I>(
 	file: &mut F,
@@ -48,7 +50,7 @@ where
 	let mut header_ape_tag = (false, (0, 0));
 
 	let start = file.stream_position()?;
-	match read::read_ape_tag(file, false)? {
+	match read::read_ape_tag(file, false, ParseOptions::new())? {
 		Some((mut existing_tag, header)) => {
 			if write_options.respect_read_only {
 				// Only keep metadata around that's marked read only
@@ -80,7 +82,7 @@ where
 
 	// Also check this tag for any read only items
 	let start = file.stream_position()? as usize + 32;
-	if let Some((mut existing_tag, header)) = read::read_ape_tag(file, true)? {
+	if let Some((mut existing_tag, header)) = read::read_ape_tag(file, true, ParseOptions::new())? {
 		if write_options.respect_read_only {
 			existing_tag.items.retain(|i| i.read_only);
 
@@ -166,8 +168,11 @@ where
 	let mut tag_write = Cursor::new(Vec::<u8>::new());
 
 	let mut item_count = 0_u32;
+	let mut has_encoder = false;
 
 	for item in peek {
+		has_encoder |= item.key.eq_ignore_ascii_case(ENCODER_KEY);
+
 		let (mut flags, value) = match item.value {
 			ItemValueRef::Binary(value) => {
 				tag_write.write_u32::<LittleEndian>(value.len() as u32)?;
@@ -198,6 +203,21 @@ where
 		item_count += 1;
 	}
 
+	// See `WriteOptions::encoder_stamp`
+	if let Some(encoder_stamp) = write_options.encoder_stamp_value() {
+		if !has_encoder {
+			log::debug!("Stamping APE tag with encoder: {}", encoder_stamp);
+
+			tag_write.write_u32::<LittleEndian>(encoder_stamp.len() as u32)?;
+			tag_write.write_u32::<LittleEndian>(0)?;
+			tag_write.write_all(ENCODER_KEY.as_bytes())?;
+			tag_write.write_u8(0)?;
+			tag_write.write_all(encoder_stamp.as_bytes())?;
+
+			item_count += 1;
+		}
+	}
+
 	let size = tag_write.get_ref().len();
 
 	if size as u64 + 32 > u64::from(u32::MAX) {
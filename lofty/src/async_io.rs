@@ -0,0 +1,256 @@
+//! Async file reading and writing
+//!
+//! This module is only available with the `tokio` feature.
+//!
+//! Only the file I/O is asynchronous. The file is read into memory in its entirety, and all parsing
+//! and tag writing happens on that in-memory buffer, so none of these functions will block on I/O.
+//!
+//! NOTE: The parsing and tag writing still happen synchronously on the calling task. This is CPU-bound work,
+//!       and can take a while for large files. If that is a concern, consider moving it to
+//!       [`tokio::task::spawn_blocking`].
+//!
+//! Files are saved atomically: the new contents are written to a temporary file next to the original,
+//! which is then renamed over it. If the future is cancelled or the process is interrupted, the original
+//! file will be left untouched.
+//!
+//! # Examples
+//!
+//! ```rust,no_run
+//! use lofty::async_io::{read_from_path_async, save_to_path_async};
+//! use lofty::config::WriteOptions;
+//! use lofty::prelude::*;
+//!
+//! # async fn run() -> lofty::error::Result<()> {
+//! let tagged_file = read_from_path_async("test.mp3").await?;
+//!
+//! if let Some(tag) = tagged_file.primary_tag() {
+//! 	let mut tag = tag.clone();
+//! 	tag.set_title(String::from("Foo title"));
+//!
+//! 	save_to_path_async(&tag, "test.mp3", WriteOptions::default()).await?;
+//! }
+//! # Ok(()) }
+//! ```
+
+use crate::config::WriteOptions;
+use crate::error::Result;
+use crate::file::{AudioFile, FileType, TaggedFile};
+use crate::probe::Probe;
+use crate::tag::TagExt;
+
+use std::io::Cursor;
+use std::path::Path;
+
+/// Read a file into memory and create a [`Probe`] for it
+///
+/// This is the async equivalent of [`Probe::open`]. The [`FileType`] will be guessed from the extension,
+/// and can be changed with [`Probe::guess_file_type`] or [`Probe::set_file_type`].
+///
+/// # Errors
+///
+/// * `path` does not exist
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use lofty::async_io::probe_path_async;
+/// use lofty::config::ParseOptions;
+///
+/// # async fn run() -> lofty::error::Result<()> {
+/// let tagged_file = probe_path_async("test.mp3")
+/// 	.await?
+/// 	.options(ParseOptions::new().read_properties(false))
+/// 	.read()?;
+/// # Ok(()) }
+/// ```
+pub async fn probe_path_async<P>(path: P) -> Result<Probe<Cursor<Vec<u8>>>>
+where
+	P: AsRef<Path>,
+{
+	let path = path.as_ref();
+	log::debug!("Probe: Reading `{}` into memory", path.display());
+
+	let contents = tokio::fs::read(path).await?;
+	let reader = Cursor::new(contents);
+
+	Ok(match FileType::from_path(path) {
+		Some(file_type) => Probe::with_file_type(reader, file_type),
+		None => Probe::new(reader),
+	})
+}
+
+/// Read a [`TaggedFile`] from a path
+///
+/// This is the async equivalent of [`read_from_path`](crate::read_from_path).
+///
+/// # Errors
+///
+/// See:
+///
+/// * [`probe_path_async`]
+/// * [`Probe::read`]
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use lofty::async_io::read_from_path_async;
+///
+/// # async fn run() -> lofty::error::Result<()> {
+/// let tagged_file = read_from_path_async("test.mp3").await?;
+/// # Ok(()) }
+/// ```
+pub async fn read_from_path_async<P>(path: P) -> Result<TaggedFile>
+where
+	P: AsRef<Path>,
+{
+	probe_path_async(path).await?.read()
+}
+
+/// Save a tag to a path
+///
+/// This is the async equivalent of [`TagExt::save_to_path`], and works with any tag type.
+///
+/// NOTE: Unlike [`TagExt::save_to_path`], the entire file will always be rewritten. See the
+///       [module docs](self) for details.
+///
+/// # Errors
+///
+/// * `path` does not exist or could not be written to
+/// * See [`TagExt::save_to`]
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use lofty::async_io::save_to_path_async;
+/// use lofty::config::WriteOptions;
+/// use lofty::id3::v2::Id3v2Tag;
+/// use lofty::prelude::*;
+///
+/// # async fn run() -> lofty::error::Result<()> {
+/// let mut tag = Id3v2Tag::new();
+/// tag.set_artist(String::from("Foo artist"));
+///
+/// save_to_path_async(&tag, "test.mp3", WriteOptions::default()).await?;
+/// # Ok(()) }
+/// ```
+pub async fn save_to_path_async<T, P>(
+	tag: &T,
+	path: P,
+	write_options: WriteOptions,
+) -> std::result::Result<(), T::Err>
+where
+	T: TagExt,
+	P: AsRef<Path>,
+{
+	let path = path.as_ref();
+
+	let mut file = Cursor::new(tokio::fs::read(path).await?);
+	tag.save_to(&mut file, write_options)?;
+
+	write_atomic(path, file.into_inner()).await?;
+	Ok(())
+}
+
+/// Save all tags of an [`AudioFile`] to a path
+///
+/// This is the async equivalent of [`AudioFile::save_to_path`].
+///
+/// NOTE: Unlike [`AudioFile::save_to_path`], the entire file will always be rewritten. See the
+///       [module docs](self) for details.
+///
+/// # Errors
+///
+/// * `path` does not exist or could not be written to
+/// * See [`AudioFile::save_to`]
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use lofty::async_io::{read_from_path_async, save_file_to_path_async};
+/// use lofty::config::WriteOptions;
+/// use lofty::prelude::*;
+///
+/// # async fn run() -> lofty::error::Result<()> {
+/// let mut tagged_file = read_from_path_async("test.mp3").await?;
+///
+/// if let Some(tag) = tagged_file.primary_tag_mut() {
+/// 	tag.set_artist(String::from("Foo artist"));
+/// }
+///
+/// save_file_to_path_async(&tagged_file, "test.mp3", WriteOptions::default()).await?;
+/// # Ok(()) }
+/// ```
+pub async fn save_file_to_path_async<F, P>(
+	audio_file: &F,
+	path: P,
+	write_options: WriteOptions,
+) -> Result<()>
+where
+	F: AudioFile,
+	P: AsRef<Path>,
+{
+	let path = path.as_ref();
+
+	let mut file = Cursor::new(tokio::fs::read(path).await?);
+	audio_file.save_to(&mut file, write_options)?;
+
+	write_atomic(path, file.into_inner()).await?;
+	Ok(())
+}
+
+async fn write_atomic(path: &Path, contents: Vec<u8>) -> std::io::Result<()> {
+	let temp_path = crate::util::io::temp_path(path);
+
+	let result = async {
+		tokio::fs::write(&temp_path, contents).await?;
+		tokio::fs::File::open(&temp_path).await?.sync_all().await?;
+		tokio::fs::rename(&temp_path, path).await
+	}
+	.await;
+
+	if result.is_err() {
+		let _ = tokio::fs::remove_file(&temp_path).await;
+	}
+
+	result
+}
+
+#[cfg(test)]
+mod tests {
+	use super::{read_from_path_async, save_to_path_async};
+	use crate::config::WriteOptions;
+	use crate::prelude::*;
+	use crate::tag::{Tag, TagType};
+
+	use std::future::Future;
+
+	fn block_on<F: Future>(future: F) -> F::Output {
+		tokio::runtime::Builder::new_current_thread()
+			.build()
+			.unwrap()
+			.block_on(future)
+	}
+
+	#[test]
+	fn read_write_round_trip() {
+		let dir = tempfile::tempdir().unwrap();
+		let path = dir.path().join("a.mp3");
+		std::fs::copy("tests/files/assets/minimal/full_test.mp3", &path).unwrap();
+
+		let tagged_file = block_on(read_from_path_async(&path)).unwrap();
+		assert!(tagged_file.tag(TagType::Id3v2).is_some());
+
+		let mut tag = Tag::new(TagType::Id3v2);
+		tag.set_title(String::from("Foo title"));
+		block_on(save_to_path_async(&tag, &path, WriteOptions::default())).unwrap();
+
+		let tagged_file = crate::read_from_path(&path).unwrap();
+		assert_eq!(
+			tagged_file.tag(TagType::Id3v2).unwrap().title().as_deref(),
+			Some("Foo title")
+		);
+
+		// No temporary files are left behind
+		assert_eq!(std::fs::read_dir(dir.path()).unwrap().count(), 1);
+	}
+}
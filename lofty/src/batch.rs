@@ -0,0 +1,269 @@
+//! Tagging many files at once
+//!
+//! This module is only available with the `batch` feature.
+//!
+//! Files are processed in parallel, and every write is transactional: the tag is written to a
+//! temporary copy of the file, which is then renamed over the original. If the process is interrupted,
+//! the original file will be left untouched.
+//!
+//! # Examples
+//!
+//! ```rust,no_run
+//! use lofty::batch::retag_paths;
+//! use lofty::prelude::*;
+//!
+//! let paths = ["a.mp3", "b.flac", "c.m4a"];
+//!
+//! // Set the album of every file
+//! let results = retag_paths(paths, |tag, _path| {
+//! 	tag.set_album(String::from("Foo album"));
+//! });
+//!
+//! for (path, result) in results {
+//! 	if let Err(e) = result {
+//! 		eprintln!("Failed to retag {}: {e}", path.display());
+//! 	}
+//! }
+//! ```
+
+use crate::config::{ParseOptions, WriteOptions};
+use crate::error::Result;
+use crate::file::TaggedFileExt;
+use crate::probe::Probe;
+use crate::tag::{Tag, TagExt};
+
+use std::fs::OpenOptions;
+use std::num::NonZeroUsize;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+/// Apply `f` to the primary tag of every file in `paths`, and write it back
+///
+/// This is the same as [`retag_paths_with`], using the default [`WriteOptions`] and as many
+/// threads as are available.
+///
+/// # Examples
+///
+/// See the [module-level documentation](crate::batch).
+pub fn retag_paths<I, P, F>(paths: I, f: F) -> Vec<(PathBuf, Result<()>)>
+where
+	I: IntoIterator<Item = P>,
+	P: AsRef<Path>,
+	F: Fn(&mut Tag, &Path) + Sync,
+{
+	let threads = std::thread::available_parallelism().map_or(1, NonZeroUsize::get);
+	retag_paths_with(paths, threads, WriteOptions::default(), f)
+}
+
+/// Apply `f` to the primary tag of every file in `paths` using `threads` threads, and write it back
+///
+/// If a file does not have its primary tag, an empty one will be created and passed to `f`.
+///
+/// Each file is written atomically, by writing to a temporary copy in the same directory and renaming it
+/// over the original. Every write uses a uniquely named copy, so duplicate paths are safe, though only one
+/// of their writes will be kept. An error with one file will not stop the others from being processed.
+///
+/// The result of every file is returned, in the same order as `paths`.
+///
+/// # Panics
+///
+/// If `f` panics, the panic will be propagated once all threads have stopped.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use lofty::batch::retag_paths_with;
+/// use lofty::config::WriteOptions;
+/// use lofty::prelude::*;
+///
+/// let paths = ["a.mp3", "b.mp3"];
+///
+/// // Write ID3v2.3 tags, using at most 2 threads
+/// let results = retag_paths_with(paths, 2, WriteOptions::new().use_id3v23(true), |tag, _path| {
+/// 	tag.remove_comment();
+/// });
+///
+/// assert!(results.iter().all(|(_, result)| result.is_ok()));
+/// ```
+pub fn retag_paths_with<I, P, F>(
+	paths: I,
+	threads: usize,
+	write_options: WriteOptions,
+	f: F,
+) -> Vec<(PathBuf, Result<()>)>
+where
+	I: IntoIterator<Item = P>,
+	P: AsRef<Path>,
+	F: Fn(&mut Tag, &Path) + Sync,
+{
+	let paths = paths
+		.into_iter()
+		.map(|p| p.as_ref().to_path_buf())
+		.collect::<Vec<_>>();
+
+	let threads = threads.clamp(1, paths.len().max(1));
+	let next = AtomicUsize::new(0);
+
+	let mut results = Vec::with_capacity(paths.len());
+	std::thread::scope(|s| {
+		let workers = (0..threads)
+			.map(|_| {
+				s.spawn(|| {
+					let mut finished = Vec::new();
+					loop {
+						let idx = next.fetch_add(1, Ordering::Relaxed);
+						let Some(path) = paths.get(idx) else {
+							break;
+						};
+
+						finished.push((idx, retag_file(path, &f, write_options)));
+					}
+
+					finished
+				})
+			})
+			.collect::<Vec<_>>();
+
+		for worker in workers {
+			match worker.join() {
+				Ok(finished) => results.extend(finished),
+				Err(panic) => std::panic::resume_unwind(panic),
+			}
+		}
+	});
+
+	// Every path was processed exactly once, so sorting restores the original order
+	results.sort_unstable_by_key(|(idx, _)| *idx);
+	paths
+		.into_iter()
+		.zip(results)
+		.map(|(path, (_, result))| (path, result))
+		.collect()
+}
+
+pub(crate) fn retag_file<F>(path: &Path, f: &F, write_options: WriteOptions) -> Result<()>
+where
+	F: Fn(&mut Tag, &Path),
+{
+	let mut tagged_file = Probe::open(path)?
+		.options(ParseOptions::new().read_properties(false))
+		.read()?;
+
+	let primary_tag_type = tagged_file.primary_tag_type();
+	let mut tag = tagged_file
+		.remove(primary_tag_type)
+		.unwrap_or_else(|| Tag::new(primary_tag_type));
+
+	f(&mut tag, path);
+
+	let temp_path = crate::util::io::temp_path(path);
+
+	let write_temp = || -> Result<()> {
+		std::fs::copy(path, &temp_path)?;
+
+		let mut temp_file = OpenOptions::new().read(true).write(true).open(&temp_path)?;
+		tag.save_to(&mut temp_file, write_options)?;
+		temp_file.sync_all()?;
+
+		std::fs::rename(&temp_path, path)?;
+		Ok(())
+	};
+
+	let result = write_temp();
+	if result.is_err() {
+		let _ = std::fs::remove_file(&temp_path);
+	}
+
+	result
+}
+
+#[cfg(test)]
+mod tests {
+	use super::{retag_paths, retag_paths_with};
+	use crate::config::WriteOptions;
+	use crate::prelude::*;
+
+	const MP3: &str = "tests/files/assets/minimal/full_test.mp3";
+
+	#[test]
+	fn retag_in_parallel() {
+		let dir = tempfile::tempdir().unwrap();
+
+		let mut paths = Vec::new();
+		for i in 0..8 {
+			let path = dir.path().join(format!("{i}.mp3"));
+			std::fs::copy(MP3, &path).unwrap();
+			paths.push(path);
+		}
+
+		let broken = dir.path().join("broken.mp3");
+		std::fs::write(&broken, b"").unwrap();
+		paths.insert(3, broken.clone());
+
+		let results = retag_paths(&paths, |tag, path| {
+			tag.set_title(path.file_stem().unwrap().to_string_lossy().into_owned());
+		});
+
+		// Results are in the same order as the input
+		assert_eq!(results.len(), paths.len());
+		for ((path, result), expected_path) in results.iter().zip(&paths) {
+			assert_eq!(path, expected_path);
+			assert_eq!(result.is_err(), path == &broken);
+		}
+
+		for path in paths.iter().filter(|p| *p != &broken) {
+			let tagged_file = crate::read_from_path(path).unwrap();
+			assert_eq!(
+				tagged_file.primary_tag().unwrap().title().as_deref(),
+				path.file_stem().unwrap().to_str()
+			);
+		}
+
+		// No temporary files are left behind
+		assert_eq!(std::fs::read_dir(dir.path()).unwrap().count(), 9);
+	}
+
+	#[test]
+	fn single_thread() {
+		let dir = tempfile::tempdir().unwrap();
+		let path = dir.path().join("a.mp3");
+		std::fs::copy(MP3, &path).unwrap();
+
+		// More threads than paths, and no threads at all, are both fine
+		for threads in [0, 1, 4] {
+			let results = retag_paths_with([&path], threads, WriteOptions::default(), |tag, _| {
+				tag.set_album(format!("Album {threads}"));
+			});
+			assert!(results[0].1.is_ok());
+		}
+
+		let tagged_file = crate::read_from_path(&path).unwrap();
+		assert_eq!(
+			tagged_file.primary_tag().unwrap().album().as_deref(),
+			Some("Album 4")
+		);
+
+		assert!(retag_paths(Vec::<&str>::new(), |_, _| {}).is_empty());
+	}
+
+	#[test]
+	fn duplicate_paths() {
+		let dir = tempfile::tempdir().unwrap();
+		let path = dir.path().join("a.mp3");
+		std::fs::copy(MP3, &path).unwrap();
+
+		// Each write uses its own temporary file, so they can't clobber each other
+		let results = retag_paths_with([&path; 8], 8, WriteOptions::default(), |tag, _| {
+			tag.set_album(String::from("Foo album"));
+		});
+		assert!(results.iter().all(|(_, result)| result.is_ok()));
+
+		let tagged_file = crate::read_from_path(&path).unwrap();
+		assert_eq!(
+			tagged_file.primary_tag().unwrap().album().as_deref(),
+			Some("Foo album")
+		);
+
+		assert_eq!(std::fs::read_dir(dir.path()).unwrap().count(), 1);
+	}
+}
@@ -1,10 +1,18 @@
+use crate::error::Result;
+use crate::macros::err;
+
+use std::time::Duration;
+
 /// Options to control how Lofty parses a file
 #[derive(Copy, Clone, Debug, Eq, PartialEq)]
 #[non_exhaustive]
 pub struct ParseOptions {
 	pub(crate) read_properties: bool,
+	pub(crate) read_pictures: bool,
 	pub(crate) parsing_mode: ParsingMode,
 	pub(crate) max_junk_bytes: usize,
+	pub(crate) max_tag_size: u64,
+	pub(crate) properties_accuracy: PropertiesAccuracy,
 }
 
 impl Default for ParseOptions {
@@ -15,8 +23,11 @@ impl Default for ParseOptions {
 	/// ```rust,ignore
 	/// ParseOptions {
 	/// 	read_properties: true,
+	/// 	read_pictures: true,
 	/// 	parsing_mode: ParsingMode::BestAttempt,
-	///     max_junk_bytes: 1024
+	///     max_junk_bytes: 1024,
+	/// 	max_tag_size: 67_108_864, // 64 MiB
+	/// 	properties_accuracy: PropertiesAccuracy::Estimate,
 	/// }
 	/// ```
 	fn default() -> Self {
@@ -31,6 +42,9 @@ impl ParseOptions {
 	/// Default number of junk bytes to read
 	pub const DEFAULT_MAX_JUNK_BYTES: usize = 1024;
 
+	/// Default maximum tag size (64 MiB)
+	pub const DEFAULT_MAX_TAG_SIZE: u64 = 64 * 1024 * 1024;
+
 	/// Creates a new `ParseOptions`, alias for `Default` implementation
 	///
 	/// See also: [`ParseOptions::default`]
@@ -46,8 +60,11 @@ impl ParseOptions {
 	pub const fn new() -> Self {
 		Self {
 			read_properties: true,
+			read_pictures: true,
 			parsing_mode: Self::DEFAULT_PARSING_MODE,
 			max_junk_bytes: Self::DEFAULT_MAX_JUNK_BYTES,
+			max_tag_size: Self::DEFAULT_MAX_TAG_SIZE,
+			properties_accuracy: PropertiesAccuracy::Estimate,
 		}
 	}
 
@@ -66,6 +83,37 @@ impl ParseOptions {
 		*self
 	}
 
+	/// Whether or not to read embedded pictures
+	///
+	/// Pictures are often the largest part of a tag, so skipping them can make reading much faster
+	/// when only the text items are needed, such as when scanning a large library.
+	///
+	/// Skipped pictures keep their type, mimetype, and description, but have no data. The data can be
+	/// read later with [`Picture::load`]. Writing a tag with pictures that haven't been loaded will fail
+	/// with [`ErrorKind::PictureNotLoaded`], rather than removing them from the file.
+	///
+	/// NOTE: Pictures that can't be read separately from the rest of the tag are always read. This includes
+	///       the pictures of APE tags and Vorbis Comments, and those of ID3v2 tags that are unsynchronised,
+	///       compressed, or within chapters.
+	///
+	/// [`Picture::load`]: crate::picture::Picture::load
+	/// [`ErrorKind::PictureNotLoaded`]: crate::error::ErrorKind::PictureNotLoaded
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use lofty::config::ParseOptions;
+	///
+	/// // By default, `read_pictures` is enabled. Here, I only need the text items.
+	/// let parsing_options = ParseOptions::new()
+	/// 	.read_properties(false)
+	/// 	.read_pictures(false);
+	/// ```
+	pub fn read_pictures(&mut self, read_pictures: bool) -> Self {
+		self.read_pictures = read_pictures;
+		*self
+	}
+
 	/// The parsing mode to use, see [`ParsingMode`] for details
 	///
 	/// # Examples
@@ -98,6 +146,97 @@ impl ParseOptions {
 		self.max_junk_bytes = max_junk_bytes;
 		*self
 	}
+
+	/// The maximum size of a tag, in bytes
+	///
+	/// The size of a tag is read from the file, so a malformed or malicious file can claim a tag far
+	/// larger than any real one. Tags larger than this are rejected with [`ErrorKind::TooMuchData`] before
+	/// their contents are read, regardless of the [`ParsingMode`].
+	///
+	/// NOTE: Individual allocations are also bound by [`GlobalOptions::allocation_limit`].
+	///
+	/// [`ErrorKind::TooMuchData`]: crate::error::ErrorKind::TooMuchData
+	/// [`GlobalOptions::allocation_limit`]: crate::config::GlobalOptions::allocation_limit
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use lofty::config::ParseOptions;
+	///
+	/// // My files only ever have small text tags, anything bigger is suspicious
+	/// let parsing_options = ParseOptions::new().max_tag_size(1024 * 1024);
+	/// ```
+	pub fn max_tag_size(&mut self, max_tag_size: u64) -> Self {
+		self.max_tag_size = max_tag_size;
+		*self
+	}
+
+	/// How to calculate the duration of streams that don't store it, see [`PropertiesAccuracy`] for details
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use lofty::config::{ParseOptions, PropertiesAccuracy};
+	///
+	/// // I need exact durations for my MP3s, even if they lack a Xing header
+	/// let parsing_options = ParseOptions::new().properties_accuracy(PropertiesAccuracy::Accurate);
+	/// ```
+	pub fn properties_accuracy(&mut self, properties_accuracy: PropertiesAccuracy) -> Self {
+		self.properties_accuracy = properties_accuracy;
+		*self
+	}
+
+	/// Verify that a tag (or item) of `size` bytes is within [`ParseOptions::max_tag_size`]
+	pub(crate) fn check_tag_size(&self, size: u64) -> Result<()> {
+		if size > self.max_tag_size {
+			log::warn!(
+				"Tag size ({size}) exceeds the maximum tag size ({})",
+				self.max_tag_size
+			);
+			err!(TooMuchData);
+		}
+
+		Ok(())
+	}
+}
+
+/// How to calculate the duration of streams that don't store it
+///
+/// Some streams, such as MP3 files without a Xing/Info header, don't store their duration anywhere.
+/// By default, Lofty will estimate it using the bitrate of the first frame, which is fast, but inaccurate
+/// for VBR streams.
+///
+/// This is currently only used for MPEG files.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use lofty::config::{ParseOptions, PropertiesAccuracy};
+/// use lofty::probe::Probe;
+/// use std::time::Duration;
+///
+/// # fn main() -> lofty::error::Result<()> {
+/// // Scan the frames for at most 50ms, extrapolating the rest
+/// let parsing_options = ParseOptions::new()
+/// 	.properties_accuracy(PropertiesAccuracy::TimeLimited(Duration::from_millis(50)));
+/// let tagged_file = Probe::open("foo.mp3")?.options(parsing_options).read()?;
+/// # Ok(()) }
+/// ```
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Default)]
+#[non_exhaustive]
+pub enum PropertiesAccuracy {
+	/// Estimate the duration from the bitrate of the first frame
+	#[default]
+	Estimate,
+	/// Scan every frame in the stream
+	///
+	/// This will give the exact duration and average bitrate, but requires reading the entire file.
+	Accurate,
+	/// Scan frames until the time limit is reached
+	///
+	/// The duration and bitrate of the remainder of the stream are extrapolated from the frames that
+	/// were scanned. If the entire stream is scanned within the limit, this is the same as [`PropertiesAccuracy::Accurate`].
+	TimeLimited(Duration),
 }
 
 /// The parsing strictness mode
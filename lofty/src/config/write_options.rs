@@ -1,3 +1,5 @@
+use crate::util::text::TextEncoding;
+
 /// Options to control how Lofty writes to a file
 ///
 /// This acts as a dumping ground for all sorts of format-specific settings. As such, this is best
@@ -9,12 +11,29 @@ pub struct WriteOptions {
 	pub(crate) remove_others: bool,
 	pub(crate) respect_read_only: bool,
 	pub(crate) uppercase_id3v2_chunk: bool,
+	pub(crate) encoder_stamp: Option<&'static str>,
+	pub(crate) stamp_encoder: bool,
+	pub(crate) use_id3v23: bool,
+	pub(crate) sync_id3v1: bool,
+	pub(crate) id3v2_text_encoding: Option<TextEncoding>,
+	pub(crate) force_rewrite: bool,
+	pub(crate) verify_audio: bool,
 }
 
 impl WriteOptions {
 	/// Default preferred padding size in bytes
 	pub const DEFAULT_PREFERRED_PADDING: u32 = 1024;
 
+	/// The encoder identifier written with [`WriteOptions::stamp_encoder`]
+	///
+	/// This is `lofty` followed by the major and minor version, such as `lofty 0.19`.
+	pub const LOFTY_ENCODER_STAMP: &'static str = concat!(
+		"lofty ",
+		env!("CARGO_PKG_VERSION_MAJOR"),
+		".",
+		env!("CARGO_PKG_VERSION_MINOR")
+	);
+
 	/// Creates a new `WriteOptions`, alias for `Default` implementation
 	///
 	/// See also: [`WriteOptions::default`]
@@ -32,6 +51,13 @@ impl WriteOptions {
 			remove_others: false,
 			respect_read_only: true,
 			uppercase_id3v2_chunk: true,
+			encoder_stamp: None,
+			stamp_encoder: false,
+			use_id3v23: false,
+			sync_id3v1: false,
+			id3v2_text_encoding: None,
+			force_rewrite: false,
+			verify_audio: false,
 		}
 	}
 
@@ -44,6 +70,7 @@ impl WriteOptions {
 	///
 	/// * Not all tag formats support padding
 	/// * The actual padding size may be different from this value, depending on tag size limitations
+	/// * Existing padding may be reused instead, to avoid rewriting the entire file
 	///
 	/// # Examples
 	///
@@ -68,6 +95,9 @@ impl WriteOptions {
 	///
 	/// If set to `true`, only the tag being written will be kept in the file.
 	///
+	/// NOTE: This is currently only supported when writing a [`Tag`](crate::tag::Tag) to a WAV file,
+	/// where it can be used to choose between an ID3v2 chunk and a RIFF INFO list.
+	///
 	/// # Examples
 	///
 	/// ```rust,no_run
@@ -148,6 +178,274 @@ impl WriteOptions {
 		self.uppercase_id3v2_chunk = uppercase_id3v2_chunk;
 		self
 	}
+
+	/// Set an encoder identifier to stamp into tags when writing
+	///
+	/// If the tag being written does not already have an encoder set (see [`Accessor::encoder`]),
+	/// this identifier will be written in its place. Existing values will never be overwritten.
+	///
+	/// An empty string disables stamping, which is the default.
+	///
+	/// This applies to the following tags:
+	///
+	/// * APE (`Encoder`)
+	/// * ID3v2 (`TSSE`)
+	/// * MP4 ilst (`©too`)
+	/// * RIFF INFO (`ISFT`)
+	/// * Vorbis Comments (`ENCODER`)
+	///
+	/// See also: [`WriteOptions::stamp_encoder`]
+	///
+	/// [`Accessor::encoder`]: crate::tag::Accessor::encoder
+	///
+	/// # Examples
+	///
+	/// ```rust,no_run
+	/// use lofty::config::WriteOptions;
+	/// use lofty::iff::wav::RiffInfoList;
+	/// use lofty::prelude::*;
+	///
+	/// # fn main() -> lofty::error::Result<()> {
+	/// let mut riff_info = RiffInfoList::new();
+	///
+	/// // ...
+	///
+	/// // I want to know which files my application has touched
+	/// let options = WriteOptions::new().encoder_stamp("My Tagger 1.0.0");
+	/// riff_info.save_to_path("test.wav", options)?;
+	/// # Ok(()) }
+	/// ```
+	pub fn encoder_stamp(mut self, encoder_stamp: &'static str) -> Self {
+		match encoder_stamp {
+			"" => self.encoder_stamp = None,
+			_ => self.encoder_stamp = Some(encoder_stamp),
+		}
+		self
+	}
+
+	/// Whether to stamp tags with Lofty's own encoder identifier when writing
+	///
+	/// This is the same as [`WriteOptions::encoder_stamp`], using [`WriteOptions::LOFTY_ENCODER_STAMP`].
+	/// This can be useful when debugging, to find out which files were last written by Lofty.
+	///
+	/// NOTE: A custom stamp set with [`WriteOptions::encoder_stamp`] takes priority, and is unaffected
+	///       by this option.
+	///
+	/// # Examples
+	///
+	/// ```rust,no_run
+	/// use lofty::config::WriteOptions;
+	/// use lofty::prelude::*;
+	/// use lofty::tag::{Tag, TagType};
+	///
+	/// # fn main() -> lofty::error::Result<()> {
+	/// let mut tag = Tag::new(TagType::Id3v2);
+	/// tag.set_title(String::from("Foo title"));
+	///
+	/// // The tag will now have a `TSSE` frame with the value "lofty x.y"
+	/// let options = WriteOptions::new().stamp_encoder(true);
+	/// tag.save_to_path("test.mp3", options)?;
+	/// # Ok(()) }
+	/// ```
+	pub fn stamp_encoder(mut self, stamp_encoder: bool) -> Self {
+		self.stamp_encoder = stamp_encoder;
+		self
+	}
+
+	/// The encoder identifier to stamp into tags, if any
+	///
+	/// See [`WriteOptions::encoder_stamp`] and [`WriteOptions::stamp_encoder`]
+	pub(crate) fn encoder_stamp_value(&self) -> Option<&'static str> {
+		self.encoder_stamp
+			.or_else(|| self.stamp_encoder.then_some(Self::LOFTY_ENCODER_STAMP))
+	}
+
+	/// Whether to write ID3v2.3 tags instead of ID3v2.4
+	///
+	/// ID3v2.4 is the latest version of the format, but some software still only supports ID3v2.3.
+	///
+	/// When writing ID3v2.3, some information will be converted or lost:
+	///
+	/// * UTF-8 and UTF-16BE text will be written as UTF-16
+	/// * Multiple values in text frames will be separated by `/`
+	/// * `TDRC` will be split into `TYER`, `TDAT`, and `TIME`, and `TDOR` will become `TORY`
+	/// * `TIPL` and `TMCL` will be combined into `IPLS`
+	/// * Frames with no ID3v2.3 equivalent (such as `TDEN`, `TDRL`, `TDTG`, `TMOO`, `TPRO`, `TSST`, and `RVA2`) will be discarded
+	/// * The extended header and footer will not be written
+	///
+	/// NOTE: ID3v2.2 tags can be read, but not written.
+	///
+	/// # Examples
+	///
+	/// ```rust,no_run
+	/// use lofty::config::WriteOptions;
+	/// use lofty::prelude::*;
+	/// use lofty::tag::{Tag, TagType};
+	///
+	/// # fn main() -> lofty::error::Result<()> {
+	/// let mut id3v2_tag = Tag::new(TagType::Id3v2);
+	///
+	/// // ...
+	///
+	/// // My old car stereo can only read ID3v2.3
+	/// let options = WriteOptions::new().use_id3v23(true);
+	/// id3v2_tag.save_to_path("test.mp3", options)?;
+	/// # Ok(()) }
+	/// ```
+	pub fn use_id3v23(mut self, use_id3v23: bool) -> Self {
+		self.use_id3v23 = use_id3v23;
+		self
+	}
+
+	/// Whether to keep an ID3v1 tag in sync with the ID3v2 tag in MPEG files
+	///
+	/// If set to `true`, writing an ID3v2 tag to an MPEG file will also write an ID3v1 tag
+	/// created from it. Any fields that are too long for ID3v1 will be truncated.
+	///
+	/// NOTE: When saving an entire [`MpegFile`](crate::mpeg::MpegFile) with an ID3v2 tag, its ID3v1 tag
+	///       is ignored in favor of the synced one.
+	///
+	/// # Examples
+	///
+	/// ```rust,no_run
+	/// use lofty::config::WriteOptions;
+	/// use lofty::prelude::*;
+	/// use lofty::tag::{Tag, TagType};
+	///
+	/// # fn main() -> lofty::error::Result<()> {
+	/// let mut id3v2_tag = Tag::new(TagType::Id3v2);
+	///
+	/// // ...
+	///
+	/// // Some older players can only read ID3v1, so keep one around
+	/// let options = WriteOptions::new().sync_id3v1(true);
+	/// id3v2_tag.save_to_path("test.mp3", options)?;
+	/// # Ok(()) }
+	/// ```
+	pub fn sync_id3v1(mut self, sync_id3v1: bool) -> Self {
+		self.sync_id3v1 = sync_id3v1;
+		self
+	}
+
+	/// The text encoding to use for all ID3v2 frames
+	///
+	/// By default, every frame is written with its own [`TextEncoding`], which will be the encoding it was
+	/// read with, or UTF-8 for new frames. See [`Frame::encoding`](crate::id3::v2::Frame::encoding).
+	///
+	/// Setting this will write every frame with `encoding` instead, with the following exceptions:
+	///
+	/// * Frames with text that can't be represented in [`TextEncoding::Latin1`] will use [`TextEncoding::UTF16`]
+	/// * ID3v2.3 does not support [`TextEncoding::UTF8`] or [`TextEncoding::UTF16BE`], so when writing
+	///   ID3v2.3 tags (see [`WriteOptions::use_id3v23`]), these will be written as [`TextEncoding::UTF16`]
+	///
+	/// # Examples
+	///
+	/// ```rust,no_run
+	/// use lofty::config::WriteOptions;
+	/// use lofty::prelude::*;
+	/// use lofty::tag::{Tag, TagType};
+	/// use lofty::TextEncoding;
+	///
+	/// # fn main() -> lofty::error::Result<()> {
+	/// let mut id3v2_tag = Tag::new(TagType::Id3v2);
+	///
+	/// // ...
+	///
+	/// // I want my tags to be as small as possible!
+	/// let options = WriteOptions::new().id3v2_text_encoding(TextEncoding::Latin1);
+	/// id3v2_tag.save_to_path("test.mp3", options)?;
+	/// # Ok(()) }
+	/// ```
+	pub fn id3v2_text_encoding(mut self, encoding: TextEncoding) -> Self {
+		self.id3v2_text_encoding = Some(encoding);
+		self
+	}
+
+	/// Whether to always rewrite the entire file
+	///
+	/// By default, if the new tag fits within the space of the existing tag and its padding, it
+	/// will be written in place. Otherwise, the file will be rewritten with [`WriteOptions::preferred_padding`]
+	/// bytes of padding, to make future in place writes possible.
+	///
+	/// This currently applies to:
+	///
+	/// * ID3v2 tags at the start of a file (such as in MP3)
+	/// * MP4 `ilst` tags
+	/// * FLAC Vorbis Comments and pictures
+	///
+	/// Setting this to `true` will discard any existing padding, and replace it with the preferred padding.
+	///
+	/// # Examples
+	///
+	/// ```rust,no_run
+	/// use lofty::config::WriteOptions;
+	/// use lofty::prelude::*;
+	/// use lofty::tag::{Tag, TagType};
+	///
+	/// # fn main() -> lofty::error::Result<()> {
+	/// let mut id3v2_tag = Tag::new(TagType::Id3v2);
+	///
+	/// // ...
+	///
+	/// // My files have megabytes of padding, I want to get rid of it
+	/// let options = WriteOptions::new().preferred_padding(0).force_rewrite(true);
+	/// id3v2_tag.save_to_path("test.mp3", options)?;
+	/// # Ok(()) }
+	/// ```
+	pub fn force_rewrite(mut self, force_rewrite: bool) -> Self {
+		self.force_rewrite = force_rewrite;
+		self
+	}
+
+	/// Whether to verify that the audio stream is untouched after writing
+	///
+	/// If set to `true`, the audio stream is checksummed before and after writing, and
+	/// [`ErrorKind::AudioDataModified`] is returned if the two differ. For FLAC files, this includes
+	/// the MD5 signature in the `STREAMINFO` block.
+	///
+	/// The audio stream is:
+	///
+	/// * FLAC: The frames following the metadata blocks
+	/// * MP4: The contents of all `mdat` atoms
+	/// * OGG: The contents of every page, other than those holding a stream's header packets
+	/// * WAV/AIFF/DSF: The contents of the `data`/`SSND` chunk
+	/// * All other formats: Everything between the leading and trailing tags, see [`file_layout`]
+	///
+	/// If verification fails, or an error occurs while writing, the original file is restored before
+	/// the error is returned.
+	///
+	/// NOTE: The entire file is buffered in memory while writing, so that it can be restored. The audio
+	///       stream is also read twice.
+	///
+	/// [`ErrorKind::AudioDataModified`]: crate::error::ErrorKind::AudioDataModified
+	/// [`file_layout`]: crate::file::file_layout
+	///
+	/// # Examples
+	///
+	/// ```rust,no_run
+	/// use lofty::config::WriteOptions;
+	/// use lofty::error::ErrorKind;
+	/// use lofty::prelude::*;
+	/// use lofty::tag::{Tag, TagType};
+	///
+	/// # fn main() -> lofty::error::Result<()> {
+	/// let mut id3v2_tag = Tag::new(TagType::Id3v2);
+	///
+	/// // ...
+	///
+	/// // These files are irreplaceable, I need to know if anything goes wrong
+	/// let options = WriteOptions::new().verify_audio(true);
+	/// if let Err(e) = id3v2_tag.save_to_path("test.mp3", options) {
+	/// 	if let ErrorKind::AudioDataModified = e.kind() {
+	/// 		eprintln!("The audio of test.mp3 would have been modified, the file is unchanged");
+	/// 	}
+	/// }
+	/// # Ok(()) }
+	/// ```
+	pub fn verify_audio(mut self, verify_audio: bool) -> Self {
+		self.verify_audio = verify_audio;
+		self
+	}
 }
 
 impl Default for WriteOptions {
@@ -161,6 +459,13 @@ impl Default for WriteOptions {
 	///     remove_others: false,
 	///     respect_read_only: true,
 	///     uppercase_id3v2_chunk: true,
+	///     encoder_stamp: None,
+	///     stamp_encoder: false,
+	///     use_id3v23: false,
+	///     sync_id3v1: false,
+	///     id3v2_text_encoding: None,
+	///     force_rewrite: false,
+	///     verify_audio: false,
 	/// }
 	/// ```
 	fn default() -> Self {
@@ -0,0 +1,53 @@
+use super::read::{audio_payload_region, read_dsd_chunk};
+use crate::error::{LoftyError, Result};
+use crate::util::io::{FileLike, Length, Truncate};
+
+use std::io::SeekFrom;
+
+use byteorder::{LittleEndian, WriteBytesExt};
+
+// The offset of the file size in the `DSD ` chunk, followed by the metadata pointer
+const FILE_SIZE_OFFSET: u64 = 12;
+
+/// Replace the ID3v2 tag at the end of a DSF file, updating the metadata pointer
+///
+/// An empty `tag` removes the existing tag.
+pub(crate) fn write_id3v2<F>(file: &mut F, tag: &[u8]) -> Result<()>
+where
+	F: FileLike,
+	LoftyError: From<<F as Truncate>::Error>,
+	LoftyError: From<<F as Length>::Error>,
+{
+	let (audio_offset, audio_size) = audio_payload_region(file)?;
+	let audio_end = audio_offset + audio_size;
+
+	file.rewind()?;
+	let dsd_chunk = read_dsd_chunk(file)?;
+	let file_len = file.len()?;
+
+	// Anything from the existing tag onwards gets replaced
+	let mut tag_offset = dsd_chunk.metadata_offset;
+	if tag_offset == 0 || tag_offset > file_len {
+		tag_offset = file_len;
+	} else if tag_offset < audio_end {
+		// Truncating here would remove audio, so the pointer can't be trusted
+		log::warn!(
+			"DSF metadata pointer ({tag_offset}) points before the end of the audio ({audio_end}), \
+			 appending the tag"
+		);
+		tag_offset = file_len;
+	}
+
+	file.truncate(tag_offset)?;
+	file.seek(SeekFrom::Start(tag_offset))?;
+	file.write_all(tag)?;
+
+	let new_file_len = tag_offset + tag.len() as u64;
+	let metadata_offset = if tag.is_empty() { 0 } else { tag_offset };
+
+	file.seek(SeekFrom::Start(FILE_SIZE_OFFSET))?;
+	file.write_u64::<LittleEndian>(new_file_len)?;
+	file.write_u64::<LittleEndian>(metadata_offset)?;
+
+	Ok(())
+}
@@ -0,0 +1,31 @@
+//! DSF (DSD Stream File) specific items
+//!
+//! ## File notes
+//!
+//! DSF files store a single ID3v2 tag at the end of the file, located by a pointer in the `DSD ` chunk.
+
+pub(crate) mod id3v2;
+mod properties;
+mod read;
+
+use crate::id3::v2::tag::Id3v2Tag;
+
+use lofty_attr::LoftyFile;
+
+// Exports
+
+pub use properties::DsfProperties;
+
+pub(crate) use read::audio_payload_region;
+
+/// A DSF file
+#[derive(LoftyFile, Default)]
+#[lofty(read_fn = "read::read_from")]
+#[lofty(internal_write_module_do_not_use_anywhere_else)]
+pub struct DsfFile {
+	/// An ID3v2 tag
+	#[lofty(tag_type = "Id3v2")]
+	pub(crate) id3v2_tag: Option<Id3v2Tag>,
+	/// The file's audio properties
+	pub(crate) properties: DsfProperties,
+}
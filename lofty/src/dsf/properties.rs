@@ -0,0 +1,145 @@
+use crate::error::Result;
+use crate::macros::decode_err;
+use crate::properties::{ChannelMask, FileProperties};
+
+use std::io::Read;
+use std::time::Duration;
+
+use byteorder::{LittleEndian, ReadBytesExt};
+
+/// A DSF file's audio properties
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[non_exhaustive]
+pub struct DsfProperties {
+	pub(crate) duration: Duration,
+	pub(crate) overall_bitrate: u32,
+	pub(crate) audio_bitrate: u32,
+	pub(crate) sample_rate: u32,
+	pub(crate) channels: u8,
+	pub(crate) channel_mask: Option<ChannelMask>,
+	pub(crate) sample_count: u64,
+}
+
+impl From<DsfProperties> for FileProperties {
+	fn from(input: DsfProperties) -> Self {
+		Self {
+			duration: input.duration,
+			overall_bitrate: Some(input.overall_bitrate),
+			audio_bitrate: Some(input.audio_bitrate),
+			sample_rate: Some(input.sample_rate),
+			bit_depth: Some(1),
+			channels: Some(input.channels),
+			channel_mask: input.channel_mask,
+		}
+	}
+}
+
+impl DsfProperties {
+	/// Duration of the audio
+	pub fn duration(&self) -> Duration {
+		self.duration
+	}
+
+	/// Overall bitrate (kbps)
+	pub fn overall_bitrate(&self) -> u32 {
+		self.overall_bitrate
+	}
+
+	/// Audio bitrate (kbps)
+	pub fn audio_bitrate(&self) -> u32 {
+		self.audio_bitrate
+	}
+
+	/// Sample rate (Hz)
+	///
+	/// This will be a multiple of 44100, such as 2822400 for DSD64.
+	pub fn sample_rate(&self) -> u32 {
+		self.sample_rate
+	}
+
+	/// Channel count
+	pub fn channels(&self) -> u8 {
+		self.channels
+	}
+
+	/// Channel mask
+	pub fn channel_mask(&self) -> Option<ChannelMask> {
+		self.channel_mask
+	}
+
+	/// Bits per sample
+	///
+	/// DSD audio is always 1-bit.
+	pub fn bit_depth(&self) -> u8 {
+		1
+	}
+
+	/// Number of samples per channel
+	pub fn sample_count(&self) -> u64 {
+		self.sample_count
+	}
+}
+
+// https://dsd-guide.com/sites/default/files/white-papers/DSFFileFormatSpec_E.pdf
+pub(super) fn read_properties<R>(fmt: &mut R, file_length: u64) -> Result<DsfProperties>
+where
+	R: Read,
+{
+	let format_version = fmt.read_u32::<LittleEndian>()?;
+	let format_id = fmt.read_u32::<LittleEndian>()?;
+
+	if format_version != 1 || format_id != 0 {
+		decode_err!(@BAIL Dsf, "Unsupported DSF format, expected version 1 DSD raw");
+	}
+
+	let channel_type = fmt.read_u32::<LittleEndian>()?;
+	let channels = fmt.read_u32::<LittleEndian>()?;
+	let sample_rate = fmt.read_u32::<LittleEndian>()?;
+	let _bits_per_sample = fmt.read_u32::<LittleEndian>()?;
+	let sample_count = fmt.read_u64::<LittleEndian>()?;
+
+	let Ok(channels) = u8::try_from(channels) else {
+		decode_err!(@BAIL Dsf, "File has an invalid channel count");
+	};
+
+	if channels == 0 || sample_rate == 0 {
+		decode_err!(@BAIL Dsf, "File has no channels or a sample rate of 0");
+	}
+
+	let mut properties = DsfProperties {
+		sample_rate,
+		channels,
+		channel_mask: channel_mask(channel_type),
+		sample_count,
+		audio_bitrate: (u64::from(sample_rate) * u64::from(channels) / 1000) as u32,
+		..DsfProperties::default()
+	};
+
+	let length = sample_count * 1000 / u64::from(sample_rate);
+	if length > 0 {
+		properties.duration = Duration::from_millis(length);
+		properties.overall_bitrate = ((file_length * 8) / length) as u32;
+	}
+
+	Ok(properties)
+}
+
+fn channel_mask(channel_type: u32) -> Option<ChannelMask> {
+	let mask = match channel_type {
+		1 => ChannelMask::mono(),
+		2 => ChannelMask::stereo(),
+		3 => ChannelMask::linear_surround(),
+		4 => ChannelMask::stereo() | ChannelMask::BACK_LEFT | ChannelMask::BACK_RIGHT,
+		5 => ChannelMask::linear_surround() | ChannelMask::LOW_FREQUENCY,
+		6 => ChannelMask::linear_surround() | ChannelMask::BACK_LEFT | ChannelMask::BACK_RIGHT,
+		7 => {
+			ChannelMask::linear_surround()
+				| ChannelMask::LOW_FREQUENCY
+				| ChannelMask::BACK_LEFT
+				| ChannelMask::BACK_RIGHT
+		},
+		_ => return None,
+	};
+
+	Some(mask)
+}
@@ -0,0 +1,141 @@
+use super::properties::DsfProperties;
+use super::DsfFile;
+use crate::config::{ParseOptions, ParsingMode};
+use crate::error::Result;
+use crate::id3::v2::header::Id3v2Header;
+use crate::id3::v2::read::parse_id3v2;
+use crate::macros::decode_err;
+
+use std::io::{Read, Seek, SeekFrom};
+
+use byteorder::{LittleEndian, ReadBytesExt};
+
+/// The contents of the `DSD ` chunk
+pub(crate) struct DsdChunk {
+	/// The offset of the ID3v2 tag, or 0 if there is none
+	pub(crate) metadata_offset: u64,
+}
+
+const DSD_CHUNK_SIZE: u64 = 28;
+
+pub(crate) fn read_dsd_chunk<R>(reader: &mut R) -> Result<DsdChunk>
+where
+	R: Read,
+{
+	let mut id = [0; 4];
+	reader.read_exact(&mut id)?;
+
+	if &id != b"DSD " {
+		decode_err!(@BAIL Dsf, "File doesn't start with a \"DSD \" chunk");
+	}
+
+	let chunk_size = reader.read_u64::<LittleEndian>()?;
+	if chunk_size != DSD_CHUNK_SIZE {
+		decode_err!(@BAIL Dsf, "\"DSD \" chunk has an invalid size");
+	}
+
+	let _file_size = reader.read_u64::<LittleEndian>()?;
+	let metadata_offset = reader.read_u64::<LittleEndian>()?;
+
+	Ok(DsdChunk { metadata_offset })
+}
+
+/// Get the offset and size of the contents of the `data` chunk
+///
+/// If the chunk claims to be larger than the remainder of the file, the remainder is used instead.
+pub(crate) fn audio_payload_region<R>(reader: &mut R) -> Result<(u64, u64)>
+where
+	R: Read + Seek,
+{
+	let file_length = reader.seek(SeekFrom::End(0))?;
+	reader.rewind()?;
+
+	read_dsd_chunk(reader)?;
+
+	let mut id = [0; 4];
+	reader.read_exact(&mut id)?;
+
+	if &id != b"fmt " {
+		decode_err!(@BAIL Dsf, "File missing \"fmt \" chunk");
+	}
+
+	let fmt_size = reader.read_u64::<LittleEndian>()?;
+	let Some(fmt_content_size) = fmt_size.checked_sub(12) else {
+		decode_err!(@BAIL Dsf, "\"fmt \" chunk has an invalid size");
+	};
+
+	reader.seek(SeekFrom::Current(fmt_content_size as i64))?;
+	reader.read_exact(&mut id)?;
+
+	if &id != b"data" {
+		decode_err!(@BAIL Dsf, "File missing \"data\" chunk");
+	}
+
+	let data_size = reader.read_u64::<LittleEndian>()?;
+	let Some(data_content_size) = data_size.checked_sub(12) else {
+		decode_err!(@BAIL Dsf, "\"data\" chunk has an invalid size");
+	};
+
+	let start = reader.stream_position()?;
+	let available = file_length.saturating_sub(start);
+	Ok((start, data_content_size.min(available)))
+}
+
+pub(super) fn read_from<R>(reader: &mut R, parse_options: ParseOptions) -> Result<DsfFile>
+where
+	R: Read + Seek,
+{
+	let start = reader.stream_position()?;
+	let file_length = reader.seek(SeekFrom::End(0))? - start;
+	reader.seek(SeekFrom::Start(start))?;
+
+	let dsd_chunk = read_dsd_chunk(reader)?;
+
+	let mut id = [0; 4];
+	reader.read_exact(&mut id)?;
+
+	if &id != b"fmt " {
+		decode_err!(@BAIL Dsf, "File missing \"fmt \" chunk");
+	}
+
+	let fmt_size = reader.read_u64::<LittleEndian>()?;
+	let Some(fmt_content_size) = fmt_size.checked_sub(12) else {
+		decode_err!(@BAIL Dsf, "\"fmt \" chunk has an invalid size");
+	};
+
+	let mut fmt = reader.by_ref().take(fmt_content_size);
+	let properties = if parse_options.read_properties {
+		super::properties::read_properties(&mut fmt, file_length)?
+	} else {
+		DsfProperties::default()
+	};
+
+	let mut id3v2_tag = None;
+
+	// The tag is supposed to be at the end of the file, but we can't trust that
+	let metadata_offset = dsd_chunk.metadata_offset;
+	if metadata_offset != 0 && metadata_offset < file_length {
+		reader.seek(SeekFrom::Start(start + metadata_offset))?;
+
+		match Id3v2Header::parse(reader) {
+			Ok(header) => {
+				let frames_offset = reader.stream_position()?;
+				id3v2_tag = Some(parse_id3v2(
+					reader,
+					header,
+					parse_options,
+					Some(frames_offset),
+				)?);
+			},
+			Err(e) if parse_options.parsing_mode == ParsingMode::Strict => return Err(e),
+			Err(e) => {
+				log::warn!("DSF metadata pointer doesn't point to an ID3v2 tag, skipping: {e}")
+			},
+		}
+	}
+
+	Ok(DsfFile {
+		id3v2_tag,
+		properties,
+	})
+}
@@ -31,16 +31,27 @@ pub enum ErrorKind {
 	/// This occurs when the size of an item is written as one value, but that size is either too
 	/// big or small to be valid within the bounds of that item.
 	SizeMismatch,
+	/// Arises when an IFF (WAV/AIFF) chunk's size is too small or extends beyond the file
+	BadChunkSize {
+		/// The chunk's FourCC, lossily converted to a `String`
+		chunk: String,
+		/// The offset of the chunk's header
+		offset: u64,
+	},
 	/// Errors that occur while decoding a file
 	FileDecoding(FileDecodingError),
 	/// Errors that occur while encoding a file
 	FileEncoding(FileEncodingError),
+	/// Arises when the audio stream of a file changed while writing, see [`WriteOptions::verify_audio`](crate::config::WriteOptions::verify_audio)
+	AudioDataModified,
 
 	// Picture related errors
 	/// Provided an invalid picture
 	NotAPicture,
 	/// Attempted to write a picture that the format does not support
 	UnsupportedPicture,
+	/// Attempted to write a picture that was skipped while reading, see [`Picture::load`](crate::picture::Picture::load)
+	PictureNotLoaded,
 
 	// Tag related errors
 	/// Arises when writing a tag to a file type that doesn't support it
@@ -51,6 +62,8 @@ pub enum ErrorKind {
 	TextDecode(&'static str),
 	/// Arises when decoding OR encoding a problematic [`Timestamp`](crate::tag::items::Timestamp)
 	BadTimestamp(&'static str),
+	/// Arises when an item can't be stored in a tag, see [`TagBuilder::build`](crate::tag::TagBuilder::build)
+	BadItem(ItemKey, &'static str),
 	/// Errors that arise while reading/writing ID3v2 tags
 	Id3v2(Id3v2Error),
 
@@ -62,6 +75,9 @@ pub enum ErrorKind {
 	// Conversions for external errors
 	/// Errors that arise while parsing OGG pages
 	OggPage(ogg_pager::PageError),
+	/// Errors that arise while converting pictures, see [`Picture::convert_to`](crate::picture::Picture::convert_to)
+	#[cfg(feature = "image")]
+	Image(ImageError),
 	/// Unable to convert bytes to a String
 	StringFromUtf8(std::string::FromUtf8Error),
 	/// Unable to convert bytes to a str
@@ -225,6 +241,17 @@ impl Id3v2Error {
 	}
 }
 
+impl std::error::Error for Id3v2Error {
+	fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+		#[cfg(feature = "id3v2_compression_support")]
+		if let Id3v2ErrorKind::Decompression(ref err) = self.kind {
+			return Some(err);
+		}
+
+		None
+	}
+}
+
 impl Debug for Id3v2Error {
 	fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
 		write!(f, "ID3v2: {:?}", self.kind)
@@ -272,6 +299,8 @@ impl FileDecodingError {
 	}
 }
 
+impl std::error::Error for FileDecodingError {}
+
 impl Debug for FileDecodingError {
 	fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
 		if let Some(format) = self.format {
@@ -376,6 +405,8 @@ impl FileEncodingError {
 	}
 }
 
+impl std::error::Error for FileEncodingError {}
+
 impl Debug for FileEncodingError {
 	fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
 		if let Some(format) = self.format {
@@ -396,6 +427,40 @@ impl Display for FileEncodingError {
 	}
 }
 
+/// An error that arises while converting a picture
+///
+/// The underlying error is available through [`std::error::Error::source`].
+#[cfg(feature = "image")]
+pub struct ImageError(image::ImageError);
+
+#[cfg(feature = "image")]
+impl ImageError {
+	pub(crate) fn new(err: image::ImageError) -> Self {
+		Self(err)
+	}
+}
+
+#[cfg(feature = "image")]
+impl std::error::Error for ImageError {
+	fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+		Some(&self.0)
+	}
+}
+
+#[cfg(feature = "image")]
+impl Debug for ImageError {
+	fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+		write!(f, "Image: {:?}", self.0)
+	}
+}
+
+#[cfg(feature = "image")]
+impl Display for ImageError {
+	fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+		write!(f, "Image: {}", self.0)
+	}
+}
+
 /// Errors that could occur within Lofty
 pub struct LoftyError {
 	pub(crate) kind: ErrorKind,
@@ -433,7 +498,24 @@ impl LoftyError {
 	}
 }
 
-impl std::error::Error for LoftyError {}
+impl std::error::Error for LoftyError {
+	fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+		match self.kind {
+			ErrorKind::FileDecoding(ref err) => Some(err),
+			ErrorKind::FileEncoding(ref err) => Some(err),
+			ErrorKind::Id3v2(ref err) => Some(err),
+			ErrorKind::OggPage(ref err) => Some(err),
+			#[cfg(feature = "image")]
+			ErrorKind::Image(ref err) => Some(err),
+			ErrorKind::StringFromUtf8(ref err) => Some(err),
+			ErrorKind::StrFromUtf8(ref err) => Some(err),
+			ErrorKind::Io(ref err) => Some(err),
+			ErrorKind::Fmt(ref err) => Some(err),
+			ErrorKind::Alloc(ref err) => Some(err),
+			_ => None,
+		}
+	}
+}
 
 impl Debug for LoftyError {
 	fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
@@ -473,6 +555,15 @@ impl From<ogg_pager::PageError> for LoftyError {
 	}
 }
 
+#[cfg(feature = "image")]
+impl From<ImageError> for LoftyError {
+	fn from(input: ImageError) -> Self {
+		Self {
+			kind: ErrorKind::Image(input),
+		}
+	}
+}
+
 impl From<std::io::Error> for LoftyError {
 	fn from(input: std::io::Error) -> Self {
 		Self {
@@ -526,6 +617,8 @@ impl Display for LoftyError {
 		match self.kind {
 			// Conversions
 			ErrorKind::OggPage(ref err) => write!(f, "{err}"),
+			#[cfg(feature = "image")]
+			ErrorKind::Image(ref err) => write!(f, "{err}"),
 			ErrorKind::StringFromUtf8(ref err) => write!(f, "{err}"),
 			ErrorKind::StrFromUtf8(ref err) => write!(f, "{err}"),
 			ErrorKind::Io(ref err) => write!(f, "{err}"),
@@ -539,6 +632,10 @@ impl Display for LoftyError {
 			ErrorKind::UnsupportedPicture => {
 				write!(f, "Picture: attempted to write an unsupported picture")
 			},
+			ErrorKind::PictureNotLoaded => write!(
+				f,
+				"Picture: attempted to write a picture that was not loaded"
+			),
 			ErrorKind::UnsupportedTag => write!(
 				f,
 				"Attempted to write a tag to a format that does not support it"
@@ -548,6 +645,7 @@ impl Display for LoftyError {
 			ErrorKind::BadTimestamp(message) => {
 				write!(f, "Encountered an invalid timestamp: {message}")
 			},
+			ErrorKind::BadItem(ref key, message) => write!(f, "Item `{key:?}`: {message}"),
 			ErrorKind::Id3v2(ref id3v2_err) => write!(f, "{id3v2_err}"),
 			ErrorKind::BadAtom(message) => write!(f, "MP4 Atom: {message}"),
 			ErrorKind::AtomMismatch => write!(
@@ -564,8 +662,14 @@ impl Display for LoftyError {
 				f,
 				"Encountered an invalid item size, either too big or too small to be valid"
 			),
+			ErrorKind::BadChunkSize { ref chunk, offset } => {
+				write!(f, "Chunk `{chunk}` at offset {offset} has an invalid size")
+			},
 			ErrorKind::FileDecoding(ref file_decode_err) => write!(f, "{file_decode_err}"),
 			ErrorKind::FileEncoding(ref file_encode_err) => write!(f, "{file_encode_err}"),
+			ErrorKind::AudioDataModified => {
+				write!(f, "The audio stream was modified while writing")
+			},
 
 			ErrorKind::Infallible(_) => write!(f, "A expected condition was not upheld"),
 		}
@@ -0,0 +1,254 @@
+use super::{file_layout, FileType};
+use crate::config::{ParsingMode, WriteOptions};
+use crate::error::{LoftyError, Result};
+use crate::macros::err;
+use crate::probe::Probe;
+use crate::util::io::FileLike;
+
+use std::collections::hash_map::DefaultHasher;
+use std::hash::Hasher;
+use std::io::{Read, Seek, SeekFrom, Write};
+
+const BLOCK_SIZE: usize = 8192;
+
+/// Run `write`, verifying that the audio stream is untouched if [`WriteOptions::verify_audio`] is set
+///
+/// If the audio stream was modified, or `write` fails, the original contents of `file` are restored.
+///
+/// `write` receives the options with verification disabled, so nested writes (such as a `Tag` being
+/// written as an `Id3v2Tag`) don't checksum the file again.
+pub(crate) fn write_verified<F, W>(
+	file: &mut F,
+	write_options: WriteOptions,
+	write: W,
+) -> Result<()>
+where
+	F: FileLike,
+	W: FnOnce(&mut F, WriteOptions) -> Result<()>,
+{
+	if !write_options.verify_audio {
+		return write(file, write_options);
+	}
+
+	let probe = Probe::new(file).guess_file_type()?;
+	let Some(file_type) = probe.file_type() else {
+		err!(UnknownFormat);
+	};
+
+	let file = probe.into_inner();
+
+	let start = file.stream_position()?;
+	let checksum = audio_checksum(file, file_type)?;
+
+	// Keep a copy of the original file, so it can be restored if anything goes wrong
+	file.rewind()?;
+	let mut original = Vec::new();
+	file.read_to_end(&mut original)?;
+	file.seek(SeekFrom::Start(start))?;
+
+	let result = write(file, write_options.verify_audio(false)).and_then(|()| {
+		if audio_checksum(file, file_type)? != checksum {
+			err!(AudioDataModified);
+		}
+
+		Ok(())
+	});
+
+	// A failed write may have left the file partially written
+	if result.is_err() {
+		file.rewind()?;
+		file.write_all(&original)?;
+		file.truncate(original.len() as u64)
+			.map_err(Into::<LoftyError>::into)?;
+	}
+
+	file.seek(SeekFrom::Start(start))?;
+	result
+}
+
+fn audio_checksum<R>(reader: &mut R, file_type: FileType) -> Result<u64>
+where
+	R: Read + Seek,
+{
+	reader.rewind()?;
+
+	let regions = match file_type {
+		FileType::Aiff => crate::iff::aiff::audio_payload_region(reader)?
+			.into_iter()
+			.collect(),
+		FileType::Dsf => vec![crate::dsf::audio_payload_region(reader)?],
+		FileType::Flac => crate::flac::audio_payload_regions(reader)?,
+		FileType::Mp4 => crate::mp4::audio_payload_regions(reader, ParsingMode::default())?,
+		FileType::Opus | FileType::Speex | FileType::Vorbis => {
+			crate::ogg::audio_payload_regions(reader)?
+		},
+		FileType::Aac
+		| FileType::Ape
+		| FileType::Mpc
+		| FileType::Mpeg
+		| FileType::OptimFrog
+		| FileType::Wav
+		| FileType::WavPack => file_layout(reader, file_type)?
+			.audio()
+			.map(|block| (block.offset, block.size))
+			.into_iter()
+			.collect(),
+		_ => err!(UnknownFormat),
+	};
+
+	let file_len = reader.seek(SeekFrom::End(0))?;
+
+	let mut hasher = DefaultHasher::new();
+	let mut block = [0; BLOCK_SIZE];
+	for (offset, len) in regions {
+		reader.seek(SeekFrom::Start(offset))?;
+
+		let mut remaining = len.min(file_len.saturating_sub(offset));
+
+		// Always hash in the same sized blocks, so the checksum doesn't depend on how the reads are split
+		while remaining > 0 {
+			let block_len = remaining.min(BLOCK_SIZE as u64) as usize;
+			reader.read_exact(&mut block[..block_len])?;
+			hasher.write(&block[..block_len]);
+
+			remaining -= block_len as u64;
+		}
+
+		// Separate the regions, so bytes moving between them are detected
+		hasher.write_u64(len);
+	}
+
+	Ok(hasher.finish())
+}
+
+#[cfg(test)]
+mod tests {
+	use super::write_verified;
+	use crate::config::WriteOptions;
+	use crate::error::{ErrorKind, LoftyError};
+	use crate::prelude::*;
+	use crate::tag::{Tag, TagType};
+
+	use std::io::{Cursor, Seek, SeekFrom, Write};
+
+	fn verify(path: &str, offset: SeekFrom) {
+		let mut file = Cursor::new(std::fs::read(path).unwrap());
+		let options = WriteOptions::new().verify_audio(true);
+
+		// Writing outside of the audio stream is fine
+		write_verified(&mut file, options, |_, _| Ok(())).unwrap();
+
+		let original = file.get_ref().clone();
+		let err = write_verified(&mut file, options, |file, write_options| {
+			assert!(!write_options.verify_audio);
+
+			file.seek(offset)?;
+			file.write_all(&[0xAA, 0xBB])?;
+
+			// Changing the length must be undone as well
+			file.seek(SeekFrom::End(0))?;
+			file.write_all(&[0; 16])?;
+			Ok(())
+		})
+		.unwrap_err();
+		assert!(matches!(err.kind(), ErrorKind::AudioDataModified));
+
+		// The original file is restored
+		assert_eq!(file.get_ref(), &original);
+	}
+
+	#[test]
+	fn modified_mpeg() {
+		verify(
+			"tests/files/assets/minimal/full_test.mp3",
+			SeekFrom::End(-500),
+		);
+	}
+
+	#[test]
+	fn modified_flac() {
+		verify(
+			"tests/files/assets/minimal/full_test.flac",
+			SeekFrom::End(-10),
+		);
+	}
+
+	#[test]
+	fn modified_mp4() {
+		verify(
+			"tests/files/assets/minimal/m4a_codec_aac.m4a",
+			SeekFrom::Start(100),
+		);
+	}
+
+	#[test]
+	fn modified_ogg() {
+		verify(
+			"tests/files/assets/minimal/full_test.ogg",
+			SeekFrom::End(-10),
+		);
+	}
+
+	#[test]
+	fn modified_wav() {
+		verify(
+			"tests/files/assets/odd_length_data_chunk.wav",
+			SeekFrom::Start(100),
+		);
+	}
+
+	#[test]
+	fn failed_write() {
+		let mut file =
+			Cursor::new(std::fs::read("tests/files/assets/minimal/full_test.mp3").unwrap());
+		let original = file.get_ref().clone();
+
+		let err = write_verified(
+			&mut file,
+			WriteOptions::new().verify_audio(true),
+			|file, _| {
+				// Fail partway through writing
+				file.rewind()?;
+				file.write_all(&[0; 64])?;
+				Err(LoftyError::new(ErrorKind::TooMuchData))
+			},
+		)
+		.unwrap_err();
+		assert!(matches!(err.kind(), ErrorKind::TooMuchData));
+
+		// The original file is restored
+		assert_eq!(file.get_ref(), &original);
+	}
+
+	#[test]
+	fn unmodified() {
+		for (path, tag_type) in [
+			(
+				"tests/files/assets/minimal/full_test.flac",
+				TagType::VorbisComments,
+			),
+			("tests/files/assets/minimal/full_test.mp3", TagType::Id3v2),
+			(
+				"tests/files/assets/minimal/full_test.ogg",
+				TagType::VorbisComments,
+			),
+			(
+				"tests/files/assets/minimal/m4a_codec_aac.m4a",
+				TagType::Mp4Ilst,
+			),
+			(
+				"tests/files/assets/odd_length_data_chunk.wav",
+				TagType::RiffInfo,
+			),
+		] {
+			let mut file = Cursor::new(std::fs::read(path).unwrap());
+
+			// Large enough to force the tag to be moved
+			let mut tag = Tag::new(tag_type);
+			tag.set_title("Foo title".repeat(1000));
+
+			tag.save_to(&mut file, WriteOptions::new().verify_audio(true))
+				.unwrap();
+		}
+	}
+}
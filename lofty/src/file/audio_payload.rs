@@ -0,0 +1,106 @@
+use super::FileType;
+use crate::config::ParsingMode;
+use crate::error::Result;
+use crate::macros::err;
+
+use std::fs::File;
+use std::io::BufReader;
+use std::path::Path;
+
+/// Get the size of a file's audio payload, in bytes
+///
+/// This only reads the headers needed to locate the payload, making it a cheap way to find
+/// files with valid tags but missing or truncated audio.
+///
+/// The payload is:
+///
+/// * [`FileType::Mp4`]: The combined size of all `mdat` atoms, excluding their headers
+/// * [`FileType::Wav`]: The size of the `data` chunk, excluding its pad byte
+///
+/// If the file has no payload, this will return `0`.
+///
+/// # Errors
+///
+/// * `path` does not exist
+/// * `file_type` is not one of the types listed above ([`ErrorKind::UnknownFormat`](crate::error::ErrorKind::UnknownFormat))
+/// * The file is not a valid `file_type`
+///
+/// # Examples
+///
+/// ```rust
+/// use lofty::file::{audio_byte_len, FileType};
+///
+/// # fn main() -> lofty::error::Result<()> {
+/// # let path = "tests/files/assets/minimal/wav_format_pcm.wav";
+/// let len = audio_byte_len(path, FileType::Wav)?;
+///
+/// if len == 0 {
+/// 	println!("The file has no audio!");
+/// }
+/// # Ok(()) }
+/// ```
+pub fn audio_byte_len<P>(path: P, file_type: FileType) -> Result<u64>
+where
+	P: AsRef<Path>,
+{
+	let mut reader = BufReader::new(File::open(path)?);
+
+	match file_type {
+		FileType::Mp4 => crate::mp4::audio_payload_len(&mut reader, ParsingMode::default()),
+		FileType::Wav => crate::iff::wav::audio_payload_len(&mut reader),
+		_ => err!(UnknownFormat),
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::audio_byte_len;
+	use crate::config::ParsingMode;
+	use crate::file::FileType;
+
+	use std::io::Cursor;
+
+	#[test]
+	fn mp4_payload() {
+		let len = audio_byte_len(
+			"tests/files/assets/minimal/m4a_codec_aac.m4a",
+			FileType::Mp4,
+		)
+		.unwrap();
+		assert_eq!(len, 22489);
+
+		// Only an `ftyp` atom
+		let mut ftyp = Vec::new();
+		ftyp.extend(16_u32.to_be_bytes());
+		ftyp.extend(b"ftypM4A \0\0\0\0");
+
+		let len =
+			crate::mp4::audio_payload_len(&mut Cursor::new(ftyp), ParsingMode::default()).unwrap();
+		assert_eq!(len, 0);
+	}
+
+	#[test]
+	fn wav_payload() {
+		let len = audio_byte_len(
+			"tests/files/assets/odd_length_data_chunk.wav",
+			FileType::Wav,
+		)
+		.unwrap();
+		assert_eq!(len, 8001);
+
+		let mut riff = Vec::new();
+		riff.extend(b"RIFF");
+		riff.extend(4_u32.to_le_bytes());
+		riff.extend(b"WAVE");
+
+		let len = crate::iff::wav::audio_payload_len(&mut Cursor::new(riff)).unwrap();
+		assert_eq!(len, 0);
+	}
+
+	#[test]
+	fn unsupported_file_type() {
+		assert!(
+			audio_byte_len("tests/files/assets/minimal/full_test.mp3", FileType::Mpeg).is_err()
+		);
+	}
+}
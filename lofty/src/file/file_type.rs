@@ -13,10 +13,12 @@ pub enum FileType {
 	Aac,
 	Aiff,
 	Ape,
+	Dsf,
 	Flac,
 	Mpeg,
 	Mp4,
 	Mpc,
+	OptimFrog,
 	Opus,
 	Vorbis,
 	Speex,
@@ -28,12 +30,12 @@ pub enum FileType {
 impl FileType {
 	/// Returns the file type's "primary" [`TagType`], or the one most likely to be used in the target format
 	///
-	/// | [`FileType`]                      | [`TagType`]      |
-	/// |-----------------------------------|------------------|
-	/// | `Aac`, `Aiff`, `Mp3`, `Wav`       | `Id3v2`          |
-	/// | `Ape` , `Mpc`, `WavPack`          | `Ape`            |
-	/// | `Flac`, `Opus`, `Vorbis`, `Speex` | `VorbisComments` |
-	/// | `Mp4`                             | `Mp4Ilst`        |
+	/// | [`FileType`]                          | [`TagType`]      |
+	/// |----------------------------------------|------------------|
+	/// | `Aac`, `Aiff`, `Dsf`, `Mp3`, `Wav`     | `Id3v2`          |
+	/// | `Ape` , `Mpc`, `OptimFrog`, `WavPack`  | `Ape`            |
+	/// | `Flac`, `Opus`, `Vorbis`, `Speex`      | `VorbisComments` |
+	/// | `Mp4`                                  | `Mp4Ilst`        |
 	///
 	/// # Panics
 	///
@@ -50,8 +52,10 @@ impl FileType {
 	/// ```
 	pub fn primary_tag_type(&self) -> TagType {
 		match self {
-			FileType::Aac | FileType::Aiff | FileType::Mpeg | FileType::Wav => TagType::Id3v2,
-			FileType::Ape | FileType::Mpc | FileType::WavPack => TagType::Ape,
+			FileType::Aac | FileType::Aiff | FileType::Dsf | FileType::Mpeg | FileType::Wav => {
+				TagType::Id3v2
+			},
+			FileType::Ape | FileType::Mpc | FileType::OptimFrog | FileType::WavPack => TagType::Ape,
 			FileType::Flac | FileType::Opus | FileType::Vorbis | FileType::Speex => {
 				TagType::VorbisComments
 			},
@@ -131,9 +135,11 @@ impl FileType {
 			"aac" => Some(Self::Aac),
 			"ape" => Some(Self::Ape),
 			"aiff" | "aif" | "afc" | "aifc" => Some(Self::Aiff),
+			"dsf" => Some(Self::Dsf),
 			"mp3" | "mp2" | "mp1" => Some(Self::Mpeg),
 			"wav" | "wave" => Some(Self::Wav),
 			"wv" => Some(Self::WavPack),
+			"ofr" => Some(Self::OptimFrog),
 			"opus" => Some(Self::Opus),
 			"flac" => Some(Self::Flac),
 			"ogg" => Some(Self::Vorbis),
@@ -238,6 +244,7 @@ impl FileType {
 		// Safe to index, since we return early on an empty buffer
 		match buf[0] {
 			77 if buf.starts_with(b"MAC") => Some(Self::Ape),
+			68 if buf.starts_with(b"DSD ") => Some(Self::Dsf),
 			255 if buf.len() >= 2 && verify_frame_sync([buf[0], buf[1]]) => {
 				// ADTS and MPEG frame headers are way too similar
 
@@ -281,6 +288,7 @@ impl FileType {
 
 				None
 			},
+			79 if buf.starts_with(b"OFR ") => Some(Self::OptimFrog),
 			79 if buf.len() >= 36 && &buf[..4] == b"OggS" => {
 				if &buf[29..35] == b"vorbis" {
 					return Some(Self::Vorbis);
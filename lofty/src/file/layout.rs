@@ -0,0 +1,314 @@
+use super::FileType;
+use crate::ape::constants::APE_PREAMBLE;
+use crate::ape::header::read_ape_header;
+use crate::error::Result;
+use crate::id3::{find_id3v1, find_id3v2, find_lyrics3v2, FindId3v2Config, ID3FindResults};
+use crate::macros::err;
+
+use std::io::{Read, Seek, SeekFrom};
+
+/// The contents of a [`LayoutBlock`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum BlockKind {
+	/// An ID3v2 tag, including its header and footer
+	///
+	/// In WAV files, this is the entire `ID3 ` chunk.
+	Id3v2,
+	/// An ID3v1 tag
+	Id3v1,
+	/// An APE tag, including its header and footer
+	Ape,
+	/// A Lyrics3v2 tag
+	Lyrics3v2,
+	/// A RIFF INFO list, the entire `LIST` chunk
+	RiffInfo,
+	/// The audio stream
+	///
+	/// For WAV files, this is the contents of the `data` chunk. For all other formats, this is
+	/// everything between the leading and trailing tags.
+	Audio,
+	/// Data beyond the end of the file's container
+	///
+	/// This is currently only detected in WAV files, where it is anything following the `RIFF` chunk.
+	Trailing,
+}
+
+/// A region of a file, see [`FileLayout`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct LayoutBlock {
+	/// What the block contains
+	pub kind: BlockKind,
+	/// The offset of the block from the start of the file
+	pub offset: u64,
+	/// The size of the block
+	pub size: u64,
+}
+
+/// The locations of a file's tags and audio stream
+///
+/// This is created with [`file_layout`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FileLayout {
+	pub(crate) file_len: u64,
+	pub(crate) blocks: Vec<LayoutBlock>,
+}
+
+impl FileLayout {
+	pub(crate) fn new(file_len: u64) -> Self {
+		Self {
+			file_len,
+			blocks: Vec::new(),
+		}
+	}
+
+	pub(crate) fn push(&mut self, kind: BlockKind, offset: u64, size: u64) {
+		self.blocks.push(LayoutBlock { kind, offset, size });
+	}
+
+	/// The length of the file
+	pub fn file_len(&self) -> u64 {
+		self.file_len
+	}
+
+	/// All located blocks, ordered by their offsets
+	///
+	/// NOTE: Regions of the file that aren't tags or audio (such as a WAV `fmt ` chunk) are not included.
+	pub fn blocks(&self) -> &[LayoutBlock] {
+		&self.blocks
+	}
+
+	/// The block containing the audio stream, if one was found
+	pub fn audio(&self) -> Option<&LayoutBlock> {
+		self.blocks
+			.iter()
+			.find(|block| block.kind == BlockKind::Audio)
+	}
+
+	/// Whether the file has data beyond the end of its container
+	///
+	/// See [`BlockKind::Trailing`].
+	pub fn has_trailing_data(&self) -> bool {
+		self.blocks
+			.iter()
+			.any(|block| block.kind == BlockKind::Trailing)
+	}
+}
+
+/// Locate the tags and audio stream of a file
+///
+/// This only reads the headers needed to locate each block, and can be used for tools that need to
+/// repair files or checksum only the audio stream.
+///
+/// The supported file types are:
+///
+/// * [`FileType::Aac`], [`FileType::Ape`], [`FileType::Mpc`], [`FileType::Mpeg`], [`FileType::OptimFrog`],
+///   and [`FileType::WavPack`]:
+///   ID3v2 tags at the start of the file, and APE, Lyrics3v2, and ID3v1 tags at the end.
+/// * [`FileType::Wav`]: The `ID3 `, `LIST INFO`, and `data` chunks
+///
+/// # Errors
+///
+/// * `file_type` is not one of the types listed above ([`ErrorKind::UnknownFormat`](crate::error::ErrorKind::UnknownFormat))
+/// * The file is not a valid `file_type`
+///
+/// # Examples
+///
+/// ```rust
+/// use lofty::file::{file_layout, BlockKind, FileType};
+/// use std::fs::File;
+///
+/// # fn main() -> lofty::error::Result<()> {
+/// # let path = "tests/files/assets/minimal/full_test.mp3";
+/// let mut file = File::open(path)?;
+/// let layout = file_layout(&mut file, FileType::Mpeg)?;
+///
+/// for block in layout.blocks() {
+/// 	println!("{:?}: {} bytes at offset {}", block.kind, block.size, block.offset);
+/// }
+///
+/// if let Some(audio) = layout.audio() {
+/// 	println!("The audio stream starts at offset {}", audio.offset);
+/// }
+///
+/// assert_eq!(layout.blocks()[0].kind, BlockKind::Id3v2);
+/// # Ok(()) }
+/// ```
+pub fn file_layout<R>(reader: &mut R, file_type: FileType) -> Result<FileLayout>
+where
+	R: Read + Seek,
+{
+	reader.rewind()?;
+
+	let mut layout = match file_type {
+		FileType::Aac
+		| FileType::Ape
+		| FileType::Mpc
+		| FileType::Mpeg
+		| FileType::OptimFrog
+		| FileType::WavPack => tags_and_stream_layout(reader)?,
+		FileType::Wav => crate::iff::wav::layout(reader)?,
+		_ => err!(UnknownFormat),
+	};
+
+	layout.blocks.sort_by_key(|block| block.offset);
+	Ok(layout)
+}
+
+// ID3v2 tags at the start of the stream, and APE/Lyrics3v2/ID3v1 tags at the end
+fn tags_and_stream_layout<R>(reader: &mut R) -> Result<FileLayout>
+where
+	R: Read + Seek,
+{
+	let file_len = reader.seek(SeekFrom::End(0))?;
+	reader.rewind()?;
+
+	let mut layout = FileLayout::new(file_len);
+
+	// There can be multiple ID3v2 tags in a row
+	let mut audio_start = 0;
+	while file_len - audio_start >= 10 {
+		reader.seek(SeekFrom::Start(audio_start))?;
+
+		let ID3FindResults(Some(_), _) = find_id3v2(reader, FindId3v2Config::NO_READ_TAG)? else {
+			break;
+		};
+
+		let tag_end = reader.stream_position()?.min(file_len);
+		layout.push(BlockKind::Id3v2, audio_start, tag_end - audio_start);
+		audio_start = tag_end;
+	}
+
+	let mut audio_end = file_len;
+	if file_len - audio_start >= 128 {
+		if let ID3FindResults(Some(()), _) = find_id3v1(reader, false)? {
+			audio_end -= 128;
+			layout.push(BlockKind::Id3v1, audio_end, 128);
+		}
+	}
+
+	if audio_end - audio_start >= 15 {
+		reader.seek(SeekFrom::Start(audio_end))?;
+		if let ID3FindResults(Some(()), _) = find_lyrics3v2(reader)? {
+			let tag_start = reader.stream_position()?.max(audio_start);
+			layout.push(BlockKind::Lyrics3v2, tag_start, audio_end - tag_start);
+			audio_end = tag_start;
+		}
+	}
+
+	if audio_end - audio_start >= 32 {
+		reader.seek(SeekFrom::Start(audio_end - 32))?;
+
+		let mut preamble = [0; 8];
+		reader.read_exact(&mut preamble)?;
+
+		if &preamble == APE_PREAMBLE {
+			let header = read_ape_header(reader, true)?;
+			let tag_start = audio_end
+				.saturating_sub(u64::from(header.size))
+				.max(audio_start);
+
+			layout.push(BlockKind::Ape, tag_start, audio_end - tag_start);
+			audio_end = tag_start;
+		}
+	}
+
+	if audio_end > audio_start {
+		layout.push(BlockKind::Audio, audio_start, audio_end - audio_start);
+	}
+
+	Ok(layout)
+}
+
+#[cfg(test)]
+mod tests {
+	use super::{file_layout, BlockKind, LayoutBlock};
+	use crate::file::FileType;
+
+	use std::io::Cursor;
+
+	fn block(kind: BlockKind, offset: u64, size: u64) -> LayoutBlock {
+		LayoutBlock { kind, offset, size }
+	}
+
+	#[test]
+	fn mpeg_layout() {
+		let mut file = Vec::new();
+
+		// ID3v2.4, no frames, 6 bytes of padding
+		file.extend(b"ID3\x04\x00\x00\x00\x00\x00\x06");
+		file.extend([0; 6]);
+
+		let audio = [0xFF; 100];
+		file.extend(audio);
+
+		// APE footer only (version 1000), with no items
+		file.extend(b"APETAGEX");
+		file.extend(1000_u32.to_le_bytes());
+		file.extend(32_u32.to_le_bytes());
+		file.extend(0_u32.to_le_bytes());
+		file.extend([0; 12]);
+
+		file.extend(b"TAG");
+		file.extend([0; 125]);
+
+		let layout = file_layout(&mut Cursor::new(&file), FileType::Mpeg).unwrap();
+		assert_eq!(layout.file_len(), file.len() as u64);
+		assert_eq!(
+			layout.blocks(),
+			&[
+				block(BlockKind::Id3v2, 0, 16),
+				block(BlockKind::Audio, 16, 100),
+				block(BlockKind::Ape, 116, 32),
+				block(BlockKind::Id3v1, 148, 128),
+			]
+		);
+		assert!(!layout.has_trailing_data());
+
+		// No tags at all
+		let layout = file_layout(&mut Cursor::new(audio), FileType::Mpeg).unwrap();
+		assert_eq!(layout.blocks(), &[block(BlockKind::Audio, 0, 100)]);
+	}
+
+	#[test]
+	fn wav_layout() {
+		let mut file = Vec::new();
+		file.extend(b"RIFF");
+		file.extend(42_u32.to_le_bytes());
+		file.extend(b"WAVE");
+
+		file.extend(b"fmt ");
+		file.extend(2_u32.to_le_bytes());
+		file.extend([0; 2]);
+
+		// Odd length, with a pad byte
+		file.extend(b"data");
+		file.extend(3_u32.to_le_bytes());
+		file.extend([1, 2, 3, 0]);
+
+		file.extend(b"LIST");
+		file.extend(8_u32.to_le_bytes());
+		file.extend(b"INFO");
+		file.extend([0; 4]);
+
+		// Not a part of the RIFF chunk
+		file.extend(b"garbage");
+
+		let layout = file_layout(&mut Cursor::new(&file), FileType::Wav).unwrap();
+		assert_eq!(
+			layout.blocks(),
+			&[
+				block(BlockKind::Audio, 30, 3),
+				block(BlockKind::RiffInfo, 34, 16),
+				block(BlockKind::Trailing, 50, 7),
+			]
+		);
+		assert!(layout.has_trailing_data());
+		assert_eq!(layout.audio(), Some(&block(BlockKind::Audio, 30, 3)));
+	}
+
+	#[test]
+	fn unsupported_file_type() {
+		assert!(file_layout(&mut Cursor::new([0; 10]), FileType::Flac).is_err());
+	}
+}
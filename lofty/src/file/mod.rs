@@ -1,11 +1,17 @@
 //! Generic file handling utilities
 
+mod audio_checksum;
 mod audio_file;
+mod audio_payload;
 mod file_type;
+mod layout;
 mod tagged_file;
 
 pub use audio_file::AudioFile;
+pub use audio_payload::audio_byte_len;
 pub use file_type::FileType;
+pub use layout::{file_layout, BlockKind, FileLayout, LayoutBlock};
 pub use tagged_file::{BoundTaggedFile, TaggedFile, TaggedFileExt};
 
+pub(crate) use audio_checksum::write_verified;
 pub(crate) use file_type::FileTypeGuessResult;
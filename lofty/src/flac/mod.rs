@@ -24,6 +24,8 @@ use lofty_attr::LoftyFile;
 // Exports
 pub use properties::FlacProperties;
 
+pub(crate) use read::audio_payload_regions;
+
 /// A FLAC file
 ///
 /// ## Notes
@@ -121,9 +123,8 @@ impl From<FlacFile> for TaggedFile {
 					},
 					None if !value.pictures.is_empty() => tags.push(
 						VorbisComments {
-							vendor: String::new(),
-							items: Vec::new(),
 							pictures: value.pictures,
+							..VorbisComments::default()
 						}
 						.into(),
 					),
@@ -36,6 +36,38 @@ where
 	Ok(block)
 }
 
+/// Get the offsets and sizes of the `STREAMINFO` MD5 signature and the audio frames
+pub(crate) fn audio_payload_regions<R>(data: &mut R) -> Result<Vec<(u64, u64)>>
+where
+	R: Read + Seek,
+{
+	let file_len = data.seek(SeekFrom::End(0))?;
+	data.rewind()?;
+
+	// The ID3v2 tag can be stripped, so it isn't a part of the stream
+	find_id3v2(data, FindId3v2Config::NO_READ_TAG)?;
+
+	let stream_info = verify_flac(data)?;
+	if stream_info.content.len() < 34 {
+		decode_err!(@BAIL Flac, "File has an invalid STREAMINFO block size (< 34)");
+	}
+
+	// Skip the 4 byte block header, and the 18 bytes of stream information preceding the signature
+	let md5_offset = stream_info.start + 4 + 18;
+
+	let mut last_block = stream_info.last;
+	while !last_block {
+		let block = Block::read(data)?;
+		last_block = block.last;
+	}
+
+	let frames_start = data.stream_position()?;
+	Ok(vec![
+		(md5_offset, 16),
+		(frames_start, file_len.saturating_sub(frames_start)),
+	])
+}
+
 pub(crate) fn read_from<R>(data: &mut R, parse_options: ParseOptions) -> Result<FlacFile>
 where
 	R: Read + Seek,
@@ -55,7 +87,7 @@ where
 
 		let reader = &mut &*content;
 
-		let id3v2 = parse_id3v2(reader, header, parse_options.parsing_mode)?;
+		let id3v2 = parse_id3v2(reader, header, parse_options, None)?;
 		flac_file.id3v2_tag = Some(id3v2);
 	}
 
@@ -99,7 +131,7 @@ where
 			let vorbis_comments = read_comments(
 				&mut &*block.content,
 				block.content.len() as u64,
-				parse_options.parsing_mode,
+				parse_options,
 			)?;
 
 			flac_file.vorbis_comments_tag = Some(vorbis_comments);
@@ -109,7 +141,19 @@ where
 		if block.ty == BLOCK_ID_PICTURE {
 			log::debug!("Encountered a FLAC picture block, parsing");
 
-			match Picture::from_flac_bytes(&block.content, false, parse_options.parsing_mode) {
+			let picture = if parse_options.read_pictures {
+				Picture::from_flac_bytes(&block.content, false, parse_options.parsing_mode)
+			} else {
+				// The content follows the 4 byte block header
+				let content_offset = block.start + 4;
+				Picture::from_flac_bytes_unloaded(
+					&block.content,
+					content_offset,
+					parse_options.parsing_mode,
+				)
+			};
+
+			match picture {
 				Ok(picture) => flac_file.pictures.push(picture),
 				Err(e) => {
 					if parse_options.parsing_mode == ParsingMode::Strict {
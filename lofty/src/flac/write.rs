@@ -1,4 +1,4 @@
-use super::block::Block;
+use super::block::{Block, BLOCK_ID_PADDING, BLOCK_ID_PICTURE, BLOCK_ID_VORBIS_COMMENTS};
 use super::read::verify_flac;
 use crate::config::WriteOptions;
 use crate::error::{LoftyError, Result};
@@ -54,6 +54,28 @@ where
 	let stream_info = verify_flac(file)?;
 	let stream_info_end = stream_info.end as usize;
 
+	let mut comment_blocks = Cursor::new(Vec::new());
+
+	create_comment_block(
+		&mut comment_blocks,
+		tag.vendor,
+		&mut tag.items,
+		write_options,
+	)?;
+
+	let mut comment_blocks = comment_blocks.into_inner();
+
+	create_picture_blocks(&mut comment_blocks, &mut tag.pictures)?;
+
+	// Try to fit the new blocks into the existing metadata, so we don't have to rewrite the entire file
+	if !stream_info.last && !write_options.force_rewrite {
+		if write_in_place(file, stream_info.end, &comment_blocks)? {
+			return Ok(());
+		}
+
+		file.seek(SeekFrom::Start(stream_info.end))?;
+	}
+
 	let mut last_block = stream_info.last;
 
 	let mut file_bytes = Vec::new();
@@ -114,14 +136,6 @@ where
 		}
 	}
 
-	let mut comment_blocks = Cursor::new(Vec::new());
-
-	create_comment_block(&mut comment_blocks, tag.vendor, &mut tag.items)?;
-
-	let mut comment_blocks = comment_blocks.into_inner();
-
-	create_picture_blocks(&mut comment_blocks, &mut tag.pictures)?;
-
 	if blocks_remove.is_empty() {
 		file_bytes.splice(0..0, comment_blocks);
 	} else {
@@ -144,10 +158,74 @@ where
 	Ok(())
 }
 
+// Replaces the VORBIS_COMMENT, PICTURE, and PADDING blocks with `new_blocks`, resizing the padding
+// to keep the audio data in place.
+//
+// Returns `false` (without writing anything) if the new blocks don't fit in the existing metadata.
+fn write_in_place<F>(file: &mut F, metadata_start: u64, new_blocks: &[u8]) -> Result<bool>
+where
+	F: FileLike,
+	LoftyError: From<<F as Truncate>::Error>,
+{
+	let mut metadata = Vec::new();
+	let mut inserted = false;
+
+	loop {
+		let block = Block::read(file)?;
+
+		match block.ty {
+			BLOCK_ID_VORBIS_COMMENTS | BLOCK_ID_PICTURE => {
+				if !inserted {
+					metadata.extend_from_slice(new_blocks);
+					inserted = true;
+				}
+			},
+			BLOCK_ID_PADDING => {},
+			_ => {
+				metadata.push(block.ty);
+				metadata.extend_from_slice(&(block.content.len() as u32).to_be_bytes()[1..]);
+				metadata.extend_from_slice(&block.content);
+			},
+		}
+
+		if block.last {
+			break;
+		}
+	}
+
+	if !inserted {
+		metadata.splice(0..0, new_blocks.iter().copied());
+	}
+
+	let metadata_end = file.stream_position()?;
+	let available = metadata_end - metadata_start;
+
+	// The leftover space needs to be large enough for a PADDING block header
+	let Some(padding_size) = available
+		.checked_sub((metadata.len() + BLOCK_HEADER_SIZE) as u64)
+		.filter(|size| *size <= u64::from(MAX_BLOCK_SIZE))
+	else {
+		log::debug!("New metadata doesn't fit in the existing blocks, rewriting the file");
+		return Ok(false);
+	};
+
+	log::debug!("Writing metadata in place, resizing padding to {padding_size} bytes");
+
+	metadata.push(0x80 | BLOCK_ID_PADDING);
+	metadata.extend_from_slice(&(padding_size as u32).to_be_bytes()[1..]);
+	metadata.resize(metadata.len() + padding_size as usize, 0);
+
+	file.seek(SeekFrom::Start(metadata_start))?;
+	file.write_all(&metadata)?;
+
+	Ok(true)
+}
+
 fn create_comment_block(
 	writer: &mut Cursor<Vec<u8>>,
 	vendor: &str,
 	items: &mut dyn Iterator<Item = (&str, &str)>,
+	write_options: WriteOptions,
 ) -> Result<()> {
 	let mut peek = items.peekable();
 
@@ -164,7 +242,12 @@ fn create_comment_block(
 
 		writer.write_u32::<LittleEndian>(count)?;
 
-		create_comments(writer, &mut count, &mut peek)?;
+		create_comments(
+			writer,
+			&mut count,
+			&mut peek,
+			write_options.encoder_stamp_value(),
+		)?;
 
 		let len = (writer.get_ref().len() - 1) as u32;
 
@@ -200,6 +283,8 @@ fn create_picture_blocks(
 	byte |= 6 & 0x7F;
 
 	for (pic, info) in pictures {
+		pic.check_loaded()?;
+
 		writer.write_u8(byte)?;
 
 		let pic_bytes = pic.as_flac_bytes(info, false);
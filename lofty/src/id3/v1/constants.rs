@@ -194,6 +194,53 @@ pub const GENRES: [&str; 192] = [
 	"Psybient",
 ];
 
+/// Get the genre name for an ID3v1 genre ID
+///
+/// The ID can be on its own (`"17"`), or in parentheses (`"(17)"`) as is common in ID3v2 and
+/// some RIFF INFO tags.
+///
+/// # Examples
+///
+/// ```rust
+/// use lofty::id3::v1::genre_from_id;
+///
+/// assert_eq!(genre_from_id("17"), Some("Rock"));
+/// assert_eq!(genre_from_id("(17)"), Some("Rock"));
+/// assert_eq!(genre_from_id("Rock"), None);
+/// ```
+pub fn genre_from_id(id: &str) -> Option<&'static str> {
+	parse_genre_id(id).map(|id| GENRES[id])
+}
+
+/// Get the ID3v1 genre ID for a genre
+///
+/// The genre can either be a name, compared case-insensitively against [`GENRES`], or an ID
+/// (see [`genre_from_id`]).
+///
+/// # Examples
+///
+/// ```rust
+/// use lofty::id3::v1::genre_id;
+///
+/// assert_eq!(genre_id("rock"), Some(17));
+/// assert_eq!(genre_id("(17)"), Some(17));
+/// assert_eq!(genre_id("Not a genre"), None);
+/// ```
+pub fn genre_id(genre: &str) -> Option<u8> {
+	parse_genre_id(genre)
+		.or_else(|| GENRES.iter().position(|g| g.eq_ignore_ascii_case(genre)))
+		.map(|id| id as u8)
+}
+
+fn parse_genre_id(id: &str) -> Option<usize> {
+	let id = id
+		.strip_prefix('(')
+		.and_then(|id| id.strip_suffix(')'))
+		.unwrap_or(id);
+
+	id.parse::<usize>().ok().filter(|id| *id < GENRES.len())
+}
+
 use crate::tag::ItemKey;
 pub(crate) const VALID_ITEMKEYS: [ItemKey; 7] = [
 	ItemKey::TrackTitle,
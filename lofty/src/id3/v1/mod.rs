@@ -7,7 +7,8 @@
 //! ## Genres
 //!
 //! ID3v1 stores the genre in a single byte ranging from 0 to 192 (inclusive).
-//! All possible genres have been stored in the [`GENRES`] constant.
+//! All possible genres have been stored in the [`GENRES`] constant, and can be looked up with
+//! [`genre_from_id`] and [`genre_id`].
 //!
 //! ## Track Numbers
 //!
@@ -21,5 +22,5 @@ pub(crate) mod write;
 
 // Exports
 
-pub use constants::GENRES;
+pub use constants::{genre_from_id, genre_id, GENRES};
 pub use tag::Id3v1Tag;
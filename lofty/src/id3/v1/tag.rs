@@ -1,11 +1,17 @@
 use crate::config::WriteOptions;
 use crate::error::{LoftyError, Result};
-use crate::id3::v1::constants::GENRES;
-use crate::tag::{Accessor, ItemKey, ItemValue, MergeTag, SplitTag, Tag, TagExt, TagItem, TagType};
+use crate::file::write_verified;
+use crate::id3::v1::constants::{genre_id, GENRES};
+use crate::id3::v1::read::parse_id3v1;
+use crate::macros::err;
+use crate::tag::{
+	verify_number_range, verify_year, Accessor, ItemKey, ItemValue, MergeTag, SplitTag, Tag,
+	TagExt, TagItem, TagType,
+};
 use crate::util::io::{FileLike, Length, Truncate};
 
 use std::borrow::Cow;
-use std::io::Write;
+use std::io::{Read, Write};
 use std::path::Path;
 
 use lofty_attr::tag;
@@ -66,12 +72,12 @@ macro_rules! impl_accessor {
 /// * [`ItemKey::TrackNumber`] - Will only be used if the value can be parsed as a `u8`
 /// * [`ItemKey::Genre`] - Will only be used if:
 ///
-/// 	[`GENRES`] contains the string **OR** The [`ItemValue`](crate::ItemValue) can be parsed into
-/// 	a `u8` ***and*** it is a valid index into [`GENRES`]
+/// 	[`GENRES`] contains the string (ignoring case) **OR** The [`ItemValue`](crate::ItemValue) is
+/// 	a valid index into [`GENRES`], such as `"17"` or `"(17)"` (see [`genre_id`](crate::id3::v1::genre_id))
 #[derive(Default, Debug, PartialEq, Eq, Clone)]
 #[tag(
 	description = "An ID3v1 tag",
-	supported_formats(Aac, Ape, Mpeg, WavPack, read_only(Mpc))
+	supported_formats(Aac, Ape, Mpeg, OptimFrog, WavPack, read_only(Mpc))
 )]
 pub struct Id3v1Tag {
 	/// Track title, 30 bytes max
@@ -121,6 +127,91 @@ impl Id3v1Tag {
 	pub fn new() -> Self {
 		Self::default()
 	}
+
+	/// Read a tag previously written with [`TagExt::dump_to`]
+	///
+	/// # Errors
+	///
+	/// * `reader` is shorter than 128 bytes
+	/// * `reader` does not start with `TAG`
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use lofty::config::WriteOptions;
+	/// use lofty::id3::v1::Id3v1Tag;
+	/// use lofty::tag::{Accessor, TagExt};
+	///
+	/// # fn main() -> lofty::error::Result<()> {
+	/// let mut tag = Id3v1Tag::new();
+	/// tag.set_title(String::from("Foo title"));
+	///
+	/// let mut dump = Vec::new();
+	/// tag.dump_to(&mut dump, WriteOptions::default())?;
+	///
+	/// let re_read = Id3v1Tag::read_from_dump(&mut &dump[..])?;
+	/// assert_eq!(re_read.title().as_deref(), Some("Foo title"));
+	/// # Ok(()) }
+	/// ```
+	pub fn read_from_dump<R>(reader: &mut R) -> Result<Self>
+	where
+		R: Read,
+	{
+		let mut id3v1 = [0; 128];
+		reader.read_exact(&mut id3v1)?;
+
+		if &id3v1[..3] != b"TAG" {
+			err!(FakeTag);
+		}
+
+		Ok(parse_id3v1(id3v1))
+	}
+
+	/// Set the track number, failing if it doesn't fit in a single byte
+	///
+	/// Unlike [`Accessor::set_track`], which only logs a warning, this returns an error. In both cases, the tag
+	/// is left unchanged.
+	///
+	/// # Errors
+	///
+	/// * `value` is greater than 255 ([`ErrorKind::BadItem`](crate::error::ErrorKind::BadItem))
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use lofty::id3::v1::Id3v1Tag;
+	/// use lofty::tag::Accessor;
+	///
+	/// let mut id3v1 = Id3v1Tag::new();
+	///
+	/// assert!(id3v1.try_set_track(256).is_err());
+	/// assert!(id3v1.track().is_none());
+	///
+	/// id3v1.try_set_track(255)?;
+	/// assert_eq!(id3v1.track(), Some(255));
+	/// # Ok::<(), lofty::error::LoftyError>(())
+	/// ```
+	pub fn try_set_track(&mut self, value: u32) -> Result<()> {
+		verify_number_range(TagType::Id3v1, &ItemKey::TrackNumber, value)?;
+
+		// The value was checked above, it fits in a u8
+		self.track_number = Some(value as u8);
+		Ok(())
+	}
+
+	/// Set the year, failing if it has more than 4 digits
+	///
+	/// Only the first 4 digits of a year are read back, so unlike [`Accessor::set_year`], this will not
+	/// store a year that would be read back differently. The tag is left unchanged on failure.
+	///
+	/// # Errors
+	///
+	/// * `value` is greater than 9999 ([`ErrorKind::BadItem`](crate::error::ErrorKind::BadItem))
+	pub fn try_set_year(&mut self, value: u32) -> Result<()> {
+		verify_year(value)?;
+		self.set_year(value);
+		Ok(())
+	}
 }
 
 impl Accessor for Id3v1Tag {
@@ -139,13 +230,8 @@ impl Accessor for Id3v1Tag {
 	}
 
 	fn set_genre(&mut self, genre: String) {
-		let g_str = genre.as_str();
-
-		for (i, g) in GENRES.iter().enumerate() {
-			if g.eq_ignore_ascii_case(g_str) {
-				self.genre = Some(i as u8);
-				break;
-			}
+		if let Some(id) = genre_id(&genre) {
+			self.genre = Some(id);
 		}
 	}
 
@@ -158,7 +244,9 @@ impl Accessor for Id3v1Tag {
 	}
 
 	fn set_track(&mut self, value: u32) {
-		self.track_number = Some(value as u8);
+		if let Err(e) = self.try_set_track(value) {
+			log::warn!("Unable to set track number: {e}");
+		}
 	}
 
 	fn remove_track(&mut self) {
@@ -362,15 +450,7 @@ impl From<Tag> for Id3v1Tag {
 				.get_string(&ItemKey::TrackNumber)
 				.map(|g| g.parse::<u8>().ok())
 				.and_then(|g| g),
-			genre: input
-				.get_string(&ItemKey::Genre)
-				.map(|g| {
-					GENRES
-						.iter()
-						.position(|v| v == &g)
-						.map_or_else(|| g.parse::<u8>().ok(), |p| Some(p as u8))
-				})
-				.and_then(|g| g),
+			genre: input.get_string(&ItemKey::Genre).and_then(genre_id),
 		}
 	}
 }
@@ -411,15 +491,7 @@ impl<'a> Into<Id3v1TagRef<'a>> for &'a Tag {
 				.get_string(&ItemKey::TrackNumber)
 				.map(|g| g.parse::<u8>().ok())
 				.and_then(|g| g),
-			genre: self
-				.get_string(&ItemKey::Genre)
-				.map(|g| {
-					GENRES
-						.iter()
-						.position(|v| v == &g)
-						.map_or_else(|| g.parse::<u8>().ok(), |p| Some(p as u8))
-				})
-				.and_then(|g| g),
+			genre: self.get_string(&ItemKey::Genre).and_then(genre_id),
 		}
 	}
 }
@@ -441,7 +513,9 @@ impl<'a> Id3v1TagRef<'a> {
 		LoftyError: From<<F as Truncate>::Error>,
 		LoftyError: From<<F as Length>::Error>,
 	{
-		super::write::write_id3v1(file, self, write_options)
+		write_verified(file, write_options, |file, write_options| {
+			super::write::write_id3v1(file, self, write_options)
+		})
 	}
 
 	pub(crate) fn dump_to<W: Write>(
@@ -519,4 +593,19 @@ mod tests {
 		assert_eq!(id3v1_tag.track_number, Some(1));
 		assert_eq!(id3v1_tag.genre, Some(32));
 	}
+
+	#[test]
+	fn truncate_multi_byte_field() {
+		// "ü" is 2 bytes, so the 30 byte limit falls in the middle of a character
+		let id3v1 = Id3v1Tag {
+			title: Some(format!("a{}", "ü".repeat(16))),
+			..Id3v1Tag::default()
+		};
+
+		let mut writer = Vec::new();
+		id3v1.dump_to(&mut writer, WriteOptions::default()).unwrap();
+
+		let parsed_tag = crate::id3::v1::read::parse_id3v1(writer.try_into().unwrap());
+		assert_eq!(parsed_tag.title.map(|t| t.chars().count()), Some(29));
+	}
 }
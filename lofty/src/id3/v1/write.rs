@@ -1,6 +1,7 @@
 use super::tag::Id3v1TagRef;
 use crate::config::WriteOptions;
 use crate::error::{LoftyError, Result};
+use crate::file::FileType;
 use crate::id3::{find_id3v1, ID3FindResults};
 use crate::macros::err;
 use crate::probe::Probe;
@@ -49,6 +50,31 @@ where
 	Ok(())
 }
 
+/// Writes `tag` if the file is an MPEG file, see [`WriteOptions::sync_id3v1`]
+pub(crate) fn write_synced_id3v1<F>(
+	file: &mut F,
+	tag: &Id3v1TagRef<'_>,
+	write_options: WriteOptions,
+) -> Result<()>
+where
+	F: FileLike,
+	LoftyError: From<<F as Truncate>::Error>,
+	LoftyError: From<<F as Length>::Error>,
+{
+	file.rewind()?;
+
+	let probe = Probe::new(file).guess_file_type()?;
+	if probe.file_type() != Some(FileType::Mpeg) {
+		return Ok(());
+	}
+
+	let file = probe.into_inner();
+	file.rewind()?;
+
+	log::debug!("Syncing ID3v1 tag with ID3v2 tag");
+	write_id3v1(file, tag, write_options)
+}
+
 pub(super) fn encode(tag: &Id3v1TagRef<'_>) -> std::io::Result<Vec<u8>> {
 	fn resize_string(value: Option<&str>, size: usize) -> std::io::Result<Vec<u8>> {
 		let mut cursor = Cursor::new(vec![0; size]);
@@ -56,7 +82,13 @@ pub(super) fn encode(tag: &Id3v1TagRef<'_>) -> std::io::Result<Vec<u8>> {
 
 		if let Some(val) = value {
 			if val.len() > size {
-				cursor.write_all(val.split_at(size).0.as_bytes())?;
+				// Make sure we don't split a character
+				let mut end = size;
+				while !val.is_char_boundary(end) {
+					end -= 1;
+				}
+
+				cursor.write_all(val[..end].as_bytes())?;
 			} else {
 				cursor.write_all(val.as_bytes())?;
 			}
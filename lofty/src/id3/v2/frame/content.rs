@@ -1,6 +1,7 @@
-use crate::config::ParsingMode;
+use crate::config::{ParseOptions, ParsingMode};
 use crate::error::{Id3v2Error, Id3v2ErrorKind, Result};
 use crate::id3::v2::header::Id3v2Version;
+use crate::id3::v2::items::chapter::convert_sub_frames;
 use crate::id3::v2::items::{
 	AttachedPictureFrame, CommentFrame, EventTimingCodesFrame, ExtendedTextFrame, ExtendedUrlFrame,
 	KeyValueFrame, OwnershipFrame, PopularimeterFrame, PrivateFrame, RelativeVolumeAdjustmentFrame,
@@ -19,8 +20,10 @@ pub(super) fn parse_content<R: Read>(
     id: FrameId<'static>,
 	flags: FrameFlags,
     version: Id3v2Version,
-	parse_mode: ParsingMode,
+	parse_options: ParseOptions,
 ) -> Result<Option<Frame<'static>>> {
+	let parse_mode = parse_options.parsing_mode;
+
 	Ok(match id.as_str() {
 		// The ID was previously upgraded, but the content remains unchanged, so version is necessary
 		"APIC" => {
@@ -43,6 +46,20 @@ pub(super) fn parse_content<R: Read>(
 		i if i.starts_with('W') => UrlLinkFrame::parse(reader, id, flags)?.map(Frame::Url),
 		"POPM" => Some(Frame::Popularimeter(PopularimeterFrame::parse(reader, flags)?)),
 		"TDEN" | "TDOR" | "TDRC" | "TDRL" | "TDTG" => TimestampFrame::parse(reader, id, flags, parse_mode)?.map(Frame::Timestamp),
+		// The sub-frames are upgraded to ID3v2.4, the rest of the content is stored as-is
+		"CHAP" | "CTOC" => {
+			let mut frame = BinaryFrame::parse(reader, id, flags)?;
+			if version != Id3v2Version::V4 {
+				match convert_sub_frames(&frame.header.id, &frame.data, version, parse_options, false, None, &mut Vec::new()) {
+					Ok(data) => frame.data = data,
+					Err(e) if parse_mode == ParsingMode::Strict => return Err(e),
+					// The frame is kept as-is, so nothing is lost when writing it back
+					Err(e) => log::warn!("Failed to read the sub-frames of \"{}\", keeping them as-is: {e}", frame.header.id),
+				}
+			}
+
+			Some(Frame::Binary(frame))
+		},
 		// SYLT, GEOB, and any unknown frames
 		_ => {
 			Some(Frame::Binary(BinaryFrame::parse(reader, id, flags)?))
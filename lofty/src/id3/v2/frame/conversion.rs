@@ -9,6 +9,7 @@ use crate::id3::v2::{
 	UniqueFileIdentifierFrame,
 };
 use crate::macros::err;
+use crate::tag::items::{popm_from_rating, rating_from_item_value};
 use crate::tag::{ItemKey, ItemValue, TagItem, TagType};
 use crate::TextEncoding;
 
@@ -28,9 +29,26 @@ fn frame_from_unknown_item(id: FrameId<'_>, item_value: ItemValue) -> Result<Fra
 	}
 }
 
+/// Create a `POPM` frame from either its contents, or a 0-100 rating
+fn popularimeter_from_item(item_value: &ItemValue) -> Option<Frame<'static>> {
+	let frame = match item_value {
+		ItemValue::Binary(contents) => {
+			PopularimeterFrame::parse(&mut &contents[..], FrameFlags::default()).ok()?
+		},
+		ItemValue::Text(_) => popm_from_rating(rating_from_item_value(item_value)?),
+		ItemValue::Locator(_) => return None,
+	};
+
+	Some(Frame::Popularimeter(frame))
+}
+
 impl From<TagItem> for Option<Frame<'static>> {
 	fn from(input: TagItem) -> Self {
 		let value;
+		if input.item_key == ItemKey::Popularimeter {
+			return popularimeter_from_item(&input.item_value);
+		}
+
 		if let Ok(id) = input.key().try_into().map(FrameId::into_owned) {
 			return frame_from_unknown_item(id, input.item_value).ok();
 		}
@@ -109,6 +127,17 @@ impl<'a> TryFrom<&'a TagItem> for FrameRef<'a> {
 							FrameFlags::default(),
 						)?);
 					},
+					("POPM", item_value @ ItemValue::Text(_)) => {
+						let Some(frame) = popularimeter_from_item(item_value) else {
+							return Err(Id3v2Error::new(Id3v2ErrorKind::BadFrame(
+								String::from("POPM"),
+								"Rating must be a number between 0 and 100",
+							))
+							.into());
+						};
+
+						value = frame;
+					},
 					(_, item_value) => value = frame_from_unknown_item(id, item_value.clone())?,
 				};
 			},
@@ -154,6 +154,40 @@ impl<'a> Frame<'a> {
 		self.id().as_str()
 	}
 
+	/// Get the [`TextEncoding`] of the frame
+	///
+	/// Returns `None` if the frame has no text encoding.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use lofty::id3::v2::{Frame, FrameId, TextInformationFrame};
+	/// use lofty::TextEncoding;
+	///
+	/// let frame = Frame::Text(TextInformationFrame::new(
+	/// 	FrameId::new("TALB")?,
+	/// 	TextEncoding::Latin1,
+	/// 	String::from("Foo album"),
+	/// ));
+	///
+	/// assert_eq!(frame.encoding(), Some(TextEncoding::Latin1));
+	/// # Ok::<(), lofty::error::LoftyError>(())
+	/// ```
+	pub fn encoding(&self) -> Option<TextEncoding> {
+		match self {
+			Frame::Comment(CommentFrame { encoding, .. })
+			| Frame::UnsynchronizedText(UnsynchronizedTextFrame { encoding, .. })
+			| Frame::Text(TextInformationFrame { encoding, .. })
+			| Frame::UserText(ExtendedTextFrame { encoding, .. })
+			| Frame::UserUrl(ExtendedUrlFrame { encoding, .. })
+			| Frame::Picture(AttachedPictureFrame { encoding, .. })
+			| Frame::KeyValue(KeyValueFrame { encoding, .. })
+			| Frame::Ownership(OwnershipFrame { encoding, .. })
+			| Frame::Timestamp(TimestampFrame { encoding, .. }) => Some(*encoding),
+			_ => None,
+		}
+	}
+
 	// Used internally, has no correctness checks
 	pub(crate) fn text(id: Cow<'a, str>, content: String) -> Self {
 		Frame::Text(TextInformationFrame {
@@ -176,7 +210,9 @@ impl<'a> Frame<'a> {
 			Frame::UserUrl(extended_url) => extended_url.content.is_empty(),
 			Frame::Comment(comment) => comment.content.is_empty(),
 			Frame::UnsynchronizedText(unsync_text) => unsync_text.content.is_empty(),
-			Frame::Picture(picture) => picture.picture.data.is_empty(),
+			Frame::Picture(picture) => {
+				picture.picture.is_loaded() && picture.picture.data.is_empty()
+			},
 			Frame::KeyValue(key_value) => key_value.key_value_pairs.is_empty(),
 			Frame::UniqueFileIdentifier(ufid) => ufid.identifier.is_empty(),
 			Frame::EventTimingCodes(event_timing) => event_timing.events.is_empty(),
@@ -195,7 +231,13 @@ impl<'a> Frame<'a> {
 }
 
 impl<'a> Frame<'a> {
-	pub(super) fn as_bytes(&self) -> Result<Vec<u8>> {
+	pub(super) fn as_bytes(&self, is_id3v23: bool) -> Result<Vec<u8>> {
+		if is_id3v23 {
+			if let Some(frame) = self.downgrade_to_id3v23() {
+				return frame.as_bytes(true);
+			}
+		}
+
 		Ok(match self {
 			Frame::Comment(comment) => comment.as_bytes()?,
 			Frame::UnsynchronizedText(lf) => lf.as_bytes()?,
@@ -203,7 +245,15 @@ impl<'a> Frame<'a> {
 			Frame::UserText(content) => content.as_bytes(),
 			Frame::UserUrl(content) => content.as_bytes(),
 			Frame::Url(link) => link.as_bytes(),
-			Frame::Picture(attached_picture) => attached_picture.as_bytes(Id3v2Version::V4)?,
+			Frame::Picture(attached_picture) => {
+				let version = if is_id3v23 {
+					Id3v2Version::V3
+				} else {
+					Id3v2Version::V4
+				};
+
+				attached_picture.as_bytes(version)?
+			},
 			Frame::Popularimeter(popularimeter) => popularimeter.as_bytes(),
 			Frame::KeyValue(content) => content.as_bytes(),
 			Frame::RelativeVolumeAdjustment(frame) => frame.as_bytes(),
@@ -216,6 +266,124 @@ impl<'a> Frame<'a> {
 		})
 	}
 
+	/// Creates a copy of the frame with its text encoded as `encoding`
+	///
+	/// If the text can't be represented in `encoding` (only possible with [`TextEncoding::Latin1`]),
+	/// [`TextEncoding::UTF16`] will be used instead.
+	///
+	/// Returns `None` if the frame has no text encoding, or it is already `encoding`.
+	pub(crate) fn reencode(&self, encoding: TextEncoding) -> Option<Self> {
+		let current_encoding = self.encoding()?;
+
+		let mut encoding = encoding;
+		if encoding == TextEncoding::Latin1 && !self.fits_latin1() {
+			log::warn!(
+				"Frame \"{}\" can't be encoded as Latin-1, using UTF-16",
+				self.id_str()
+			);
+			encoding = TextEncoding::UTF16;
+		}
+
+		if current_encoding == encoding {
+			return None;
+		}
+
+		let mut frame = self.clone();
+		match &mut frame {
+			Frame::Comment(CommentFrame { encoding: e, .. })
+			| Frame::UnsynchronizedText(UnsynchronizedTextFrame { encoding: e, .. })
+			| Frame::Text(TextInformationFrame { encoding: e, .. })
+			| Frame::UserText(ExtendedTextFrame { encoding: e, .. })
+			| Frame::UserUrl(ExtendedUrlFrame { encoding: e, .. })
+			| Frame::Picture(AttachedPictureFrame { encoding: e, .. })
+			| Frame::KeyValue(KeyValueFrame { encoding: e, .. })
+			| Frame::Ownership(OwnershipFrame { encoding: e, .. })
+			| Frame::Timestamp(TimestampFrame { encoding: e, .. }) => *e = encoding,
+			_ => unreachable!(),
+		}
+
+		Some(frame)
+	}
+
+	// Whether all of the text in the frame can be encoded as Latin-1
+	fn fits_latin1(&self) -> bool {
+		fn is_latin1(text: &str) -> bool {
+			text.chars().all(|c| u32::from(c) <= 0xFF)
+		}
+
+		match self {
+			Frame::Comment(CommentFrame {
+				description,
+				content,
+				..
+			})
+			| Frame::UnsynchronizedText(UnsynchronizedTextFrame {
+				description,
+				content,
+				..
+			})
+			| Frame::UserText(ExtendedTextFrame {
+				description,
+				content,
+				..
+			}) => is_latin1(description) && is_latin1(content),
+			Frame::Text(TextInformationFrame { value, .. }) => is_latin1(value),
+			Frame::UserUrl(ExtendedUrlFrame { description, .. }) => is_latin1(description),
+			Frame::Picture(AttachedPictureFrame { picture, .. }) => {
+				picture.description().map_or(true, is_latin1)
+			},
+			Frame::KeyValue(KeyValueFrame {
+				key_value_pairs, ..
+			}) => key_value_pairs
+				.iter()
+				.all(|(key, value)| is_latin1(key) && is_latin1(value)),
+			Frame::Ownership(OwnershipFrame { seller, .. }) => is_latin1(seller),
+			_ => true,
+		}
+	}
+
+	/// Creates a copy of the frame with its contents made ID3v2.3 compatible
+	///
+	/// Returns `None` if the frame can be written as-is.
+	fn downgrade_to_id3v23(&self) -> Option<Self> {
+		let encoding = match self {
+			Frame::Comment(CommentFrame { encoding, .. })
+			| Frame::UnsynchronizedText(UnsynchronizedTextFrame { encoding, .. })
+			| Frame::Text(TextInformationFrame { encoding, .. })
+			| Frame::UserText(ExtendedTextFrame { encoding, .. })
+			| Frame::UserUrl(ExtendedUrlFrame { encoding, .. })
+			| Frame::Picture(AttachedPictureFrame { encoding, .. })
+			| Frame::KeyValue(KeyValueFrame { encoding, .. })
+			| Frame::Ownership(OwnershipFrame { encoding, .. }) => *encoding,
+			_ => return None,
+		};
+
+		// ID3v2.3 has no concept of multiple values, the convention is to separate them with a "/"
+		let multiple_values = matches!(self, Frame::Text(text) if text.value.contains('\0'));
+
+		if encoding.to_id3v23() == encoding && !multiple_values {
+			return None;
+		}
+
+		let mut frame = self.clone();
+		match &mut frame {
+			Frame::Comment(CommentFrame { encoding, .. })
+			| Frame::UnsynchronizedText(UnsynchronizedTextFrame { encoding, .. })
+			| Frame::UserText(ExtendedTextFrame { encoding, .. })
+			| Frame::UserUrl(ExtendedUrlFrame { encoding, .. })
+			| Frame::Picture(AttachedPictureFrame { encoding, .. })
+			| Frame::KeyValue(KeyValueFrame { encoding, .. })
+			| Frame::Ownership(OwnershipFrame { encoding, .. }) => *encoding = encoding.to_id3v23(),
+			Frame::Text(text) => {
+				text.encoding = text.encoding.to_id3v23();
+				text.value = text.value.replace('\0', "/");
+			},
+			_ => unreachable!(),
+		}
+
+		Some(frame)
+	}
+
 	/// Used for errors in write::frame::verify_frame
 	pub(super) fn name(&self) -> &'static str {
 		match self {
@@ -1,12 +1,14 @@
 use super::header::parse::{parse_header, parse_v2_header};
 use super::Frame;
-use crate::config::ParsingMode;
+use crate::config::{ParseOptions, ParsingMode};
 use crate::error::{Id3v2Error, Id3v2ErrorKind, Result};
 use crate::id3::v2::frame::content::parse_content;
 use crate::id3::v2::header::Id3v2Version;
+use crate::id3::v2::items::AttachedPictureFrame;
 use crate::id3::v2::util::synchsafe::{SynchsafeInteger, UnsynchronizedStream};
 use crate::id3::v2::{BinaryFrame, FrameFlags, FrameHeader, FrameId};
 use crate::macros::try_vec;
+use crate::picture::PictureLocation;
 
 use std::io::Read;
 
@@ -19,14 +21,20 @@ pub(crate) enum ParsedFrame<'a> {
 }
 
 impl<'a> ParsedFrame<'a> {
+	/// Read the next frame
+	///
+	/// `frame_offset` is the offset of the frame in the file, if known. It's needed to skip pictures,
+	/// see [`ParseOptions::read_pictures`].
 	pub(crate) fn read<R>(
 		reader: &mut R,
 		version: Id3v2Version,
-		parse_mode: ParsingMode,
+		parse_options: ParseOptions,
+		frame_offset: Option<u64>,
 	) -> Result<Self>
 	where
 		R: Read,
 	{
+		let parse_mode = parse_options.parsing_mode;
 		let mut size = 0u32;
 
 		// The header will be upgraded to ID3v2.4 past this point, so they can all be treated the same
@@ -62,6 +70,25 @@ impl<'a> ParsedFrame<'a> {
 			return Ok(Self::Skip { size });
 		}
 
+		// The picture data can only be skipped if it's stored as-is, otherwise it's read as usual
+		if !parse_options.read_pictures
+			&& id.as_str() == "APIC"
+			&& !flags.unsynchronisation
+			&& !flags.compression
+			&& flags.encryption.is_none()
+			&& flags.grouping_identity.is_none()
+			&& flags.data_length_indicator.is_none()
+		{
+			if let Some(frame_offset) = frame_offset {
+				let header_len = match version {
+					Id3v2Version::V2 => 6,
+					Id3v2Version::V3 | Id3v2Version::V4 => 10,
+				};
+
+				return skip_picture(reader, size, flags, version, frame_offset + header_len);
+			}
+		}
+
 		// Get the encryption method symbol
 		if let Some(enc) = flags.encryption.as_mut() {
 			log::trace!("Reading encryption method symbol");
@@ -140,7 +167,7 @@ impl<'a> ParsedFrame<'a> {
 						id,
 						flags,
 						version,
-						parse_mode,
+						parse_options,
 					);
 				}
 
@@ -154,7 +181,7 @@ impl<'a> ParsedFrame<'a> {
 					id,
 					flags,
 					version,
-					parse_mode,
+					parse_options,
 				);
 			},
 			// Possible combinations:
@@ -176,7 +203,7 @@ impl<'a> ParsedFrame<'a> {
 					id,
 					flags,
 					version,
-					parse_mode,
+					parse_options,
 				);
 			},
 			// Possible combinations:
@@ -190,7 +217,7 @@ impl<'a> ParsedFrame<'a> {
 			},
 			// Everything else that doesn't have special flags
 			_ => {
-				return parse_frame(&mut reader, size, id, flags, version, parse_mode);
+				return parse_frame(&mut reader, size, id, flags, version, parse_options);
 			},
 		}
 	}
@@ -230,15 +257,41 @@ fn handle_encryption<R: Read>(
 	Ok(ParsedFrame::Next(encrypted_frame))
 }
 
+fn skip_picture<R: Read>(
+	reader: &mut R,
+	size: u32,
+	flags: FrameFlags,
+	version: Id3v2Version,
+	content_offset: u64,
+) -> Result<ParsedFrame<'static>> {
+	log::trace!("Skipping picture data");
+
+	let mut reader = reader.take(u64::from(size));
+	let mut frame = AttachedPictureFrame::parse_without_data(&mut reader, flags, version)?;
+
+	let data_len = reader.limit();
+	let skipped = std::io::copy(&mut reader, &mut std::io::sink())?;
+	if skipped != data_len {
+		return Err(Id3v2Error::new(Id3v2ErrorKind::BadFrameLength).into());
+	}
+
+	frame.picture.location = Some(PictureLocation {
+		offset: content_offset + (u64::from(size) - data_len),
+		len: data_len,
+	});
+
+	Ok(ParsedFrame::Next(Frame::Picture(frame)))
+}
+
 fn parse_frame<R: Read>(
 	reader: &mut R,
 	size: u32,
 	id: FrameId<'static>,
 	flags: FrameFlags,
 	version: Id3v2Version,
-	parse_mode: ParsingMode,
+	parse_options: ParseOptions,
 ) -> Result<ParsedFrame<'static>> {
-	match parse_content(reader, id, flags, version, parse_mode)? {
+	match parse_content(reader, id, flags, version, parse_options)? {
 		Some(frame) => Ok(ParsedFrame::Next(frame)),
 		None => Ok(ParsedFrame::Skip { size }),
 	}
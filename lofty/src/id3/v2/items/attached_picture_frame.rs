@@ -63,6 +63,26 @@ impl<'a> AttachedPictureFrame<'a> {
 	///
 	/// * The format is not "PNG" or "JPG"
 	pub fn parse<R>(reader: &mut R, frame_flags: FrameFlags, version: Id3v2Version) -> Result<Self>
+	where
+		R: Read,
+	{
+		let mut frame = Self::parse_without_data(reader, frame_flags, version)?;
+
+		let mut data = Vec::new();
+		reader.read_to_end(&mut data)?;
+
+		frame.picture.data = Cow::from(data);
+		Ok(frame)
+	}
+
+	/// Same as [`AttachedPictureFrame::parse`], but stops before the picture data
+	///
+	/// The data is left in `reader`, so it can be skipped, see [`Picture::load`].
+	pub(crate) fn parse_without_data<R>(
+		reader: &mut R,
+		frame_flags: FrameFlags,
+		version: Id3v2Version,
+	) -> Result<Self>
 	where
 		R: Read,
 	{
@@ -105,14 +125,12 @@ impl<'a> AttachedPictureFrame<'a> {
 		.text_or_none()
 		.map(Cow::from);
 
-		let mut data = Vec::new();
-		reader.read_to_end(&mut data)?;
-
 		let picture = Picture {
 			pic_type,
 			mime_type,
 			description,
-			data: Cow::from(data),
+			data: Cow::Owned(Vec::new()),
+			location: None,
 		};
 
 		let header = FrameHeader::new(FRAME_ID, frame_flags);
@@ -130,11 +148,14 @@ impl<'a> AttachedPictureFrame<'a> {
 	/// # Errors
 	///
 	/// * Too much data was provided
+	/// * The picture was skipped while reading, see [`Picture::load`]
 	///
 	/// ID3v2.2:
 	///
 	/// * The mimetype is not [`MimeType::Png`] or [`MimeType::Jpeg`]
 	pub fn as_bytes(&self, version: Id3v2Version) -> Result<Vec<u8>> {
+		self.picture.check_loaded()?;
+
 		let mut data = vec![self.encoding as u8];
 
 		let max_size = match version {
@@ -0,0 +1,378 @@
+use crate::config::{ParseOptions, ParsingMode};
+use crate::error::{Id3v2Error, Id3v2ErrorKind, Result};
+use crate::id3::v2::frame::read::ParsedFrame;
+use crate::id3::v2::frame::FrameRef;
+use crate::id3::v2::header::Id3v2Version;
+use crate::id3::v2::tag::{new_picture_frame, new_text_frame};
+use crate::id3::v2::write::frame::{create_items, write_items};
+use crate::id3::v2::{AttachedPictureFrame, Frame, FrameId, TextInformationFrame};
+use crate::picture::Picture;
+use crate::util::text::{decode_text, encode_text, TextDecodeOptions, TextEncoding};
+
+use std::borrow::Cow;
+use std::io::{Read, Write};
+
+use byteorder::{BigEndian, ReadBytesExt, WriteBytesExt};
+
+pub(in crate::id3::v2) const CHAPTER_ID: FrameId<'static> = FrameId::Valid(Cow::Borrowed("CHAP"));
+pub(in crate::id3::v2) const TABLE_OF_CONTENTS_ID: FrameId<'static> =
+	FrameId::Valid(Cow::Borrowed("CTOC"));
+
+const TITLE_ID: FrameId<'static> = FrameId::Valid(Cow::Borrowed("TIT2"));
+
+// Used for the start/end byte offsets, which we don't make use of
+const OFFSET_UNUSED: u32 = u32::MAX;
+
+// CTOC flags
+const TOP_LEVEL: u8 = 0b10;
+const ORDERED: u8 = 0b01;
+
+/// A chapter, stored in an ID3v2 `CHAP` frame
+///
+/// Since `CHAP` frames are stored as [`Frame::Binary`], chapters are best handled with
+/// [`Id3v2Tag::chapters`](crate::id3::v2::Id3v2Tag::chapters) and
+/// [`Id3v2Tag::add_chapter`](crate::id3::v2::Id3v2Tag::add_chapter).
+///
+/// Only the title (`TIT2`) and image (`APIC`) sub-frames are retained, any others will be discarded.
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Default)]
+pub struct Chapter {
+	/// A unique identifier for the chapter within the tag
+	///
+	/// If this is empty when using [`Id3v2Tag::add_chapter`](crate::id3::v2::Id3v2Tag::add_chapter),
+	/// one will be generated.
+	pub element_id: String,
+	/// The start of the chapter, in milliseconds
+	pub start_ms: u32,
+	/// The end of the chapter, in milliseconds
+	pub end_ms: u32,
+	/// The chapter title
+	pub title: Option<String>,
+	/// An image for the chapter
+	pub image: Option<Picture>,
+}
+
+impl Chapter {
+	/// Create a new [`Chapter`]
+	pub fn new(start_ms: u32, end_ms: u32, title: Option<String>) -> Self {
+		Self {
+			start_ms,
+			end_ms,
+			title,
+			..Self::default()
+		}
+	}
+
+	/// Read a [`Chapter`] from the contents of a `CHAP` frame
+	///
+	/// NOTE: This expects the frame header to have already been skipped, and the sub-frames to be
+	///       ID3v2.4 (as they are in an [`Id3v2Tag`](crate::id3::v2::Id3v2Tag))
+	///
+	/// # Errors
+	///
+	/// * `data` doesn't contain enough data
+	/// * A sub-frame is invalid, see [`ParsingMode`]
+	pub fn parse(data: &[u8], parse_mode: ParsingMode) -> Result<Self> {
+		let mut reader = data;
+
+		let element_id = read_element_id(&mut reader)?;
+		let start_ms = reader.read_u32::<BigEndian>()?;
+		let end_ms = reader.read_u32::<BigEndian>()?;
+		let _start_offset = reader.read_u32::<BigEndian>()?;
+		let _end_offset = reader.read_u32::<BigEndian>()?;
+
+		let mut chapter = Self {
+			element_id,
+			start_ms,
+			end_ms,
+			..Self::default()
+		};
+
+		for frame in read_sub_frames(
+			&mut reader,
+			Id3v2Version::V4,
+			ParseOptions::new().parsing_mode(parse_mode),
+		)? {
+			match frame {
+				Frame::Text(TextInformationFrame { header, value, .. })
+					if header.id == TITLE_ID =>
+				{
+					chapter.title = Some(value);
+				},
+				Frame::Picture(AttachedPictureFrame { picture, .. }) => {
+					chapter.image = Some(picture);
+				},
+				_ => {},
+			}
+		}
+
+		Ok(chapter)
+	}
+
+	/// Convert a [`Chapter`] to the contents of a `CHAP` frame
+	///
+	/// NOTE: This does not include the frame header
+	///
+	/// # Errors
+	///
+	/// * `element_id` is empty or not ISO-8859-1
+	/// * The title or image could not be written
+	pub fn as_bytes(&self) -> Result<Vec<u8>> {
+		let mut bytes = element_id_bytes(&CHAPTER_ID, &self.element_id)?;
+
+		bytes.write_u32::<BigEndian>(self.start_ms)?;
+		bytes.write_u32::<BigEndian>(self.end_ms)?;
+		bytes.write_u32::<BigEndian>(OFFSET_UNUSED)?;
+		bytes.write_u32::<BigEndian>(OFFSET_UNUSED)?;
+
+		let mut sub_frames = Vec::new();
+		if let Some(title) = &self.title {
+			sub_frames.push(new_text_frame(TITLE_ID, title.clone()));
+		}
+
+		if let Some(image) = &self.image {
+			sub_frames.push(new_picture_frame(image.clone()));
+		}
+
+		create_items(
+			&mut bytes,
+			&mut sub_frames.into_iter().map(|f| FrameRef(Cow::Owned(f))),
+			false,
+			None,
+		)?;
+
+		Ok(bytes)
+	}
+}
+
+/// The contents of a `CTOC` frame
+///
+/// Any sub-frames are kept as-is.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub(in crate::id3::v2) struct TableOfContents {
+	pub(in crate::id3::v2) element_id: String,
+	pub(in crate::id3::v2) top_level: bool,
+	pub(in crate::id3::v2) ordered: bool,
+	pub(in crate::id3::v2) children: Vec<String>,
+	pub(in crate::id3::v2) sub_frames: Vec<u8>,
+}
+
+impl TableOfContents {
+	pub(in crate::id3::v2) fn parse(data: &[u8]) -> Result<Self> {
+		let mut reader = data;
+
+		let element_id = read_element_id(&mut reader)?;
+		let flags = reader.read_u8()?;
+		let entry_count = reader.read_u8()?;
+
+		let mut children = Vec::with_capacity(usize::from(entry_count));
+		for _ in 0..entry_count {
+			children.push(read_element_id(&mut reader)?);
+		}
+
+		Ok(Self {
+			element_id,
+			top_level: flags & TOP_LEVEL == TOP_LEVEL,
+			ordered: flags & ORDERED == ORDERED,
+			children,
+			sub_frames: reader.to_vec(),
+		})
+	}
+
+	pub(in crate::id3::v2) fn as_bytes(&self) -> Result<Vec<u8>> {
+		let Ok(entry_count) = u8::try_from(self.children.len()) else {
+			return Err(Id3v2Error::new(Id3v2ErrorKind::BadFrame(
+				TABLE_OF_CONTENTS_ID.as_str().to_owned(),
+				"Table of contents has more than 255 entries",
+			))
+			.into());
+		};
+
+		let mut bytes = element_id_bytes(&TABLE_OF_CONTENTS_ID, &self.element_id)?;
+
+		let mut flags = 0;
+		if self.top_level {
+			flags |= TOP_LEVEL;
+		}
+
+		if self.ordered {
+			flags |= ORDERED;
+		}
+
+		bytes.write_u8(flags)?;
+		bytes.write_u8(entry_count)?;
+
+		for child in &self.children {
+			bytes.write_all(&element_id_bytes(&TABLE_OF_CONTENTS_ID, child)?)?;
+		}
+
+		bytes.write_all(&self.sub_frames)?;
+
+		Ok(bytes)
+	}
+}
+
+fn read_element_id(reader: &mut &[u8]) -> Result<String> {
+	if reader.is_empty() {
+		return Err(Id3v2Error::new(Id3v2ErrorKind::BadFrameLength).into());
+	}
+
+	Ok(decode_text(
+		reader,
+		TextDecodeOptions::new()
+			.encoding(TextEncoding::Latin1)
+			.terminated(true),
+	)?
+	.content)
+}
+
+fn element_id_bytes(frame_id: &FrameId<'_>, element_id: &str) -> Result<Vec<u8>> {
+	if element_id.is_empty() || element_id.chars().any(|c| u32::from(c) > 0xFF) {
+		return Err(Id3v2Error::new(Id3v2ErrorKind::BadFrame(
+			frame_id.as_str().to_owned(),
+			"Element ID must be non-empty ISO-8859-1 text",
+		))
+		.into());
+	}
+
+	Ok(encode_text(element_id, TextEncoding::Latin1, true))
+}
+
+/// Re-encode the sub-frames of a `CHAP` or `CTOC` frame
+///
+/// Sub-frames are kept as ID3v2.4 in memory, so they need to be converted when reading other versions,
+/// and when writing ID3v2.3 tags.
+///
+/// The sub-frames are re-encoded with `text_encoding`, like any other frame (see [`WriteOptions::id3v2_text_encoding`]).
+/// The IDs of any sub-frames that can't be written to ID3v2.3 are added to `discarded`.
+///
+/// [`WriteOptions::id3v2_text_encoding`]: crate::config::WriteOptions::id3v2_text_encoding
+pub(in crate::id3::v2) fn convert_sub_frames(
+	frame_id: &FrameId<'_>,
+	data: &[u8],
+	version: Id3v2Version,
+	parse_options: ParseOptions,
+	is_id3v23: bool,
+	text_encoding: Option<TextEncoding>,
+	discarded: &mut Vec<String>,
+) -> Result<Vec<u8>> {
+	let mut reader = data;
+
+	read_element_id(&mut reader)?;
+	if *frame_id == CHAPTER_ID {
+		// Start/end times and offsets
+		reader.read_exact(&mut [0; 16])?;
+	} else {
+		let _flags = reader.read_u8()?;
+		let entry_count = reader.read_u8()?;
+		for _ in 0..entry_count {
+			read_element_id(&mut reader)?;
+		}
+	}
+
+	let header_len = data.len() - reader.len();
+	let sub_frames = read_sub_frames(&mut reader, version, parse_options)?;
+
+	let mut bytes = data[..header_len].to_vec();
+	write_items(
+		&mut bytes,
+		&mut sub_frames.into_iter().map(|f| FrameRef(Cow::Owned(f))),
+		is_id3v23,
+		text_encoding,
+		discarded,
+	)?;
+
+	Ok(bytes)
+}
+
+fn read_sub_frames(
+	reader: &mut &[u8],
+	version: Id3v2Version,
+	parse_options: ParseOptions,
+) -> Result<Vec<Frame<'static>>> {
+	let mut frames = Vec::new();
+
+	// Anything smaller than a frame header is padding
+	while reader.len() >= 10 {
+		match ParsedFrame::read(reader, version, parse_options, None)? {
+			ParsedFrame::Next(frame) => frames.push(frame),
+			ParsedFrame::Skip { size } => {
+				let size = (size as usize).min(reader.len());
+				*reader = &reader[size..];
+			},
+			ParsedFrame::Eof => break,
+		}
+	}
+
+	Ok(frames)
+}
+
+#[cfg(test)]
+mod tests {
+	use super::TableOfContents;
+	use crate::config::ParsingMode;
+	use crate::id3::v2::Chapter;
+	use crate::picture::{MimeType, Picture, PictureType};
+
+	#[test]
+	fn chapter_round_trip() {
+		let chapter = Chapter {
+			element_id: String::from("chp0"),
+			start_ms: 0,
+			end_ms: 30_000,
+			title: Some(String::from("Foo chapter")),
+			image: Some(Picture::new_unchecked(
+				PictureType::Other,
+				Some(MimeType::Png),
+				None,
+				vec![1; 10],
+			)),
+		};
+
+		let bytes = chapter.as_bytes().unwrap();
+		assert_eq!(&bytes[..5], b"chp0\0");
+
+		let parsed = Chapter::parse(&bytes, ParsingMode::Strict).unwrap();
+		assert_eq!(parsed, chapter);
+
+		// No sub-frames
+		let chapter = Chapter {
+			element_id: String::from("chp1"),
+			..Chapter::new(30_000, 60_000, None)
+		};
+
+		let bytes = chapter.as_bytes().unwrap();
+		assert_eq!(bytes.len(), 5 + 16);
+		assert_eq!(
+			Chapter::parse(&bytes, ParsingMode::Strict).unwrap(),
+			chapter
+		);
+
+		// Missing element ID
+		assert!(Chapter::new(0, 1, None).as_bytes().is_err());
+		assert!(Chapter::parse(&[], ParsingMode::Strict).is_err());
+	}
+
+	#[test]
+	fn table_of_contents_round_trip() {
+		let mut toc = TableOfContents {
+			element_id: String::from("toc"),
+			top_level: true,
+			ordered: true,
+			children: vec![String::from("chp0"), String::from("chp1")],
+			sub_frames: Vec::new(),
+		};
+
+		let bytes = toc.as_bytes().unwrap();
+		assert_eq!(bytes, b"toc\0\x03\x02chp0\0chp1\0");
+
+		assert_eq!(TableOfContents::parse(&bytes).unwrap(), toc);
+
+		// Sub-frames are kept as-is
+		toc.ordered = false;
+		toc.sub_frames = b"TIT2\0\0\0\x04\0\0\0Foo".to_vec();
+
+		let bytes = toc.as_bytes().unwrap();
+		assert_eq!(&bytes[..6], b"toc\0\x02\x02");
+		assert_eq!(TableOfContents::parse(&bytes).unwrap(), toc);
+	}
+}
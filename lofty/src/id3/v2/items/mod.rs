@@ -1,6 +1,7 @@
 mod attached_picture_frame;
 mod audio_text_frame;
 mod binary_frame;
+pub(in crate::id3::v2) mod chapter;
 mod encapsulated_object;
 mod event_timing_codes_frame;
 mod extended_text_frame;
@@ -20,6 +21,7 @@ mod url_link_frame;
 pub use attached_picture_frame::AttachedPictureFrame;
 pub use audio_text_frame::{scramble, AudioTextFrame, AudioTextFrameFlags};
 pub use binary_frame::BinaryFrame;
+pub use chapter::Chapter;
 pub use encapsulated_object::GeneralEncapsulatedObject;
 pub use event_timing_codes_frame::{Event, EventTimingCodesFrame, EventType};
 pub use extended_text_frame::ExtendedTextFrame;
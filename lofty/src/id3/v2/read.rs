@@ -1,16 +1,21 @@
 use super::frame::read::ParsedFrame;
-use super::header::Id3v2Header;
+use super::header::{Id3v2Header, Id3v2Version};
 use super::tag::Id3v2Tag;
-use crate::config::ParsingMode;
+use crate::config::ParseOptions;
 use crate::error::{Id3v2Error, Id3v2ErrorKind, Result};
 use crate::id3::v2::util::synchsafe::UnsynchronizedStream;
 
 use std::io::Read;
 
+/// Parse an ID3v2 tag, with `bytes` positioned after the header
+///
+/// `frames_offset` is the offset of the first frame in the file, if `bytes` is reading the file directly.
+/// It's needed to skip pictures, see [`ParseOptions::read_pictures`].
 pub(crate) fn parse_id3v2<R>(
 	bytes: &mut R,
 	header: Id3v2Header,
-	parse_mode: ParsingMode,
+	parse_options: ParseOptions,
+	frames_offset: Option<u64>,
 ) -> Result<Id3v2Tag>
 where
 	R: Read,
@@ -21,18 +26,26 @@ where
 		header.version
 	);
 
-	let mut tag_bytes = bytes.take(u64::from(header.size - header.extended_size));
+	parse_options.check_tag_size(u64::from(header.size))?;
+
+	let tag_len = u64::from(header.size - header.extended_size);
+	let mut tag_bytes = bytes.take(tag_len);
 
 	let ret;
 	if header.flags.unsynchronisation {
 		// Unsynchronize the entire tag
+		//
+		// The frames can't be located in the file anymore
 		let mut unsynchronized_reader = UnsynchronizedStream::new(tag_bytes);
-		ret = read_all_frames_into_tag(&mut unsynchronized_reader, header, parse_mode)?;
+		ret =
+			read_all_frames_into_tag(&mut unsynchronized_reader, header, parse_options, |_| None)?;
 
 		// Get the `Take` back from the `UnsynchronizedStream`
 		tag_bytes = unsynchronized_reader.into_inner();
 	} else {
-		ret = read_all_frames_into_tag(&mut tag_bytes, header, parse_mode)?;
+		ret = read_all_frames_into_tag(&mut tag_bytes, header, parse_options, |reader| {
+			frames_offset.map(|offset| offset + (tag_len - reader.limit()))
+		})?;
 	};
 
 	// Throw away the rest of the tag (padding, bad frames)
@@ -40,6 +53,22 @@ where
 	Ok(ret)
 }
 
+/// Parse an ID3v2 tag from a window of bytes, starting with the tag header
+///
+/// This is used by containers that store the tag in a chunk, rather than directly in the stream.
+/// `window_offset` is the offset of `window` in the file, if known.
+pub(crate) fn parse_id3v2_window(
+	window: &[u8],
+	parse_options: ParseOptions,
+	window_offset: Option<u64>,
+) -> Result<Id3v2Tag> {
+	let reader = &mut &*window;
+
+	let header = Id3v2Header::parse(reader)?;
+	let frames_offset = window_offset.map(|offset| offset + (window.len() - reader.len()) as u64);
+	parse_id3v2(reader, header, parse_options, frames_offset)
+}
+
 fn skip_frame(reader: &mut impl Read, size: u32) -> Result<()> {
 	log::trace!("Skipping frame of size {}", size);
 
@@ -56,7 +85,8 @@ fn skip_frame(reader: &mut impl Read, size: u32) -> Result<()> {
 fn read_all_frames_into_tag<R>(
 	reader: &mut R,
 	header: Id3v2Header,
-	parse_mode: ParsingMode,
+	parse_options: ParseOptions,
+	frame_offset: impl Fn(&R) -> Option<u64>,
 ) -> Result<Id3v2Tag>
 where
 	R: Read,
@@ -66,7 +96,8 @@ where
 	tag.set_flags(header.flags);
 
 	loop {
-		match ParsedFrame::read(reader, header.version, parse_mode)? {
+		let offset = frame_offset(reader);
+		match ParsedFrame::read(reader, header.version, parse_options, offset)? {
 			ParsedFrame::Next(frame) => {
 				let frame_value_is_empty = frame.is_empty();
 				if let Some(replaced_frame) = tag.insert(frame) {
@@ -99,24 +130,34 @@ where
 		}
 	}
 
+	if header.version == Id3v2Version::V3 {
+		tag.merge_id3v23_date();
+	}
+
 	Ok(tag)
 }
 
 #[test]
 fn zero_size_id3v2() {
-	use crate::config::ParsingMode;
+	use crate::config::{ParseOptions, ParsingMode};
 	use crate::id3::v2::header::Id3v2Header;
 
 	use std::io::Cursor;
 
 	let mut f = Cursor::new(std::fs::read("tests/tags/assets/id3v2/zero.id3v2").unwrap());
 	let header = Id3v2Header::parse(&mut f).unwrap();
-	assert!(parse_id3v2(&mut f, header, ParsingMode::Strict).is_ok());
+	assert!(parse_id3v2(
+		&mut f,
+		header,
+		ParseOptions::new().parsing_mode(ParsingMode::Strict),
+		None,
+	)
+	.is_ok());
 }
 
 #[test]
 fn bad_frame_id_relaxed_id3v2() {
-	use crate::config::ParsingMode;
+	use crate::config::{ParseOptions, ParsingMode};
 	use crate::id3::v2::header::Id3v2Header;
 	use crate::prelude::*;
 
@@ -128,7 +169,12 @@ fn bad_frame_id_relaxed_id3v2() {
 		std::fs::read("tests/tags/assets/id3v2/bad_frame_otherwise_valid.id3v24").unwrap(),
 	);
 	let header = Id3v2Header::parse(&mut f).unwrap();
-	let id3v2 = parse_id3v2(&mut f, header, ParsingMode::Relaxed);
+	let id3v2 = parse_id3v2(
+		&mut f,
+		header,
+		ParseOptions::new().parsing_mode(ParsingMode::Relaxed),
+		None,
+	);
 	assert!(id3v2.is_ok());
 
 	let id3v2 = id3v2.unwrap();
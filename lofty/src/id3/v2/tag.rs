@@ -2,14 +2,19 @@
 mod tests;
 
 use super::frame::{Frame, EMPTY_CONTENT_DESCRIPTOR};
-use super::header::{Id3v2TagFlags, Id3v2Version};
-use crate::config::{global_options, WriteOptions};
+use super::header::{Id3v2Header, Id3v2TagFlags, Id3v2Version};
+use super::read::parse_id3v2;
+use crate::config::{global_options, ParseOptions, ParsingMode, WriteOptions};
 use crate::error::{LoftyError, Result};
-use crate::id3::v1::GENRES;
+use crate::file::write_verified;
+use crate::id3::v1::tag::Id3v1TagRef;
+use crate::id3::v1::{genre_from_id, genre_id};
 use crate::id3::v2::frame::{FrameRef, MUSICBRAINZ_UFID_OWNER};
+use crate::id3::v2::items::chapter::{TableOfContents, CHAPTER_ID, TABLE_OF_CONTENTS_ID};
 use crate::id3::v2::items::{
-	AttachedPictureFrame, CommentFrame, ExtendedTextFrame, ExtendedUrlFrame, TextInformationFrame,
-	UniqueFileIdentifierFrame, UnsynchronizedTextFrame, UrlLinkFrame,
+	AttachedPictureFrame, Chapter, CommentFrame, ExtendedTextFrame, ExtendedUrlFrame,
+	SyncTextContentType, SynchronizedTextFrame, TextInformationFrame, UniqueFileIdentifierFrame,
+	UnsynchronizedTextFrame, UrlLinkFrame,
 };
 use crate::id3::v2::util::mappings::TIPL_MAPPINGS;
 use crate::id3::v2::util::pairs::{
@@ -21,14 +26,15 @@ use crate::picture::{Picture, PictureType, TOMBSTONE_PICTURE};
 use crate::tag::companion_tag::CompanionTag;
 use crate::tag::items::{Lang, Timestamp, UNKNOWN_LANGUAGE};
 use crate::tag::{
-	try_parse_year, Accessor, ItemKey, ItemValue, MergeTag, SplitTag, Tag, TagExt, TagItem, TagType,
+	try_parse_year, verify_year, Accessor, ItemKey, ItemValue, MergeTag, SplitTag, Tag, TagExt,
+	TagItem, TagType,
 };
 use crate::util::flag_item;
 use crate::util::io::{FileLike, Length, Truncate};
 use crate::util::text::{decode_text, TextDecodeOptions, TextEncoding};
 
 use std::borrow::Cow;
-use std::io::{Cursor, Write};
+use std::io::{Cursor, Read, Write};
 use std::iter::Peekable;
 use std::ops::Deref;
 use std::str::FromStr;
@@ -103,6 +109,7 @@ macro_rules! impl_accessor {
 /// otherwise [`ItemKey::Unknown`] will be used.
 /// * Frames that require a language (COMM/USLT) - With ID3v2 being the only format that allows for language-specific items, this information is not retained.
 /// * POPM - These frames will be stored as a raw [`ItemValue::Binary`] value under the [`ItemKey::Popularimeter`] key.
+/// They can be read as a 0-100 rating with [`Tag::rating`].
 ///
 /// ## Special Frames
 ///
@@ -111,10 +118,14 @@ macro_rules! impl_accessor {
 /// and [`SynchronizedText::parse`](crate::id3::v2::SynchronizedTextFrame::parse) respectively, and converted back to binary with
 /// [`GeneralEncapsulatedObject::as_bytes`](crate::id3::v2::GeneralEncapsulatedObject::as_bytes) and
 /// [`SynchronizedText::as_bytes`](crate::id3::v2::SynchronizedTextFrame::as_bytes) for writing.
+///
+/// For synchronized lyrics, this is handled by [`Id3v2Tag::synchronized_lyrics`] and [`Id3v2Tag::insert_synchronized_lyrics`].
+///
+/// Chapters (`CHAP` and `CTOC` frames) are also stored as [`Frame::Binary`], see [`Id3v2Tag::chapters`] and [`Id3v2Tag::add_chapter`].
 #[derive(PartialEq, Eq, Debug, Clone)]
 #[tag(
 	description = "An `ID3v2` tag",
-	supported_formats(Aac, Aiff, Mpeg, Wav, read_only(Ape, Flac, Mpc))
+	supported_formats(Aac, Aiff, Dsf, Mpeg, Wav, read_only(Ape, Flac, Mpc))
 )]
 pub struct Id3v2Tag {
 	flags: Id3v2TagFlags,
@@ -183,6 +194,44 @@ impl Id3v2Tag {
 	pub fn original_version(&self) -> Id3v2Version {
 		self.original_version
 	}
+
+	/// Read a tag previously written with [`TagExt::dump_to`]
+	///
+	/// # Errors
+	///
+	/// * `reader` does not start with an ID3v2 header
+	/// * The tag is otherwise malformed, see [`ParsingMode`]
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use lofty::config::{ParsingMode, WriteOptions};
+	/// use lofty::id3::v2::Id3v2Tag;
+	/// use lofty::tag::{Accessor, TagExt};
+	///
+	/// # fn main() -> lofty::error::Result<()> {
+	/// let mut tag = Id3v2Tag::new();
+	/// tag.set_title(String::from("Foo title"));
+	///
+	/// let mut dump = Vec::new();
+	/// tag.dump_to(&mut dump, WriteOptions::default())?;
+	///
+	/// let re_read = Id3v2Tag::read_from_dump(&mut &dump[..], ParsingMode::default())?;
+	/// assert_eq!(re_read.title().as_deref(), Some("Foo title"));
+	/// # Ok(()) }
+	/// ```
+	pub fn read_from_dump<R>(reader: &mut R, parse_mode: ParsingMode) -> Result<Self>
+	where
+		R: Read,
+	{
+		let header = Id3v2Header::parse(reader)?;
+		parse_id3v2(
+			reader,
+			header,
+			ParseOptions::new().parsing_mode(parse_mode),
+			None,
+		)
+	}
 }
 
 impl Id3v2Tag {
@@ -191,6 +240,32 @@ impl Id3v2Tag {
 		self.frames.iter().find(|f| f.id() == id)
 	}
 
+	/// Gets a mutable reference to a [`Frame`] from an id
+	///
+	/// Editing a frame in place will retain its [`FrameFlags`](crate::id3::v2::FrameFlags).
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use lofty::id3::v2::{Frame, FrameId, Id3v2Tag};
+	/// use lofty::tag::Accessor;
+	/// use std::borrow::Cow;
+	///
+	/// const TITLE_ID: FrameId<'_> = FrameId::Valid(Cow::Borrowed("TIT2"));
+	///
+	/// let mut tag = Id3v2Tag::new();
+	/// tag.set_title(String::from("Foo"));
+	///
+	/// if let Some(Frame::Text(frame)) = tag.get_mut(&TITLE_ID) {
+	/// 	frame.value = String::from("Bar");
+	/// }
+	///
+	/// assert_eq!(tag.get_text(&TITLE_ID), Some("Bar"));
+	/// ```
+	pub fn get_mut(&mut self, id: &FrameId<'_>) -> Option<&mut Frame<'static>> {
+		self.frames.iter_mut().find(|f| f.id() == id)
+	}
+
 	/// Gets the text for a frame
 	///
 	/// NOTE: If the tag is [`Id3v2Version::V4`], there could be multiple values separated by null characters (`'\0'`).
@@ -299,6 +374,7 @@ impl Id3v2Tag {
 	/// NOTE: The encoding will be UTF-8
 	///
 	/// This will replace any TXXX frame with the same description, see [`Id3v2Tag::insert`].
+	/// The flags of the replaced frame will be retained.
 	///
 	/// # Examples
 	///
@@ -322,11 +398,7 @@ impl Id3v2Tag {
 		description: String,
 		content: String,
 	) -> Option<Frame<'static>> {
-		self.insert(Frame::UserText(ExtendedTextFrame::new(
-			TextEncoding::UTF8,
-			description,
-			content,
-		)))
+		self.insert_retaining_flags(new_user_text_frame(description, content))
 	}
 
 	/// Inserts a [`Frame`]
@@ -354,6 +426,15 @@ impl Id3v2Tag {
 		replaced
 	}
 
+	// Same as `insert`, but the new frame takes on the flags of the frame it replaces
+	fn insert_retaining_flags(&mut self, mut frame: Frame<'static>) -> Option<Frame<'static>> {
+		if let Some(existing) = self.frames.iter().find(|f| *f == &frame) {
+			frame.set_flags(existing.flags());
+		}
+
+		self.insert(frame)
+	}
+
 	/// Removes a user-defined text frame (`TXXX`) by its description
 	///
 	/// This will return the matching frame.
@@ -386,10 +467,123 @@ impl Id3v2Tag {
 			.map(|pos| self.frames.remove(pos))
 	}
 
+	/// Gets the URL for a user-defined URL frame (`WXXX`)
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use lofty::id3::v2::Id3v2Tag;
+	///
+	/// let mut tag = Id3v2Tag::new();
+	///
+	/// // Add a new "WXXX" frame identified by "SOME_DESCRIPTION"
+	/// let _ = tag.insert_user_url(
+	/// 	String::from("SOME_DESCRIPTION"),
+	/// 	String::from("https://example.com"),
+	/// );
+	///
+	/// // Now we can get the URL back using the description
+	/// let url = tag.get_user_url("SOME_DESCRIPTION");
+	/// assert_eq!(url, Some("https://example.com"));
+	/// ```
+	pub fn get_user_url(&self, description: &str) -> Option<&str> {
+		self.frames.iter().find_map(|frame| match frame {
+			Frame::UserUrl(ExtendedUrlFrame {
+				description: desc,
+				content,
+				..
+			}) if desc == description => Some(content.as_str()),
+			_ => None,
+		})
+	}
+
+	/// Inserts a new user-defined URL frame (`WXXX`)
+	///
+	/// NOTE: The encoding of the description will be UTF-8
+	///
+	/// This will replace any WXXX frame with the same description, see [`Id3v2Tag::insert`].
+	/// The flags of the replaced frame will be retained.
+	pub fn insert_user_url(&mut self, description: String, url: String) -> Option<Frame<'static>> {
+		self.insert_retaining_flags(new_user_url_frame(description, url))
+	}
+
+	/// Removes a user-defined URL frame (`WXXX`) by its description
+	///
+	/// This will return the matching frame.
+	pub fn remove_user_url(&mut self, description: &str) -> Option<Frame<'static>> {
+		self.frames
+			.iter()
+			.position(|frame| {
+				matches!(frame, Frame::UserUrl(ExtendedUrlFrame {
+                             description: desc, ..
+                         }) if desc == description)
+			})
+			.map(|pos| self.frames.remove(pos))
+	}
+
+	/// Gets the text of a comment frame (`COMM`) by its language and description
+	///
+	/// To get all comments with an empty description, see [`Id3v2Tag::comments`].
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use lofty::id3::v2::Id3v2Tag;
+	///
+	/// let mut tag = Id3v2Tag::new();
+	///
+	/// let _ = tag.insert_comment(*b"eng", String::from("Foo"), String::from("Some comment"));
+	///
+	/// assert_eq!(tag.get_comment(*b"eng", "Foo"), Some("Some comment"));
+	///
+	/// // Both the language and description need to match
+	/// assert_eq!(tag.get_comment(*b"deu", "Foo"), None);
+	/// ```
+	pub fn get_comment(&self, language: Lang, description: &str) -> Option<&str> {
+		self.frames.iter().find_map(|frame| {
+			filter_comment_frame_by_description(frame, description)
+				.filter(|comment| comment.language == language)
+				.map(|comment| comment.content.as_str())
+		})
+	}
+
+	/// Inserts a new comment frame (`COMM`)
+	///
+	/// NOTE: The encoding will be UTF-8
+	///
+	/// This will replace any COMM frame with the same language and description, see [`Id3v2Tag::insert`].
+	/// The flags of the replaced frame will be retained.
+	pub fn insert_comment(
+		&mut self,
+		language: Lang,
+		description: String,
+		content: String,
+	) -> Option<Frame<'static>> {
+		self.insert_retaining_flags(Frame::Comment(CommentFrame::new(
+			TextEncoding::UTF8,
+			language,
+			description,
+			content,
+		)))
+	}
+
+	/// Removes a comment frame (`COMM`) by its language and description
+	///
+	/// This will return the matching frame.
+	pub fn remove_comment(&mut self, language: Lang, description: &str) -> Option<Frame<'static>> {
+		self.frames
+			.iter()
+			.position(|frame| {
+				filter_comment_frame_by_description(frame, description)
+					.is_some_and(|comment| comment.language == language)
+			})
+			.map(|pos| self.frames.remove(pos))
+	}
+
 	/// Removes a [`Frame`] by id
 	///
-	/// This will remove any frames with the same ID. To remove `TXXX` frames by their descriptions,
-	/// see [`Id3v2Tag::remove_user_text`].
+	/// This will remove any frames with the same ID. To remove `TXXX`, `WXXX`, or `COMM` frames by their descriptions,
+	/// see [`Id3v2Tag::remove_user_text`], [`Id3v2Tag::remove_user_url`], and [`Id3v2Tag::remove_comment`].
 	///
 	/// # Examples
 	///
@@ -509,6 +703,237 @@ impl Id3v2Tag {
 		})
 	}
 
+	/// Returns all `SYLT` frames with a content type of [`SyncTextContentType::Lyrics`]
+	///
+	/// Since `SYLT` frames are stored as [`Frame::Binary`], they will be parsed here. Any
+	/// frames that fail to parse will be skipped.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use lofty::id3::v2::{Id3v2Tag, SyncTextContentType, SynchronizedTextFrame, TimestampFormat};
+	/// use lofty::TextEncoding;
+	///
+	/// # fn main() -> lofty::error::Result<()> {
+	/// let mut tag = Id3v2Tag::new();
+	///
+	/// let lyrics = SynchronizedTextFrame::new(
+	/// 	TextEncoding::UTF8,
+	/// 	*b"eng",
+	/// 	TimestampFormat::MS,
+	/// 	SyncTextContentType::Lyrics,
+	/// 	None,
+	/// 	vec![(0, String::from("Foo")), (1500, String::from("Bar"))],
+	/// );
+	/// tag.insert_synchronized_lyrics(lyrics.clone())?;
+	///
+	/// assert_eq!(tag.synchronized_lyrics().next(), Some(lyrics));
+	/// # Ok(()) }
+	/// ```
+	pub fn synchronized_lyrics(&self) -> impl Iterator<Item = SynchronizedTextFrame<'static>> + '_ {
+		self.frames.iter().filter_map(parse_synchronized_lyrics)
+	}
+
+	/// Insert a `SYLT` frame
+	///
+	/// The content type will be set to [`SyncTextContentType::Lyrics`], and any existing
+	/// synchronized lyrics with the same language and description will be replaced.
+	///
+	/// # Errors
+	///
+	/// See [`SynchronizedTextFrame::as_bytes`]
+	pub fn insert_synchronized_lyrics(
+		&mut self,
+		mut lyrics: SynchronizedTextFrame<'_>,
+	) -> Result<()> {
+		lyrics.content_type = SyncTextContentType::Lyrics;
+		let data = lyrics.as_bytes()?;
+
+		self.frames.retain(|frame| {
+			parse_synchronized_lyrics(frame).map_or(true, |existing| {
+				existing.language != lyrics.language || existing.description != lyrics.description
+			})
+		});
+		self.frames.push(new_binary_frame(SYLT_ID, data));
+
+		Ok(())
+	}
+
+	/// Remove all `SYLT` frames with a content type of [`SyncTextContentType::Lyrics`]
+	pub fn remove_synchronized_lyrics(&mut self) {
+		self.frames
+			.retain(|frame| parse_synchronized_lyrics(frame).is_none());
+	}
+
+	/// Returns all chapters (`CHAP` frames), sorted by their start time
+	///
+	/// Since `CHAP` frames are stored as [`Frame::Binary`], they will be parsed here. Any
+	/// frames that fail to parse will be skipped.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use lofty::id3::v2::{Chapter, Id3v2Tag};
+	///
+	/// # fn main() -> lofty::error::Result<()> {
+	/// let mut tag = Id3v2Tag::new();
+	///
+	/// tag.add_chapter(Chapter::new(30_000, 60_000, Some(String::from("Bar chapter"))))?;
+	/// tag.add_chapter(Chapter::new(0, 30_000, Some(String::from("Foo chapter"))))?;
+	///
+	/// let chapters = tag.chapters();
+	/// assert_eq!(chapters.len(), 2);
+	/// assert_eq!(chapters[0].title.as_deref(), Some("Foo chapter"));
+	/// # Ok(()) }
+	/// ```
+	pub fn chapters(&self) -> Vec<Chapter> {
+		let mut chapters = self
+			.frames
+			.iter()
+			.filter_map(|frame| match frame {
+				Frame::Binary(BinaryFrame { header, data }) if header.id == CHAPTER_ID => {
+					Chapter::parse(data, ParsingMode::BestAttempt).ok()
+				},
+				_ => None,
+			})
+			.collect::<Vec<_>>();
+
+		chapters.sort_by_key(|chapter| chapter.start_ms);
+		chapters
+	}
+
+	/// Insert a chapter (`CHAP` frame)
+	///
+	/// This will replace any existing chapter with the same [`Chapter::element_id`]. If the element ID
+	/// is empty, a unique one will be generated.
+	///
+	/// The chapter is added to the top-level table of contents (`CTOC` frame), in order of start time
+	/// if the table is ordered, otherwise at the end. Its other entries (including nested tables of
+	/// contents) and sub-frames are kept. If there is no top-level table of contents, one listing every
+	/// chapter in order is created.
+	///
+	/// # Errors
+	///
+	/// * See [`Chapter::as_bytes`]
+	/// * The top-level table of contents would have more than 255 entries
+	pub fn add_chapter(&mut self, mut chapter: Chapter) -> Result<()> {
+		let chapters = self.chapters();
+
+		if chapter.element_id.is_empty() {
+			let mut n = chapters.len();
+			while chapters.iter().any(|c| c.element_id == format!("chp{n}")) {
+				n += 1;
+			}
+
+			chapter.element_id = format!("chp{n}");
+		}
+
+		let chapter_bytes = chapter.as_bytes()?;
+
+		// The null-terminated element ID, used to find the frame to replace
+		let element_id = chapter_bytes[..=chapter.element_id.chars().count()].to_vec();
+
+		let top_level_toc = self
+			.frames
+			.iter()
+			.enumerate()
+			.find_map(|(pos, frame)| match frame {
+				Frame::Binary(BinaryFrame { header, data })
+					if header.id == TABLE_OF_CONTENTS_ID =>
+				{
+					let toc = TableOfContents::parse(data).ok()?;
+					toc.top_level.then_some((pos, toc))
+				},
+				_ => None,
+			});
+
+		let (toc_pos, toc_bytes) = match top_level_toc {
+			Some((pos, mut toc)) => {
+				if toc.ordered {
+					// The chapter may have been moved
+					toc.children.retain(|child| *child != chapter.element_id);
+
+					let start_ms = |child: &String| {
+						chapters
+							.iter()
+							.find(|c| c.element_id == *child)
+							.map(|c| c.start_ms)
+					};
+					let index = toc
+						.children
+						.iter()
+						.position(|child| start_ms(child).is_some_and(|s| s > chapter.start_ms))
+						.unwrap_or(toc.children.len());
+
+					toc.children.insert(index, chapter.element_id.clone());
+				} else if !toc.children.contains(&chapter.element_id) {
+					toc.children.push(chapter.element_id.clone());
+				}
+
+				(Some(pos), toc.as_bytes()?)
+			},
+			None => {
+				let mut chapters = chapters;
+				chapters.retain(|c| c.element_id != chapter.element_id);
+				chapters.push(chapter.clone());
+				chapters.sort_by_key(|chapter| chapter.start_ms);
+
+				let toc = TableOfContents {
+					element_id: String::from("toc"),
+					top_level: true,
+					ordered: true,
+					children: chapters.into_iter().map(|c| c.element_id).collect(),
+					sub_frames: Vec::new(),
+				};
+
+				(None, toc.as_bytes()?)
+			},
+		};
+
+		// Update the table of contents in place, keeping its flags
+		match toc_pos {
+			Some(pos) => {
+				if let Frame::Binary(BinaryFrame { data, .. }) = &mut self.frames[pos] {
+					*data = toc_bytes;
+				}
+			},
+			None => self
+				.frames
+				.push(new_binary_frame(TABLE_OF_CONTENTS_ID, toc_bytes)),
+		}
+
+		self.frames.retain(|frame| match frame {
+			Frame::Binary(BinaryFrame { header, data }) if header.id == CHAPTER_ID => {
+				!data.starts_with(&element_id)
+			},
+			_ => true,
+		});
+
+		self.frames
+			.push(new_binary_frame(CHAPTER_ID, chapter_bytes));
+
+		Ok(())
+	}
+
+	/// Remove all chapters (`CHAP` frames) and tables of contents (`CTOC` frames)
+	pub fn remove_chapters(&mut self) {
+		self.frames.retain(|frame| match frame {
+			Frame::Binary(BinaryFrame { header, .. }) => {
+				header.id != CHAPTER_ID && header.id != TABLE_OF_CONTENTS_ID
+			},
+			_ => true,
+		});
+	}
+
+	fn tables_of_contents(&self) -> impl Iterator<Item = TableOfContents> + '_ {
+		self.frames.iter().filter_map(|frame| match frame {
+			Frame::Binary(BinaryFrame { header, data }) if header.id == TABLE_OF_CONTENTS_ID => {
+				TableOfContents::parse(data).ok()
+			},
+			_ => None,
+		})
+	}
+
 	/// Returns all `COMM` frames with an empty content descriptor
 	pub fn comments(&self) -> impl Iterator<Item = &CommentFrame<'_>> {
 		self.frames.iter().filter_map(|frame| {
@@ -516,6 +941,58 @@ impl Id3v2Tag {
 		})
 	}
 
+	// ID3v2.3 splits the recording date into `TYER`, `TDAT` (DDMM), and `TIME` (HHMM). `TYER` is
+	// upgraded to `TDRC` when read, so the rest of the date needs to be merged back into it.
+	pub(super) fn merge_id3v23_date(&mut self) {
+		fn split_digit_pair(value: &str) -> Option<(u8, u8)> {
+			if value.len() != 4 || !value.bytes().all(|b| b.is_ascii_digit()) {
+				return None;
+			}
+
+			Some((value[..2].parse().ok()?, value[2..].parse().ok()?))
+		}
+
+		let Some((day, month)) = self.get_text(&DATE_ID).and_then(split_digit_pair) else {
+			return;
+		};
+		let time = self.get_text(&TIME_ID).and_then(split_digit_pair);
+
+		let Some(Frame::Timestamp(recording_date)) = self
+			.frames
+			.iter_mut()
+			.find(|frame| frame.id() == &RECORDING_TIME_ID)
+		else {
+			return;
+		};
+
+		if recording_date.timestamp.month.is_some() {
+			return;
+		}
+
+		let mut timestamp = Timestamp {
+			month: Some(month),
+			day: Some(day),
+			..recording_date.timestamp
+		};
+
+		if let Some((hour, minute)) = time {
+			timestamp.hour = Some(hour);
+			timestamp.minute = Some(minute);
+		}
+
+		if timestamp.verify().is_err() {
+			log::warn!("Encountered an invalid TDAT/TIME pair, not merging into TDRC");
+			return;
+		}
+
+		recording_date.timestamp = timestamp;
+
+		let _ = self.remove(&DATE_ID);
+		if time.is_some() {
+			let _ = self.remove(&TIME_ID);
+		}
+	}
+
 	fn split_num_pair(&self, id: &FrameId<'_>) -> (Option<u32>, Option<u32>) {
 		if let Some(Frame::Text(TextInformationFrame { ref value, .. })) = self.get(id) {
 			let mut split = value
@@ -555,6 +1032,20 @@ impl Id3v2Tag {
 		None
 	}
 
+	/// Set the year, failing if it has more than 4 digits
+	///
+	/// Only the first 4 digits of a year are read back, so unlike [`Accessor::set_year`], this will not
+	/// store a year that would be read back differently. The tag is left unchanged on failure.
+	///
+	/// # Errors
+	///
+	/// * `value` is greater than 9999 ([`ErrorKind::BadItem`](crate::error::ErrorKind::BadItem))
+	pub fn try_set_year(&mut self, value: u32) -> Result<()> {
+		verify_year(value)?;
+		self.set_year(value);
+		Ok(())
+	}
+
 	fn insert_number_pair(
 		&mut self,
 		id: FrameId<'static>,
@@ -617,12 +1108,8 @@ fn parse_genre(genre: &str) -> &str {
 	if genre.len() > 3 {
 		return genre;
 	}
-	if let Ok(id) = genre.parse::<usize>() {
-		if id < GENRES.len() {
-			GENRES[id]
-		} else {
-			genre
-		}
+	if let Some(name) = genre_from_id(genre) {
+		name
 	} else if genre == "RX" {
 		"Remix"
 	} else if genre == "CR" {
@@ -632,6 +1119,44 @@ fn parse_genre(genre: &str) -> &str {
 	}
 }
 
+fn parse_synchronized_lyrics(frame: &Frame<'_>) -> Option<SynchronizedTextFrame<'static>> {
+	let Frame::Binary(BinaryFrame { header, data }) = frame else {
+		return None;
+	};
+
+	if header.id != SYLT_ID {
+		return None;
+	}
+
+	SynchronizedTextFrame::parse(data, header.flags)
+		.ok()
+		.filter(|sylt| sylt.content_type == SyncTextContentType::Lyrics)
+}
+
+fn filter_lyrics_frame_by_description<'a>(
+	frame: &'a Frame<'_>,
+	description: &str,
+) -> Option<&'a UnsynchronizedTextFrame<'a>> {
+	match &frame {
+		Frame::UnsynchronizedText(lyrics_frame) => {
+			(lyrics_frame.description == description).then_some(lyrics_frame)
+		},
+		_ => None,
+	}
+}
+
+fn filter_lyrics_frame_by_description_mut<'a, 'f: 'a>(
+	frame: &'a mut Frame<'f>,
+	description: &str,
+) -> Option<&'a mut UnsynchronizedTextFrame<'f>> {
+	match frame {
+		Frame::UnsynchronizedText(lyrics_frame) => {
+			(lyrics_frame.description == description).then_some(lyrics_frame)
+		},
+		_ => None,
+	}
+}
+
 fn filter_comment_frame_by_description<'a>(
 	frame: &'a Frame<'_>,
 	description: &str,
@@ -717,6 +1242,7 @@ pub(super) fn new_binary_frame(id: FrameId<'_>, data: Vec<u8>) -> Frame<'_> {
 	Frame::Binary(BinaryFrame::new(id, data))
 }
 
+const SYLT_ID: FrameId<'static> = FrameId::Valid(Cow::Borrowed("SYLT"));
 const TITLE_ID: FrameId<'static> = FrameId::Valid(Cow::Borrowed("TIT2"));
 const ARTIST_ID: FrameId<'static> = FrameId::Valid(Cow::Borrowed("TPE1"));
 const ALBUM_ID: FrameId<'static> = FrameId::Valid(Cow::Borrowed("TALB"));
@@ -724,12 +1250,23 @@ const GENRE_ID: FrameId<'static> = FrameId::Valid(Cow::Borrowed("TCON"));
 const TRACK_ID: FrameId<'static> = FrameId::Valid(Cow::Borrowed("TRCK"));
 const DISC_ID: FrameId<'static> = FrameId::Valid(Cow::Borrowed("TPOS"));
 const RECORDING_TIME_ID: FrameId<'static> = FrameId::Valid(Cow::Borrowed("TDRC"));
+const DATE_ID: FrameId<'static> = FrameId::Valid(Cow::Borrowed("TDAT"));
+const TIME_ID: FrameId<'static> = FrameId::Valid(Cow::Borrowed("TIME"));
+const BARCODE_DESCRIPTION: &str = "BARCODE";
+const CATALOG_NUMBER_DESCRIPTION: &str = "CATALOGNUMBER";
 
 impl Accessor for Id3v2Tag {
 	impl_accessor!(
-		title  => "TIT2";
-		artist => "TPE1";
-		album  => "TALB";
+		title             => "TIT2";
+		artist            => "TPE1";
+		album             => "TALB";
+		title_sort        => "TSOT";
+		artist_sort       => "TSOP";
+		album_title_sort  => "TSOA";
+		album_artist_sort => "TSO2";
+		encoder           => "TSSE";
+		isrc              => "TSRC";
+		label             => "TPUB";
 	);
 
 	fn track(&self) -> Option<u32> {
@@ -866,6 +1403,66 @@ impl Accessor for Id3v2Tag {
 			filter_comment_frame_by_description(frame, &EMPTY_CONTENT_DESCRIPTOR).is_none()
 		})
 	}
+
+	fn lyrics(&self) -> Option<Cow<'_, str>> {
+		self.frames
+			.iter()
+			.find_map(|frame| filter_lyrics_frame_by_description(frame, &EMPTY_CONTENT_DESCRIPTOR))
+			.map(|UnsynchronizedTextFrame { content, .. }| Cow::Borrowed(content.as_str()))
+	}
+
+	fn set_lyrics(&mut self, value: String) {
+		let mut value = Some(value);
+		self.frames.retain_mut(|frame| {
+			let Some(UnsynchronizedTextFrame { content, .. }) =
+				filter_lyrics_frame_by_description_mut(frame, &EMPTY_CONTENT_DESCRIPTOR)
+			else {
+				return true;
+			};
+			if let Some(value) = value.take() {
+				// Replace value in first lyrics frame
+				*content = value;
+				true
+			} else {
+				// Remove all subsequent lyrics frames
+				false
+			}
+		});
+		if let Some(value) = value {
+			self.frames.push(new_unsync_text_frame(value));
+		}
+	}
+
+	fn remove_lyrics(&mut self) {
+		self.frames.retain(|frame| {
+			filter_lyrics_frame_by_description(frame, &EMPTY_CONTENT_DESCRIPTOR).is_none()
+		})
+	}
+
+	fn barcode(&self) -> Option<Cow<'_, str>> {
+		self.get_user_text(BARCODE_DESCRIPTION).map(Cow::Borrowed)
+	}
+
+	fn set_barcode(&mut self, value: String) {
+		let _ = self.insert_user_text(String::from(BARCODE_DESCRIPTION), value);
+	}
+
+	fn remove_barcode(&mut self) {
+		let _ = self.remove_user_text(BARCODE_DESCRIPTION);
+	}
+
+	fn catalog_number(&self) -> Option<Cow<'_, str>> {
+		self.get_user_text(CATALOG_NUMBER_DESCRIPTION)
+			.map(Cow::Borrowed)
+	}
+
+	fn set_catalog_number(&mut self, value: String) {
+		let _ = self.insert_user_text(String::from(CATALOG_NUMBER_DESCRIPTION), value);
+	}
+
+	fn remove_catalog_number(&mut self) {
+		let _ = self.remove_user_text(CATALOG_NUMBER_DESCRIPTION);
+	}
 }
 
 impl TagExt for Id3v2Tag {
@@ -910,7 +1507,32 @@ impl TagExt for Id3v2Tag {
 			flags: self.flags,
 			frames: self.frames.iter().filter_map(Frame::as_opt_ref).peekable(),
 		}
-		.write_to(file, write_options)
+		.write_to(file, write_options)?;
+
+		if write_options.sync_id3v1 {
+			// Only the few items ID3v1 can hold are needed, there's no need to convert the entire tag
+			let (title, artist, album) = (self.title(), self.artist(), self.album());
+			let comment = self.comment();
+			let year = self.year().map(|year| year.to_string());
+
+			let id3v1 = Id3v1TagRef {
+				title: title.as_deref(),
+				artist: artist.as_deref(),
+				album: album.as_deref(),
+				year: year.as_deref(),
+				comment: comment.as_deref(),
+				track_number: self.track().and_then(|track| u8::try_from(track).ok()),
+				// ID3v1 can only hold a single genre
+				genre: self
+					.genres()
+					.and_then(|mut genres| genres.next())
+					.and_then(genre_id),
+			};
+
+			crate::id3::v1::write::write_synced_id3v1(file, &id3v1, write_options)?;
+		}
+
+		Ok(())
 	}
 
 	/// Dumps the tag to a writer
@@ -1222,6 +1844,15 @@ fn handle_tag_split(tag: &mut Tag, frame: &mut Frame<'_>) -> bool {
 			return FRAME_CONSUMED;
 		},
 
+		Frame::Popularimeter(ref popularimeter) => {
+			tag.items.push(TagItem::new(
+				ItemKey::Popularimeter,
+				ItemValue::Binary(popularimeter.as_bytes()),
+			));
+
+			return FRAME_CONSUMED;
+		},
+
 		Frame::Binary(_)
 		| Frame::UserText(_)
 		| Frame::UserUrl(_) // Bare extended text/URL frames make no sense to support.
@@ -1229,7 +1860,6 @@ fn handle_tag_split(tag: &mut Tag, frame: &mut Frame<'_>) -> bool {
 		| Frame::RelativeVolumeAdjustment(_)
 		| Frame::Ownership(_)
 		| Frame::EventTimingCodes(_)
-		| Frame::Popularimeter(_)
 		| Frame::Private(_) => {
 			return FRAME_RETAINED; // Keep unsupported frame
 		},
@@ -1385,6 +2015,7 @@ impl MergeTag for SplitTagRemainder {
 			&ItemKey::FileOwner,
 			&ItemKey::CopyrightMessage,
 			&ItemKey::Language,
+			&ItemKey::Isrc,
 		] {
 			let frame_id = item_key
 				.map_key(TagType::Id3v2, false)
@@ -1680,7 +2311,9 @@ impl<'a, I: Iterator<Item = FrameRef<'a>> + 'a> Id3v2TagRef<'a, I> {
 		LoftyError: From<<F as Truncate>::Error>,
 		LoftyError: From<<F as Length>::Error>,
 	{
-		super::write::write_id3v2(file, self, write_options)
+		write_verified(file, write_options, |file, write_options| {
+			super::write::write_id3v2(file, self, write_options)
+		})
 	}
 
 	pub(crate) fn dump_to<W: Write>(
@@ -1,9 +1,11 @@
-use crate::config::ParsingMode;
+use crate::config::{ParseOptions, ParsingMode};
+use crate::error::ErrorKind;
 use crate::id3::v2::header::Id3v2Header;
 use crate::id3::v2::items::PopularimeterFrame;
 use crate::id3::v2::util::pairs::DEFAULT_NUMBER_IN_PAIR;
 use crate::id3::v2::{
-	ChannelInformation, ChannelType, RelativeVolumeAdjustmentFrame, TimestampFrame,
+	ChannelInformation, ChannelType, FrameFlags, RelativeVolumeAdjustmentFrame, TimestampFormat,
+	TimestampFrame,
 };
 use crate::picture::MimeType;
 use crate::tag::items::{Timestamp, ENGLISH};
@@ -24,7 +26,13 @@ fn read_tag_raw(bytes: &[u8]) -> Id3v2Tag {
 	let mut reader = Cursor::new(bytes);
 
 	let header = Id3v2Header::parse(&mut reader).unwrap();
-	crate::id3::v2::read::parse_id3v2(&mut reader, header, ParsingMode::Strict).unwrap()
+	crate::id3::v2::read::parse_id3v2(
+		&mut reader,
+		header,
+		ParseOptions::new().parsing_mode(ParsingMode::Strict),
+		None,
+	)
+	.unwrap()
 }
 
 #[test]
@@ -93,8 +101,13 @@ fn id3v2_re_read() {
 	let temp_reader = &mut &*writer;
 
 	let temp_header = Id3v2Header::parse(temp_reader).unwrap();
-	let temp_parsed_tag =
-		crate::id3::v2::read::parse_id3v2(temp_reader, temp_header, ParsingMode::Strict).unwrap();
+	let temp_parsed_tag = crate::id3::v2::read::parse_id3v2(
+		temp_reader,
+		temp_header,
+		ParseOptions::new().parsing_mode(ParsingMode::Strict),
+		None,
+	)
+	.unwrap();
 
 	assert_eq!(parsed_tag, temp_parsed_tag);
 }
@@ -226,6 +239,7 @@ fn create_full_test_tag(version: Id3v2Version) -> Id3v2Tag {
 			mime_type: Some(MimeType::Png),
 			description: None,
 			data: read_path("tests/tags/assets/id3v2/test_full_cover.png").into(),
+			location: None,
 		},
 	)));
 
@@ -267,7 +281,13 @@ fn id3v24_footer() {
 	let mut reader = &mut &writer[..];
 
 	let header = Id3v2Header::parse(&mut reader).unwrap();
-	let _ = crate::id3::v2::read::parse_id3v2(reader, header, ParsingMode::Strict).unwrap();
+	let _ = crate::id3::v2::read::parse_id3v2(
+		reader,
+		header,
+		ParseOptions::new().parsing_mode(ParsingMode::Strict),
+		None,
+	)
+	.unwrap();
 
 	assert_eq!(writer[3..10], writer[writer.len() - 7..])
 }
@@ -292,7 +312,13 @@ fn issue_36() {
 	let mut reader = &mut &writer[..];
 
 	let header = Id3v2Header::parse(&mut reader).unwrap();
-	let tag = crate::id3::v2::read::parse_id3v2(reader, header, ParsingMode::Strict).unwrap();
+	let tag = crate::id3::v2::read::parse_id3v2(
+		reader,
+		header,
+		ParseOptions::new().parsing_mode(ParsingMode::Strict),
+		None,
+	)
+	.unwrap();
 
 	assert_eq!(tag.len(), 1);
 	assert_eq!(
@@ -354,6 +380,48 @@ fn multi_item_tag_to_id3v2() {
 	assert_eq!(tag.artist().as_deref(), Some("foo/bar/baz"))
 }
 
+#[test]
+fn distributor_identifiers() {
+	use crate::mp4::Ilst;
+	use crate::ogg::VorbisComments;
+
+	let mut vorbis_comments = VorbisComments::default();
+	vorbis_comments.push(String::from("ISRC"), String::from("USRC17607839"));
+	vorbis_comments.push(String::from("ISRC"), String::from("USRC17607840"));
+	vorbis_comments.set_barcode(String::from("0602537869159"));
+	vorbis_comments.set_catalog_number(String::from("FOO-001"));
+	vorbis_comments.set_label(String::from("Foo Records"));
+
+	let tag: Tag = vorbis_comments.into();
+
+	let ilst: Ilst = tag.clone().into();
+	assert_eq!(ilst.isrc().as_deref(), Some("USRC17607839"));
+	assert_eq!(ilst.barcode().as_deref(), Some("0602537869159"));
+	assert_eq!(ilst.catalog_number().as_deref(), Some("FOO-001"));
+	assert_eq!(ilst.label().as_deref(), Some("Foo Records"));
+
+	let id3v2: Id3v2Tag = tag.into();
+
+	// Both ISRCs should end up in the same frame
+	assert_eq!(
+		id3v2.get_text(&FrameId::Valid(Cow::Borrowed("TSRC"))),
+		Some("USRC17607839\0USRC17607840")
+	);
+	assert_eq!(id3v2.barcode().as_deref(), Some("0602537869159"));
+	assert_eq!(id3v2.catalog_number().as_deref(), Some("FOO-001"));
+	assert_eq!(id3v2.label().as_deref(), Some("Foo Records"));
+
+	let tag: Tag = id3v2.into();
+	assert_eq!(
+		tag.get_strings(&ItemKey::Isrc).collect::<Vec<_>>(),
+		["USRC17607839", "USRC17607840"]
+	);
+	assert_eq!(tag.barcode().as_deref(), Some("0602537869159"));
+	assert_eq!(tag.catalog_number().as_deref(), Some("FOO-001"));
+	// `TPUB` is read as the publisher, which is used as a fallback for the label
+	assert_eq!(tag.label().as_deref(), Some("Foo Records"));
+}
+
 #[test]
 fn utf16_txxx_with_single_bom() {
 	let _ = read_tag("tests/tags/assets/id3v2/issue_53.id3v24");
@@ -567,8 +635,13 @@ fn user_defined_frames_conversion() {
 	let mut reader = std::io::Cursor::new(&content[..]);
 
 	let header = Id3v2Header::parse(&mut reader).unwrap();
-	let reparsed =
-		crate::id3::v2::read::parse_id3v2(&mut reader, header, ParsingMode::Strict).unwrap();
+	let reparsed = crate::id3::v2::read::parse_id3v2(
+		&mut reader,
+		header,
+		ParseOptions::new().parsing_mode(ParsingMode::Strict),
+		None,
+	)
+	.unwrap();
 
 	assert_eq!(id3v2, reparsed);
 }
@@ -1305,3 +1378,569 @@ fn hold_back_4_character_txxx_description() {
 	let tag: Id3v2Tag = tag.into();
 	assert_eq!(tag.len(), 1);
 }
+
+#[test]
+fn write_id3v23() {
+	let mut tag = Id3v2Tag::default();
+	tag.insert(Frame::Text(TextInformationFrame::new(
+		FrameId::Valid(Cow::Borrowed("TPE1")),
+		TextEncoding::UTF8,
+		String::from("Foo artist\0Bar artist"),
+	)));
+	tag.insert(Frame::Timestamp(TimestampFrame::new(
+		FrameId::Valid(Cow::Borrowed("TDRC")),
+		TextEncoding::Latin1,
+		Timestamp {
+			year: 2024,
+			month: Some(5),
+			day: Some(6),
+			hour: Some(7),
+			minute: Some(8),
+			second: None,
+		},
+	)));
+	tag.insert(Frame::Timestamp(TimestampFrame::new(
+		FrameId::Valid(Cow::Borrowed("TDRL")),
+		TextEncoding::Latin1,
+		Timestamp {
+			year: 2024,
+			..Timestamp::default()
+		},
+	)));
+	tag.insert(Frame::KeyValue(KeyValueFrame::new(
+		FrameId::Valid(Cow::Borrowed("TIPL")),
+		TextEncoding::Latin1,
+		vec![(String::from("producer"), String::from("Foo producer"))],
+	)));
+	tag.insert(Frame::KeyValue(KeyValueFrame::new(
+		FrameId::Valid(Cow::Borrowed("TMCL")),
+		TextEncoding::Latin1,
+		vec![(String::from("guitar"), String::from("Foo guitarist"))],
+	)));
+
+	let mut writer = Vec::new();
+	tag.dump_to(&mut writer, WriteOptions::default().use_id3v23(true))
+		.unwrap();
+
+	assert_eq!(&writer[..5], b"ID3\x03\x00");
+
+	// The ID3v2.3 frames should be visible before any upgrades
+	let contains_frame_id = |id: &[u8]| writer.windows(4).any(|w| w == id);
+	for id in [b"TYER", b"TDAT", b"TIME", b"IPLS"] {
+		assert!(contains_frame_id(id));
+	}
+	for id in [b"TDRC", b"TDRL", b"TIPL", b"TMCL"] {
+		assert!(!contains_frame_id(id));
+	}
+
+	let tag = read_tag_raw(&writer);
+	assert_eq!(tag.original_version(), Id3v2Version::V3);
+
+	let Some(Frame::Text(artist)) = tag.get(&FrameId::Valid(Cow::Borrowed("TPE1"))) else {
+		panic!("Expected a TPE1 frame");
+	};
+	assert_eq!(artist.value, "Foo artist/Bar artist");
+	assert_eq!(artist.encoding, TextEncoding::UTF16);
+
+	// TYER, TDAT, and TIME are merged back into TDRC when read
+	let Some(Frame::Timestamp(recording_date)) = tag.get(&FrameId::Valid(Cow::Borrowed("TDRC")))
+	else {
+		panic!("Expected a TDRC frame");
+	};
+	assert_eq!(
+		recording_date.timestamp,
+		Timestamp {
+			year: 2024,
+			month: Some(5),
+			day: Some(6),
+			hour: Some(7),
+			minute: Some(8),
+			second: None,
+		}
+	);
+	assert!(tag.get(&FrameId::Valid(Cow::Borrowed("TDAT"))).is_none());
+	assert!(tag.get(&FrameId::Valid(Cow::Borrowed("TIME"))).is_none());
+
+	// IPLS is upgraded to TIPL when read
+	let Some(Frame::KeyValue(involved_people)) = tag.get(&FrameId::Valid(Cow::Borrowed("TIPL")))
+	else {
+		panic!("Expected a TIPL frame");
+	};
+	assert_eq!(involved_people.key_value_pairs.len(), 2);
+}
+
+#[test]
+fn lyrics() {
+	let mut tag = Id3v2Tag::default();
+	tag.set_lyrics(String::from("Foo lyrics"));
+
+	assert_eq!(tag.lyrics().as_deref(), Some("Foo lyrics"));
+	assert_eq!(tag.unsync_text().count(), 1);
+
+	// Lyrics with a description are left alone
+	tag.insert(Frame::UnsynchronizedText(UnsynchronizedTextFrame::new(
+		TextEncoding::UTF8,
+		ENGLISH,
+		String::from("Qux description"),
+		String::from("Bar lyrics"),
+	)));
+
+	tag.set_lyrics(String::from("Baz lyrics"));
+	assert_eq!(tag.lyrics().as_deref(), Some("Baz lyrics"));
+	assert_eq!(tag.unsync_text().count(), 2);
+
+	let tag: Tag = tag.into();
+	assert_eq!(tag.lyrics().as_deref(), Some("Baz lyrics"));
+
+	let mut tag: Id3v2Tag = tag.into();
+	tag.remove_lyrics();
+	assert!(tag.lyrics().is_none());
+}
+
+#[test]
+fn synchronized_lyrics() {
+	let lyrics = |language: [u8; 3], content: &str| {
+		SynchronizedTextFrame::new(
+			TextEncoding::UTF8,
+			language,
+			TimestampFormat::MS,
+			SyncTextContentType::Lyrics,
+			None,
+			vec![(0, String::from(content)), (1000, String::from("Baz"))],
+		)
+	};
+
+	let mut tag = Id3v2Tag::default();
+	tag.insert_synchronized_lyrics(lyrics(*b"eng", "Foo"))
+		.unwrap();
+	tag.insert_synchronized_lyrics(lyrics(*b"deu", "Bar"))
+		.unwrap();
+
+	// Non-lyrics SYLT frames are ignored
+	let mut events = lyrics(*b"eng", "Qux");
+	events.content_type = SyncTextContentType::Events;
+	tag.insert(new_binary_frame(
+		FrameId::Valid(Cow::Borrowed("SYLT")),
+		events.as_bytes().unwrap(),
+	));
+
+	assert_eq!(tag.len(), 3);
+	assert_eq!(tag.synchronized_lyrics().count(), 2);
+
+	// Replaces the existing English lyrics
+	tag.insert_synchronized_lyrics(lyrics(*b"eng", "Quux"))
+		.unwrap();
+	assert_eq!(tag.len(), 3);
+
+	let english = tag
+		.synchronized_lyrics()
+		.find(|sylt| &sylt.language == b"eng")
+		.unwrap();
+	assert_eq!(english.content[0].1, "Quux");
+
+	// Survives a round trip
+	let mut writer = Vec::new();
+	tag.dump_to(&mut writer, WriteOptions::default()).unwrap();
+
+	let mut tag = read_tag_raw(&writer);
+	assert_eq!(tag.synchronized_lyrics().count(), 2);
+
+	tag.remove_synchronized_lyrics();
+	assert_eq!(tag.len(), 1);
+}
+
+#[test]
+fn chapters() {
+	let mut tag = Id3v2Tag::default();
+	tag.add_chapter(Chapter::new(
+		30_000,
+		60_000,
+		Some(String::from("Bar chapter")),
+	))
+	.unwrap();
+	tag.add_chapter(Chapter::new(0, 30_000, Some(String::from("Foo chapter"))))
+		.unwrap();
+
+	// 2 CHAP frames, and a single top-level CTOC
+	assert_eq!(tag.len(), 3);
+
+	let chapters = tag.chapters();
+	assert_eq!(chapters.len(), 2);
+	assert_eq!(chapters[0].element_id, "chp1");
+	assert_eq!(chapters[0].title.as_deref(), Some("Foo chapter"));
+	assert_eq!(chapters[1].element_id, "chp0");
+
+	let toc = tag.tables_of_contents().next().unwrap();
+	assert!(toc.top_level);
+	assert_eq!(toc.children, ["chp1", "chp0"]);
+
+	// Replace the first chapter
+	let mut replacement = chapters[0].clone();
+	replacement.title = Some(String::from("Baz chapter"));
+	tag.add_chapter(replacement).unwrap();
+
+	assert_eq!(tag.len(), 3);
+	assert_eq!(tag.chapters()[0].title.as_deref(), Some("Baz chapter"));
+
+	// Survives a round trip
+	let mut writer = Vec::new();
+	tag.dump_to(&mut writer, WriteOptions::default()).unwrap();
+
+	let mut tag = read_tag_raw(&writer);
+	assert_eq!(tag.chapters().len(), 2);
+
+	tag.remove_chapters();
+	assert!(tag.is_empty());
+}
+
+#[test]
+fn add_chapter_keeps_table_of_contents() {
+	// A table of contents with a nested table of contents and a title
+	let toc = TableOfContents {
+		element_id: String::from("toc"),
+		top_level: true,
+		ordered: true,
+		children: vec![String::from("toc2")],
+		sub_frames: b"TIT2\0\0\0\x04\0\0\0Foo".to_vec(),
+	};
+
+	let mut tag = Id3v2Tag::default();
+	tag.frames.push(new_binary_frame(
+		TABLE_OF_CONTENTS_ID,
+		toc.as_bytes().unwrap(),
+	));
+
+	// Ordered, so each chapter goes before the first one starting after it
+	tag.add_chapter(Chapter::new(60_000, 90_000, None)).unwrap();
+	tag.add_chapter(Chapter::new(0, 30_000, None)).unwrap();
+	tag.add_chapter(Chapter::new(30_000, 60_000, None)).unwrap();
+
+	let tables_of_contents = tag.tables_of_contents().collect::<Vec<_>>();
+	assert_eq!(tables_of_contents.len(), 1);
+	assert_eq!(
+		tables_of_contents[0].children,
+		["toc2", "chp1", "chp2", "chp0"]
+	);
+	assert_eq!(tables_of_contents[0].sub_frames, toc.sub_frames);
+
+	// Unordered tables of contents are only appended to
+	let toc = TableOfContents {
+		ordered: false,
+		..tables_of_contents[0].clone()
+	};
+	tag.frames
+		.retain(|frame| frame.id() != &TABLE_OF_CONTENTS_ID);
+	tag.frames.push(new_binary_frame(
+		TABLE_OF_CONTENTS_ID,
+		toc.as_bytes().unwrap(),
+	));
+
+	tag.add_chapter(Chapter::new(0, 10_000, None)).unwrap();
+
+	let toc = tag.tables_of_contents().next().unwrap();
+	assert!(!toc.ordered);
+	assert_eq!(toc.children, ["toc2", "chp1", "chp2", "chp0", "chp3"]);
+}
+
+#[test]
+fn chapters_id3v23() {
+	// Long enough for the sub-frame sizes to differ between ID3v2.3 and ID3v2.4
+	let title = "Foo chapter".repeat(20);
+
+	let mut tag = Id3v2Tag::default();
+	tag.add_chapter(Chapter::new(0, 30_000, Some(title.clone())))
+		.unwrap();
+
+	let mut writer = Vec::new();
+	tag.dump_to(&mut writer, WriteOptions::default().use_id3v23(true))
+		.unwrap();
+
+	assert_eq!(&writer[..5], b"ID3\x03\x00");
+
+	let tag = read_tag_raw(&writer);
+	let chapters = tag.chapters();
+	assert_eq!(chapters.len(), 1);
+	assert_eq!(chapters[0].title.as_deref(), Some(&*title));
+}
+
+#[test]
+fn chapters_bad_sub_frames() {
+	let mut tag = Id3v2Tag::default();
+	tag.set_title(String::from("Foo title"));
+
+	// Just an element ID, without the start and end times
+	tag.insert(Frame::Binary(BinaryFrame::new(
+		FrameId::Valid(Cow::Borrowed("CHAP")),
+		b"chp0\0".to_vec(),
+	)));
+
+	// The chapter can't be converted, so it's written as-is
+	let mut writer = Vec::new();
+	tag.dump_to(&mut writer, WriteOptions::default().use_id3v23(true))
+		.unwrap();
+
+	let mut reader = Cursor::new(&writer[..]);
+	let header = Id3v2Header::parse(&mut reader).unwrap();
+	assert!(crate::id3::v2::read::parse_id3v2(
+		&mut reader,
+		header,
+		ParseOptions::new().parsing_mode(ParsingMode::Strict),
+		None,
+	)
+	.is_err());
+
+	// The rest of the tag is still readable outside of strict mode
+	let mut reader = Cursor::new(&writer[..]);
+	let header = Id3v2Header::parse(&mut reader).unwrap();
+	let tag =
+		crate::id3::v2::read::parse_id3v2(&mut reader, header, ParseOptions::new(), None).unwrap();
+
+	assert_eq!(tag.title().as_deref(), Some("Foo title"));
+	assert_eq!(
+		tag.get(&FrameId::Valid(Cow::Borrowed("CHAP"))),
+		Some(&Frame::Binary(BinaryFrame::new(
+			FrameId::Valid(Cow::Borrowed("CHAP")),
+			b"chp0\0".to_vec(),
+		)))
+	);
+}
+
+#[test]
+fn skip_pictures() {
+	let picture = Picture::new_unchecked(
+		PictureType::CoverFront,
+		Some(MimeType::Png),
+		Some(String::from("Foo description")),
+		vec![1; 64],
+	);
+
+	let mut tag = Id3v2Tag::default();
+	tag.set_title(String::from("Foo title"));
+	let _ = tag.insert_picture(picture.clone());
+
+	let mut writer = Vec::new();
+	tag.dump_to(&mut writer, WriteOptions::default()).unwrap();
+
+	let mut reader = Cursor::new(&writer[..]);
+	let header = Id3v2Header::parse(&mut reader).unwrap();
+	let frames_offset = reader.position();
+	let tag = crate::id3::v2::read::parse_id3v2(
+		&mut reader,
+		header,
+		ParseOptions::new().read_pictures(false),
+		Some(frames_offset),
+	)
+	.unwrap();
+
+	assert_eq!(tag.title().as_deref(), Some("Foo title"));
+
+	let mut skipped = tag
+		.frames
+		.iter()
+		.find_map(|frame| match frame {
+			Frame::Picture(AttachedPictureFrame { picture, .. }) => Some(picture.clone()),
+			_ => None,
+		})
+		.unwrap();
+	assert!(!skipped.is_loaded());
+	assert!(skipped.data().is_empty());
+	assert_eq!(skipped.description(), Some("Foo description"));
+
+	// Writing the tag back would remove the picture
+	let err = tag
+		.dump_to(&mut Vec::new(), WriteOptions::default())
+		.unwrap_err();
+	assert!(matches!(err.kind(), ErrorKind::PictureNotLoaded));
+
+	skipped.load(&mut reader).unwrap();
+	assert_eq!(skipped, picture);
+}
+
+#[test]
+fn chapters_keep_pictures() {
+	let mut chapter = Chapter::new(0, 30_000, Some(String::from("Foo chapter")));
+	chapter.image = Some(Picture::new_unchecked(
+		PictureType::Other,
+		Some(MimeType::Png),
+		None,
+		vec![1; 10],
+	));
+
+	let mut tag = Id3v2Tag::default();
+	tag.add_chapter(chapter).unwrap();
+
+	let mut writer = Vec::new();
+	tag.dump_to(&mut writer, WriteOptions::default()).unwrap();
+
+	let mut reader = Cursor::new(&writer[..]);
+	let header = Id3v2Header::parse(&mut reader).unwrap();
+	let tag = crate::id3::v2::read::parse_id3v2(
+		&mut reader,
+		header,
+		ParseOptions::new().read_pictures(false),
+		None,
+	)
+	.unwrap();
+
+	let chapters = tag.chapters();
+	assert_eq!(chapters[0].title.as_deref(), Some("Foo chapter"));
+	assert!(chapters[0].image.is_some());
+}
+
+#[test]
+fn frames_by_description() {
+	let mut tag = Id3v2Tag::default();
+
+	let _ = tag.insert_user_url(String::from("Foo"), String::from("https://foo.example"));
+	let _ = tag.insert_user_url(String::from("Bar"), String::from("https://bar.example"));
+	assert_eq!(tag.get_user_url("Foo"), Some("https://foo.example"));
+	assert_eq!(tag.get_user_url("Bar"), Some("https://bar.example"));
+
+	let _ = tag.insert_comment(ENGLISH, String::from("Foo"), String::from("Foo comment"));
+	let _ = tag.insert_comment(*b"deu", String::from("Foo"), String::from("Foo Kommentar"));
+	assert_eq!(tag.get_comment(ENGLISH, "Foo"), Some("Foo comment"));
+	assert_eq!(tag.get_comment(*b"deu", "Foo"), Some("Foo Kommentar"));
+	assert_eq!(tag.get_comment(ENGLISH, "Bar"), None);
+	assert_eq!(tag.len(), 4);
+
+	// Replacing a frame keeps its flags
+	let flags = FrameFlags {
+		read_only: true,
+		..FrameFlags::default()
+	};
+	tag.get_mut(&FrameId::Valid(Cow::Borrowed(COMMENT_FRAME_ID)))
+		.unwrap()
+		.set_flags(flags);
+
+	let replaced = tag.insert_comment(ENGLISH, String::from("Foo"), String::from("Baz comment"));
+	assert_eq!(replaced.unwrap().flags(), flags);
+	assert_eq!(tag.len(), 4);
+
+	// Survives a round trip
+	let mut writer = Vec::new();
+	tag.dump_to(&mut writer, WriteOptions::default()).unwrap();
+
+	let mut tag = read_tag_raw(&writer);
+	assert_eq!(tag.get_user_url("Foo"), Some("https://foo.example"));
+	assert_eq!(tag.get_comment(ENGLISH, "Foo"), Some("Baz comment"));
+	assert_eq!(tag.get_comment(*b"deu", "Foo"), Some("Foo Kommentar"));
+
+	let comment = tag
+		.frames
+		.iter()
+		.find(|f| matches!(f, Frame::Comment(c) if c.language == ENGLISH))
+		.unwrap();
+	assert_eq!(comment.flags(), flags);
+
+	assert!(tag.remove_user_url("Foo").is_some());
+	assert!(tag.remove_comment(ENGLISH, "Foo").is_some());
+	assert!(tag.remove_comment(ENGLISH, "Foo").is_none());
+	assert_eq!(tag.len(), 2);
+}
+
+#[test]
+fn choose_text_encoding() {
+	let mut tag = Id3v2Tag::default();
+	tag.set_title(String::from("Foo title"));
+	tag.set_artist(String::from("ジェイソン"));
+	let _ = tag.insert_user_text(String::from("FOO"), String::from("Bär"));
+
+	let encodings = |tag: &Id3v2Tag| {
+		tag.frames
+			.iter()
+			.map(|frame| (frame.id_str().to_owned(), frame.encoding()))
+			.collect::<HashMap<_, _>>()
+	};
+
+	assert!(encodings(&tag)
+		.values()
+		.all(|encoding| *encoding == Some(TextEncoding::UTF8)));
+
+	let write_options = WriteOptions::new().id3v2_text_encoding(TextEncoding::Latin1);
+
+	let mut writer = Vec::new();
+	tag.dump_to(&mut writer, write_options).unwrap();
+
+	let reread = read_tag_raw(&writer);
+	let reread_encodings = encodings(&reread);
+	assert_eq!(reread_encodings["TIT2"], Some(TextEncoding::Latin1));
+	assert_eq!(reread_encodings["TXXX"], Some(TextEncoding::Latin1));
+	// Can't be represented in Latin-1
+	assert_eq!(reread_encodings["TPE1"], Some(TextEncoding::UTF16));
+
+	assert_eq!(reread.artist().as_deref(), Some("ジェイソン"));
+	assert_eq!(reread.get_user_text("FOO"), Some("Bär"));
+
+	// ID3v2.3 can't use UTF-8
+	let mut writer = Vec::new();
+	tag.dump_to(
+		&mut writer,
+		WriteOptions::new()
+			.use_id3v23(true)
+			.id3v2_text_encoding(TextEncoding::UTF8),
+	)
+	.unwrap();
+
+	let reread = read_tag_raw(&writer);
+	assert!(encodings(&reread)
+		.values()
+		.all(|encoding| *encoding == Some(TextEncoding::UTF16)));
+	assert_eq!(reread.title().as_deref(), Some("Foo title"));
+}
+
+#[test]
+fn try_set_year() {
+	let mut tag = Id3v2Tag::new();
+
+	tag.try_set_year(2024).unwrap();
+	assert!(tag.try_set_year(20_240).is_err());
+	assert_eq!(tag.year(), Some(2024));
+}
+
+#[test]
+fn chapters_text_encoding() {
+	// The encoding of the chapter's `TIT2` sub-frame
+	let title_encoding = |tag: &Id3v2Tag| {
+		let Some(Frame::Binary(chapter)) = tag.frames.iter().find(|f| f.id_str() == "CHAP") else {
+			panic!("Expected a CHAP frame");
+		};
+
+		let pos = chapter
+			.data
+			.windows(4)
+			.position(|window| window == b"TIT2")
+			.unwrap();
+
+		// Skip the sub-frame header
+		TextEncoding::from_u8(chapter.data[pos + 10])
+	};
+
+	let mut tag = Id3v2Tag::default();
+	tag.add_chapter(Chapter::new(0, 30_000, Some(String::from("Foo chapter"))))
+		.unwrap();
+	assert_eq!(title_encoding(&tag), Some(TextEncoding::UTF8));
+
+	let mut writer = Vec::new();
+	tag.dump_to(
+		&mut writer,
+		WriteOptions::new().id3v2_text_encoding(TextEncoding::Latin1),
+	)
+	.unwrap();
+
+	let reread = read_tag_raw(&writer);
+	assert_eq!(title_encoding(&reread), Some(TextEncoding::Latin1));
+	assert_eq!(reread.chapters()[0].title.as_deref(), Some("Foo chapter"));
+
+	let mut writer = Vec::new();
+	tag.dump_to(
+		&mut writer,
+		WriteOptions::new()
+			.use_id3v23(true)
+			.id3v2_text_encoding(TextEncoding::Latin1),
+	)
+	.unwrap();
+
+	let reread = read_tag_raw(&writer);
+	assert_eq!(title_encoding(&reread), Some(TextEncoding::Latin1));
+	assert_eq!(reread.chapters()[0].title.as_deref(), Some("Foo chapter"));
+}
@@ -1,30 +1,221 @@
+use crate::config::ParseOptions;
 use crate::error::{Id3v2Error, Id3v2ErrorKind, Result};
 use crate::id3::v2::frame::{FrameFlags, FrameRef};
+use crate::id3::v2::header::Id3v2Version;
+use crate::id3::v2::items::chapter::convert_sub_frames;
 use crate::id3::v2::util::synchsafe::SynchsafeInteger;
+use crate::id3::v2::{BinaryFrame, FrameId, KeyValueFrame, TextInformationFrame};
+use crate::util::text::TextEncoding;
 
+use std::borrow::Cow;
 use std::io::Write;
 
 use crate::id3::v2::Frame;
 use byteorder::{BigEndian, WriteBytesExt};
 
+// ID3v2.4 frames with no ID3v2.3 equivalent
+const ID3V24_ONLY_FRAMES: [&str; 11] = [
+	"ASPI", "EQU2", "RVA2", "SEEK", "SIGN", "TDEN", "TDRL", "TDTG", "TMOO", "TPRO", "TSST",
+];
+
 pub(in crate::id3::v2) fn create_items<W>(
 	writer: &mut W,
 	frames: &mut dyn Iterator<Item = FrameRef<'_>>,
+	is_id3v23: bool,
+	text_encoding: Option<TextEncoding>,
 ) -> Result<()>
 where
 	W: Write,
 {
+	let mut discarded = Vec::new();
+	write_items(writer, frames, is_id3v23, text_encoding, &mut discarded)?;
+
+	if !discarded.is_empty() {
+		log::warn!(
+			"Discarding frames with no ID3v2.3 equivalent: {}",
+			discarded.join(", ")
+		);
+	}
+
+	Ok(())
+}
+
+// Same as `create_items`, but the IDs of any frames discarded when writing ID3v2.3 are added to
+// `discarded` rather than logged, so that chapter sub-frames can be reported along with the rest
+pub(in crate::id3::v2) fn write_items<W>(
+	writer: &mut W,
+	frames: &mut dyn Iterator<Item = FrameRef<'_>>,
+	is_id3v23: bool,
+	text_encoding: Option<TextEncoding>,
+	discarded: &mut Vec<String>,
+) -> Result<()>
+where
+	W: Write,
+{
+	let frames = &mut frames.map(|frame| {
+		match text_encoding.and_then(|encoding| frame.reencode(encoding)) {
+			Some(reencoded) => FrameRef(Cow::Owned(reencoded)),
+			None => frame,
+		}
+	});
+
+	if is_id3v23 {
+		for frame in id3v23_frames(frames, text_encoding, discarded) {
+			write_item(writer, &frame, true)?;
+		}
+
+		return Ok(());
+	}
+
 	for frame in frames {
-		verify_frame(&frame)?;
-		let value = frame.as_bytes()?;
+		// Chapter sub-frames need to be re-encoded as well
+		let frame = match text_encoding {
+			Some(_) => convert_chapter_frame(frame, false, text_encoding, discarded),
+			None => frame,
+		};
 
-		write_frame(writer, frame.id().as_str(), frame.flags(), &value)?;
+		write_item(writer, &frame, false)?;
 	}
 
 	Ok(())
 }
 
-fn verify_frame(frame: &FrameRef<'_>) -> Result<()> {
+// Rewrites the sub-frames of a `CHAP` or `CTOC` frame with the same options as the rest of the tag
+//
+// Any other frame, or a frame with sub-frames that can't be converted, is returned as-is.
+fn convert_chapter_frame<'a>(
+	frame: FrameRef<'a>,
+	is_id3v23: bool,
+	text_encoding: Option<TextEncoding>,
+	discarded: &mut Vec<String>,
+) -> FrameRef<'a> {
+	let Frame::Binary(BinaryFrame { header, data }) = &*frame else {
+		return frame;
+	};
+
+	if header.id.as_str() != "CHAP" && header.id.as_str() != "CTOC" {
+		return frame;
+	}
+
+	// The sub-frames are only discarded if the conversion succeeds
+	let mut sub_frames_discarded = Vec::new();
+	match convert_sub_frames(
+		&header.id,
+		data,
+		Id3v2Version::V4,
+		ParseOptions::new(),
+		is_id3v23,
+		text_encoding,
+		&mut sub_frames_discarded,
+	) {
+		Ok(data) => {
+			discarded.append(&mut sub_frames_discarded);
+			FrameRef(Cow::Owned(Frame::Binary(BinaryFrame {
+				header: header.clone(),
+				data,
+			})))
+		},
+		Err(e) => {
+			log::warn!(
+				"Failed to convert the sub-frames of \"{}\", writing them as-is: {e}",
+				header.id
+			);
+			frame
+		},
+	}
+}
+
+fn write_item<W>(writer: &mut W, frame: &FrameRef<'_>, is_id3v23: bool) -> Result<()>
+where
+	W: Write,
+{
+	verify_frame(frame, is_id3v23)?;
+	let value = frame.as_bytes(is_id3v23)?;
+
+	if is_id3v23 {
+		return write_frame_id3v23(writer, frame.id().as_str(), frame.flags(), &value);
+	}
+
+	write_frame(writer, frame.id().as_str(), frame.flags(), &value)
+}
+
+// Converts any ID3v2.4 specific frames to their ID3v2.3 counterparts
+fn id3v23_frames<'a>(
+	frames: &mut dyn Iterator<Item = FrameRef<'a>>,
+	text_encoding: Option<TextEncoding>,
+	discarded: &mut Vec<String>,
+) -> Vec<FrameRef<'a>> {
+	let mut ret = Vec::new();
+	let mut involved_people: Option<KeyValueFrame<'static>> = None;
+
+	for frame in frames {
+		let id = frame.id().as_str();
+
+		if ID3V24_ONLY_FRAMES.contains(&id) {
+			discarded.push(id.to_owned());
+			continue;
+		}
+
+		match &*frame {
+			Frame::Timestamp(timestamp_frame) if id == "TDRC" => {
+				let timestamp = timestamp_frame.timestamp;
+
+				let mut push_text = |id: &'static str, value: String| {
+					ret.push(FrameRef(Cow::Owned(Frame::Text(
+						TextInformationFrame::new(
+							FrameId::Valid(Cow::Borrowed(id)),
+							timestamp_frame.encoding,
+							value,
+						),
+					))));
+				};
+
+				push_text("TYER", format!("{:04}", timestamp.year));
+
+				if let (Some(month), Some(day)) = (timestamp.month, timestamp.day) {
+					push_text("TDAT", format!("{day:02}{month:02}"));
+				}
+
+				if let (Some(hour), Some(minute)) = (timestamp.hour, timestamp.minute) {
+					push_text("TIME", format!("{hour:02}{minute:02}"));
+				}
+			},
+			Frame::Timestamp(timestamp_frame) if id == "TDOR" => {
+				ret.push(FrameRef(Cow::Owned(Frame::Text(
+					TextInformationFrame::new(
+						FrameId::Valid(Cow::Borrowed("TORY")),
+						timestamp_frame.encoding,
+						format!("{:04}", timestamp_frame.timestamp.year),
+					),
+				))));
+			},
+			Frame::KeyValue(key_value) if id == "TIPL" || id == "TMCL" => match involved_people {
+				Some(ref mut involved_people) => involved_people
+					.key_value_pairs
+					.extend(key_value.key_value_pairs.iter().cloned()),
+				None => {
+					involved_people = Some(KeyValueFrame::new(
+						FrameId::Valid(Cow::Borrowed("IPLS")),
+						key_value.encoding,
+						key_value.key_value_pairs.clone(),
+					))
+				},
+			},
+			Frame::Binary(_) if id == "CHAP" || id == "CTOC" => {
+				ret.push(convert_chapter_frame(frame, true, text_encoding, discarded))
+			},
+			_ => ret.push(frame),
+		}
+	}
+
+	if let Some(involved_people) = involved_people {
+		ret.push(FrameRef(Cow::Owned(Frame::KeyValue(involved_people))));
+	}
+
+	ret
+}
+
+fn verify_frame(frame: &FrameRef<'_>, is_id3v23: bool) -> Result<()> {
 	match (frame.id().as_str(), &**frame) {
 		("APIC", Frame::Picture { .. })
 		| ("USLT", Frame::UnsynchronizedText(_))
@@ -39,6 +230,7 @@ fn verify_frame(frame: &FrameRef<'_>) -> Result<()> {
 		| ("TDEN" | "TDOR" | "TDRC" | "TDRL" | "TDTG", Frame::Timestamp(_))
 		| ("RVA2", Frame::RelativeVolumeAdjustment(_))
 		| ("PRIV", Frame::Private(_)) => Ok(()),
+		("IPLS", Frame::KeyValue { .. }) if is_id3v23 => Ok(()),
 		(id, Frame::Text { .. }) if id.starts_with('T') => Ok(()),
 		(id, Frame::Url(_)) if id.starts_with('W') => Ok(()),
 		(id, frame_value) => Err(Id3v2Error::new(Id3v2ErrorKind::BadFrame(
@@ -116,6 +308,80 @@ where
 	Ok(())
 }
 
+fn write_frame_id3v23<W>(writer: &mut W, name: &str, flags: FrameFlags, value: &[u8]) -> Result<()>
+where
+	W: Write,
+{
+	// In ID3v2.3, the additional frame data is ordered: decompressed size, encryption method, group identifier
+	let mut additional_data = Vec::new();
+
+	if flags.compression {
+		let Some(len) = flags.data_length_indicator else {
+			return Err(Id3v2Error::new(Id3v2ErrorKind::MissingDataLengthIndicator).into());
+		};
+
+		additional_data.write_u32::<BigEndian>(len)?;
+	}
+
+	if let Some(method_symbol) = flags.encryption {
+		if method_symbol > 0x80 {
+			return Err(
+				Id3v2Error::new(Id3v2ErrorKind::InvalidEncryptionMethodSymbol(method_symbol))
+					.into(),
+			);
+		}
+
+		additional_data.push(method_symbol);
+	}
+
+	if let Some(group) = flags.grouping_identity {
+		additional_data.push(group);
+	}
+
+	// Unlike ID3v2.4, the frame size is not synchsafe
+	writer.write_all(name.as_bytes())?;
+	writer.write_u32::<BigEndian>((additional_data.len() + value.len()) as u32)?;
+	writer.write_u16::<BigEndian>(get_flags_id3v23(flags))?;
+	writer.write_all(&additional_data)?;
+	writer.write_all(value)?;
+
+	Ok(())
+}
+
+fn get_flags_id3v23(tag_flags: FrameFlags) -> u16 {
+	let mut flags = 0;
+
+	if tag_flags == FrameFlags::default() {
+		return flags;
+	}
+
+	if tag_flags.tag_alter_preservation {
+		flags |= 0x8000
+	}
+
+	if tag_flags.file_alter_preservation {
+		flags |= 0x4000
+	}
+
+	if tag_flags.read_only {
+		flags |= 0x2000
+	}
+
+	if tag_flags.compression {
+		flags |= 0x0080
+	}
+
+	if tag_flags.encryption.is_some() {
+		flags |= 0x0040
+	}
+
+	if tag_flags.grouping_identity.is_some() {
+		flags |= 0x0020
+	}
+
+	flags
+}
+
 fn get_flags(tag_flags: FrameFlags) -> u16 {
 	let mut flags = 0;
 
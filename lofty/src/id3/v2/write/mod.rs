@@ -1,25 +1,27 @@
-mod chunk_file;
-mod frame;
+pub(in crate::id3::v2) mod frame;
 
 use super::Id3v2TagFlags;
 use crate::config::WriteOptions;
 use crate::error::{LoftyError, Result};
 use crate::file::FileType;
-use crate::id3::v2::frame::FrameRef;
+use crate::id3::v2::frame::{Frame, FrameRef};
 use crate::id3::v2::tag::Id3v2TagRef;
 use crate::id3::v2::util::synchsafe::SynchsafeInteger;
-use crate::id3::v2::Id3v2Tag;
-use crate::id3::{find_id3v2, FindId3v2Config};
+use crate::id3::v2::{FrameId, Id3v2Tag, TextInformationFrame};
 use crate::macros::{err, try_vec};
 use crate::probe::Probe;
 use crate::util::io::{FileLike, Length, Truncate};
+use crate::util::text::TextEncoding;
 
+use std::borrow::Cow;
 use std::io::{Cursor, Read, Seek, SeekFrom, Write};
 use std::ops::Not;
 use std::sync::OnceLock;
 
 use byteorder::{BigEndian, LittleEndian, WriteBytesExt};
 
+const ENCODER_ID: &str = "TSSE";
+
 // In the very rare chance someone wants to write a CRC in their extended header
 fn crc_32_table() -> &'static [u32; 256] {
 	static INSTANCE: OnceLock<[u32; 256]> = OnceLock::new();
@@ -71,35 +73,55 @@ where
 		err!(UnsupportedTag);
 	}
 
-	let id3v2 = create_tag(tag, write_options)?;
-
+	// The tag itself doesn't care where it ends up, locating and replacing the existing tag is
+	// left to the container.
 	match file_type {
 		// Formats such as WAV and AIFF store the ID3v2 tag in an 'ID3 ' chunk rather than at the beginning of the file
 		FileType::Wav => {
 			tag.flags.footer = false;
-			return chunk_file::write_to_chunk_file::<F, LittleEndian>(file, &id3v2, write_options);
+			let id3v2 = create_tag(tag, write_options)?;
+			crate::iff::id3v2::write_id3v2::<F, LittleEndian>(file, &id3v2, write_options)
 		},
 		FileType::Aiff => {
 			tag.flags.footer = false;
-			return chunk_file::write_to_chunk_file::<F, BigEndian>(file, &id3v2, write_options);
+			let id3v2 = create_tag(tag, write_options)?;
+			crate::iff::id3v2::write_id3v2::<F, BigEndian>(file, &id3v2, write_options)
+		},
+		// DSF files store the tag at the end of the file
+		FileType::Dsf => {
+			let id3v2 = create_tag(tag, write_options)?;
+			crate::dsf::id3v2::write_id3v2(file, &id3v2)
+		},
+		_ => {
+			let id3v2 = create_tag(tag, write_options)?;
+			crate::mpeg::id3v2::write_id3v2(file, id3v2, tag.flags.footer, write_options)
 		},
-		_ => {},
 	}
+}
 
-	// find_id3v2 will seek us to the end of the tag
-	// TODO: Search through junk
-	find_id3v2(file, FindId3v2Config::NO_READ_TAG)?;
+// Resizes the padding of a newly created tag, so that its contents are `existing_size` bytes (the same
+// as an existing tag's).
+//
+// Returns `false` (leaving the tag untouched) if the tag doesn't fit.
+pub(crate) fn fit_to_region(
+	id3v2: &mut Vec<u8>,
+	write_options: WriteOptions,
+	existing_size: u32,
+) -> Result<bool> {
+	const HEADER_LEN: usize = 10;
 
-	let mut file_bytes = Vec::new();
-	file.read_to_end(&mut file_bytes)?;
+	let padding_len = write_options.preferred_padding.unwrap_or(0) as usize;
+	let content_len = id3v2.len() - HEADER_LEN - padding_len;
 
-	file_bytes.splice(0..0, id3v2);
+	if content_len > existing_size as usize {
+		log::debug!("New tag doesn't fit in the existing one, rewriting the file");
+		return Ok(false);
+	}
 
-	file.rewind()?;
-	file.truncate(0)?;
-	file.write_all(&file_bytes)?;
+	id3v2.resize(HEADER_LEN + existing_size as usize, 0);
+	id3v2[6..HEADER_LEN].copy_from_slice(&existing_size.synch()?.to_be_bytes());
 
-	Ok(())
+	Ok(true)
 }
 
 pub(super) fn create_tag<'a, I: Iterator<Item = FrameRef<'a>> + 'a>(
@@ -114,15 +136,55 @@ pub(super) fn create_tag<'a, I: Iterator<Item = FrameRef<'a>> + 'a>(
 		return Ok(Vec::new());
 	}
 
+	let is_id3v23 = write_options.use_id3v23;
+	if is_id3v23 {
+		log::debug!("Writing an ID3v2.3 tag");
+
+		// TODO: Support the ID3v2.3 extended header
+		if tag.flags.crc || tag.flags.restrictions.is_some() || tag.flags.footer {
+			log::warn!("ID3v2.3 tags can't have a footer or extended header, discarding");
+		}
+
+		tag.flags.crc = false;
+		tag.flags.restrictions = None;
+		tag.flags.footer = false;
+	}
+
 	let has_footer = tag.flags.footer;
 	let needs_crc = tag.flags.crc;
 	let has_restrictions = tag.flags.restrictions.is_some();
 
-	let (mut id3v2, extended_header_len) = create_tag_header(tag.flags)?;
+	let (mut id3v2, extended_header_len) = create_tag_header(tag.flags, is_id3v23)?;
 	let header_len = id3v2.get_ref().len();
 
 	// Write the items
-	frame::create_items(&mut id3v2, &mut peek)?;
+	let mut has_encoder = false;
+	frame::create_items(
+		&mut id3v2,
+		&mut peek.inspect(|frame| has_encoder |= frame.id().as_str() == ENCODER_ID),
+		is_id3v23,
+		write_options.id3v2_text_encoding,
+	)?;
+
+	// See `WriteOptions::encoder_stamp`
+	if let Some(encoder_stamp) = write_options.encoder_stamp_value() {
+		if !has_encoder {
+			log::debug!("Stamping ID3v2 tag with encoder: {}", encoder_stamp);
+
+			let stamp = Frame::Text(TextInformationFrame::new(
+				FrameId::Valid(Cow::Borrowed(ENCODER_ID)),
+				TextEncoding::UTF8,
+				String::from(encoder_stamp),
+			));
+
+			frame::create_items(
+				&mut id3v2,
+				&mut std::iter::once(FrameRef(Cow::Owned(stamp))),
+				is_id3v23,
+				write_options.id3v2_text_encoding,
+			)?;
+		}
+	}
 
 	let mut len = id3v2.get_ref().len() - header_len;
 
@@ -190,15 +252,19 @@ pub(super) fn create_tag<'a, I: Iterator<Item = FrameRef<'a>> + 'a>(
 	Ok(id3v2.into_inner())
 }
 
-fn create_tag_header(flags: Id3v2TagFlags) -> Result<(Cursor<Vec<u8>>, u32)> {
+fn create_tag_header(flags: Id3v2TagFlags, is_id3v23: bool) -> Result<(Cursor<Vec<u8>>, u32)> {
 	let mut header = Cursor::new(Vec::new());
 
 	header.write_all(&[b'I', b'D', b'3'])?;
 
 	let mut tag_flags = 0;
 
-	// Version 4, rev 0
-	header.write_all(&[4, 0])?;
+	// Version 3 or 4, rev 0
+	if is_id3v23 {
+		header.write_all(&[3, 0])?;
+	} else {
+		header.write_all(&[4, 0])?;
+	}
 
 	let extended_header = flags.crc || flags.restrictions.is_some();
 
@@ -286,9 +352,14 @@ fn calculate_crc(content: &[u8]) -> [u8; 5] {
 
 #[cfg(test)]
 mod tests {
-	use crate::config::WriteOptions;
+	use crate::config::{ParseOptions, WriteOptions};
 	use crate::id3::v2::{Id3v2Tag, Id3v2TagFlags};
+	use crate::mpeg::MpegFile;
 	use crate::prelude::*;
+	use crate::probe::Probe;
+	use crate::tag::TagType;
+
+	use std::io::Cursor;
 
 	#[test]
 	fn id3v2_write_crc32() {
@@ -320,4 +391,87 @@ mod tests {
 
 		assert_eq!(unsynch_crc, 0x66BA_7E94);
 	}
+
+	#[test]
+	fn write_in_place() {
+		fn write(file: &mut Cursor<Vec<u8>>, title: &str, write_options: WriteOptions) -> usize {
+			let mut tag = Id3v2Tag::default();
+			tag.set_title(title.to_string());
+
+			file.set_position(0);
+			tag.save_to(file, write_options).unwrap();
+
+			file.set_position(0);
+			let mpeg_file = MpegFile::read_from(file, ParseOptions::new()).unwrap();
+			assert_eq!(
+				mpeg_file.id3v2().and_then(Id3v2Tag::title).as_deref(),
+				Some(title)
+			);
+
+			file.get_ref().len()
+		}
+
+		let mut file =
+			Cursor::new(std::fs::read("tests/files/assets/minimal/full_test.mp3").unwrap());
+
+		// The existing tag has no padding, force a rewrite to add the default padding
+		let write_options = WriteOptions::default().force_rewrite(true);
+		let len = write(&mut file, "Foo title", write_options);
+
+		// Fits within the padding
+		let title = "Bar title".repeat(50);
+		assert_eq!(write(&mut file, &title, WriteOptions::default()), len);
+
+		// No longer fits, the file has to grow
+		let title = "Baz title".repeat(200);
+		let grown_len = write(&mut file, &title, WriteOptions::default());
+		assert!(grown_len > len);
+
+		// Shrinking the tag keeps the existing space, unless a rewrite is forced
+		assert_eq!(
+			write(&mut file, "Foo title", WriteOptions::default()),
+			grown_len
+		);
+
+		assert_eq!(write(&mut file, "Foo title", write_options), len);
+	}
+
+	#[test]
+	fn write_to_carriers() {
+		fn read_title(file: &mut Cursor<Vec<u8>>) -> Option<String> {
+			file.set_position(0);
+			let tagged_file = Probe::new(file).guess_file_type().unwrap().read().unwrap();
+
+			tagged_file
+				.tag(TagType::Id3v2)
+				.and_then(|tag| tag.title().map(|title| title.into_owned()))
+		}
+
+		for file_name in ["full_test.mp3", "wav_format_pcm.wav", "full_test.aiff"] {
+			let mut file = Cursor::new(
+				std::fs::read(format!("tests/files/assets/minimal/{file_name}")).unwrap(),
+			);
+
+			let mut tag = Id3v2Tag::default();
+			tag.set_title(String::from("Foo title"));
+
+			file.set_position(0);
+			tag.save_to(&mut file, WriteOptions::default()).unwrap();
+			assert_eq!(read_title(&mut file).as_deref(), Some("Foo title"));
+
+			// Replaces the existing tag, rather than adding another
+			tag.set_title(String::from("Bar title"));
+
+			file.set_position(0);
+			tag.save_to(&mut file, WriteOptions::default()).unwrap();
+			assert_eq!(read_title(&mut file).as_deref(), Some("Bar title"));
+
+			// An empty tag removes the existing one
+			file.set_position(0);
+			Id3v2Tag::default()
+				.save_to(&mut file, WriteOptions::default())
+				.unwrap();
+			assert_eq!(read_title(&mut file), None);
+		}
+	}
 }
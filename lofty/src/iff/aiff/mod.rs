@@ -13,6 +13,8 @@ use lofty_attr::LoftyFile;
 pub use properties::{AiffCompressionType, AiffProperties};
 pub use tag::{AiffTextChunks, Comment};
 
+pub(crate) use read::audio_payload_region;
+
 /// An AIFF file
 #[derive(LoftyFile)]
 #[lofty(read_fn = "read::read_from")]
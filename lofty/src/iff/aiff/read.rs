@@ -43,6 +43,34 @@ where
 	Ok(compression_present)
 }
 
+/// Get the offset and size of the contents of the first `SSND` chunk
+///
+/// If the chunk claims to be larger than the remainder of the file, the remainder is used instead.
+pub(crate) fn audio_payload_region<R>(data: &mut R) -> Result<Option<(u64, u64)>>
+where
+	R: Read + Seek,
+{
+	verify_aiff(data)?;
+
+	let current_pos = data.stream_position()?;
+	let file_len = data.seek(SeekFrom::End(0))?;
+
+	data.seek(SeekFrom::Start(current_pos))?;
+
+	let mut chunks = Chunks::<BigEndian>::new(file_len);
+	while chunks.next(data).is_ok() {
+		if &chunks.fourcc == b"SSND" {
+			let start = data.stream_position()?;
+			let available = file_len.saturating_sub(start);
+			return Ok(Some((start, u64::from(chunks.size).min(available))));
+		}
+
+		chunks.skip(data)?;
+	}
+
+	Ok(None)
+}
+
 pub(crate) fn read_from<R>(data: &mut R, parse_options: ParseOptions) -> Result<AiffFile>
 where
 	R: Read + Seek,
@@ -62,15 +90,25 @@ where
 	let mut text_chunks = AiffTextChunks::default();
 	let mut annotations = Vec::new();
 	let mut comments = Vec::new();
+	let mut text_chunks_size = 0_u64;
 
 	let mut id3v2_tag: Option<Id3v2Tag> = None;
 
 	let mut chunks = Chunks::<BigEndian>::new(file_len);
 
 	while chunks.next(data).is_ok() {
+		// The text chunks are all part of a single tag, so their sizes are checked together
+		if matches!(
+			&chunks.fourcc,
+			b"ANNO" | b"COMT" | b"NAME" | b"AUTH" | b"(c) "
+		) {
+			text_chunks_size += u64::from(chunks.size);
+			parse_options.check_tag_size(text_chunks_size)?;
+		}
+
 		match &chunks.fourcc {
 			b"ID3 " | b"id3 " => {
-				let tag = chunks.id3_chunk(data, parse_options.parsing_mode)?;
+				let tag = chunks.id3_chunk(data, parse_options)?;
 				if let Some(existing_tag) = id3v2_tag.as_mut() {
 					log::warn!("Duplicate ID3v2 tag found, appending frames to previous tag");
 
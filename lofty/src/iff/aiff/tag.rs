@@ -1,5 +1,6 @@
-use crate::config::WriteOptions;
+use crate::config::{ParseOptions, WriteOptions};
 use crate::error::{LoftyError, Result};
+use crate::file::write_verified;
 use crate::iff::chunk::Chunks;
 use crate::macros::err;
 use crate::tag::{Accessor, ItemKey, ItemValue, MergeTag, SplitTag, Tag, TagExt, TagItem, TagType};
@@ -7,7 +8,7 @@ use crate::util::io::{FileLike, Length, Truncate};
 
 use std::borrow::Cow;
 use std::convert::TryFrom;
-use std::io::{SeekFrom, Write};
+use std::io::{Cursor, Read, SeekFrom, Write};
 
 use byteorder::BigEndian;
 use lofty_attr::tag;
@@ -136,6 +137,55 @@ impl AiffTextChunks {
 		Self::default()
 	}
 
+	/// Read a tag previously written with [`TagExt::dump_to`]
+	///
+	/// The entire remainder of `reader` is expected to be text chunks.
+	///
+	/// # Errors
+	///
+	/// * `reader` is too large to be contained in an AIFF file
+	/// * A chunk is malformed
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use lofty::config::WriteOptions;
+	/// use lofty::iff::aiff::AiffTextChunks;
+	/// use lofty::tag::{Accessor, TagExt};
+	///
+	/// # fn main() -> lofty::error::Result<()> {
+	/// let mut tag = AiffTextChunks::new();
+	/// tag.set_title(String::from("Foo title"));
+	///
+	/// let mut dump = Vec::new();
+	/// tag.dump_to(&mut dump, WriteOptions::default())?;
+	///
+	/// let re_read = AiffTextChunks::read_from_dump(&mut &dump[..])?;
+	/// assert_eq!(re_read.title().as_deref(), Some("Foo title"));
+	/// # Ok(()) }
+	/// ```
+	pub fn read_from_dump<R>(reader: &mut R) -> Result<Self>
+	where
+		R: Read,
+	{
+		// The chunk reader expects a complete file, so the chunks
+		// need to be wrapped in a fake FORM chunk
+		let mut form = b"FORM\0\0\0\0AIFF".to_vec();
+		reader.read_to_end(&mut form)?;
+
+		let Ok(form_size) = u32::try_from(form.len() - 8) else {
+			err!(TooMuchData);
+		};
+		form[4..8].copy_from_slice(&form_size.to_be_bytes());
+
+		let file = super::read::read_from(
+			&mut Cursor::new(form),
+			ParseOptions::new().read_properties(false),
+		)?;
+
+		Ok(file.text_chunks_tag.unwrap_or_default())
+	}
+
 	/// Returns the copyright message
 	pub fn copyright(&self) -> Option<&str> {
 		self.copyright.as_deref()
@@ -317,13 +367,15 @@ where
 	T: AsRef<str>,
 	AI: IntoIterator<Item = T>,
 {
-	pub(crate) fn write_to<F>(self, file: &mut F, _write_options: WriteOptions) -> Result<()>
+	pub(crate) fn write_to<F>(self, file: &mut F, write_options: WriteOptions) -> Result<()>
 	where
 		F: FileLike,
 		LoftyError: From<<F as Truncate>::Error>,
 		LoftyError: From<<F as Length>::Error>,
 	{
-		AiffTextChunksRef::write_to_inner(file, self)
+		write_verified(file, write_options, |file, _| {
+			AiffTextChunksRef::write_to_inner(file, self)
+		})
 	}
 
 	pub(crate) fn dump_to<W: Write>(
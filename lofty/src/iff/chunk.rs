@@ -1,7 +1,7 @@
-use crate::config::ParsingMode;
-use crate::error::Result;
+use crate::config::ParseOptions;
+use crate::error::{ErrorKind, LoftyError, Result};
 use crate::id3::v2::tag::Id3v2Tag;
-use crate::macros::{err, try_vec};
+use crate::macros::try_vec;
 use crate::util::text::utf8_decode;
 
 use std::io::{Read, Seek, SeekFrom};
@@ -15,6 +15,8 @@ where
 {
 	pub fourcc: [u8; 4],
 	pub size: u32,
+	/// The offset of the current chunk's header
+	pub offset: u64,
 	remaining_size: u64,
 	_phantom: PhantomData<B>,
 }
@@ -25,6 +27,7 @@ impl<B: ByteOrder> Chunks<B> {
 		Self {
 			fourcc: [0; 4],
 			size: 0,
+			offset: 0,
 			remaining_size: file_size,
 			_phantom: PhantomData,
 		}
@@ -32,8 +35,9 @@ impl<B: ByteOrder> Chunks<B> {
 
 	pub fn next<R>(&mut self, data: &mut R) -> Result<()>
 	where
-		R: Read,
+		R: Read + Seek,
 	{
+		self.offset = data.stream_position()?;
 		data.read_exact(&mut self.fourcc)?;
 		self.size = data.read_u32::<B>()?;
 
@@ -81,7 +85,7 @@ impl<B: ByteOrder> Chunks<B> {
 		R: Read,
 	{
 		if size > self.remaining_size {
-			err!(SizeMismatch);
+			return Err(self.bad_size());
 		}
 
 		let mut content = try_vec![0; size as usize];
@@ -91,19 +95,25 @@ impl<B: ByteOrder> Chunks<B> {
 		Ok(content)
 	}
 
-	pub fn id3_chunk<R>(&mut self, data: &mut R, parse_mode: ParsingMode) -> Result<Id3v2Tag>
+	/// An error for a chunk whose size can't be valid, pointing to the current chunk
+	pub fn bad_size(&self) -> LoftyError {
+		LoftyError::new(ErrorKind::BadChunkSize {
+			chunk: String::from_utf8_lossy(&self.fourcc).into_owned(),
+			offset: self.offset,
+		})
+	}
+
+	pub fn id3_chunk<R>(&mut self, data: &mut R, parse_options: ParseOptions) -> Result<Id3v2Tag>
 	where
 		R: Read + Seek,
 	{
-		use crate::id3::v2::header::Id3v2Header;
-		use crate::id3::v2::read::parse_id3v2;
+		use crate::id3::v2::read::parse_id3v2_window;
 
-		let content = self.content(data)?;
-
-		let reader = &mut &*content;
+		parse_options.check_tag_size(u64::from(self.size))?;
 
-		let header = Id3v2Header::parse(reader)?;
-		let id3v2 = parse_id3v2(reader, header, parse_mode)?;
+		let content_offset = data.stream_position()?;
+		let content = self.content(data)?;
+		let id3v2 = parse_id3v2_window(&content, parse_options, Some(content_offset))?;
 
 		// Skip over the footer
 		if id3v2.flags().footer {
@@ -1,6 +1,6 @@
+use super::chunk::Chunks;
 use crate::config::WriteOptions;
 use crate::error::{LoftyError, Result};
-use crate::iff::chunk::Chunks;
 use crate::util::io::{FileLike, Length, Truncate};
 
 use std::io::SeekFrom;
@@ -10,11 +10,11 @@ use byteorder::{ByteOrder, WriteBytesExt};
 const CHUNK_NAME_UPPER: [u8; 4] = [b'I', b'D', b'3', b' '];
 const CHUNK_NAME_LOWER: [u8; 4] = [b'i', b'd', b'3', b' '];
 
-pub(in crate::id3::v2) fn write_to_chunk_file<F, B>(
-	file: &mut F,
-	tag: &[u8],
-	write_options: WriteOptions,
-) -> Result<()>
+/// Replace the `ID3 ` chunk of a WAV or AIFF file
+///
+/// Any existing chunk is removed, and the new one is appended to the end of the file.
+/// An empty `tag` removes the existing chunk.
+pub(crate) fn write_id3v2<F, B>(file: &mut F, tag: &[u8], write_options: WriteOptions) -> Result<()>
 where
 	F: FileLike,
 	LoftyError: From<<F as Truncate>::Error>,
@@ -63,7 +63,13 @@ where
 	}
 
 	if !tag.is_empty() {
-		file.seek(SeekFrom::End(0))?;
+		let file_end = file.seek(SeekFrom::End(0))?;
+
+		// Chunks must start on even boundaries, the final chunk may be missing its pad byte
+		if file_end % 2 != 0 {
+			log::warn!("Final chunk is missing its pad byte, adding it");
+			file.write_u8(0)?;
+		}
 
 		if write_options.uppercase_id3v2_chunk {
 			file.write_all(&CHUNK_NAME_UPPER)?;
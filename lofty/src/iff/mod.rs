@@ -1,4 +1,5 @@
 //! WAV/AIFF specific items
 pub mod aiff;
 pub(crate) mod chunk;
+pub(crate) mod id3v2;
 pub mod wav;
@@ -0,0 +1,511 @@
+use crate::error::{LoftyError, Result};
+use crate::iff::chunk::Chunks;
+use crate::iff::wav::read::verify_wav;
+use crate::macros::{decode_err, err};
+use crate::tag::{ItemKey, Tag};
+use crate::util::io::{FileLike, Length, Truncate};
+use crate::util::text::latin1_decode;
+
+use std::io::{Read, Seek, SeekFrom, Write};
+
+use byteorder::{LittleEndian, ReadBytesExt, WriteBytesExt};
+
+const DESCRIPTION_LEN: usize = 256;
+const ORIGINATOR_LEN: usize = 32;
+const ORIGINATOR_REFERENCE_LEN: usize = 32;
+const ORIGINATION_DATE_LEN: usize = 10;
+const ORIGINATION_TIME_LEN: usize = 8;
+const UMID_LEN: usize = 64;
+const RESERVED_LEN: usize = 180;
+
+// Everything before the coding history
+const BEXT_HEADER_LEN: usize = 602;
+
+/// Loudness values of a [`BextChunk`]
+///
+/// These are only present in version 2 and above. All values are stored in hundredths,
+/// for example, a loudness value of -23 LUFS is stored as `-2300`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Default)]
+pub struct BextLoudness {
+	/// The integrated loudness, in LUFS
+	pub loudness_value: i16,
+	/// The loudness range, in LU
+	pub loudness_range: i16,
+	/// The maximum true peak level, in dBTP
+	pub max_true_peak_level: i16,
+	/// The highest momentary loudness, in LUFS
+	pub max_momentary_loudness: i16,
+	/// The highest short-term loudness, in LUFS
+	pub max_short_term_loudness: i16,
+}
+
+/// A Broadcast Wave Format `bext` chunk
+///
+/// This is not a tag, and is not written with [`AudioFile::save_to`](crate::file::AudioFile::save_to).
+/// Use [`BextChunk::write_to`] and [`BextChunk::remove_from`] instead.
+///
+/// The text fields have fixed sizes, and are stored as ISO-8859-1. When writing, longer values are
+/// truncated and characters outside of ISO-8859-1 are replaced with `?`.
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Default)]
+pub struct BextChunk {
+	/// A description of the sound sequence (256 bytes)
+	pub description: String,
+	/// The name of the originator (32 bytes)
+	pub originator: String,
+	/// A reference to the originator (32 bytes)
+	pub originator_reference: String,
+	/// The date of creation, formatted as `yyyy-mm-dd` (10 bytes)
+	pub origination_date: String,
+	/// The time of creation, formatted as `hh:mm:ss` (8 bytes)
+	pub origination_time: String,
+	/// The timecode of the first sample, as a sample count since midnight
+	pub time_reference: u64,
+	/// The version of the chunk
+	pub version: u16,
+	/// A SMPTE UMID (64 bytes)
+	///
+	/// This is empty if the chunk has no UMID.
+	pub umid: Vec<u8>,
+	/// The loudness values, only present in version 2 and above
+	pub loudness: Option<BextLoudness>,
+	/// The coding history, a series of lines describing how the audio was processed
+	pub coding_history: String,
+}
+
+impl BextChunk {
+	/// Read a [`BextChunk`] from the contents of a `bext` chunk
+	///
+	/// NOTE: This expects the chunk header to have already been skipped
+	///
+	/// # Errors
+	///
+	/// * `data` is shorter than the 602 byte header
+	pub fn parse(data: &[u8]) -> Result<Self> {
+		if data.len() < BEXT_HEADER_LEN {
+			decode_err!(@BAIL Wav, "bext chunk is too short");
+		}
+
+		let (mut header, coding_history) = data.split_at(BEXT_HEADER_LEN);
+
+		let description = read_text(&mut header, DESCRIPTION_LEN);
+		let originator = read_text(&mut header, ORIGINATOR_LEN);
+		let originator_reference = read_text(&mut header, ORIGINATOR_REFERENCE_LEN);
+		let origination_date = read_text(&mut header, ORIGINATION_DATE_LEN);
+		let origination_time = read_text(&mut header, ORIGINATION_TIME_LEN);
+
+		let time_reference_low = header.read_u32::<LittleEndian>()?;
+		let time_reference_high = header.read_u32::<LittleEndian>()?;
+		let time_reference = (u64::from(time_reference_high) << 32) | u64::from(time_reference_low);
+
+		let version = header.read_u16::<LittleEndian>()?;
+
+		let (umid, mut header) = header.split_at(UMID_LEN);
+		let umid = if umid.iter().all(|b| *b == 0) {
+			Vec::new()
+		} else {
+			umid.to_vec()
+		};
+
+		let loudness = BextLoudness {
+			loudness_value: header.read_i16::<LittleEndian>()?,
+			loudness_range: header.read_i16::<LittleEndian>()?,
+			max_true_peak_level: header.read_i16::<LittleEndian>()?,
+			max_momentary_loudness: header.read_i16::<LittleEndian>()?,
+			max_short_term_loudness: header.read_i16::<LittleEndian>()?,
+		};
+
+		Ok(Self {
+			description,
+			originator,
+			originator_reference,
+			origination_date,
+			origination_time,
+			time_reference,
+			version,
+			umid,
+			loudness: (version >= 2).then_some(loudness),
+			coding_history: latin1_decode(coding_history),
+		})
+	}
+
+	/// Convert a [`BextChunk`] to the contents of a `bext` chunk
+	///
+	/// NOTE: This does not include the chunk header
+	pub fn as_bytes(&self) -> Vec<u8> {
+		let mut bytes = Vec::with_capacity(BEXT_HEADER_LEN + self.coding_history.len());
+
+		write_text(&mut bytes, &self.description, DESCRIPTION_LEN);
+		write_text(&mut bytes, &self.originator, ORIGINATOR_LEN);
+		write_text(
+			&mut bytes,
+			&self.originator_reference,
+			ORIGINATOR_REFERENCE_LEN,
+		);
+		write_text(&mut bytes, &self.origination_date, ORIGINATION_DATE_LEN);
+		write_text(&mut bytes, &self.origination_time, ORIGINATION_TIME_LEN);
+
+		bytes.extend((self.time_reference as u32).to_le_bytes());
+		bytes.extend(((self.time_reference >> 32) as u32).to_le_bytes());
+		bytes.extend(self.version.to_le_bytes());
+
+		let mut umid = [0; UMID_LEN];
+		let umid_len = self.umid.len().min(UMID_LEN);
+		umid[..umid_len].copy_from_slice(&self.umid[..umid_len]);
+		bytes.extend(umid);
+
+		let loudness = self.loudness.unwrap_or_default();
+		for value in [
+			loudness.loudness_value,
+			loudness.loudness_range,
+			loudness.max_true_peak_level,
+			loudness.max_momentary_loudness,
+			loudness.max_short_term_loudness,
+		] {
+			bytes.extend(value.to_le_bytes());
+		}
+
+		bytes.extend([0; RESERVED_LEN]);
+
+		bytes.extend(self.coding_history.chars().map(latin1_byte));
+		bytes
+	}
+
+	/// Copy the description and origination date into a [`Tag`]
+	///
+	/// The description is stored as [`ItemKey::Comment`], and the origination date (and time, if
+	/// available) as [`ItemKey::RecordingDate`]. Items that already exist in `tag` are not replaced.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use lofty::iff::wav::BextChunk;
+	/// use lofty::prelude::*;
+	/// use lofty::tag::{ItemKey, Tag, TagType};
+	///
+	/// let bext = BextChunk {
+	/// 	description: String::from("Foo description"),
+	/// 	origination_date: String::from("2024-01-02"),
+	/// 	origination_time: String::from("03:04:05"),
+	/// 	..BextChunk::default()
+	/// };
+	///
+	/// let mut tag = Tag::new(TagType::RiffInfo);
+	/// bext.merge_into(&mut tag);
+	///
+	/// assert_eq!(tag.comment().as_deref(), Some("Foo description"));
+	/// assert_eq!(
+	/// 	tag.get_string(&ItemKey::RecordingDate),
+	/// 	Some("2024-01-02T03:04:05")
+	/// );
+	/// ```
+	pub fn merge_into(&self, tag: &mut Tag) {
+		if !self.description.is_empty() && tag.get(&ItemKey::Comment).is_none() {
+			tag.insert_text(ItemKey::Comment, self.description.clone());
+		}
+
+		if self.origination_date.is_empty() || tag.get(&ItemKey::RecordingDate).is_some() {
+			return;
+		}
+
+		// The separators can be any of '-', '_', ':', ' ', or '.'
+		let mut date = self.origination_date.replace(['_', ':', ' ', '.'], "-");
+		if !self.origination_time.is_empty() {
+			date.push('T');
+			date.push_str(&self.origination_time.replace(['-', '_', ' ', '.'], ":"));
+		}
+
+		tag.insert_text(ItemKey::RecordingDate, date);
+	}
+
+	/// Write the chunk to a WAV file
+	///
+	/// This replaces an existing `bext` chunk. Otherwise, the chunk is placed directly after the
+	/// RIFF header.
+	///
+	/// # Errors
+	///
+	/// * `file` is not a valid WAV file
+	/// * The coding history is too large to fit in a chunk
+	/// * [`std::io::Error`]
+	///
+	/// # Examples
+	///
+	/// ```rust,no_run
+	/// use lofty::iff::wav::BextChunk;
+	/// use std::fs::OpenOptions;
+	///
+	/// # fn main() -> lofty::error::Result<()> {
+	/// let mut file = OpenOptions::new().read(true).write(true).open("foo.wav")?;
+	///
+	/// let bext = BextChunk {
+	/// 	description: String::from("Foo description"),
+	/// 	..BextChunk::default()
+	/// };
+	///
+	/// bext.write_to(&mut file)?;
+	/// # Ok(()) }
+	/// ```
+	pub fn write_to<F>(&self, file: &mut F) -> Result<()>
+	where
+		F: FileLike,
+		LoftyError: From<<F as Truncate>::Error>,
+		LoftyError: From<<F as Length>::Error>,
+	{
+		let content = self.as_bytes();
+		let Ok(len) = u32::try_from(content.len()) else {
+			err!(TooMuchData);
+		};
+
+		let mut chunk = Vec::with_capacity(content.len() + 9);
+		chunk.extend(b"bext");
+		chunk.extend(len.to_le_bytes());
+		chunk.extend(content);
+
+		// Chunks must start on even boundaries
+		if len % 2 != 0 {
+			chunk.push(0);
+		}
+
+		replace_bext(file, &chunk)
+	}
+
+	/// Remove the `bext` chunk from a WAV file
+	///
+	/// # Errors
+	///
+	/// * `file` is not a valid WAV file
+	/// * [`std::io::Error`]
+	pub fn remove_from<F>(file: &mut F) -> Result<()>
+	where
+		F: FileLike,
+		LoftyError: From<<F as Truncate>::Error>,
+		LoftyError: From<<F as Length>::Error>,
+	{
+		replace_bext(file, &[])
+	}
+}
+
+fn read_text(reader: &mut &[u8], len: usize) -> String {
+	let (text, remaining) = reader.split_at(len);
+	*reader = remaining;
+
+	let end = text.iter().position(|b| *b == 0).unwrap_or(len);
+	latin1_decode(&text[..end])
+}
+
+fn write_text(bytes: &mut Vec<u8>, text: &str, len: usize) {
+	let start = bytes.len();
+	bytes.extend(text.chars().take(len).map(latin1_byte));
+	bytes.resize(start + len, 0);
+}
+
+fn latin1_byte(c: char) -> u8 {
+	u8::try_from(c).unwrap_or(b'?')
+}
+
+// Replaces the existing `bext` chunk with `chunk`, or inserts it after the RIFF header
+fn replace_bext<F>(file: &mut F, chunk: &[u8]) -> Result<()>
+where
+	F: FileLike,
+	LoftyError: From<<F as Truncate>::Error>,
+	LoftyError: From<<F as Length>::Error>,
+{
+	verify_wav(file)?;
+	let file_len = file.len()?.saturating_sub(12);
+
+	let mut existing = None;
+
+	let mut chunks = Chunks::<LittleEndian>::new(file_len);
+	while chunks.next(file).is_ok() {
+		if &chunks.fourcc == b"bext" {
+			log::debug!("Found existing bext chunk, size: {} bytes", chunks.size);
+
+			let mut size = u64::from(chunks.size);
+			if size % 2 != 0 {
+				size += 1;
+			}
+
+			existing = Some((chunks.offset, size + 8));
+			break;
+		}
+
+		chunks.skip(file)?;
+	}
+
+	if existing.is_none() && chunk.is_empty() {
+		return Ok(());
+	}
+
+	file.rewind()?;
+
+	let mut file_bytes = Vec::new();
+	file.read_to_end(&mut file_bytes)?;
+
+	let (start, end) = match existing {
+		// The pad byte may be missing if this is the final chunk
+		Some((offset, size)) => (
+			offset as usize,
+			std::cmp::min((offset + size) as usize, file_bytes.len()),
+		),
+		None => (12, 12),
+	};
+
+	let _ = file_bytes.splice(start..end, chunk.iter().copied());
+
+	let total_size = (file_bytes.len() - 8) as u32;
+	let _ = file_bytes.splice(4..8, total_size.to_le_bytes());
+
+	file.rewind()?;
+	file.truncate(0)?;
+	file.write_all(&file_bytes)?;
+
+	Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+	use super::{BextChunk, BextLoudness};
+	use crate::config::ParseOptions;
+	use crate::file::AudioFile;
+	use crate::iff::wav::WavFile;
+	use crate::tag::{ItemKey, Tag, TagType};
+
+	use std::io::Cursor;
+
+	fn bext() -> BextChunk {
+		BextChunk {
+			description: String::from("Foo description"),
+			originator: String::from("Foo originator"),
+			originator_reference: String::from("FOO0123456789"),
+			origination_date: String::from("2024-01-02"),
+			origination_time: String::from("03:04:05"),
+			time_reference: 0x1_0000_0002,
+			version: 2,
+			umid: vec![1; 64],
+			loudness: Some(BextLoudness {
+				loudness_value: -2300,
+				loudness_range: 500,
+				max_true_peak_level: -100,
+				max_momentary_loudness: -1800,
+				max_short_term_loudness: -2000,
+			}),
+			coding_history: String::from("A=PCM,F=48000,W=16,M=stereo\r\n"),
+		}
+	}
+
+	fn wav_file() -> Vec<u8> {
+		let mut file = Vec::new();
+		file.extend(b"RIFF");
+		file.extend(24_u32.to_le_bytes());
+		file.extend(b"WAVE");
+
+		file.extend(b"fmt ");
+		file.extend(16_u32.to_le_bytes());
+		file.extend(1_u16.to_le_bytes()); // PCM
+		file.extend(1_u16.to_le_bytes()); // Channels
+		file.extend(8000_u32.to_le_bytes()); // Sample rate
+		file.extend(16000_u32.to_le_bytes()); // Bytes per second
+		file.extend(2_u16.to_le_bytes()); // Block align
+		file.extend(16_u16.to_le_bytes()); // Bits per sample
+
+		file.extend(b"data");
+		file.extend(0_u32.to_le_bytes());
+
+		let riff_size = (file.len() - 8) as u32;
+		file[4..8].copy_from_slice(&riff_size.to_le_bytes());
+		file
+	}
+
+	#[test]
+	fn bext_round_trip() {
+		let bext = bext();
+
+		let bytes = bext.as_bytes();
+		assert_eq!(bytes.len(), 602 + bext.coding_history.len());
+		assert_eq!(BextChunk::parse(&bytes).unwrap(), bext);
+
+		// No UMID or loudness values in version 1
+		let bext = BextChunk {
+			version: 1,
+			umid: Vec::new(),
+			loudness: None,
+			..bext
+		};
+		assert_eq!(BextChunk::parse(&bext.as_bytes()).unwrap(), bext);
+
+		// Text is truncated to the size of the field
+		let long = BextChunk {
+			origination_date: String::from("2024-01-02 and more"),
+			..BextChunk::default()
+		};
+		let parsed = BextChunk::parse(&long.as_bytes()).unwrap();
+		assert_eq!(parsed.origination_date, "2024-01-02");
+
+		assert!(BextChunk::parse(&[0; 601]).is_err());
+	}
+
+	#[test]
+	fn write_bext() {
+		let mut file = Cursor::new(wav_file());
+
+		let wav =
+			WavFile::read_from(&mut file, ParseOptions::new().read_properties(false)).unwrap();
+		assert!(wav.bext().is_none());
+
+		bext().write_to(&mut file).unwrap();
+
+		file.set_position(0);
+		let wav =
+			WavFile::read_from(&mut file, ParseOptions::new().read_properties(false)).unwrap();
+		assert_eq!(wav.bext(), Some(&bext()));
+
+		// Replace the existing chunk, now with an odd size
+		let new_bext = BextChunk {
+			coding_history: String::from("A=PCM"),
+			..bext()
+		};
+		new_bext.write_to(&mut file).unwrap();
+
+		let bytes = file.get_ref();
+		assert_eq!(&bytes[12..16], b"bext");
+		assert_eq!(bytes.len(), wav_file().len() + 8 + 608);
+		assert_eq!(
+			u32::from_le_bytes(bytes[4..8].try_into().unwrap()) as usize,
+			bytes.len() - 8
+		);
+
+		file.set_position(0);
+		let wav =
+			WavFile::read_from(&mut file, ParseOptions::new().read_properties(false)).unwrap();
+		assert_eq!(wav.bext(), Some(&new_bext));
+
+		BextChunk::remove_from(&mut file).unwrap();
+		assert_eq!(file.get_ref(), &wav_file());
+	}
+
+	#[test]
+	fn merge_into_tag() {
+		let mut tag = Tag::new(TagType::RiffInfo);
+		bext().merge_into(&mut tag);
+
+		assert_eq!(tag.get_string(&ItemKey::Comment), Some("Foo description"));
+		assert_eq!(
+			tag.get_string(&ItemKey::RecordingDate),
+			Some("2024-01-02T03:04:05")
+		);
+
+		// Existing items are kept, alternative separators are normalized
+		let mut tag = Tag::new(TagType::RiffInfo);
+		tag.insert_text(ItemKey::Comment, String::from("Bar comment"));
+
+		let bext = BextChunk {
+			origination_time: String::new(),
+			origination_date: String::from("2024:01:02"),
+			..bext()
+		};
+		bext.merge_into(&mut tag);
+
+		assert_eq!(tag.get_string(&ItemKey::Comment), Some("Bar comment"));
+		assert_eq!(tag.get_string(&ItemKey::RecordingDate), Some("2024-01-02"));
+	}
+}
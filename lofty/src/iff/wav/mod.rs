@@ -1,5 +1,6 @@
 //! WAV specific items
 
+mod bext;
 mod properties;
 mod read;
 pub(crate) mod tag;
@@ -10,8 +11,11 @@ use lofty_attr::LoftyFile;
 
 // Exports
 pub use crate::iff::wav::properties::{WavFormat, WavProperties};
+pub use bext::{BextChunk, BextLoudness};
 pub use tag::RiffInfoList;
 
+pub(crate) use read::{audio_payload_len, layout};
+
 /// A WAV file
 #[derive(LoftyFile)]
 #[lofty(read_fn = "read::read_from")]
@@ -23,6 +27,17 @@ pub struct WavFile {
 	/// An ID3v2 tag
 	#[lofty(tag_type = "Id3v2")]
 	pub(crate) id3v2_tag: Option<Id3v2Tag>,
+	/// The file's `bext` chunk, if it exists
+	pub(crate) bext: Option<BextChunk>,
 	/// The file's audio properties
 	pub(crate) properties: WavProperties,
 }
+
+impl WavFile {
+	/// Returns the file's Broadcast Wave Format `bext` chunk, if it exists
+	///
+	/// See [`BextChunk`] for writing the chunk.
+	pub fn bext(&self) -> Option<&BextChunk> {
+		self.bext.as_ref()
+	}
+}
@@ -1,11 +1,14 @@
+use super::bext::BextChunk;
 use super::properties::WavProperties;
 use super::tag::RiffInfoList;
 use super::WavFile;
-use crate::config::ParseOptions;
+use crate::config::{ParseOptions, ParsingMode};
 use crate::error::Result;
+use crate::file::{BlockKind, FileLayout};
 use crate::id3::v2::tag::Id3v2Tag;
 use crate::iff::chunk::Chunks;
 use crate::macros::decode_err;
+use crate::tag::TagExt;
 
 use std::io::{Read, Seek, SeekFrom};
 
@@ -30,6 +33,87 @@ where
 	Ok(())
 }
 
+/// Get the size of the first `data` chunk, excluding its pad byte
+///
+/// If the chunk claims to be larger than the remainder of the file, the remainder is used instead.
+pub(crate) fn audio_payload_len<R>(data: &mut R) -> Result<u64>
+where
+	R: Read + Seek,
+{
+	verify_wav(data)?;
+
+	let current_pos = data.stream_position()?;
+	let file_len = data.seek(SeekFrom::End(0))?;
+
+	data.seek(SeekFrom::Start(current_pos))?;
+
+	let mut chunks = Chunks::<LittleEndian>::new(file_len);
+	while chunks.next(data).is_ok() {
+		if &chunks.fourcc == b"data" {
+			let available = file_len.saturating_sub(data.stream_position()?);
+			return Ok(u64::from(chunks.size).min(available));
+		}
+
+		chunks.skip(data)?;
+	}
+
+	Ok(0)
+}
+
+/// Locate the `ID3 `, `LIST INFO`, and `data` chunks, and anything following the `RIFF` chunk
+pub(crate) fn layout<R>(data: &mut R) -> Result<FileLayout>
+where
+	R: Read + Seek,
+{
+	verify_wav(data)?;
+
+	let file_len = data.seek(SeekFrom::End(0))?;
+
+	data.seek(SeekFrom::Start(4))?;
+	let riff_end = (u64::from(data.read_u32::<LittleEndian>()?) + 8).min(file_len);
+
+	data.seek(SeekFrom::Start(12))?;
+
+	let mut layout = FileLayout::new(file_len);
+
+	let mut chunks = Chunks::<LittleEndian>::new(riff_end);
+	while data.stream_position()? + 8 <= riff_end && chunks.next(data).is_ok() {
+		let chunk_end = (chunks.offset + 8 + u64::from(chunks.size)).min(riff_end);
+		match &chunks.fourcc {
+			b"ID3 " | b"id3 " => {
+				layout.push(BlockKind::Id3v2, chunks.offset, chunk_end - chunks.offset);
+			},
+			b"LIST" => {
+				let mut list_type = [0; 4];
+				data.read_exact(&mut list_type)?;
+				data.seek(SeekFrom::Current(-4))?;
+
+				if &list_type == b"INFO" {
+					layout.push(
+						BlockKind::RiffInfo,
+						chunks.offset,
+						chunk_end - chunks.offset,
+					);
+				}
+			},
+			// The pad byte of an odd length `data` chunk is not a part of the stream
+			b"data" => {
+				let start = chunks.offset + 8;
+				layout.push(BlockKind::Audio, start, chunk_end.saturating_sub(start));
+			},
+			_ => {},
+		}
+
+		chunks.skip(data)?;
+	}
+
+	if riff_end < file_len {
+		layout.push(BlockKind::Trailing, riff_end, file_len - riff_end);
+	}
+
+	Ok(layout)
+}
+
 pub(super) fn read_from<R>(data: &mut R, parse_options: ParseOptions) -> Result<WavFile>
 where
 	R: Read + Seek,
@@ -47,6 +131,7 @@ where
 
 	let mut riff_info = RiffInfoList::default();
 	let mut id3v2_tag: Option<Id3v2Tag> = None;
+	let mut bext = None;
 
 	let mut chunks = Chunks::<LittleEndian>::new(file_len);
 
@@ -55,6 +140,7 @@ where
 			b"fmt " if parse_options.read_properties => {
 				if fmt.is_empty() {
 					fmt = chunks.content(data)?;
+					chunks.correct_position(data)?;
 				} else {
 					chunks.skip(data)?;
 				}
@@ -67,6 +153,7 @@ where
 				}
 			},
 			b"data" if parse_options.read_properties => {
+				// The pad byte of an odd length `data` chunk is not a part of the stream
 				if stream_len == 0 {
 					stream_len += chunks.size
 				}
@@ -79,7 +166,13 @@ where
 
 				match &list_type {
 					b"INFO" => {
-						let end = data.stream_position()? + u64::from(chunks.size - 4);
+						let Some(info_size) = chunks.size.checked_sub(4) else {
+							return Err(chunks.bad_size());
+						};
+
+						parse_options.check_tag_size(u64::from(info_size))?;
+
+						let end = data.stream_position()? + u64::from(info_size);
 						super::tag::read::parse_riff_info(data, &mut chunks, end, &mut riff_info)?;
 					},
 					_ => {
@@ -88,8 +181,18 @@ where
 					},
 				}
 			},
+			b"bext" if bext.is_none() => {
+				let content = chunks.content(data)?;
+				chunks.correct_position(data)?;
+
+				match BextChunk::parse(&content) {
+					Ok(chunk) => bext = Some(chunk),
+					Err(e) if parse_options.parsing_mode == ParsingMode::Strict => return Err(e),
+					Err(_) => log::warn!("Invalid bext chunk, discarding"),
+				}
+			},
 			b"ID3 " | b"id3 " => {
-				let tag = chunks.id3_chunk(data, parse_options.parsing_mode)?;
+				let tag = chunks.id3_chunk(data, parse_options)?;
 				if let Some(existing_tag) = id3v2_tag.as_mut() {
 					log::warn!("Duplicate ID3v2 tag found, appending frames to previous tag");
 
@@ -107,16 +210,17 @@ where
 	}
 
 	let properties = if parse_options.read_properties {
-		let file_length = data.stream_position()?;
-
-		super::properties::read_properties(&mut &*fmt, total_samples, stream_len, file_length)?
+		// NOTE: We can't rely on the current position here, as skipping the final chunk's pad byte
+		//       may have put us past the end of the file if it was missing.
+		super::properties::read_properties(&mut &*fmt, total_samples, stream_len, file_len)?
 	} else {
 		WavProperties::default()
 	};
 
 	Ok(WavFile {
 		properties,
-		riff_info_tag: (!riff_info.items.is_empty()).then_some(riff_info),
+		riff_info_tag: (!riff_info.is_empty()).then_some(riff_info),
 		id3v2_tag,
+		bext,
 	})
 }
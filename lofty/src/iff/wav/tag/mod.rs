@@ -3,14 +3,21 @@ mod write;
 
 use crate::config::WriteOptions;
 use crate::error::{LoftyError, Result};
+use crate::file::write_verified;
+use crate::id3::v1::genre_from_id;
+use crate::iff::chunk::Chunks;
+use crate::macros::err;
+use crate::tag::items::popm_item_to_text;
 use crate::tag::{
-	try_parse_year, Accessor, ItemKey, ItemValue, MergeTag, SplitTag, Tag, TagExt, TagItem, TagType,
+	try_parse_year, verify_year, Accessor, ItemKey, ItemValue, KeyAliases, MergeTag, SplitTag, Tag,
+	TagExt, TagItem, TagType,
 };
-use crate::util::io::{FileLike, Length, Truncate};
+use crate::util::io::{FileLike, Length, SeekStreamLen, Truncate};
 
 use std::borrow::Cow;
-use std::io::Write;
+use std::io::{Read, Seek, Write};
 
+use byteorder::LittleEndian;
 use lofty_attr::tag;
 
 macro_rules! impl_accessor {
@@ -45,13 +52,72 @@ macro_rules! impl_accessor {
 ///
 /// * The [`TagItem`] has a value other than [`ItemValue::Binary`](crate::ItemValue::Binary)
 /// * It has a key that is 4 bytes in length and within the ASCII range
-#[derive(Default, Debug, PartialEq, Eq, Clone)]
+///
+/// ## Disc number
+///
+/// There is no standard chunk for the disc number or total discs. The disc number is stored in `DISC`,
+/// and the total discs in the non-standard `DTOT`.
+///
+/// ## Multiple values
+///
+/// Every item holds a single string, so multiple artists (`IART`) or genres (`IGNR`) are joined with a
+/// delimiter (`;` by default, see [`RiffInfoList::with_delimiter`]). There is no standard chunk for album artists.
+///
+/// The same delimiter is used to split the values in [`RiffInfoList::artists`] and [`RiffInfoList::genres`],
+/// and when converting to a [`Tag`]. Converting from a [`Tag`] uses the default delimiter, unless it was
+/// split from a `RiffInfoList` with [`SplitTag`], in which case the original delimiter is kept.
+///
+/// The delimiter is not considered when comparing tags.
+///
+/// ## Unknown chunks
+///
+/// Any chunks in the list that are not text items with a valid key (such as vendor-specific chunks) are kept
+/// as-is, and written back after the text items. See [`RiffInfoList::unknown_chunks`].
+///
+/// These chunks are discarded when converting to a [`Tag`].
+///
+/// ## Key aliases
+///
+/// Some items have multiple common keys, such as `IPRT` and `ITRK` for the track number. These are handled
+/// by a [`KeyAliases`] table, see [`RiffInfoList::key_aliases`].
+#[derive(Debug, Eq, Clone)]
 #[tag(description = "A RIFF INFO LIST", supported_formats(Wav))]
 pub struct RiffInfoList {
 	/// A collection of chunk-value pairs
 	pub(crate) items: Vec<(String, String)>,
+	/// Chunks that could not be read as text items, stored as-is
+	pub(crate) unknown_chunks: Vec<([u8; 4], Vec<u8>)>,
+	pub(crate) delimiter: String,
+	pub(crate) key_aliases: KeyAliases,
+}
+
+impl PartialEq for RiffInfoList {
+	fn eq(&self, other: &Self) -> bool {
+		self.items == other.items
+			&& self.unknown_chunks == other.unknown_chunks
+			&& self.key_aliases == other.key_aliases
+	}
+}
+
+impl Default for RiffInfoList {
+	fn default() -> Self {
+		Self {
+			items: Vec::new(),
+			unknown_chunks: Vec::new(),
+			delimiter: String::from(DEFAULT_DELIMITER),
+			key_aliases: KeyAliases::from_static(&DEFAULT_KEY_ALIASES),
+		}
+	}
 }
 
+const DEFAULT_DELIMITER: &str = ";";
+
+// (alias, canonical)
+const DEFAULT_KEY_ALIASES: [(&str, &str); 1] = [("ITRK", "IPRT")];
+
+// Keys that can hold multiple values, separated by `RiffInfoList::delimiter`
+const MULTI_VALUE_KEYS: [&str; 2] = ["IART", "IGNR"];
+
 impl RiffInfoList {
 	/// Create a new empty `RIFFInfoList`
 	///
@@ -68,11 +134,239 @@ impl RiffInfoList {
 		Self::default()
 	}
 
+	/// Read a tag previously written with [`TagExt::dump_to`]
+	///
+	/// # Errors
+	///
+	/// * `reader` does not start with a `LIST` chunk of type `INFO`
+	/// * The tag is otherwise malformed
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use lofty::config::WriteOptions;
+	/// use lofty::iff::wav::RiffInfoList;
+	/// use lofty::tag::{Accessor, TagExt};
+	/// use std::io::Cursor;
+	///
+	/// # fn main() -> lofty::error::Result<()> {
+	/// let mut tag = RiffInfoList::new();
+	/// tag.set_title(String::from("Foo title"));
+	///
+	/// let mut dump = Vec::new();
+	/// tag.dump_to(&mut dump, WriteOptions::default())?;
+	///
+	/// let re_read = RiffInfoList::read_from_dump(&mut Cursor::new(dump))?;
+	/// assert_eq!(re_read.title().as_deref(), Some("Foo title"));
+	/// # Ok(()) }
+	/// ```
+	pub fn read_from_dump<R>(reader: &mut R) -> Result<Self>
+	where
+		R: Read + Seek,
+	{
+		let len = reader.stream_len_hack()? - reader.stream_position()?;
+		let mut chunks = Chunks::<LittleEndian>::new(len);
+		chunks.next(reader)?;
+
+		let mut list_type = [0; 4];
+		reader.read_exact(&mut list_type)?;
+
+		if &chunks.fourcc != b"LIST" || &list_type != b"INFO" || chunks.size < 4 {
+			err!(FakeTag);
+		}
+
+		let mut tag = Self::new();
+
+		let end = reader.stream_position()? + u64::from(chunks.size - 4);
+		read::parse_riff_info(reader, &mut chunks, end, &mut tag)?;
+
+		Ok(tag)
+	}
+
+	/// Create a new empty `RIFFInfoList`, using `delimiter` to separate multiple values
+	///
+	/// See [`RiffInfoList::set_delimiter`]
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use lofty::iff::wav::RiffInfoList;
+	///
+	/// let mut riff_info_tag = RiffInfoList::with_delimiter(String::from(" / "));
+	/// riff_info_tag.set_artists(&["Foo artist", "Bar artist"]);
+	///
+	/// assert_eq!(riff_info_tag.get("IART"), Some("Foo artist / Bar artist"));
+	/// ```
+	pub fn with_delimiter(delimiter: String) -> Self {
+		Self {
+			delimiter,
+			..Self::default()
+		}
+	}
+
+	/// The delimiter used to separate multiple values
+	///
+	/// This is `;` by default.
+	pub fn delimiter(&self) -> &str {
+		&self.delimiter
+	}
+
+	/// Set the delimiter used to separate multiple values
+	///
+	/// This only affects how items are split and joined from this point on, existing items
+	/// will not be rewritten.
+	///
+	/// NOTE: An empty delimiter will be ignored
+	pub fn set_delimiter(&mut self, delimiter: String) {
+		if !delimiter.is_empty() {
+			self.delimiter = delimiter;
+		}
+	}
+
+	/// The [`KeyAliases`] used to look up items
+	///
+	/// By default, this treats `IPRT` and `ITRK` (track number) as the same item.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use lofty::iff::wav::RiffInfoList;
+	///
+	/// let mut riff_info_tag = RiffInfoList::new();
+	/// riff_info_tag.insert(String::from("ITRK"), String::from("1"));
+	///
+	/// assert_eq!(riff_info_tag.get("IPRT"), Some("1"));
+	/// assert_eq!(riff_info_tag.get("ITRK"), Some("1"));
+	/// ```
+	pub fn key_aliases(&self) -> &KeyAliases {
+		&self.key_aliases
+	}
+
+	/// Replace the [`KeyAliases`] used to look up items
+	///
+	/// NOTE: Existing items are not renamed.
+	pub fn set_key_aliases(&mut self, key_aliases: KeyAliases) {
+		self.key_aliases = key_aliases;
+	}
+
+	/// Get all artists (`IART`)
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use lofty::iff::wav::RiffInfoList;
+	///
+	/// let mut riff_info_tag = RiffInfoList::new();
+	/// riff_info_tag.insert(String::from("IART"), String::from("Foo artist; Bar artist"));
+	///
+	/// assert_eq!(riff_info_tag.artists(), vec!["Foo artist", "Bar artist"]);
+	/// ```
+	pub fn artists(&self) -> Vec<&str> {
+		self.get_multi("IART")
+	}
+
+	/// Set the artists (`IART`), joined with the [delimiter](RiffInfoList::delimiter)
+	///
+	/// NOTE: If `artists` is empty, the item will be removed
+	pub fn set_artists<S: AsRef<str>>(&mut self, artists: &[S]) {
+		self.set_multi("IART", artists);
+	}
+
+	/// Get all genres (`IGNR`)
+	///
+	/// Any ID3v1 genre IDs (such as `"(17)"`) will be translated to their names, see [`genre_from_id`].
+	///
+	/// See [`RiffInfoList::artists`]
+	pub fn genres(&self) -> Vec<&str> {
+		let mut genres = self.get_multi("IGNR");
+		for genre in &mut genres {
+			if let Some(name) = genre_from_id(genre) {
+				*genre = name;
+			}
+		}
+
+		genres
+	}
+
+	/// Set the genres (`IGNR`), joined with the [delimiter](RiffInfoList::delimiter)
+	///
+	/// NOTE: If `genres` is empty, the item will be removed
+	pub fn set_genres<S: AsRef<str>>(&mut self, genres: &[S]) {
+		self.set_multi("IGNR", genres);
+	}
+
+	pub(crate) fn as_ref(&self) -> RIFFInfoListRef<'_, impl Iterator<Item = (&str, &str)>> {
+		RIFFInfoListRef::new(
+			self.items.iter().map(|(k, v)| (k.as_str(), v.as_str())),
+			&self.unknown_chunks,
+		)
+	}
+
+	/// Returns the chunks that could not be read as text items, in the order they were read
+	///
+	/// This includes vendor-specific chunks, and chunks whose content is not valid UTF-8.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use lofty::iff::wav::RiffInfoList;
+	///
+	/// let mut riff_info = RiffInfoList::new();
+	/// riff_info.insert_unknown_chunk(*b"abcd", vec![1, 2, 3]);
+	///
+	/// let (fourcc, content) = riff_info.unknown_chunks().next().unwrap();
+	/// assert_eq!(fourcc, b"abcd");
+	/// assert_eq!(content, &[1, 2, 3]);
+	/// ```
+	pub fn unknown_chunks(&self) -> impl Iterator<Item = (&[u8; 4], &[u8])> {
+		self.unknown_chunks
+			.iter()
+			.map(|(fourcc, content)| (fourcc, content.as_slice()))
+	}
+
+	/// Insert an unknown chunk, replacing any with the same FourCC
+	///
+	/// The content will be written as-is, followed by a pad byte if necessary.
+	pub fn insert_unknown_chunk(&mut self, fourcc: [u8; 4], content: Vec<u8>) {
+		self.unknown_chunks.retain(|(f, _)| *f != fourcc);
+		self.unknown_chunks.push((fourcc, content));
+	}
+
+	/// Remove an unknown chunk, returning its content if it exists
+	pub fn remove_unknown_chunk(&mut self, fourcc: &[u8; 4]) -> Option<Vec<u8>> {
+		let pos = self.unknown_chunks.iter().position(|(f, _)| f == fourcc)?;
+		Some(self.unknown_chunks.remove(pos).1)
+	}
+
+	fn get_multi(&self, key: &str) -> Vec<&str> {
+		match self.get(key) {
+			Some(value) => split_values(value, &self.delimiter).collect(),
+			None => Vec::new(),
+		}
+	}
+
+	fn set_multi<S: AsRef<str>>(&mut self, key: &str, values: &[S]) {
+		if values.is_empty() {
+			self.remove(key);
+			return;
+		}
+
+		let joined = values
+			.iter()
+			.map(AsRef::as_ref)
+			.collect::<Vec<_>>()
+			.join(&self.delimiter);
+		self.insert(String::from(key), joined);
+	}
+
 	/// Get an item by key
+	///
+	/// This is case-insensitive, and will find items stored with an alias of `key`
+	/// (see [`RiffInfoList::key_aliases`]).
 	pub fn get(&self, key: &str) -> Option<&str> {
 		self.items
 			.iter()
-			.find(|(k, _)| k == key)
+			.find(|(k, _)| self.key_aliases.matches(k, key))
 			.map(|(_, v)| v.as_str())
 	}
 
@@ -80,26 +374,31 @@ impl RiffInfoList {
 	///
 	/// NOTE: This will do nothing if `key` is invalid
 	///
-	/// This will case-insensitively replace any item with the same key
+	/// This will case-insensitively replace any item with the same key, or an alias of it.
+	/// If `key` is an alias, the item will be stored with the canonical key instead.
 	pub fn insert(&mut self, key: String, value: String) {
+		let key = match self.key_aliases.canonical(&key) {
+			canonical if canonical == key => key,
+			canonical => canonical.to_owned(),
+		};
+
 		if read::verify_key(key.as_str()) {
+			let key_aliases = &self.key_aliases;
 			self.items
-				.iter()
-				.position(|(k, _)| k.eq_ignore_ascii_case(key.as_str()))
-				.map(|p| self.items.remove(p));
+				.retain(|(k, _)| !key_aliases.matches(k, key.as_str()));
 			self.items.push((key, value))
 		}
 	}
 
 	/// Remove an item by key
 	///
-	/// This will case-insensitively remove an item with the key, returning it
+	/// This will case-insensitively remove an item with the key, or an alias of it, returning it
 	/// if it exists.
 	pub fn remove(&mut self, key: &str) -> Option<String> {
 		if let Some((_, value)) = self
 			.items
 			.iter()
-			.position(|(k, _)| k.eq_ignore_ascii_case(key))
+			.position(|(k, _)| self.key_aliases.matches(k, key))
 			.map(|p| self.items.remove(p))
 		{
 			return Some(value);
@@ -107,6 +406,20 @@ impl RiffInfoList {
 
 		None
 	}
+
+	/// Set the year, failing if it has more than 4 digits
+	///
+	/// Only the first 4 digits of a year are read back, so unlike [`Accessor::set_year`], this will not
+	/// store a year that would be read back differently. The tag is left unchanged on failure.
+	///
+	/// # Errors
+	///
+	/// * `value` is greater than 9999 ([`ErrorKind::BadItem`](crate::error::ErrorKind::BadItem))
+	pub fn try_set_year(&mut self, value: u32) -> Result<()> {
+		verify_year(value)?;
+		self.set_year(value);
+		Ok(())
+	}
 }
 
 impl Accessor for RiffInfoList {
@@ -114,7 +427,6 @@ impl Accessor for RiffInfoList {
 		artist  => "IART";
 		title   => "INAM";
 		album   => "IPRD";
-		genre   => "IGNR";
 		comment => "ICMT";
 	);
 
@@ -150,6 +462,38 @@ impl Accessor for RiffInfoList {
 		self.remove("IFRM");
 	}
 
+	fn disk(&self) -> Option<u32> {
+		if let Some(item) = self.get("DISC") {
+			return item.parse::<u32>().ok();
+		}
+
+		None
+	}
+
+	fn set_disk(&mut self, value: u32) {
+		self.insert(String::from("DISC"), value.to_string());
+	}
+
+	fn remove_disk(&mut self) {
+		self.remove("DISC");
+	}
+
+	fn disk_total(&self) -> Option<u32> {
+		if let Some(item) = self.get("DTOT") {
+			return item.parse::<u32>().ok();
+		}
+
+		None
+	}
+
+	fn set_disk_total(&mut self, value: u32) {
+		self.insert(String::from("DTOT"), value.to_string());
+	}
+
+	fn remove_disk_total(&mut self) {
+		self.remove("DTOT");
+	}
+
 	fn year(&self) -> Option<u32> {
 		if let Some(item) = self.get("ICRD") {
 			return try_parse_year(item);
@@ -165,6 +509,36 @@ impl Accessor for RiffInfoList {
 	fn remove_year(&mut self) {
 		let _ = self.remove("ICRD");
 	}
+
+	fn genre(&self) -> Option<Cow<'_, str>> {
+		self.get("IGNR")
+			.map(|genre| Cow::Borrowed(genre_from_id(genre).unwrap_or(genre)))
+	}
+
+	fn set_genre(&mut self, value: String) {
+		self.insert(String::from("IGNR"), value);
+	}
+
+	fn remove_genre(&mut self) {
+		let _ = self.remove("IGNR");
+	}
+
+	fn encoder(&self) -> Option<Cow<'_, str>> {
+		self.get("ISFT").map(Cow::Borrowed)
+	}
+
+	fn set_encoder(&mut self, value: String) {
+		if value.is_empty() {
+			self.remove_encoder();
+			return;
+		}
+
+		self.insert(String::from("ISFT"), value);
+	}
+
+	fn remove_encoder(&mut self) {
+		let _ = self.remove("ISFT");
+	}
 }
 
 impl IntoIterator for RiffInfoList {
@@ -195,7 +569,7 @@ impl TagExt for RiffInfoList {
 	}
 
 	fn len(&self) -> usize {
-		self.items.len()
+		self.items.len() + self.unknown_chunks.len()
 	}
 
 	fn contains<'a>(&'a self, key: Self::RefKey<'a>) -> bool {
@@ -205,7 +579,7 @@ impl TagExt for RiffInfoList {
 	}
 
 	fn is_empty(&self) -> bool {
-		self.items.is_empty()
+		self.items.is_empty() && self.unknown_chunks.is_empty()
 	}
 
 	fn save_to<F>(
@@ -218,8 +592,7 @@ impl TagExt for RiffInfoList {
 		LoftyError: From<<F as Truncate>::Error>,
 		LoftyError: From<<F as Length>::Error>,
 	{
-		RIFFInfoListRef::new(self.items.iter().map(|(k, v)| (k.as_str(), v.as_str())))
-			.write_to(file, write_options)
+		self.as_ref().write_to(file, write_options)
 	}
 
 	fn dump_to<W: Write>(
@@ -227,23 +600,31 @@ impl TagExt for RiffInfoList {
 		writer: &mut W,
 		write_options: WriteOptions,
 	) -> std::result::Result<(), Self::Err> {
-		RIFFInfoListRef::new(self.items.iter().map(|(k, v)| (k.as_str(), v.as_str())))
-			.dump_to(writer, write_options)
+		self.as_ref().dump_to(writer, write_options)
 	}
 
 	fn clear(&mut self) {
 		self.items.clear();
+		self.unknown_chunks.clear();
 	}
 }
 
+// Keeps the configuration of the original tag, along with its unknown chunks
 #[derive(Debug, Clone, Default)]
-pub struct SplitTagRemainder;
+pub struct SplitTagRemainder(RiffInfoList);
 
 impl SplitTag for RiffInfoList {
 	type Remainder = SplitTagRemainder;
 
-	fn split_tag(self) -> (Self::Remainder, Tag) {
-		(SplitTagRemainder, self.into())
+	fn split_tag(mut self) -> (Self::Remainder, Tag) {
+		let remainder = RiffInfoList {
+			items: Vec::new(),
+			unknown_chunks: std::mem::take(&mut self.unknown_chunks),
+			delimiter: self.delimiter.clone(),
+			key_aliases: self.key_aliases.clone(),
+		};
+
+		(SplitTagRemainder(remainder), self.into())
 	}
 }
 
@@ -251,21 +632,45 @@ impl MergeTag for SplitTagRemainder {
 	type Merged = RiffInfoList;
 
 	fn merge_tag(self, tag: Tag) -> Self::Merged {
-		tag.into()
+		let mut merged = self.0;
+		merged.extend_from_tag(&tag);
+
+		merged
 	}
 }
 
+fn split_values<'a>(value: &'a str, delimiter: &'a str) -> impl Iterator<Item = &'a str> {
+	value
+		.split(delimiter)
+		.map(str::trim)
+		.filter(|value| !value.is_empty())
+}
+
 impl From<RiffInfoList> for Tag {
 	fn from(input: RiffInfoList) -> Self {
 		let mut tag = Self::new(TagType::RiffInfo);
 
 		for (k, v) in input.items {
 			let item_key = ItemKey::from_key(TagType::RiffInfo, &k);
+			let value = v.trim_matches('\0');
+
+			if MULTI_VALUE_KEYS.contains(&k.as_str()) {
+				for mut value in split_values(value, &input.delimiter) {
+					if k == "IGNR" {
+						value = genre_from_id(value).unwrap_or(value);
+					}
+
+					tag.items.push(TagItem::new(
+						item_key.clone(),
+						ItemValue::Text(value.to_string()),
+					));
+				}
+
+				continue;
+			}
 
-			tag.items.push(TagItem::new(
-				item_key,
-				ItemValue::Text(v.trim_matches('\0').to_string()),
-			));
+			tag.items
+				.push(TagItem::new(item_key, ItemValue::Text(value.to_string())));
 		}
 
 		tag
@@ -274,26 +679,57 @@ impl From<RiffInfoList> for Tag {
 
 impl From<Tag> for RiffInfoList {
 	fn from(input: Tag) -> Self {
+		Self::from(&input)
+	}
+}
+
+impl From<&Tag> for RiffInfoList {
+	fn from(input: &Tag) -> Self {
 		let mut riff_info = RiffInfoList::default();
+		riff_info.extend_from_tag(input);
 
-		for item in input.items {
-			if let ItemValue::Text(val) | ItemValue::Locator(val) = item.item_value {
-				match item.item_key {
-					ItemKey::Unknown(unknown) => {
-						if read::verify_key(&unknown) {
-							riff_info.items.push((unknown, val))
-						}
-					},
-					k => {
-						if let Some(key) = k.map_key(TagType::RiffInfo, false) {
-							riff_info.items.push((key.to_string(), val))
+		riff_info
+	}
+}
+
+impl RiffInfoList {
+	fn extend_from_tag(&mut self, tag: &Tag) {
+		for item in &tag.items {
+			let mut item = Cow::Borrowed(item);
+			if item.item_key == ItemKey::Popularimeter {
+				popm_item_to_text(item.to_mut());
+			}
+
+			let (ItemValue::Text(val) | ItemValue::Locator(val)) = &item.item_value else {
+				continue;
+			};
+
+			match &item.item_key {
+				ItemKey::Unknown(unknown) => {
+					if read::verify_key(unknown) {
+						self.items.push((unknown.clone(), val.clone()))
+					}
+				},
+				k => {
+					let Some(key) = k.map_key(TagType::RiffInfo, false) else {
+						continue;
+					};
+
+					// Join any additional values with the existing item
+					if MULTI_VALUE_KEYS.contains(&key) {
+						if let Some((_, existing)) =
+							self.items.iter_mut().find(|(k, _)| k.as_str() == key)
+						{
+							existing.push_str(&self.delimiter);
+							existing.push_str(val);
+							continue;
 						}
-					},
-				}
+					}
+
+					self.items.push((key.to_string(), val.clone()))
+				},
 			}
 		}
-
-		riff_info
 	}
 }
 
@@ -302,14 +738,21 @@ where
 	I: Iterator<Item = (&'a str, &'a str)>,
 {
 	pub(crate) items: I,
+	pub(crate) unknown_chunks: &'a [([u8; 4], Vec<u8>)],
 }
 
 impl<'a, I> RIFFInfoListRef<'a, I>
 where
 	I: Iterator<Item = (&'a str, &'a str)>,
 {
-	pub(crate) fn new(items: I) -> RIFFInfoListRef<'a, I> {
-		RIFFInfoListRef { items }
+	pub(crate) fn new(
+		items: I,
+		unknown_chunks: &'a [([u8; 4], Vec<u8>)],
+	) -> RIFFInfoListRef<'a, I> {
+		RIFFInfoListRef {
+			items,
+			unknown_chunks,
+		}
 	}
 
 	pub(crate) fn write_to<F>(&mut self, file: &mut F, write_options: WriteOptions) -> Result<()>
@@ -318,7 +761,9 @@ where
 		LoftyError: From<<F as Truncate>::Error>,
 		LoftyError: From<<F as Length>::Error>,
 	{
-		write::write_riff_info(file, self, write_options)
+		write_verified(file, write_options, |file, write_options| {
+			write::write_riff_info(file, self, write_options)
+		})
 	}
 
 	pub(crate) fn dump_to<W: Write>(
@@ -327,7 +772,7 @@ where
 		_write_options: WriteOptions,
 	) -> Result<()> {
 		let mut temp = Vec::new();
-		write::create_riff_info(&mut self.items, &mut temp)?;
+		write::create_riff_info(&mut self.items, self.unknown_chunks, &mut temp)?;
 
 		writer.write_all(&temp)?;
 
@@ -335,30 +780,13 @@ where
 	}
 }
 
-pub(crate) fn tagitems_into_riff<'a>(
-	items: impl IntoIterator<Item = &'a TagItem>,
-) -> impl Iterator<Item = (&'a str, &'a str)> {
-	items.into_iter().filter_map(|i| {
-		let item_key = i.key().map_key(TagType::RiffInfo, true);
-
-		match (item_key, i.value()) {
-			(Some(key), ItemValue::Text(val) | ItemValue::Locator(val))
-				if read::verify_key(key) =>
-			{
-				Some((key, val.as_str()))
-			},
-			_ => None,
-		}
-	})
-}
-
 #[cfg(test)]
 mod tests {
 	use crate::config::WriteOptions;
 	use crate::iff::chunk::Chunks;
 	use crate::iff::wav::RiffInfoList;
 	use crate::prelude::*;
-	use crate::tag::{Tag, TagType};
+	use crate::tag::{KeyAliases, Tag, TagType};
 
 	use byteorder::LittleEndian;
 
@@ -421,6 +849,44 @@ mod tests {
 		assert_eq!(parsed_tag, temp_parsed_tag);
 	}
 
+	#[test]
+	fn riff_info_read_from_dump() {
+		let mut tag = RiffInfoList::default();
+		tag.insert(String::from("IART"), String::from("Foo artist"));
+		tag.insert(String::from("ICMT"), String::from("Qux comment"));
+
+		let mut writer = Vec::new();
+		tag.dump_to(&mut writer, WriteOptions::default()).unwrap();
+
+		let temp_parsed_tag = RiffInfoList::read_from_dump(&mut Cursor::new(writer)).unwrap();
+		assert_eq!(tag, temp_parsed_tag);
+
+		// Not an INFO list
+		let mut reader = Cursor::new(b"LIST\x04\0\0\0adtl".to_vec());
+		assert!(RiffInfoList::read_from_dump(&mut reader).is_err());
+	}
+
+	#[test]
+	fn unknown_chunks_re_read() {
+		let mut tag = RiffInfoList::default();
+		tag.insert(String::from("IART"), String::from("Foo artist"));
+		tag.insert_unknown_chunk(*b"vndr", vec![1, 2, 3]);
+		// A valid key, but not valid UTF-8
+		tag.insert_unknown_chunk(*b"ICMT", vec![0xFF, 0xFE]);
+
+		let mut writer = Vec::new();
+		tag.dump_to(&mut writer, WriteOptions::default()).unwrap();
+
+		// Chunks should stay word aligned
+		assert_eq!(writer.len() % 2, 0);
+
+		let temp_parsed_tag = RiffInfoList::read_from_dump(&mut Cursor::new(writer)).unwrap();
+		assert_eq!(tag, temp_parsed_tag);
+
+		assert_eq!(tag.remove_unknown_chunk(b"vndr"), Some(vec![1, 2, 3]));
+		assert_eq!(tag.unknown_chunks().count(), 1);
+	}
+
 	#[test]
 	fn riff_info_to_tag() {
 		let tag_bytes = crate::tag::utils::test_utils::read_path("tests/tags/assets/test.riff");
@@ -453,4 +919,135 @@ mod tests {
 		assert_eq!(riff_info.get("ICMT"), Some("Qux comment"));
 		assert_eq!(riff_info.get("IPRT"), Some("1"));
 	}
+
+	#[test]
+	fn disc_and_disc_total() {
+		let mut riff_info = RiffInfoList::default();
+		riff_info.set_disk(1);
+		riff_info.set_disk_total(2);
+
+		assert_eq!(riff_info.get("DISC"), Some("1"));
+		assert_eq!(riff_info.get("DTOT"), Some("2"));
+		assert_eq!(riff_info.disk(), Some(1));
+		assert_eq!(riff_info.disk_total(), Some(2));
+
+		let tag: Tag = riff_info.into();
+		assert_eq!(tag.disk(), Some(1));
+		assert_eq!(tag.disk_total(), Some(2));
+
+		let riff_info: RiffInfoList = tag.into();
+		assert_eq!(riff_info.disk(), Some(1));
+		assert_eq!(riff_info.disk_total(), Some(2));
+
+		let mut riff_info = riff_info;
+		riff_info.remove_disk();
+		assert_eq!(riff_info.disk(), None);
+		assert_eq!(riff_info.disk_total(), Some(2));
+	}
+
+	#[test]
+	fn multiple_values() {
+		let mut riff_info = RiffInfoList::with_delimiter(String::from(" // "));
+		riff_info.set_artists(&["A", "B"]);
+		riff_info.set_genres(&["Rock", "Jazz"]);
+
+		assert_eq!(riff_info.get("IART"), Some("A // B"));
+
+		let mut writer = Vec::new();
+		riff_info
+			.dump_to(&mut writer, WriteOptions::default())
+			.unwrap();
+
+		// Remove the LIST....INFO from the tag
+		let mut parsed_tag = RiffInfoList::with_delimiter(String::from(" // "));
+		super::read::parse_riff_info(
+			&mut Cursor::new(&writer[12..]),
+			&mut Chunks::<LittleEndian>::new(writer.len() as u64),
+			(writer.len() - 12) as u64,
+			&mut parsed_tag,
+		)
+		.unwrap();
+
+		assert_eq!(parsed_tag.artists(), vec!["A", "B"]);
+		assert_eq!(parsed_tag.genres(), vec!["Rock", "Jazz"]);
+
+		// Each value should be its own item in a `Tag`
+		let tag: Tag = parsed_tag.into();
+		assert_eq!(
+			tag.get_strings(&ItemKey::TrackArtist).collect::<Vec<_>>(),
+			vec!["A", "B"]
+		);
+
+		// And joined again when converting back
+		let riff_info: RiffInfoList = tag.into();
+		assert_eq!(riff_info.get("IART"), Some("A;B"));
+		assert_eq!(riff_info.artists(), vec!["A", "B"]);
+
+		let mut riff_info = riff_info;
+		riff_info.set_artists::<&str>(&[]);
+		assert!(riff_info.get("IART").is_none());
+	}
+
+	#[test]
+	fn split_merge_keeps_delimiter() {
+		let mut riff_info = RiffInfoList::with_delimiter(String::from(" // "));
+		riff_info.set_artists(&["A", "B"]);
+
+		let (remainder, tag) = riff_info.clone().split_tag();
+		let merged = remainder.merge_tag(tag);
+
+		assert_eq!(merged.delimiter(), " // ");
+		assert_eq!(merged.get("IART"), Some("A // B"));
+		assert_eq!(merged, riff_info);
+
+		// The delimiter isn't part of the tag's contents
+		let mut other = riff_info.clone();
+		other.set_delimiter(String::from("/"));
+		assert_eq!(other, riff_info);
+	}
+
+	#[test]
+	fn id3v1_genres() {
+		let mut riff_info = RiffInfoList::default();
+		riff_info.insert(String::from("IGNR"), String::from("(17)"));
+
+		assert_eq!(riff_info.genre().as_deref(), Some("Rock"));
+
+		riff_info.set_genres(&["(17)", "8", "Foo"]);
+		assert_eq!(riff_info.genres(), vec!["Rock", "Jazz", "Foo"]);
+
+		let tag: Tag = riff_info.into();
+		assert_eq!(
+			tag.get_strings(&ItemKey::Genre).collect::<Vec<_>>(),
+			vec!["Rock", "Jazz", "Foo"]
+		);
+	}
+
+	#[test]
+	fn key_aliases() {
+		let mut riff_info = RiffInfoList::default();
+		riff_info
+			.items
+			.push((String::from("ITRK"), String::from("5")));
+
+		assert_eq!(riff_info.track(), Some(5));
+
+		// Replaces the aliased item, rather than adding another
+		riff_info.set_track(6);
+		assert_eq!(
+			riff_info.items,
+			vec![(String::from("IPRT"), String::from("6"))]
+		);
+
+		let mut key_aliases = KeyAliases::new();
+		key_aliases.insert("ALBU", "IPRD");
+		riff_info.set_key_aliases(key_aliases);
+
+		riff_info.insert(String::from("ALBU"), String::from("Foo album"));
+		assert_eq!(riff_info.get("IPRD"), Some("Foo album"));
+		assert_eq!(riff_info.album().as_deref(), Some("Foo album"));
+
+		// The default aliases were replaced
+		assert!(riff_info.get("ITRK").is_none());
+	}
 }
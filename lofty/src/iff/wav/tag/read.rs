@@ -1,8 +1,7 @@
 use super::RiffInfoList;
 use crate::error::Result;
 use crate::iff::chunk::Chunks;
-use crate::macros::decode_err;
-use crate::util::text::utf8_decode_str;
+use crate::util::text::utf8_decode;
 
 use std::io::{Read, Seek};
 
@@ -18,19 +17,27 @@ where
 	R: Read + Seek,
 {
 	while data.stream_position()? != end && chunks.next(data).is_ok() {
-		let key_str = utf8_decode_str(&chunks.fourcc)
-			.map_err(|_| decode_err!(Wav, "Non UTF-8 item key found in RIFF INFO"))?;
+		let fourcc = chunks.fourcc;
+		let content = chunks.content(data)?;
+		chunks.correct_position(data)?;
 
-		if !verify_key(key_str) {
-			decode_err!(@BAIL Wav, "RIFF INFO item key contains invalid characters");
-		}
+		let key = std::str::from_utf8(&fourcc)
+			.ok()
+			.filter(|key| verify_key(key));
 
-		tag.items.push((
-			key_str.to_owned(),
-			chunks
-				.read_cstring(data)
-				.map_err(|_| decode_err!(Wav, "Failed to read RIFF INFO item value"))?,
-		));
+		match key {
+			Some(key) if std::str::from_utf8(&content).is_ok() => {
+				tag.items.push((key.to_owned(), utf8_decode(content)?));
+			},
+			// Vendor-specific or binary chunks are kept as-is, so they can be written back
+			_ => {
+				log::debug!(
+					"Found a non-text RIFF INFO chunk ({:?}), storing it as an unknown chunk",
+					String::from_utf8_lossy(&fourcc)
+				);
+				tag.unknown_chunks.push((fourcc, content));
+			},
+		}
 	}
 
 	Ok(())
@@ -13,7 +13,7 @@ use byteorder::{LittleEndian, WriteBytesExt};
 pub(in crate::iff::wav) fn write_riff_info<'a, F, I>(
 	file: &mut F,
 	tag: &mut RIFFInfoListRef<'a, I>,
-	_write_options: WriteOptions,
+	write_options: WriteOptions,
 ) -> Result<()>
 where
 	F: FileLike,
@@ -24,12 +24,35 @@ where
 	verify_wav(file)?;
 	let file_len = file.len()?.saturating_sub(12);
 
+	let mut items = tag.items.by_ref().collect::<Vec<_>>();
+	if let Some(encoder_stamp) = write_options.encoder_stamp_value() {
+		let has_encoder = items
+			.iter()
+			.any(|(k, v)| k.eq_ignore_ascii_case("ISFT") && !v.is_empty());
+
+		// An empty tag is being removed, no need to stamp it
+		if !has_encoder && !items.is_empty() {
+			log::debug!("Stamping RIFF INFO list with encoder: {}", encoder_stamp);
+			items.push(("ISFT", encoder_stamp));
+		}
+	}
+
 	let mut riff_info_bytes = Vec::new();
-	create_riff_info(&mut tag.items, &mut riff_info_bytes)?;
+	create_riff_info(
+		&mut items.into_iter(),
+		tag.unknown_chunks,
+		&mut riff_info_bytes,
+	)?;
 
-	let Some(info_list_size) = find_info_list(file, file_len)? else {
+	let Some(mut info_list_size) = find_info_list(file, file_len)? else {
 		// Simply append the info list to the end of the file and update the file size
-		file.seek(SeekFrom::End(0))?;
+		let file_end = file.seek(SeekFrom::End(0))?;
+
+		// Chunks must start on even boundaries, the final chunk may be missing its pad byte
+		if file_end % 2 != 0 {
+			log::warn!("Final chunk is missing its pad byte, adding it");
+			file.write_u8(0)?;
+		}
 
 		file.write_all(&riff_info_bytes)?;
 
@@ -43,6 +66,11 @@ where
 
 	// Replace the existing tag
 
+	// We need to remove the pad byte if it exists
+	if info_list_size % 2 != 0 {
+		info_list_size += 1;
+	}
+
 	let info_list_start = file.seek(SeekFrom::Current(-12))? as usize;
 	let info_list_end = info_list_start + 8 + info_list_size as usize;
 
@@ -51,6 +79,9 @@ where
 	let mut file_bytes = Vec::new();
 	file.read_to_end(&mut file_bytes)?;
 
+	// The pad byte may be missing if this is the final chunk
+	let info_list_end = std::cmp::min(info_list_end, file_bytes.len());
+
 	let _ = file_bytes.splice(info_list_start..info_list_end, riff_info_bytes);
 
 	let total_size = (file_bytes.len() - 8) as u32;
@@ -83,7 +114,7 @@ where
 				break;
 			}
 
-			data.seek(SeekFrom::Current(-8))?;
+			data.seek(SeekFrom::Current(-4))?;
 		}
 
 		chunks.skip(data)?;
@@ -94,11 +125,12 @@ where
 
 pub(super) fn create_riff_info(
 	items: &mut dyn Iterator<Item = (&str, &str)>,
+	unknown_chunks: &[([u8; 4], Vec<u8>)],
 	bytes: &mut Vec<u8>,
 ) -> Result<()> {
 	let mut items = items.peekable();
 
-	if items.peek().is_none() {
+	if items.peek().is_none() && unknown_chunks.is_empty() {
 		log::debug!("No items to write, removing RIFF INFO list");
 		return Ok(());
 	}
@@ -124,6 +156,22 @@ pub(super) fn create_riff_info(
 		bytes.extend(terminator);
 	}
 
+	// Unknown chunks are written as-is, after the text items
+	for (fourcc, content) in unknown_chunks {
+		let Ok(len) = u32::try_from(content.len()) else {
+			err!(TooMuchData);
+		};
+
+		bytes.extend(fourcc);
+		bytes.extend(&len.to_le_bytes());
+		bytes.extend(content);
+
+		// Chunks must start on even boundaries
+		if len % 2 != 0 {
+			bytes.push(0);
+		}
+	}
+
 	let packet_size = Vec::len(bytes) - 4;
 
 	if packet_size > u32::MAX as usize {
@@ -106,19 +106,28 @@
 extern crate self as lofty;
 pub(crate) mod _this_is_internal {}
 
+#[cfg(feature = "tokio")]
+pub mod async_io;
+#[cfg(feature = "batch")]
+pub mod batch;
 pub mod config;
 pub mod error;
 pub mod file;
+#[cfg(feature = "library")]
+pub mod library;
 pub(crate) mod macros;
 pub mod picture;
 pub mod probe;
 pub mod properties;
 pub mod resolve;
 pub mod tag;
+#[cfg(feature = "test_utils")]
+pub mod test_utils;
 mod util;
 
 pub mod aac;
 pub mod ape;
+pub mod dsf;
 pub mod flac;
 pub mod id3;
 pub mod iff;
@@ -126,6 +135,7 @@ pub mod mp4;
 pub mod mpeg;
 pub mod musepack;
 pub mod ogg;
+pub mod optimfrog;
 pub mod wavpack;
 
 pub use crate::probe::{read_from, read_from_path};
@@ -0,0 +1,524 @@
+//! A high-level index of tagged files
+//!
+//! This module is only available with the `library` feature.
+//!
+//! A [`Library`] holds the [`Tag`] of every file added to it, allowing for simple queries
+//! across a collection of files without re-reading them.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use lofty::library::Library;
+//! use lofty::prelude::*;
+//!
+//! # fn main() -> lofty::error::Result<()> {
+//! # let path = "tests/files/assets/minimal/full_test.mp3";
+//! let mut library = Library::new();
+//! library.add_path(path)?;
+//!
+//! for path in library.by_artist("Foo artist") {
+//! 	println!("Found a track by \"Foo artist\": {}", path.display());
+//! }
+//!
+//! for path in library.missing_field(&ItemKey::Genre) {
+//! 	println!("{} has no genre", path.display());
+//! }
+//! # Ok(()) }
+//! ```
+
+use crate::config::ParseOptions;
+use crate::error::Result;
+use crate::file::{FileType, TaggedFileExt};
+use crate::probe::Probe;
+use crate::tag::{ItemKey, Tag};
+
+use std::path::{Path, PathBuf};
+
+/// A single file stored in a [`Library`]
+#[derive(Clone)]
+pub struct LibraryEntry {
+	path: PathBuf,
+	tag: Tag,
+}
+
+impl LibraryEntry {
+	/// The path of the file
+	pub fn path(&self) -> &Path {
+		&self.path
+	}
+
+	/// The tag read from the file
+	///
+	/// This will be the file's primary tag if it exists, otherwise the first tag available.
+	/// If the file has no tags, this will be an empty tag of the file's primary [`TagType`](crate::tag::TagType).
+	pub fn tag(&self) -> &Tag {
+		&self.tag
+	}
+}
+
+/// A group of [`LibraryEntry`]s sharing an album
+///
+/// See [`Library::albums`]
+#[derive(Clone)]
+pub struct AlbumGroup<'a> {
+	title: &'a str,
+	artist: Option<&'a str>,
+	entries: Vec<&'a LibraryEntry>,
+}
+
+impl<'a> AlbumGroup<'a> {
+	/// The album title
+	pub fn title(&self) -> &'a str {
+		self.title
+	}
+
+	/// The album artist
+	///
+	/// This uses [`ItemKey::AlbumArtist`], falling back to the track artist of the first entry.
+	pub fn artist(&self) -> Option<&'a str> {
+		self.artist
+	}
+
+	/// The entries in the album, in the order they were added to the [`Library`]
+	pub fn entries(&self) -> &[&'a LibraryEntry] {
+		&self.entries
+	}
+
+	/// The paths of the entries in the album
+	pub fn paths(&self) -> impl Iterator<Item = &'a Path> + '_ {
+		self.entries.iter().map(|entry| entry.path())
+	}
+}
+
+/// An index of tagged files
+///
+/// See the [module-level documentation](crate::library) for more information.
+#[derive(Clone, Default)]
+pub struct Library {
+	entries: Vec<LibraryEntry>,
+	parse_options: ParseOptions,
+}
+
+impl Library {
+	/// Create an empty `Library`
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use lofty::library::Library;
+	///
+	/// let library = Library::new();
+	/// assert!(library.is_empty());
+	/// ```
+	pub fn new() -> Self {
+		Self::default()
+	}
+
+	/// Set the [`ParseOptions`] used when reading files
+	///
+	/// Audio properties are never needed to build a `Library`, so
+	/// [`ParseOptions::read_properties`] will always be disabled.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use lofty::config::{ParseOptions, ParsingMode};
+	/// use lofty::library::Library;
+	///
+	/// let library =
+	/// 	Library::new().parse_options(ParseOptions::new().parsing_mode(ParsingMode::Relaxed));
+	/// ```
+	pub fn parse_options(mut self, parse_options: ParseOptions) -> Self {
+		self.parse_options = parse_options;
+		self
+	}
+
+	/// Read the file at `path` and add it to the `Library`
+	///
+	/// If the path already exists in the `Library`, its entry will be replaced.
+	///
+	/// # Errors
+	///
+	/// See [`Probe::open`] and [`Probe::read`]
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use lofty::library::Library;
+	///
+	/// # fn main() -> lofty::error::Result<()> {
+	/// # let path = "tests/files/assets/minimal/full_test.mp3";
+	/// let mut library = Library::new();
+	/// library.add_path(path)?;
+	///
+	/// assert_eq!(library.len(), 1);
+	/// # Ok(()) }
+	/// ```
+	pub fn add_path<P>(&mut self, path: P) -> Result<()>
+	where
+		P: AsRef<Path>,
+	{
+		let path = path.as_ref();
+
+		let mut parse_options = self.parse_options;
+		parse_options.read_properties = false;
+
+		let tagged_file = Probe::open(path)?.options(parse_options).read()?;
+		let tag = tagged_file
+			.primary_tag()
+			.or_else(|| tagged_file.first_tag())
+			.cloned()
+			.unwrap_or_else(|| Tag::new(tagged_file.primary_tag_type()));
+
+		let entry = LibraryEntry {
+			path: path.to_path_buf(),
+			tag,
+		};
+
+		match self.entries.iter_mut().find(|e| e.path == entry.path) {
+			Some(existing) => *existing = entry,
+			None => self.entries.push(entry),
+		}
+
+		Ok(())
+	}
+
+	/// Read every file in `paths` and add them to the `Library`
+	///
+	/// Files that fail to be read are skipped, and returned alongside their errors.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use lofty::library::Library;
+	///
+	/// # let mp3 = "tests/files/assets/minimal/full_test.mp3";
+	/// # let flac = "tests/files/assets/minimal/full_test.flac";
+	/// let mut library = Library::new();
+	/// let failed = library.add_paths([mp3, flac]);
+	///
+	/// assert!(failed.is_empty());
+	/// assert_eq!(library.len(), 2);
+	/// ```
+	pub fn add_paths<I, P>(&mut self, paths: I) -> Vec<(PathBuf, crate::error::LoftyError)>
+	where
+		I: IntoIterator<Item = P>,
+		P: AsRef<Path>,
+	{
+		let mut failed = Vec::new();
+		for path in paths {
+			let path = path.as_ref();
+			if let Err(e) = self.add_path(path) {
+				failed.push((path.to_path_buf(), e));
+			}
+		}
+
+		failed
+	}
+
+	/// Remove the entry for `path`, if it exists
+	pub fn remove_path<P>(&mut self, path: P) -> Option<LibraryEntry>
+	where
+		P: AsRef<Path>,
+	{
+		let pos = self.entries.iter().position(|e| e.path == path.as_ref())?;
+		Some(self.entries.remove(pos))
+	}
+
+	/// Returns the number of files in the `Library`
+	pub fn len(&self) -> usize {
+		self.entries.len()
+	}
+
+	/// Whether the `Library` is empty
+	pub fn is_empty(&self) -> bool {
+		self.entries.is_empty()
+	}
+
+	/// Returns an iterator over all entries
+	pub fn entries(&self) -> impl Iterator<Item = &LibraryEntry> + Clone {
+		self.entries.iter()
+	}
+
+	/// Get the entry for `path`, if it exists
+	pub fn get<P>(&self, path: P) -> Option<&LibraryEntry>
+	where
+		P: AsRef<Path>,
+	{
+		self.entries.iter().find(|e| e.path == path.as_ref())
+	}
+
+	/// Get the paths of all files by `artist`
+	///
+	/// This checks both [`ItemKey::TrackArtist`] and [`ItemKey::AlbumArtist`], including
+	/// any additional values of each. The comparison is case-insensitive.
+	pub fn by_artist(&self, artist: &str) -> Vec<&Path> {
+		self.entries
+			.iter()
+			.filter(|e| {
+				e.tag
+					.get_strings(&ItemKey::TrackArtist)
+					.chain(e.tag.get_strings(&ItemKey::AlbumArtist))
+					.any(|a| a.eq_ignore_ascii_case(artist))
+			})
+			.map(LibraryEntry::path)
+			.collect()
+	}
+
+	/// Group all entries by album
+	///
+	/// Entries are grouped by their album title and [album artist](AlbumGroup::artist).
+	/// Entries with no album title are not included.
+	///
+	/// The groups are returned in the order their first entry was added to the `Library`.
+	pub fn albums(&self) -> Vec<AlbumGroup<'_>> {
+		let mut groups: Vec<AlbumGroup<'_>> = Vec::new();
+
+		for entry in &self.entries {
+			let Some(title) = entry.tag.get_string(&ItemKey::AlbumTitle) else {
+				continue;
+			};
+
+			let artist = entry
+				.tag
+				.get_string(&ItemKey::AlbumArtist)
+				.or_else(|| entry.tag.get_string(&ItemKey::TrackArtist));
+
+			match groups
+				.iter_mut()
+				.find(|g| g.title == title && g.artist == artist)
+			{
+				Some(group) => group.entries.push(entry),
+				None => groups.push(AlbumGroup {
+					title,
+					artist,
+					entries: vec![entry],
+				}),
+			}
+		}
+
+		groups
+	}
+
+	/// Get the paths of all files missing a value for `key`
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use lofty::library::Library;
+	/// use lofty::prelude::*;
+	///
+	/// # fn main() -> lofty::error::Result<()> {
+	/// # let path = "tests/files/assets/minimal/full_test.mp3";
+	/// let mut library = Library::new();
+	/// library.add_path(path)?;
+	///
+	/// for path in library.missing_field(&ItemKey::Lyrics) {
+	/// 	println!("{} has no lyrics", path.display());
+	/// }
+	/// # Ok(()) }
+	/// ```
+	pub fn missing_field(&self, key: &ItemKey) -> Vec<&Path> {
+		self.entries
+			.iter()
+			.filter(|e| e.tag.get(key).is_none())
+			.map(LibraryEntry::path)
+			.collect()
+	}
+}
+
+/// Apply `f` to the primary tag of every file in `dir`, and write it back
+///
+/// This will recursively search `dir` for any files with a known [`FileType`] extension, see [`FileType::from_path`].
+/// Symbolic links are skipped, so a link can't cause a directory to be searched twice (or forever).
+/// If a file does not have its primary tag, an empty one will be created and passed to `f`.
+///
+/// The files are retagged in parallel, see [`retag_paths`](crate::batch::retag_paths).
+/// An error with one file will not stop the others from being processed.
+///
+/// The result of every file is returned, in the order they were found. If `dir` itself
+/// can't be read, its error will be returned with `dir` as the path.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use lofty::library::retag_dir;
+/// use lofty::prelude::*;
+///
+/// // Remove the comments from every file
+/// let results = retag_dir("my_music", |tag, _path| tag.remove_comment());
+///
+/// for (path, result) in results {
+/// 	if let Err(e) = result {
+/// 		eprintln!("Failed to retag {}: {e}", path.display());
+/// 	}
+/// }
+/// ```
+pub fn retag_dir<P, F>(dir: P, f: F) -> Vec<(PathBuf, Result<()>)>
+where
+	P: AsRef<Path>,
+	F: Fn(&mut Tag, &Path) + Sync,
+{
+	let mut results = Vec::new();
+
+	let mut paths = Vec::new();
+	if let Err(e) = collect_paths(dir.as_ref(), &mut paths, &mut results) {
+		results.push((dir.as_ref().to_path_buf(), Err(e)));
+	}
+
+	results.extend(crate::batch::retag_paths(paths, f));
+	results
+}
+
+fn collect_paths(
+	dir: &Path,
+	paths: &mut Vec<PathBuf>,
+	errors: &mut Vec<(PathBuf, Result<()>)>,
+) -> Result<()> {
+	let mut entries = std::fs::read_dir(dir)?
+		.map(|entry| entry.and_then(|e| Ok((e.path(), e.file_type()?))))
+		.collect::<std::io::Result<Vec<_>>>()?;
+	entries.sort_by(|(a, _), (b, _)| a.cmp(b));
+
+	for (path, file_type) in entries {
+		// `DirEntry::file_type` doesn't follow symbolic links
+		if file_type.is_symlink() {
+			continue;
+		}
+
+		if file_type.is_dir() {
+			if let Err(e) = collect_paths(&path, paths, errors) {
+				errors.push((path, Err(e)));
+			}
+
+			continue;
+		}
+
+		if FileType::from_path(&path).is_some() {
+			paths.push(path);
+		}
+	}
+
+	Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+	use super::{retag_dir, Library};
+	use crate::prelude::*;
+
+	use std::path::Path;
+
+	const MP3: &str = "tests/files/assets/minimal/full_test.mp3";
+	const FLAC: &str = "tests/files/assets/minimal/full_test.flac";
+
+	#[test]
+	fn replace_existing_path() {
+		let mut library = Library::new();
+		library.add_path(MP3).unwrap();
+		library.add_path(MP3).unwrap();
+
+		assert_eq!(library.len(), 1);
+	}
+
+	#[test]
+	fn failed_paths() {
+		let mut library = Library::new();
+		let failed = library.add_paths([MP3, "tests/files/assets/does_not_exist.mp3"]);
+
+		assert_eq!(library.len(), 1);
+		assert_eq!(failed.len(), 1);
+		assert_eq!(
+			failed[0].0,
+			Path::new("tests/files/assets/does_not_exist.mp3")
+		);
+	}
+
+	#[test]
+	fn queries() {
+		let mut library = Library::new();
+		assert!(library.add_paths([MP3, FLAC]).is_empty());
+
+		let mp3 = library.get(MP3).unwrap().tag().clone();
+		let artist = mp3.artist().unwrap();
+
+		assert!(library.by_artist(artist).contains(&Path::new(MP3)));
+		assert!(library
+			.by_artist(&artist.to_ascii_uppercase())
+			.contains(&Path::new(MP3)));
+
+		let albums = library.albums();
+		let mp3_album = mp3.album().unwrap();
+		assert!(albums
+			.iter()
+			.any(|a| a.title() == mp3_album && a.paths().any(|p| p == Path::new(MP3))));
+
+		assert_eq!(
+			library
+				.missing_field(&ItemKey::Unknown(String::from("FOO")))
+				.len(),
+			2
+		);
+		assert!(library.missing_field(&ItemKey::TrackTitle).is_empty());
+	}
+
+	#[test]
+	fn retag() {
+		let dir = tempfile::tempdir().unwrap();
+		let sub_dir = dir.path().join("sub");
+		std::fs::create_dir(&sub_dir).unwrap();
+
+		let mp3 = dir.path().join("a.mp3");
+		let m4a = sub_dir.join("b.m4a");
+		let broken = dir.path().join("c.flac");
+		std::fs::copy(MP3, &mp3).unwrap();
+		std::fs::copy("tests/files/assets/minimal/m4a_codec_aac.m4a", &m4a).unwrap();
+		std::fs::write(&broken, b"").unwrap();
+		std::fs::write(dir.path().join("notes.txt"), b"not audio").unwrap();
+
+		let results = retag_dir(dir.path(), |tag, path| {
+			tag.set_title(path.file_stem().unwrap().to_string_lossy().into_owned());
+		});
+
+		assert_eq!(results.len(), 3);
+		for (path, result) in &results {
+			assert_eq!(result.is_err(), path == &broken);
+		}
+
+		let mut library = Library::new();
+		assert!(library.add_paths([&mp3, &m4a]).is_empty());
+		assert_eq!(
+			library.get(&mp3).unwrap().tag().title().as_deref(),
+			Some("a")
+		);
+		assert_eq!(
+			library.get(&m4a).unwrap().tag().title().as_deref(),
+			Some("b")
+		);
+
+		// No temporary files are left behind
+		assert_eq!(std::fs::read_dir(dir.path()).unwrap().count(), 4);
+		assert_eq!(std::fs::read_dir(&sub_dir).unwrap().count(), 1);
+	}
+
+	#[test]
+	#[cfg(unix)]
+	fn retag_skips_symlinks() {
+		let dir = tempfile::tempdir().unwrap();
+		let sub_dir = dir.path().join("sub");
+		std::fs::create_dir(&sub_dir).unwrap();
+
+		let mp3 = sub_dir.join("a.mp3");
+		std::fs::copy(MP3, &mp3).unwrap();
+
+		// A link back to the parent would otherwise be followed forever
+		std::os::unix::fs::symlink(dir.path(), sub_dir.join("loop")).unwrap();
+		std::os::unix::fs::symlink(&mp3, dir.path().join("link.mp3")).unwrap();
+
+		let results = retag_dir(dir.path(), |_, _| {});
+
+		assert_eq!(results.len(), 1);
+		assert_eq!(results[0].0, mp3);
+		assert!(results[0].1.is_ok());
+	}
+}
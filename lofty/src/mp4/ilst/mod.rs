@@ -5,20 +5,25 @@ mod r#ref;
 pub(crate) mod write;
 
 use super::AtomIdent;
-use crate::config::{global_options, WriteOptions};
+use crate::config::{global_options, ParseOptions, ParsingMode, WriteOptions};
 use crate::error::LoftyError;
+use crate::macros::err;
 use crate::mp4::ilst::atom::AtomDataStorage;
+use crate::mp4::read::AtomReader;
 use crate::picture::{Picture, PictureType, TOMBSTONE_PICTURE};
+use crate::properties::GaplessInfo;
 use crate::tag::companion_tag::CompanionTag;
+use crate::tag::items::popm_item_to_text;
 use crate::tag::{
-	try_parse_year, Accessor, ItemKey, ItemValue, MergeTag, SplitTag, Tag, TagExt, TagItem, TagType,
+	try_parse_year, verify_number_range, verify_year, Accessor, ItemKey, ItemValue, MergeTag,
+	SplitTag, Tag, TagExt, TagItem, TagType,
 };
 use crate::util::flag_item;
-use crate::util::io::{FileLike, Length, Truncate};
+use crate::util::io::{FileLike, Length, SeekStreamLen, Truncate};
 use atom::{AdvisoryRating, Atom, AtomData};
 
 use std::borrow::Cow;
-use std::io::Write;
+use std::io::{Read, Seek, Write};
 use std::ops::Deref;
 
 use lofty_attr::tag;
@@ -28,8 +33,35 @@ const TITLE: AtomIdent<'_> = AtomIdent::Fourcc(*b"\xa9nam");
 const ALBUM: AtomIdent<'_> = AtomIdent::Fourcc(*b"\xa9alb");
 const GENRE: AtomIdent<'_> = AtomIdent::Fourcc(*b"\xa9gen");
 const COMMENT: AtomIdent<'_> = AtomIdent::Fourcc(*b"\xa9cmt");
+const LYRICS: AtomIdent<'_> = AtomIdent::Fourcc(*b"\xa9lyr");
+const TITLE_SORT: AtomIdent<'_> = AtomIdent::Fourcc(*b"sonm");
+const ARTIST_SORT: AtomIdent<'_> = AtomIdent::Fourcc(*b"soar");
+const ALBUM_TITLE_SORT: AtomIdent<'_> = AtomIdent::Fourcc(*b"soal");
+const ALBUM_ARTIST_SORT: AtomIdent<'_> = AtomIdent::Fourcc(*b"soaa");
 const ADVISORY_RATING: AtomIdent<'_> = AtomIdent::Fourcc(*b"rtng");
 const COVR: AtomIdent<'_> = AtomIdent::Fourcc(*b"covr");
+const ENCODING_TOOL: AtomIdent<'_> = AtomIdent::Fourcc(*b"\xa9too");
+const BPM: AtomIdent<'_> = AtomIdent::Fourcc(*b"tmpo");
+const ISRC: AtomIdent<'_> = AtomIdent::Freeform {
+	mean: Cow::Borrowed(ITUNES_MEAN),
+	name: Cow::Borrowed("ISRC"),
+};
+const BARCODE: AtomIdent<'_> = AtomIdent::Freeform {
+	mean: Cow::Borrowed(ITUNES_MEAN),
+	name: Cow::Borrowed("BARCODE"),
+};
+const CATALOG_NUMBER: AtomIdent<'_> = AtomIdent::Freeform {
+	mean: Cow::Borrowed(ITUNES_MEAN),
+	name: Cow::Borrowed("CATALOGNUMBER"),
+};
+const LABEL: AtomIdent<'_> = AtomIdent::Freeform {
+	mean: Cow::Borrowed(ITUNES_MEAN),
+	name: Cow::Borrowed("LABEL"),
+};
+
+const PICARD_MEAN: &str = "com.musicbrainz.Picard";
+const ITUNES_MEAN: &str = "com.apple.iTunes";
+const ITUNSMPB: &str = "iTunSMPB";
 
 macro_rules! impl_accessor {
 	($($name:ident => $const:ident;)+) => {
@@ -102,6 +134,52 @@ impl Ilst {
 		Self::default()
 	}
 
+	/// Read a tag previously written with [`TagExt::dump_to`]
+	///
+	/// # Errors
+	///
+	/// * `reader` does not start with an `ilst` atom
+	/// * The tag is otherwise malformed, see [`ParsingMode`]
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use lofty::config::{ParsingMode, WriteOptions};
+	/// use lofty::mp4::Ilst;
+	/// use lofty::tag::{Accessor, TagExt};
+	/// use std::io::Cursor;
+	///
+	/// # fn main() -> lofty::error::Result<()> {
+	/// let mut tag = Ilst::new();
+	/// tag.set_title(String::from("Foo title"));
+	///
+	/// let mut dump = Vec::new();
+	/// tag.dump_to(&mut dump, WriteOptions::default())?;
+	///
+	/// let re_read = Ilst::read_from_dump(&mut Cursor::new(dump), ParsingMode::default())?;
+	/// assert_eq!(re_read.title().as_deref(), Some("Foo title"));
+	/// # Ok(()) }
+	/// ```
+	pub fn read_from_dump<R>(reader: &mut R, parse_mode: ParsingMode) -> crate::error::Result<Self>
+	where
+		R: Read + Seek,
+	{
+		let start = reader.stream_position()?;
+		let len = reader.stream_len_hack()? - start;
+
+		let mut reader = AtomReader::new(reader, parse_mode)?;
+		reader.reset_bounds(start, len);
+
+		match reader.next()? {
+			Some(atom) if atom.ident == AtomIdent::Fourcc(*b"ilst") => read::parse_ilst(
+				&mut reader,
+				ParseOptions::new().parsing_mode(parse_mode),
+				atom.len - 8,
+			),
+			_ => err!(FakeTag),
+		}
+	}
+
 	/// Get an item by its [`AtomIdent`]
 	///
 	/// # Examples
@@ -368,6 +446,249 @@ impl Ilst {
 		})
 	}
 
+	/// Returns the software that last tagged the file
+	///
+	/// The following atoms are checked, in order:
+	///
+	/// 1. Any freeform atom with the mean `com.musicbrainz.Picard` (`----:com.musicbrainz.Picard:*`)
+	/// 2. `©too` (encoding tool)
+	///
+	/// NOTE: These atoms are never altered by Lofty, and will be written back as-is.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use lofty::mp4::{Atom, AtomData, AtomIdent, Ilst};
+	///
+	/// let mut ilst = Ilst::new();
+	/// assert!(ilst.tagging_tool().is_none());
+	///
+	/// ilst.insert(Atom::new(
+	/// 	AtomIdent::Fourcc(*b"\xa9too"),
+	/// 	AtomData::UTF8(String::from("Lavf60.16.100")),
+	/// ));
+	///
+	/// assert_eq!(ilst.tagging_tool(), Some("Lavf60.16.100"));
+	/// ```
+	pub fn tagging_tool(&self) -> Option<&str> {
+		self.atoms
+			.iter()
+			.filter(
+				|atom| matches!(&atom.ident, AtomIdent::Freeform { mean, .. } if mean == PICARD_MEAN),
+			)
+			.chain(self.get(&ENCODING_TOOL))
+			.find_map(|atom| match atom.data().next() {
+				Some(AtomData::UTF8(val) | AtomData::UTF16(val)) => Some(val.as_str()),
+				_ => None,
+			})
+	}
+
+	/// Get the text of a freeform atom (`----:mean:name`)
+	///
+	/// For non-text atoms, use [`Ilst::get`] with an [`AtomIdent::Freeform`].
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use lofty::mp4::Ilst;
+	///
+	/// let mut ilst = Ilst::new();
+	/// ilst.set_freeform("com.apple.iTunes", "Encoding Params", String::from("vers"));
+	///
+	/// assert_eq!(
+	/// 	ilst.freeform("com.apple.iTunes", "Encoding Params"),
+	/// 	Some("vers")
+	/// );
+	/// ```
+	pub fn freeform(&self, mean: &str, name: &str) -> Option<&str> {
+		let atom = self.get(&AtomIdent::Freeform {
+			mean: Cow::Borrowed(mean),
+			name: Cow::Borrowed(name),
+		})?;
+
+		match atom.data().next() {
+			Some(AtomData::UTF8(val) | AtomData::UTF16(val)) => Some(val.as_str()),
+			_ => None,
+		}
+	}
+
+	/// Set the text of a freeform atom (`----:mean:name`), replacing any existing value
+	pub fn set_freeform(&mut self, mean: &str, name: &str, value: String) {
+		self.replace_atom(Atom::new(
+			AtomIdent::Freeform {
+				mean: Cow::Owned(mean.to_owned()),
+				name: Cow::Owned(name.to_owned()),
+			},
+			AtomData::UTF8(value),
+		));
+	}
+
+	/// Remove a freeform atom (`----:mean:name`)
+	pub fn remove_freeform(&mut self, mean: &str, name: &str) {
+		let _ = self.remove(&AtomIdent::Freeform {
+			mean: Cow::Borrowed(mean),
+			name: Cow::Borrowed(name),
+		});
+	}
+
+	/// Returns an iterator over all freeform atoms, as `(mean, name, atom)`
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use lofty::mp4::Ilst;
+	///
+	/// let mut ilst = Ilst::new();
+	/// ilst.set_freeform("com.apple.iTunes", "MOOD", String::from("Happy"));
+	///
+	/// for (mean, name, atom) in ilst.freeform_atoms() {
+	/// 	println!("{mean}:{name} = {:?}", atom.data().next());
+	/// }
+	/// ```
+	pub fn freeform_atoms(&self) -> impl Iterator<Item = (&str, &str, &Atom<'static>)> {
+		self.atoms.iter().filter_map(|atom| match &atom.ident {
+			AtomIdent::Freeform { mean, name } => Some((&**mean, &**name, atom)),
+			AtomIdent::Fourcc(_) => None,
+		})
+	}
+
+	/// Get the encoder delay and padding from the `iTunSMPB` atom
+	///
+	/// See [`GaplessInfo`]
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use lofty::mp4::Ilst;
+	/// use lofty::properties::GaplessInfo;
+	///
+	/// let mut ilst = Ilst::new();
+	///
+	/// let gapless_info = GaplessInfo {
+	/// 	encoder_delay: 2112,
+	/// 	encoder_padding: 458,
+	/// 	total_samples: Some(4141558),
+	/// };
+	/// ilst.set_gapless_info(gapless_info);
+	///
+	/// assert_eq!(ilst.gapless_info(), Some(gapless_info));
+	/// ```
+	pub fn gapless_info(&self) -> Option<GaplessInfo> {
+		GaplessInfo::from_itunsmpb(self.freeform(ITUNES_MEAN, ITUNSMPB)?)
+	}
+
+	/// Set the encoder delay and padding, stored in the `iTunSMPB` atom
+	pub fn set_gapless_info(&mut self, gapless_info: GaplessInfo) {
+		self.set_freeform(ITUNES_MEAN, ITUNSMPB, gapless_info.to_itunsmpb());
+	}
+
+	/// Remove the `iTunSMPB` atom
+	pub fn remove_gapless_info(&mut self) {
+		self.remove_freeform(ITUNES_MEAN, ITUNSMPB);
+	}
+
+	/// Set the track number, failing if it doesn't fit in the 16-bit `trkn` atom
+	///
+	/// Unlike [`Accessor::set_track`], which only logs a warning, this returns an error. In both cases, the tag
+	/// is left unchanged.
+	///
+	/// # Errors
+	///
+	/// * `value` is greater than 65535 ([`ErrorKind::BadItem`](crate::error::ErrorKind::BadItem))
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use lofty::mp4::Ilst;
+	/// use lofty::tag::Accessor;
+	///
+	/// let mut ilst = Ilst::new();
+	///
+	/// assert!(ilst.try_set_track(70_000).is_err());
+	/// assert!(ilst.track().is_none());
+	///
+	/// ilst.try_set_track(1)?;
+	/// assert_eq!(ilst.track(), Some(1));
+	/// # Ok::<(), lofty::error::LoftyError>(())
+	/// ```
+	pub fn try_set_track(&mut self, value: u32) -> crate::error::Result<()> {
+		verify_number_range(TagType::Mp4Ilst, &ItemKey::TrackNumber, value)?;
+
+		let track_total = self.extract_number(*b"trkn", 6).unwrap_or(0);
+
+		// The value was checked above, it fits in a u16
+		self.set_number_pair(*b"trkn", value as u16, track_total);
+		Ok(())
+	}
+
+	/// Set the track total, failing if it doesn't fit in the 16-bit `trkn` atom
+	///
+	/// # Errors
+	///
+	/// See [`Ilst::try_set_track`]
+	pub fn try_set_track_total(&mut self, value: u32) -> crate::error::Result<()> {
+		verify_number_range(TagType::Mp4Ilst, &ItemKey::TrackTotal, value)?;
+
+		let track = self.extract_number(*b"trkn", 4).unwrap_or(0);
+
+		// The value was checked above, it fits in a u16
+		self.set_number_pair(*b"trkn", track, value as u16);
+		Ok(())
+	}
+
+	/// Set the disk number, failing if it doesn't fit in the 16-bit `disk` atom
+	///
+	/// # Errors
+	///
+	/// See [`Ilst::try_set_track`]
+	pub fn try_set_disk(&mut self, value: u32) -> crate::error::Result<()> {
+		verify_number_range(TagType::Mp4Ilst, &ItemKey::DiscNumber, value)?;
+
+		let disk_total = self.extract_number(*b"disk", 6).unwrap_or(0);
+
+		// The value was checked above, it fits in a u16
+		self.set_number_pair(*b"disk", value as u16, disk_total);
+		Ok(())
+	}
+
+	/// Set the disk total, failing if it doesn't fit in the 16-bit `disk` atom
+	///
+	/// # Errors
+	///
+	/// See [`Ilst::try_set_track`]
+	pub fn try_set_disk_total(&mut self, value: u32) -> crate::error::Result<()> {
+		verify_number_range(TagType::Mp4Ilst, &ItemKey::DiscTotal, value)?;
+
+		let disk = self.extract_number(*b"disk", 4).unwrap_or(0);
+
+		// The value was checked above, it fits in a u16
+		self.set_number_pair(*b"disk", disk, value as u16);
+		Ok(())
+	}
+
+	/// Set the year, failing if it has more than 4 digits
+	///
+	/// Only the first 4 digits of a year are read back, so unlike [`Accessor::set_year`], this will not
+	/// store a year that would be read back differently. The tag is left unchanged on failure.
+	///
+	/// # Errors
+	///
+	/// * `value` is greater than 9999 ([`ErrorKind::BadItem`](crate::error::ErrorKind::BadItem))
+	pub fn try_set_year(&mut self, value: u32) -> crate::error::Result<()> {
+		verify_year(value)?;
+		self.set_year(value);
+		Ok(())
+	}
+
+	// Replaces an integer pair, such as `trkn`
+	fn set_number_pair(&mut self, fourcc: [u8; 4], number: u16, total: u16) {
+		let number = number.to_be_bytes();
+		let total = total.to_be_bytes();
+
+		let data = vec![0, 0, number[0], number[1], total[0], total[1]];
+		self.replace_atom(Atom::unknown_implicit(AtomIdent::Fourcc(fourcc), data));
+	}
+
 	// Extracts a u16 from an integer pair
 	fn extract_number(&self, fourcc: [u8; 4], expected_size: usize) -> Option<u16> {
 		if let Some(atom) = self.get(&AtomIdent::Fourcc(fourcc)) {
@@ -406,11 +727,20 @@ impl IntoIterator for Ilst {
 
 impl Accessor for Ilst {
 	impl_accessor!(
-		artist  => ARTIST;
-		title   => TITLE;
-		album   => ALBUM;
-		genre   => GENRE;
-		comment => COMMENT;
+		artist            => ARTIST;
+		title             => TITLE;
+		album             => ALBUM;
+		genre             => GENRE;
+		comment           => COMMENT;
+		lyrics            => LYRICS;
+		title_sort        => TITLE_SORT;
+		artist_sort       => ARTIST_SORT;
+		album_title_sort  => ALBUM_TITLE_SORT;
+		album_artist_sort => ALBUM_ARTIST_SORT;
+		isrc              => ISRC;
+		barcode           => BARCODE;
+		catalog_number    => CATALOG_NUMBER;
+		label             => LABEL;
 	);
 
 	fn track(&self) -> Option<u32> {
@@ -418,11 +748,9 @@ impl Accessor for Ilst {
 	}
 
 	fn set_track(&mut self, value: u32) {
-		let track = (value as u16).to_be_bytes();
-		let track_total = (self.track_total().unwrap_or(0) as u16).to_be_bytes();
-
-		let data = vec![0, 0, track[0], track[1], track_total[0], track_total[1]];
-		self.replace_atom(Atom::unknown_implicit(AtomIdent::Fourcc(*b"trkn"), data));
+		if let Err(e) = self.try_set_track(value) {
+			log::warn!("Unable to set track number: {e}");
+		}
 	}
 
 	fn remove_track(&mut self) {
@@ -434,22 +762,17 @@ impl Accessor for Ilst {
 	}
 
 	fn set_track_total(&mut self, value: u32) {
-		let track_total = (value as u16).to_be_bytes();
-		let track = (self.track().unwrap_or(0) as u16).to_be_bytes();
-
-		let data = vec![0, 0, track[0], track[1], track_total[0], track_total[1]];
-		self.replace_atom(Atom::unknown_implicit(AtomIdent::Fourcc(*b"trkn"), data));
+		if let Err(e) = self.try_set_track_total(value) {
+			log::warn!("Unable to set track total: {e}");
+		}
 	}
 
 	fn remove_track_total(&mut self) {
-		let track = self.track();
+		let track = self.extract_number(*b"trkn", 4);
 		let _ = self.remove(&AtomIdent::Fourcc(*b"trkn"));
 
 		if let Some(track) = track {
-			let track_bytes = (track as u16).to_be_bytes();
-			let data = vec![0, 0, track_bytes[0], track_bytes[1], 0, 0];
-
-			self.replace_atom(Atom::unknown_implicit(AtomIdent::Fourcc(*b"trkn"), data));
+			self.set_number_pair(*b"trkn", track, 0);
 		}
 	}
 
@@ -458,11 +781,9 @@ impl Accessor for Ilst {
 	}
 
 	fn set_disk(&mut self, value: u32) {
-		let disk = (value as u16).to_be_bytes();
-		let disk_total = (self.disk_total().unwrap_or(0) as u16).to_be_bytes();
-
-		let data = vec![0, 0, disk[0], disk[1], disk_total[0], disk_total[1]];
-		self.replace_atom(Atom::unknown_implicit(AtomIdent::Fourcc(*b"disk"), data));
+		if let Err(e) = self.try_set_disk(value) {
+			log::warn!("Unable to set disk number: {e}");
+		}
 	}
 
 	fn remove_disk(&mut self) {
@@ -474,22 +795,17 @@ impl Accessor for Ilst {
 	}
 
 	fn set_disk_total(&mut self, value: u32) {
-		let disk_total = (value as u16).to_be_bytes();
-		let disk = (self.disk().unwrap_or(0) as u16).to_be_bytes();
-
-		let data = vec![0, 0, disk[0], disk[1], disk_total[0], disk_total[1]];
-		self.replace_atom(Atom::unknown_implicit(AtomIdent::Fourcc(*b"disk"), data));
+		if let Err(e) = self.try_set_disk_total(value) {
+			log::warn!("Unable to set disk total: {e}");
+		}
 	}
 
 	fn remove_disk_total(&mut self) {
-		let disk = self.disk();
+		let disk = self.extract_number(*b"disk", 4);
 		let _ = self.remove(&AtomIdent::Fourcc(*b"disk"));
 
 		if let Some(disk) = disk {
-			let disk_bytes = (disk as u16).to_be_bytes();
-			let data = vec![0, 0, disk_bytes[0], disk_bytes[1], 0, 0];
-
-			self.replace_atom(Atom::unknown_implicit(AtomIdent::Fourcc(*b"disk"), data));
+			self.set_number_pair(*b"disk", disk, 0);
 		}
 	}
 
@@ -513,6 +829,29 @@ impl Accessor for Ilst {
 	fn remove_year(&mut self) {
 		let _ = self.remove(&AtomIdent::Fourcc(*b"Year"));
 	}
+
+	fn encoder(&self) -> Option<Cow<'_, str>> {
+		if let Some(atom) = self.get(&ENCODING_TOOL) {
+			if let Some(AtomData::UTF8(val) | AtomData::UTF16(val)) = atom.data().next() {
+				return Some(Cow::Borrowed(val));
+			}
+		}
+
+		None
+	}
+
+	fn set_encoder(&mut self, value: String) {
+		if value.is_empty() {
+			self.remove_encoder();
+			return;
+		}
+
+		self.replace_atom(Atom::text(ENCODING_TOOL, value));
+	}
+
+	fn remove_encoder(&mut self) {
+		let _ = self.remove(&ENCODING_TOOL);
+	}
 }
 
 impl TagExt for Ilst {
@@ -600,6 +939,18 @@ impl SplitTag for Ilst {
 					let text = if *b { "1".to_owned() } else { "0".to_owned() };
 					ItemValue::Text(text)
 				},
+				AtomData::SignedInteger(bpm) if *ident == BPM => ItemValue::Text(bpm.to_string()),
+				AtomData::UnsignedInteger(bpm) if *ident == BPM => ItemValue::Text(bpm.to_string()),
+				AtomData::Unknown {
+					code: constants::BE_SIGNED_INTEGER,
+					data,
+				} if *ident == BPM => {
+					let Ok(bpm) = <[u8; 2]>::try_from(data.as_slice()) else {
+						return true; // Keep atom
+					};
+
+					ItemValue::Text(i16::from_be_bytes(bpm).to_string())
+				},
 				// We have to special case track/disc numbers since they are stored together
 				AtomData::Unknown { code: 0, data } if Vec::len(data) >= 6 => {
 					if let AtomIdent::Fourcc(ref fourcc) = ident {
@@ -698,7 +1049,8 @@ impl MergeTag for SplitTagRemainder {
 		let mut tracks: (Option<u16>, Option<u16>) = (None, None);
 		let mut discs: (Option<u16>, Option<u16>) = (None, None);
 
-		for item in tag.items {
+		for mut item in tag.items {
+			popm_item_to_text(&mut item);
 			let key = item.item_key;
 
 			if let Ok(ident) = TryInto::<AtomIdent<'_>>::try_into(&key) {
@@ -744,6 +1096,30 @@ impl MergeTag for SplitTagRemainder {
 							))),
 						})
 					},
+					ItemKey::IntegerBpm => {
+						let Ok(bpm) = text.trim().parse::<i64>() else {
+							log::warn!("BPM is not a valid integer: {}, discarding", text);
+							continue;
+						};
+
+						// `tmpo` is expected to be a 16-bit integer
+						let bpm = i16::try_from(bpm).unwrap_or_else(|_| {
+							log::warn!("BPM is out of range: {bpm}, clamping");
+							if bpm.is_negative() {
+								i16::MIN
+							} else {
+								i16::MAX
+							}
+						});
+
+						merged.atoms.push(Atom {
+							ident: ident.into_owned(),
+							data: AtomDataStorage::Single(AtomData::Unknown {
+								code: constants::BE_SIGNED_INTEGER,
+								data: bpm.to_be_bytes().to_vec(),
+							}),
+						})
+					},
 					_ => merged.atoms.push(Atom {
 						ident: ident.into_owned(),
 						data: AtomDataStorage::Single(AtomData::UTF8(text)),
@@ -806,6 +1182,7 @@ mod tests {
 	use crate::tag::utils::test_utils::read_path;
 	use crate::tag::{ItemValue, Tag, TagItem, TagType};
 
+	use std::borrow::Cow;
 	use std::io::{Cursor, Read as _, Seek as _, Write as _};
 
 	fn read_ilst(path: &str, parse_mode: ParsingMode) -> Ilst {
@@ -819,7 +1196,12 @@ mod tests {
 		let cursor = Cursor::new(bytes);
 		let mut reader = AtomReader::new(cursor, parse_mode).unwrap();
 
-		super::read::parse_ilst(&mut reader, parse_mode, len as u64).unwrap()
+		super::read::parse_ilst(
+			&mut reader,
+			ParseOptions::new().parsing_mode(parse_mode),
+			len as u64,
+		)
+		.unwrap()
 	}
 
 	fn read_ilst_strict(path: &str) -> Ilst {
@@ -895,8 +1277,12 @@ mod tests {
 		let cursor = Cursor::new(tag);
 		let mut reader = AtomReader::new(cursor, ParsingMode::Strict).unwrap();
 
-		let parsed_tag =
-			super::read::parse_ilst(&mut reader, ParsingMode::Strict, len as u64).unwrap();
+		let parsed_tag = super::read::parse_ilst(
+			&mut reader,
+			ParseOptions::new().parsing_mode(ParsingMode::Strict),
+			len as u64,
+		)
+		.unwrap();
 
 		assert_eq!(expected_tag, parsed_tag);
 	}
@@ -914,13 +1300,37 @@ mod tests {
 		let mut reader = AtomReader::new(cursor, ParsingMode::Strict).unwrap();
 
 		// Remove the ilst identifier and size
-		let temp_parsed_tag =
-			super::read::parse_ilst(&mut reader, ParsingMode::Strict, (writer.len() - 8) as u64)
-				.unwrap();
+		let temp_parsed_tag = super::read::parse_ilst(
+			&mut reader,
+			ParseOptions::new().parsing_mode(ParsingMode::Strict),
+			(writer.len() - 8) as u64,
+		)
+		.unwrap();
 
 		assert_eq!(parsed_tag, temp_parsed_tag);
 	}
 
+	#[test]
+	fn ilst_read_from_dump() {
+		let parsed_tag = read_ilst_strict("tests/tags/assets/ilst/test.ilst");
+
+		// The dump doesn't need to start at the beginning of the reader
+		let mut writer = vec![0; 4];
+		parsed_tag
+			.dump_to(&mut writer, WriteOptions::default())
+			.unwrap();
+
+		let mut reader = Cursor::new(writer);
+		reader.set_position(4);
+
+		let temp_parsed_tag = Ilst::read_from_dump(&mut reader, ParsingMode::Strict).unwrap();
+		assert_eq!(parsed_tag, temp_parsed_tag);
+
+		// Not an `ilst` atom
+		let mut reader = Cursor::new(b"\0\0\0\x08free".to_vec());
+		assert!(Ilst::read_from_dump(&mut reader, ParsingMode::Strict).is_err());
+	}
+
 	#[test]
 	fn ilst_to_tag() {
 		let tag = crate::tag::utils::test_utils::read_path("tests/tags/assets/ilst/test.ilst");
@@ -929,7 +1339,12 @@ mod tests {
 		let cursor = Cursor::new(tag);
 		let mut reader = AtomReader::new(cursor, ParsingMode::Strict).unwrap();
 
-		let ilst = super::read::parse_ilst(&mut reader, ParsingMode::Strict, len as u64).unwrap();
+		let ilst = super::read::parse_ilst(
+			&mut reader,
+			ParseOptions::new().parsing_mode(ParsingMode::Strict),
+			len as u64,
+		)
+		.unwrap();
 
 		let tag: Tag = ilst.into();
 
@@ -1048,9 +1463,12 @@ mod tests {
 			let cursor = Cursor::new(ilst_bytes);
 			let mut reader = AtomReader::new(cursor, ParsingMode::Strict).unwrap();
 
-			ilst =
-				super::read::parse_ilst(&mut reader, ParsingMode::Strict, ilst_bytes.len() as u64)
-					.unwrap();
+			ilst = super::read::parse_ilst(
+				&mut reader,
+				ParseOptions::new().parsing_mode(ParsingMode::Strict),
+				ilst_bytes.len() as u64,
+			)
+			.unwrap();
 		}
 
 		let mut file = tempfile::tempfile().unwrap();
@@ -1085,6 +1503,150 @@ mod tests {
 		assert!(Mp4File::read_from(&mut file, ParseOptions::new().read_properties(false)).is_ok());
 	}
 
+	#[test]
+	fn in_place_write() {
+		// `ilst` (42) + trailing `free` atom (990)
+		const AVAILABLE_SPACE: usize = 1032;
+		// A large atom following the `moov`, standing in for the audio data
+		const TRAILING_ATOM_SIZE: u32 = 16 * 1024 * 1024;
+
+		struct WriteCounter {
+			inner: Cursor<Vec<u8>>,
+			bytes_written: usize,
+		}
+
+		impl std::io::Read for WriteCounter {
+			fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+				self.inner.read(buf)
+			}
+		}
+
+		impl std::io::Write for WriteCounter {
+			fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+				let written = self.inner.write(buf)?;
+				self.bytes_written += written;
+				Ok(written)
+			}
+
+			fn flush(&mut self) -> std::io::Result<()> {
+				self.inner.flush()
+			}
+		}
+
+		impl std::io::Seek for WriteCounter {
+			fn seek(&mut self, pos: std::io::SeekFrom) -> std::io::Result<u64> {
+				self.inner.seek(pos)
+			}
+		}
+
+		impl crate::io::Truncate for WriteCounter {
+			type Error = std::convert::Infallible;
+
+			fn truncate(&mut self, new_len: u64) -> Result<(), Self::Error> {
+				crate::io::Truncate::truncate(&mut self.inner, new_len)
+			}
+		}
+
+		impl crate::io::Length for WriteCounter {
+			type Error = std::convert::Infallible;
+
+			fn len(&self) -> Result<u64, Self::Error> {
+				crate::io::Length::len(&self.inner)
+			}
+		}
+
+		let mut file_bytes = read_path("tests/files/assets/ilst_trailing_padding.m4a");
+		file_bytes.extend(TRAILING_ATOM_SIZE.to_be_bytes());
+		file_bytes.extend(b"free");
+		file_bytes.resize(file_bytes.len() + TRAILING_ATOM_SIZE as usize - 8, 0);
+
+		let original_len = file_bytes.len();
+		let mut file = WriteCounter {
+			inner: Cursor::new(file_bytes),
+			bytes_written: 0,
+		};
+
+		let mut ilst = Ilst::default();
+		ilst.set_title(String::from("Foo title"));
+		ilst.save_to(&mut file, WriteOptions::default()).unwrap();
+
+		// Only the `ilst` and its padding should have been touched
+		assert_eq!(file.bytes_written, AVAILABLE_SPACE);
+		assert_eq!(file.inner.get_ref().len(), original_len);
+
+		file.inner.rewind().unwrap();
+		let mp4_file =
+			Mp4File::read_from(&mut file.inner, ParseOptions::new().read_properties(false))
+				.unwrap();
+
+		let ilst = mp4_file.ilst_tag.unwrap();
+		assert_eq!(ilst.title().as_deref(), Some("Foo title"));
+		assert!(ilst.artist().is_none());
+	}
+
+	#[test]
+	fn tagging_tool() {
+		let mut ilst = Ilst::new();
+		ilst.insert(Atom::new(
+			AtomIdent::Fourcc(*b"\xa9too"),
+			AtomData::UTF8(String::from("Lavf60.16.100")),
+		));
+
+		assert_eq!(ilst.tagging_tool(), Some("Lavf60.16.100"));
+
+		// Picard's atoms take precedence over `©too`
+		ilst.insert(Atom::new(
+			AtomIdent::Freeform {
+				mean: Cow::Borrowed("com.musicbrainz.Picard"),
+				name: Cow::Borrowed("Version"),
+			},
+			AtomData::UTF8(String::from("MusicBrainz Picard 2.11")),
+		));
+
+		assert_eq!(ilst.tagging_tool(), Some("MusicBrainz Picard 2.11"));
+
+		// And they survive a round trip
+		let mut writer = Vec::new();
+		ilst.dump_to(&mut writer, WriteOptions::default()).unwrap();
+
+		let ilst = read_ilst_raw(&writer[8..], ParsingMode::Strict);
+		assert_eq!(ilst.tagging_tool(), Some("MusicBrainz Picard 2.11"));
+	}
+
+	#[test]
+	fn freeform_atoms() {
+		let mut ilst = Ilst::new();
+		ilst.set_title(String::from("Foo title"));
+		ilst.set_freeform("com.apple.iTunes", "Encoding Params", String::from("vers"));
+		ilst.set_freeform("com.apple.iTunes", "MOOD", String::from("Happy"));
+
+		// Existing values are replaced
+		ilst.set_freeform("com.apple.iTunes", "MOOD", String::from("Sad"));
+		assert_eq!(ilst.freeform("com.apple.iTunes", "MOOD"), Some("Sad"));
+
+		let mut writer = Vec::new();
+		ilst.dump_to(&mut writer, WriteOptions::default()).unwrap();
+
+		let mut ilst = read_ilst_raw(&writer[8..], ParsingMode::Strict);
+		assert_eq!(
+			ilst.freeform("com.apple.iTunes", "Encoding Params"),
+			Some("vers")
+		);
+
+		let freeform = ilst
+			.freeform_atoms()
+			.map(|(mean, name, _)| format!("{mean}:{name}"))
+			.collect::<Vec<_>>();
+		assert_eq!(
+			freeform,
+			["com.apple.iTunes:Encoding Params", "com.apple.iTunes:MOOD"]
+		);
+
+		ilst.remove_freeform("com.apple.iTunes", "MOOD");
+		assert!(ilst.freeform("com.apple.iTunes", "MOOD").is_none());
+		assert_eq!(ilst.freeform_atoms().count(), 1);
+	}
+
 	#[test]
 	fn read_non_full_meta_atom() {
 		let file_bytes = read_path("tests/files/assets/non_full_meta_atom.m4a");
@@ -1338,4 +1900,67 @@ mod tests {
 		let generic_tag_re_read = read_ilst_raw(&tag_bytes[..], ParsingMode::Strict);
 		assert_eq!(tag_re_read, generic_tag_re_read);
 	}
+
+	#[test]
+	fn sort_show_conversion() {
+		let mut ilst = Ilst::default();
+		ilst.insert(Atom::new(
+			AtomIdent::Fourcc(*b"tvsh"),
+			AtomData::UTF8(String::from("The Foo Show")),
+		));
+		ilst.insert(Atom::new(
+			AtomIdent::Fourcc(*b"sosn"),
+			AtomData::UTF8(String::from("Foo Show, The")),
+		));
+
+		let tag: Tag = ilst.into();
+		assert_eq!(tag.get_string(&ItemKey::ShowName), Some("The Foo Show"));
+		assert_eq!(
+			tag.get_string(&ItemKey::ShowNameSortOrder),
+			Some("Foo Show, The")
+		);
+
+		let ilst: Ilst = tag.into();
+		verify_atom(
+			&ilst,
+			*b"sosn",
+			&AtomData::UTF8(String::from("Foo Show, The")),
+		);
+
+		// Absent unless set
+		let tag: Tag = Ilst::default().into();
+		assert!(tag.get_string(&ItemKey::ShowNameSortOrder).is_none());
+	}
+
+	#[test]
+	fn try_set_numbers() {
+		let mut ilst = Ilst::new();
+		ilst.try_set_track(1).unwrap();
+		ilst.try_set_track_total(u32::from(u16::MAX)).unwrap();
+		ilst.try_set_disk(2).unwrap();
+		ilst.try_set_disk_total(3).unwrap();
+
+		// Values that don't fit in a u16 leave the existing values untouched
+		assert!(ilst.try_set_track(70_000).is_err());
+		assert!(ilst.try_set_track_total(70_000).is_err());
+		assert!(ilst.try_set_disk(70_000).is_err());
+		assert!(ilst.try_set_disk_total(70_000).is_err());
+
+		assert_eq!(ilst.track(), Some(1));
+		assert_eq!(ilst.track_total(), Some(u32::from(u16::MAX)));
+		assert_eq!(ilst.disk(), Some(2));
+		assert_eq!(ilst.disk_total(), Some(3));
+
+		// The same goes for the `Accessor` setters, rather than truncating the values
+		ilst.set_track(70_000);
+		ilst.set_disk_total(70_000);
+
+		assert_eq!(ilst.track(), Some(1));
+		assert_eq!(ilst.disk_total(), Some(3));
+
+		// Only the first 4 digits of a year are read
+		ilst.try_set_year(2024).unwrap();
+		assert!(ilst.try_set_year(20_240).is_err());
+		assert_eq!(ilst.year(), Some(2024));
+	}
 }
@@ -2,14 +2,14 @@ use super::constants::{
 	BE_SIGNED_INTEGER, BE_UNSIGNED_INTEGER, BMP, JPEG, PNG, RESERVED, UTF16, UTF8,
 };
 use super::{Atom, AtomData, AtomIdent, Ilst};
-use crate::config::ParsingMode;
+use crate::config::{ParseOptions, ParsingMode};
 use crate::error::{LoftyError, Result};
 use crate::id3::v1::constants::GENRES;
 use crate::macros::{err, try_vec};
 use crate::mp4::atom_info::AtomInfo;
 use crate::mp4::ilst::atom::AtomDataStorage;
 use crate::mp4::read::{skip_unneeded, AtomReader};
-use crate::picture::{MimeType, Picture, PictureType};
+use crate::picture::{MimeType, Picture, PictureLocation, PictureType};
 use crate::util::text::{utf16_decode_bytes, utf8_decode};
 
 use std::borrow::Cow;
@@ -17,12 +17,18 @@ use std::io::{Cursor, Read, Seek, SeekFrom};
 
 pub(in crate::mp4) fn parse_ilst<R>(
 	reader: &mut AtomReader<R>,
-	parsing_mode: ParsingMode,
+	parse_options: ParseOptions,
 	len: u64,
 ) -> Result<Ilst>
 where
 	R: Read + Seek,
 {
+	let parsing_mode = parse_options.parsing_mode;
+
+	parse_options.check_tag_size(len)?;
+
+	let ilst_offset = reader.stream_position()?;
+
 	let mut contents = try_vec![0; len as usize];
 	reader.read_exact(&mut contents)?;
 
@@ -40,7 +46,9 @@ where
 					continue;
 				},
 				b"covr" => {
-					handle_covr(&mut ilst_reader, parsing_mode, &mut tag, &atom)?;
+					// Skipped pictures only remember where their data is, see `Picture::load`
+					let ilst_offset = (!parse_options.read_pictures).then_some(ilst_offset);
+					handle_covr(&mut ilst_reader, parsing_mode, &mut tag, &atom, ilst_offset)?;
 					continue;
 				},
 				// Upgrade this to a \xa9gen atom
@@ -199,6 +207,27 @@ fn parse_data_inner<R>(
 	parsing_mode: ParsingMode,
 	atom_info: &AtomInfo,
 ) -> Result<Option<Vec<(u32, Vec<u8>)>>>
+where
+	R: Read + Seek,
+{
+	parse_data_atoms(reader, parsing_mode, atom_info, read_data_content)
+}
+
+fn read_data_content<R>(reader: &mut AtomReader<R>, content_len: usize) -> Result<Vec<u8>>
+where
+	R: Read + Seek,
+{
+	let mut content = try_vec![0; content_len];
+	reader.read_exact(&mut content)?;
+	Ok(content)
+}
+
+fn parse_data_atoms<R, T>(
+	reader: &mut AtomReader<R>,
+	parsing_mode: ParsingMode,
+	atom_info: &AtomInfo,
+	mut read_content: impl FnMut(&mut AtomReader<R>, usize) -> Result<T>,
+) -> Result<Option<Vec<(u32, T)>>>
 where
 	R: Read + Seek,
 {
@@ -228,9 +257,7 @@ where
 				debug_assert!(next_atom.len >= 16);
 				let content_len = (next_atom.len - 16) as usize;
 				if content_len > 0 {
-					let mut content = try_vec![0; content_len];
-					reader.read_exact(&mut content)?;
-					ret.push((flags, content));
+					ret.push((flags, read_content(reader, content_len)?));
 				} else {
 					log::warn!("Skipping empty \"data\" atom");
 				}
@@ -280,20 +307,42 @@ fn parse_int(bytes: &[u8]) -> Result<i32> {
 	})
 }
 
+/// Read a `covr` atom
+///
+/// If `ilst_offset` (the offset of the `ilst` atom's content in the file) is provided, the picture data
+/// is skipped.
 fn handle_covr<R>(
 	reader: &mut AtomReader<R>,
 	parsing_mode: ParsingMode,
 	tag: &mut Ilst,
 	atom_info: &AtomInfo,
+	ilst_offset: Option<u64>,
 ) -> Result<()>
 where
 	R: Read + Seek,
 {
-	if let Some(atom_data) = parse_data_inner(reader, parsing_mode, atom_info)? {
+	let atom_data = match ilst_offset {
+		Some(ilst_offset) => {
+			parse_data_atoms(reader, parsing_mode, atom_info, |reader, content_len| {
+				let location = PictureLocation {
+					offset: ilst_offset + reader.stream_position()?,
+					len: content_len as u64,
+				};
+
+				reader.seek(SeekFrom::Current(content_len as i64))?;
+				Ok((Vec::new(), Some(location)))
+			})?
+		},
+		None => parse_data_atoms(reader, parsing_mode, atom_info, |reader, content_len| {
+			Ok((read_data_content(reader, content_len)?, None))
+		})?,
+	};
+
+	if let Some(atom_data) = atom_data {
 		let mut data = Vec::new();
 
 		let len = atom_data.len();
-		for (flags, value) in atom_data {
+		for (flags, (value, location)) in atom_data {
 			let mime_type = match flags {
 				// Type 0 is implicit
 				RESERVED => None,
@@ -310,6 +359,7 @@ where
 				mime_type,
 				description: None,
 				data: Cow::from(value),
+				location,
 			});
 
 			if len == 1 {
@@ -4,6 +4,7 @@
 
 use crate::config::WriteOptions;
 use crate::error::{LoftyError, Result};
+use crate::file::write_verified;
 use crate::mp4::{Atom, AtomData, AtomIdent, Ilst};
 use crate::util::io::{FileLike, Length, Truncate};
 
@@ -31,7 +32,9 @@ where
 		LoftyError: From<<F as Truncate>::Error>,
 		LoftyError: From<<F as Length>::Error>,
 	{
-		super::write::write_to(file, self, write_options)
+		write_verified(file, write_options, |file, write_options| {
+			super::write::write_to(file, self, write_options)
+		})
 	}
 
 	pub(crate) fn dump_to<W: Write>(
@@ -1,4 +1,5 @@
 use super::r#ref::IlstRef;
+use super::ENCODING_TOOL;
 use crate::config::{ParseOptions, WriteOptions};
 use crate::error::{FileEncodingError, LoftyError, Result};
 use crate::file::FileType;
@@ -9,9 +10,9 @@ use crate::mp4::read::{atom_tree, meta_is_full, nested_atom, verify_mp4, AtomRea
 use crate::mp4::write::{AtomWriter, AtomWriterCompanion, ContextualAtom};
 use crate::mp4::AtomData;
 use crate::picture::{MimeType, Picture};
-use crate::util::io::{FileLike, Length, Truncate};
+use crate::util::io::{FileLike, Length, SeekStreamLen, Truncate};
 
-use std::io::{Cursor, Seek, SeekFrom, Write};
+use std::io::{Cursor, Read, Seek, SeekFrom, Write};
 
 use byteorder::{BigEndian, ReadBytesExt, WriteBytesExt};
 
@@ -37,8 +38,29 @@ where
 	let mut reader = AtomReader::new(file, ParseOptions::DEFAULT_PARSING_MODE)?;
 	verify_mp4(&mut reader)?;
 
-	// Now we can just read the entire file into memory
+	let ilst = build_stamped_ilst(&mut tag.atoms, write_options)?;
+	let remove_tag = ilst.is_empty();
+
 	let file = reader.into_inner();
+
+	// If the new `ilst` fits within the existing one and its surrounding padding, we can
+	// overwrite it directly, rather than reading the entire file into memory.
+	if !remove_tag && !write_options.force_rewrite {
+		if let Some((ilst_start, remaining_space)) = find_in_place_region(file, ilst.len() as u64)?
+		{
+			log::trace!("Found enough padding to fit the tag, writing in place");
+
+			file.seek(SeekFrom::Start(ilst_start))?;
+			file.write_all(&ilst)?;
+
+			// Write the remaining padding
+			write_free_atom(file, remaining_space)?;
+
+			return Ok(());
+		}
+	}
+
+	// Otherwise, we'll have to read the entire file into memory
 	file.rewind()?;
 
 	let mut atom_writer = AtomWriter::new_from_file(file, ParseOptions::DEFAULT_PARSING_MODE)?;
@@ -69,9 +91,6 @@ where
 	let mut write_handle = atom_writer.start_write();
 	write_handle.seek(SeekFrom::Start(moov_data_start))?;
 
-	let ilst = build_ilst(&mut tag.atoms)?;
-	let remove_tag = ilst.is_empty();
-
 	let udta = nested_atom(
 		&mut write_handle,
 		moov_len,
@@ -242,65 +261,22 @@ fn save_to_existing(
 		let existing_ilst = &tree[ilst_idx];
 		let existing_ilst_size = existing_ilst.len;
 
-		let mut range_start = existing_ilst.start;
 		let range_end = existing_ilst.start + existing_ilst_size;
 
 		if remove_tag {
 			// We just need to strip out the `ilst` atom
 
 			replacement = Vec::new();
-			range = range_start as usize..range_end as usize;
+			range = existing_ilst.start as usize..range_end as usize;
 		} else {
 			// Check for some padding atoms we can utilize
-			let mut available_space = existing_ilst_size;
-
-			// Check for one directly before the `ilst` atom
-			if ilst_idx > 0 {
-				let mut i = ilst_idx;
-				while i != 0 {
-					let atom = &tree[i - 1];
-					if atom.ident != AtomIdent::Fourcc(*b"free") {
-						break;
-					}
-
-					available_space += atom.len;
-					range_start = atom.start;
-					i -= 1;
-				}
-
-				log::trace!("Found {} preceding `free` atoms", ilst_idx - i)
-			}
-
-			// And after
-			if ilst_idx != tree.len() - 1 {
-				let mut i = ilst_idx;
-				while i < tree.len() - 1 {
-					let atom = &tree[i + 1];
-					if atom.ident != AtomIdent::Fourcc(*b"free") {
-						break;
-					}
-
-					available_space += atom.len;
-					i += 1;
-				}
-
-				log::trace!("Found {} succeeding `free` atoms", i - ilst_idx)
-			}
-
-			let ilst_len = ilst.len() as u64;
+			let (range_start, available_space) = surrounding_padding(&tree, ilst_idx);
 
 			// Check if we have enough padding to fit the `ilst` atom and a new `free` atom
-			if available_space > ilst_len && (available_space - ilst_len) > 8 {
+			if let Some(remaining_space) = remaining_padding(available_space, ilst.len() as u64)? {
 				// We have enough space to make use of the padding
 				log::trace!("Found enough padding to fit the tag, file size will not change");
 
-				let remaining_space = available_space - ilst_len;
-				if remaining_space > u64::from(u32::MAX) {
-					err!(TooMuchData);
-				}
-
-				let remaining_space = remaining_space as u32;
-
 				write_handle.seek(SeekFrom::Start(range_start))?;
 				write_handle.write_all(&ilst)?;
 
@@ -362,6 +338,103 @@ fn save_to_existing(
 	Ok(())
 }
 
+// Finds the location of the existing `ilst` atom, provided the new one will fit within it and its surrounding `free` atoms
+//
+// This expects the reader to be positioned after the `ftyp` atom.
+//
+// Returns the position to write the new `ilst` atom at, and the size of the `free` atom that needs to follow it.
+fn find_in_place_region<R>(reader: &mut R, ilst_len: u64) -> Result<Option<(u64, u32)>>
+where
+	R: Read + Seek,
+{
+	let parse_mode = ParseOptions::DEFAULT_PARSING_MODE;
+
+	let current_pos = reader.stream_position()?;
+	let file_len = reader.stream_len_hack()?;
+
+	let Some(moov) = nested_atom(reader, file_len - current_pos, b"moov", parse_mode)? else {
+		return Ok(None);
+	};
+
+	let Some(udta) = nested_atom(reader, moov.len, b"udta", parse_mode)? else {
+		return Ok(None);
+	};
+
+	let Some(meta) = nested_atom(reader, udta.len, b"meta", parse_mode)? else {
+		return Ok(None);
+	};
+
+	// We may encounter a non-full `meta` atom
+	meta_is_full(reader)?;
+
+	let (ilst_idx, tree) = atom_tree(reader, meta.len - ATOM_HEADER_LEN, b"ilst", parse_mode)?;
+
+	match tree.get(ilst_idx) {
+		Some(existing_ilst) if existing_ilst.ident == AtomIdent::Fourcc(*b"ilst") => {},
+		_ => return Ok(None),
+	}
+
+	let (range_start, available_space) = surrounding_padding(&tree, ilst_idx);
+	Ok(remaining_padding(available_space, ilst_len)?.map(|remaining| (range_start, remaining)))
+}
+
+// Gets the start of the `free` atoms preceding the `ilst`, and the total size of the `ilst` and all surrounding `free` atoms
+fn surrounding_padding(tree: &[AtomInfo], ilst_idx: usize) -> (u64, u64) {
+	let existing_ilst = &tree[ilst_idx];
+
+	let mut range_start = existing_ilst.start;
+	let mut available_space = existing_ilst.len;
+
+	// Check for one directly before the `ilst` atom
+	if ilst_idx > 0 {
+		let mut i = ilst_idx;
+		while i != 0 {
+			let atom = &tree[i - 1];
+			if atom.ident != AtomIdent::Fourcc(*b"free") {
+				break;
+			}
+
+			available_space += atom.len;
+			range_start = atom.start;
+			i -= 1;
+		}
+
+		log::trace!("Found {} preceding `free` atoms", ilst_idx - i)
+	}
+
+	// And after
+	if ilst_idx != tree.len() - 1 {
+		let mut i = ilst_idx;
+		while i < tree.len() - 1 {
+			let atom = &tree[i + 1];
+			if atom.ident != AtomIdent::Fourcc(*b"free") {
+				break;
+			}
+
+			available_space += atom.len;
+			i += 1;
+		}
+
+		log::trace!("Found {} succeeding `free` atoms", i - ilst_idx)
+	}
+
+	(range_start, available_space)
+}
+
+// Gets the size of the `free` atom needed to fill the remaining space, if the `ilst` atom fits
+fn remaining_padding(available_space: u64, ilst_len: u64) -> Result<Option<u32>> {
+	if available_space <= ilst_len || (available_space - ilst_len) <= 8 {
+		return Ok(None);
+	}
+
+	let remaining_space = available_space - ilst_len;
+	if remaining_space > u64::from(u32::MAX) {
+		err!(TooMuchData);
+	}
+
+	Ok(Some(remaining_space as u32))
+}
+
 fn pad_atom<W>(
 	writer: &mut W,
 	mut atom_size_difference: i64,
@@ -591,6 +664,50 @@ fn create_meta(writer: &AtomWriter, ilst: &[u8]) -> Result<()> {
 	Ok(())
 }
 
+// Builds the `ilst` atom, appending a `©too` atom if one is not present (see `WriteOptions::encoder_stamp`)
+fn build_stamped_ilst<'a, I>(
+	atoms: &mut dyn Iterator<Item = AtomRef<'a, I>>,
+	write_options: WriteOptions,
+) -> Result<Vec<u8>>
+where
+	I: IntoIterator<Item = &'a AtomData> + 'a,
+{
+	let Some(encoder_stamp) = write_options.encoder_stamp_value() else {
+		return build_ilst(atoms);
+	};
+
+	let mut has_encoder = false;
+	let mut ilst = build_ilst(&mut atoms.inspect(|atom| {
+		if atom.ident == ENCODING_TOOL {
+			has_encoder = true;
+		}
+	}))?;
+
+	// An empty tag is being removed, no need to stamp it
+	if has_encoder || ilst.is_empty() {
+		return Ok(ilst);
+	}
+
+	log::debug!("Stamping `ilst` atom with encoder: {}", encoder_stamp);
+
+	let data = AtomData::UTF8(String::from(encoder_stamp));
+	let stamp = build_ilst(&mut std::iter::once(AtomRef {
+		ident: ENCODING_TOOL,
+		data: std::iter::once(&data),
+	}))?;
+
+	// Skip the `ilst` header of the stamp, and update the size
+	ilst.extend_from_slice(&stamp[ATOM_HEADER_LEN as usize..]);
+
+	let Ok(ilst_len) = u32::try_from(ilst.len()) else {
+		err!(TooMuchData);
+	};
+
+	ilst[..4].copy_from_slice(&ilst_len.to_be_bytes());
+
+	Ok(ilst)
+}
+
 pub(super) fn build_ilst<'a, I>(atoms: &mut dyn Iterator<Item = AtomRef<'a, I>>) -> Result<Vec<u8>>
 where
 	I: IntoIterator<Item = &'a AtomData> + 'a,
@@ -719,6 +836,8 @@ fn write_int(
 }
 
 fn write_picture(picture: &Picture, writer: &mut AtomWriterCompanion<'_>) -> Result<()> {
+	picture.check_loaded()?;
+
 	match picture.mime_type {
 		// GIF is deprecated
 		Some(MimeType::Gif) => write_data(12, &picture.data, writer),
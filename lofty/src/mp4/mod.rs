@@ -27,6 +27,7 @@ pub use ilst::atom::{AdvisoryRating, Atom, AtomData};
 pub use ilst::Ilst;
 
 pub(crate) use properties::SAMPLE_RATES;
+pub(crate) use read::{audio_payload_len, audio_payload_regions};
 
 /// An MP4 file
 #[derive(LoftyFile)]
@@ -2,7 +2,7 @@ use super::atom_info::{AtomIdent, AtomInfo};
 use super::ilst::read::parse_ilst;
 use super::ilst::Ilst;
 use super::read::{meta_is_full, nested_atom, skip_unneeded, AtomReader};
-use crate::config::ParsingMode;
+use crate::config::ParseOptions;
 use crate::error::Result;
 use crate::macros::decode_err;
 
@@ -34,21 +34,19 @@ impl Moov {
 		moov.ok_or_else(|| decode_err!(Mp4, "No \"moov\" atom found"))
 	}
 
-	pub(super) fn parse<R>(
-		reader: &mut AtomReader<R>,
-		parse_mode: ParsingMode,
-		read_properties: bool,
-	) -> Result<Self>
+	pub(super) fn parse<R>(reader: &mut AtomReader<R>, parse_options: ParseOptions) -> Result<Self>
 	where
 		R: Read + Seek,
 	{
+		let parse_mode = parse_options.parsing_mode;
+
 		let mut traks = Vec::new();
 		let mut meta = None;
 
 		while let Ok(Some(atom)) = reader.next() {
 			if let AtomIdent::Fourcc(fourcc) = atom.ident {
 				match &fourcc {
-					b"trak" if read_properties => {
+					b"trak" if parse_options.read_properties => {
 						// All we need from here is trak.mdia
 						if let Some(mdia) = nested_atom(reader, atom.len, b"mdia", parse_mode)? {
 							skip_unneeded(reader, mdia.extended, mdia.len)?;
@@ -56,7 +54,7 @@ impl Moov {
 						}
 					},
 					b"udta" => {
-						meta = meta_from_udta(reader, parse_mode, atom.len - 8)?;
+						meta = meta_from_udta(reader, parse_options, atom.len - 8)?;
 					},
 					_ => skip_unneeded(reader, atom.extended, atom.len)?,
 				}
@@ -73,7 +71,7 @@ impl Moov {
 
 fn meta_from_udta<R>(
 	reader: &mut AtomReader<R>,
-	parsing_mode: ParsingMode,
+	parse_options: ParseOptions,
 	len: u64,
 ) -> Result<Option<Ilst>>
 where
@@ -131,7 +129,7 @@ where
 	}
 
 	if found_ilst {
-		return parse_ilst(reader, parsing_mode, ilst_atom_size - 8).map(Some);
+		return parse_ilst(reader, parse_options, ilst_atom_size - 8).map(Some);
 	}
 
 	Ok(None)
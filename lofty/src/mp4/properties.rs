@@ -3,7 +3,7 @@ use super::read::{nested_atom, skip_unneeded, AtomReader};
 use crate::config::ParsingMode;
 use crate::error::{LoftyError, Result};
 use crate::macros::{decode_err, err, try_vec};
-use crate::properties::FileProperties;
+use crate::properties::{FileProperties, GaplessInfo};
 use crate::util::math::RoundedDivision;
 
 use std::io::{Cursor, Read, Seek, SeekFrom};
@@ -143,6 +143,7 @@ pub struct Mp4Properties {
 	pub(crate) bit_depth: Option<u8>,
 	pub(crate) channels: u8,
 	pub(crate) drm_protected: bool,
+	pub(crate) gapless: Option<GaplessInfo>,
 }
 
 impl From<Mp4Properties> for FileProperties {
@@ -208,6 +209,13 @@ impl Mp4Properties {
 	pub fn is_drm_protected(&self) -> bool {
 		self.drm_protected
 	}
+
+	/// The encoder delay and padding, if the file has an `iTunSMPB` atom
+	///
+	/// See [`GaplessInfo`] and [`Ilst::gapless_info`](crate::mp4::Ilst::gapless_info)
+	pub fn gapless_info(&self) -> Option<GaplessInfo> {
+		self.gapless
+	}
 }
 
 struct TrakChildren {
@@ -1,4 +1,5 @@
 use super::atom_info::{AtomIdent, AtomInfo};
+use super::ilst::Ilst;
 use super::moov::Moov;
 use super::properties::Mp4Properties;
 use super::Mp4File;
@@ -192,30 +193,62 @@ where
 	let moov_info = Moov::find(&mut reader)?;
 	reader.reset_bounds(moov_info.start + 8, moov_info.len - 8);
 
-	let moov = Moov::parse(
-		&mut reader,
-		parse_options.parsing_mode,
-		parse_options.read_properties,
-	)?;
+	let moov = Moov::parse(&mut reader, parse_options)?;
+
+	let mut properties = Mp4Properties::default();
+	if parse_options.read_properties {
+		// Remove the length restriction
+		reader.reset_bounds(0, file_length);
+		properties = super::properties::read_properties(
+			&mut reader,
+			&moov.traks,
+			file_length,
+			parse_options.parsing_mode,
+		)?;
+
+		properties.gapless = moov.meta.as_ref().and_then(Ilst::gapless_info);
+	}
 
 	Ok(Mp4File {
 		ftyp,
 		ilst_tag: moov.meta,
-		properties: if parse_options.read_properties {
-			// Remove the length restriction
-			reader.reset_bounds(0, file_length);
-			super::properties::read_properties(
-				&mut reader,
-				&moov.traks,
-				file_length,
-				parse_options.parsing_mode,
-			)?
-		} else {
-			Mp4Properties::default()
-		},
+		properties,
 	})
 }
 
+/// Get the total size of all `mdat` atoms, excluding their headers
+pub(crate) fn audio_payload_len<R>(data: &mut R, parse_mode: ParsingMode) -> Result<u64>
+where
+	R: Read + Seek,
+{
+	let regions = audio_payload_regions(data, parse_mode)?;
+	Ok(regions.iter().map(|(_, len)| len).sum())
+}
+
+/// Get the offsets and sizes of the contents of all `mdat` atoms
+pub(crate) fn audio_payload_regions<R>(
+	data: &mut R,
+	parse_mode: ParsingMode,
+) -> Result<Vec<(u64, u64)>>
+where
+	R: Read + Seek,
+{
+	let mut reader = AtomReader::new(data, parse_mode)?;
+	verify_mp4(&mut reader)?;
+
+	let mut regions = Vec::new();
+	while let Some(atom) = reader.next()? {
+		if atom.ident == AtomIdent::Fourcc(*b"mdat") {
+			let header_len = if atom.extended { 16 } else { 8 };
+			regions.push((atom.start + header_len, atom.len.saturating_sub(header_len)));
+		}
+
+		reader.seek(SeekFrom::Start(atom.start + atom.len))?;
+	}
+
+	Ok(regions)
+}
+
 pub(super) fn skip_unneeded<R>(reader: &mut R, extended: bool, len: u64) -> Result<()>
 where
 	R: Read + Seek,
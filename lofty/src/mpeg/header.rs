@@ -2,6 +2,7 @@ use super::constants::{BITRATES, PADDING_SIZES, SAMPLES, SAMPLE_RATES, SIDE_INFO
 use crate::config::ParsingMode;
 use crate::error::Result;
 use crate::macros::decode_err;
+use crate::properties::GaplessInfo;
 
 use std::io::{Read, Seek, SeekFrom};
 
@@ -329,6 +330,7 @@ impl Header {
 pub(super) struct VbrHeader {
 	pub frames: u32,
 	pub size: u32,
+	pub gapless: Option<GaplessInfo>,
 }
 
 impl VbrHeader {
@@ -357,7 +359,22 @@ impl VbrHeader {
 				let frames = reader.read_u32::<BigEndian>()?;
 				let size = reader.read_u32::<BigEndian>()?;
 
-				Ok(Some(Self { frames, size }))
+				// The LAME extension follows the TOC (0x0004) and quality indicator (0x0008)
+				let mut lame_offset = 0;
+				if flags[3] & 0x04 == 0x04 {
+					lame_offset += 100;
+				}
+				if flags[3] & 0x08 == 0x08 {
+					lame_offset += 4;
+				}
+
+				let gapless = reader.get(lame_offset..).and_then(read_lame_gapless_info);
+
+				Ok(Some(Self {
+					frames,
+					size,
+					gapless,
+				}))
 			},
 			b"VBRI" => {
 				if reader_len < 32 {
@@ -373,7 +390,11 @@ impl VbrHeader {
 				let size = reader.read_u32::<BigEndian>()?;
 				let frames = reader.read_u32::<BigEndian>()?;
 
-				Ok(Some(Self { frames, size }))
+				Ok(Some(Self {
+					frames,
+					size,
+					gapless: None,
+				}))
 			},
 			_ => Ok(None),
 		}
@@ -384,6 +405,21 @@ impl VbrHeader {
 	}
 }
 
+// The encoder delay and padding follow the 9 byte encoder version string (Ex. "LAME3.100"),
+// and 12 bytes of other encoder information
+fn read_lame_gapless_info(lame: &[u8]) -> Option<GaplessInfo> {
+	if !matches!(lame.get(..4)?, b"LAME" | b"Lavc" | b"Lavf") {
+		return None;
+	}
+
+	let delay_padding = lame.get(21..24)?;
+	Some(GaplessInfo::from_lame_bytes([
+		delay_padding[0],
+		delay_padding[1],
+		delay_padding[2],
+	]))
+}
+
 #[cfg(test)]
 mod tests {
 	use crate::config::ParsingMode;
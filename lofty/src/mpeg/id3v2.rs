@@ -0,0 +1,53 @@
+use crate::config::WriteOptions;
+use crate::error::{LoftyError, Result};
+use crate::id3::v2::write::fit_to_region;
+use crate::id3::{find_id3v2, FindId3v2Config, ID3FindResults};
+use crate::util::io::{FileLike, Length, Truncate};
+
+/// Replace the ID3v2 tag at the start of a file
+///
+/// This is used for MPEG and ADTS streams. An empty `tag` removes the existing tag.
+pub(crate) fn write_id3v2<F>(
+	file: &mut F,
+	mut tag: Vec<u8>,
+	has_footer: bool,
+	write_options: WriteOptions,
+) -> Result<()>
+where
+	F: FileLike,
+	LoftyError: From<<F as Truncate>::Error>,
+	LoftyError: From<<F as Length>::Error>,
+{
+	// find_id3v2 will seek us to the end of the tag
+	// TODO: Search through junk
+	let ID3FindResults(existing_header, _) = find_id3v2(file, FindId3v2Config::NO_READ_TAG)?;
+
+	// If the new tag fits within the existing one and its padding, we can overwrite it directly,
+	// rather than reading the entire file into memory.
+	if let Some(existing_header) = existing_header {
+		if !write_options.force_rewrite
+			&& !tag.is_empty()
+			&& !has_footer
+			&& !existing_header.flags.footer
+			&& fit_to_region(&mut tag, write_options, existing_header.size)?
+		{
+			log::trace!("Found enough padding to fit the tag, writing in place");
+
+			file.rewind()?;
+			file.write_all(&tag)?;
+
+			return Ok(());
+		}
+	}
+
+	let mut file_bytes = Vec::new();
+	file.read_to_end(&mut file_bytes)?;
+
+	file_bytes.splice(0..0, tag);
+
+	file.rewind()?;
+	file.truncate(0)?;
+	file.write_all(&file_bytes)?;
+
+	Ok(())
+}
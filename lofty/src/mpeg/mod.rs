@@ -1,6 +1,7 @@
 //! MP3 specific items
 mod constants;
 pub(crate) mod header;
+pub(crate) mod id3v2;
 mod properties;
 mod read;
 
@@ -8,14 +9,19 @@ pub use header::{ChannelMode, Emphasis, Layer, MpegVersion};
 pub use properties::MpegProperties;
 
 use crate::ape::tag::ApeTag;
+use crate::config::WriteOptions;
+use crate::error::{LoftyError, Result};
 use crate::id3::v1::tag::Id3v1Tag;
 use crate::id3::v2::tag::Id3v2Tag;
+use crate::tag::TagExt;
+use crate::util::io::{FileLike, Length, Truncate};
 
 use lofty_attr::LoftyFile;
 
 /// An MPEG file
 #[derive(LoftyFile, Default)]
 #[lofty(read_fn = "read::read_from")]
+#[lofty(write_fn = "Self::write_to")]
 #[lofty(internal_write_module_do_not_use_anywhere_else)]
 pub struct MpegFile {
 	/// An ID3v2 tag
@@ -30,3 +36,35 @@ pub struct MpegFile {
 	/// The file's audio properties
 	pub(crate) properties: MpegProperties,
 }
+
+impl MpegFile {
+	// We need a special write fn to keep the ID3v1 tag from overwriting a synced one
+	fn write_to<F>(&self, file: &mut F, write_options: WriteOptions) -> Result<()>
+	where
+		F: FileLike,
+		LoftyError: From<<F as Truncate>::Error>,
+		LoftyError: From<<F as Length>::Error>,
+	{
+		if let Some(ref id3v2) = self.id3v2_tag {
+			file.rewind()?;
+			id3v2.save_to(file, write_options)?;
+		}
+
+		if let Some(ref id3v1) = self.id3v1_tag {
+			// The ID3v1 tag was just created from the ID3v2 tag, this one is stale
+			if write_options.sync_id3v1 && self.id3v2_tag.is_some() {
+				log::debug!("Skipping ID3v1 tag, it was synced with the ID3v2 tag");
+			} else {
+				file.rewind()?;
+				id3v1.save_to(file, write_options)?;
+			}
+		}
+
+		if let Some(ref ape) = self.ape_tag {
+			file.rewind()?;
+			ape.save_to(file, write_options)?;
+		}
+
+		Ok(())
+	}
+}
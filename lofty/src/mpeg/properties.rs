@@ -1,12 +1,14 @@
 use super::header::{ChannelMode, Emphasis, Header, Layer, MpegVersion, VbrHeader};
-use crate::config::ParsingMode;
+use crate::config::{ParsingMode, PropertiesAccuracy};
 use crate::error::Result;
-use crate::mpeg::header::rev_search_for_frame_header;
-use crate::properties::{ChannelMask, FileProperties};
+use crate::mpeg::header::{rev_search_for_frame_header, verify_frame_sync};
+use crate::properties::{ChannelMask, FileProperties, GaplessInfo};
 use crate::util::math::RoundedDivision;
 
 use std::io::{Read, Seek, SeekFrom};
-use std::time::Duration;
+use std::time::{Duration, Instant};
+
+use byteorder::{BigEndian, ReadBytesExt};
 
 /// An MPEG file's audio properties
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
@@ -24,6 +26,7 @@ pub struct MpegProperties {
 	pub(crate) copyright: bool,
 	pub(crate) original: bool,
 	pub(crate) emphasis: Option<Emphasis>,
+	pub(crate) gapless: Option<GaplessInfo>,
 }
 
 impl From<MpegProperties> for FileProperties {
@@ -41,6 +44,7 @@ impl From<MpegProperties> for FileProperties {
 			emphasis: _,
 			mode_extension: _,
 			original: _,
+			gapless: _,
 		} = input;
 		let channel_mask = match channel_mode {
 			ChannelMode::SingleChannel => Some(ChannelMask::mono()),
@@ -119,6 +123,13 @@ impl MpegProperties {
 	pub fn emphasis(&self) -> Option<Emphasis> {
 		self.emphasis
 	}
+
+	/// The encoder delay and padding, if the file has a LAME header
+	///
+	/// See [`GaplessInfo`]
+	pub fn gapless_info(&self) -> Option<GaplessInfo> {
+		self.gapless
+	}
 }
 
 pub(super) fn read_properties<R>(
@@ -129,6 +140,7 @@ pub(super) fn read_properties<R>(
 	xing_header: Option<VbrHeader>,
 	file_length: u64,
 	parse_mode: ParsingMode,
+	accuracy: PropertiesAccuracy,
 ) -> Result<()>
 where
 	R: Read + Seek,
@@ -160,6 +172,35 @@ where
 			properties.overall_bitrate = ((file_length * 8) / length) as u32;
 			properties.audio_bitrate = ((u64::from(xing_header.size) * 8) / length) as u32;
 
+			properties.gapless = xing_header.gapless.map(|mut gapless| {
+				let samples = u64::from(xing_header.frames) * u64::from(first_frame_header.samples);
+				let trimmed = u64::from(gapless.encoder_delay + gapless.encoder_padding);
+				gapless.total_samples = Some(samples.saturating_sub(trimmed));
+				gapless
+			});
+
+			return Ok(());
+		}
+	}
+
+	if accuracy != PropertiesAccuracy::Estimate {
+		log::debug!("MPEG: Scanning frames to calculate duration");
+
+		let time_limit = match accuracy {
+			PropertiesAccuracy::TimeLimited(limit) => Some(limit),
+			_ => None,
+		};
+
+		let scanned = scan_frames(
+			properties,
+			reader,
+			(first_frame_header, first_frame_offset),
+			last_frame_offset,
+			file_length,
+			time_limit,
+		)?;
+
+		if scanned {
 			return Ok(());
 		}
 	}
@@ -209,3 +250,139 @@ where
 
 	Ok(())
 }
+
+// Walks the frames between `first_frame_offset` and `stream_end`, summing their samples and sizes.
+//
+// If `time_limit` is reached before the end of the stream, the remainder is extrapolated from the
+// frames that were scanned.
+fn scan_frames<R>(
+	properties: &mut MpegProperties,
+	reader: &mut R,
+	first_frame: (Header, u64),
+	stream_end: u64,
+	file_length: u64,
+	time_limit: Option<Duration>,
+) -> Result<bool>
+where
+	R: Read + Seek,
+{
+	let (first_frame_header, first_frame_offset) = first_frame;
+
+	let start = Instant::now();
+
+	let mut pos = first_frame_offset;
+	let mut total_samples = 0_u64;
+	let mut total_frame_len = 0_u64;
+	let mut complete = true;
+
+	while pos + 4 <= stream_end {
+		if let Some(time_limit) = time_limit {
+			if total_samples > 0 && start.elapsed() >= time_limit {
+				complete = false;
+				break;
+			}
+		}
+
+		reader.seek(SeekFrom::Start(pos))?;
+		let header_data = reader.read_u32::<BigEndian>()?;
+
+		let header = if verify_frame_sync([(header_data >> 24) as u8, (header_data >> 16) as u8]) {
+			Header::read(header_data)
+		} else {
+			None
+		};
+
+		match header {
+			Some(header) if header.cmp(&first_frame_header) && header.len > 0 => {
+				// Ignore a truncated final frame
+				if pos + u64::from(header.len) > stream_end {
+					break;
+				}
+
+				total_samples += u64::from(header.samples);
+				total_frame_len += u64::from(header.len);
+				pos += u64::from(header.len);
+			},
+			// Junk between frames, search byte by byte for the next one
+			_ => pos += 1,
+		}
+	}
+
+	if total_samples == 0 {
+		return Ok(false);
+	}
+
+	let mut length = (total_samples * 1000).div_round(u64::from(first_frame_header.sample_rate));
+	let mut stream_len = total_frame_len;
+
+	if !complete {
+		let scanned_len = pos - first_frame_offset;
+		let remaining_len = stream_end - first_frame_offset;
+
+		length = (length * remaining_len).div_round(scanned_len);
+		stream_len = (total_frame_len * remaining_len).div_round(scanned_len);
+	}
+
+	if length == 0 {
+		return Ok(false);
+	}
+
+	properties.duration = Duration::from_millis(length);
+	properties.overall_bitrate = ((file_length * 8) / length) as u32;
+	properties.audio_bitrate = ((stream_len * 8) / length) as u32;
+
+	Ok(true)
+}
+
+#[cfg(test)]
+mod tests {
+	use crate::config::{ParseOptions, PropertiesAccuracy};
+	use crate::file::AudioFile;
+	use crate::mpeg::MpegFile;
+
+	use std::io::Cursor;
+	use std::time::Duration;
+
+	// MPEG-1 Layer 3, 48KHz, stereo, 24ms per frame
+	fn vbr_stream() -> Vec<u8> {
+		let mut stream = Vec::new();
+
+		// 10 frames at 128kbps, followed by 10 frames at 320kbps
+		for (bitrate_index, frame_len) in [(0x9, 384), (0xE, 960)] {
+			for _ in 0..10 {
+				stream.extend([0xFF, 0xFB, (bitrate_index << 4) | 0b0100, 0x00]);
+				stream.resize(stream.len() + frame_len - 4, 0);
+			}
+		}
+
+		stream
+	}
+
+	fn read(accuracy: PropertiesAccuracy) -> (Duration, u32) {
+		let parse_options = ParseOptions::new().properties_accuracy(accuracy);
+		let file = MpegFile::read_from(&mut Cursor::new(vbr_stream()), parse_options).unwrap();
+
+		let properties = file.properties();
+		(properties.duration(), properties.audio_bitrate())
+	}
+
+	#[test]
+	fn scan_frames() {
+		// Only the bitrate of the first frame is used
+		assert_eq!(
+			read(PropertiesAccuracy::Estimate),
+			(Duration::from_millis(840), 128)
+		);
+
+		assert_eq!(
+			read(PropertiesAccuracy::Accurate),
+			(Duration::from_millis(480), 224)
+		);
+
+		// Only the first frame is scanned, and the rest of the stream is extrapolated from it
+		assert_eq!(
+			read(PropertiesAccuracy::TimeLimited(Duration::ZERO)),
+			(Duration::from_millis(840), 128)
+		);
+	}
+}
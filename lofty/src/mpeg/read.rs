@@ -14,6 +14,8 @@ use std::io::{Read, Seek, SeekFrom};
 
 use byteorder::{BigEndian, ReadBytesExt};
 
+const XING_HEADER_MAX_LEN: usize = 156;
+
 pub(super) fn read_from<R>(reader: &mut R, parse_options: ParseOptions) -> Result<MpegFile>
 where
 	R: Read + Seek,
@@ -43,7 +45,8 @@ where
 				let header = Id3v2Header::parse(reader)?;
 				let skip_footer = header.flags.footer;
 
-				let id3v2 = parse_id3v2(reader, header, parse_options.parsing_mode)?;
+				let frames_offset = reader.stream_position()?;
+				let id3v2 = parse_id3v2(reader, header, parse_options, Some(frames_offset))?;
 				if let Some(existing_tag) = &mut file.id3v2_tag {
 					// https://github.com/Serial-ATA/lofty-rs/issues/87
 					// Duplicate tags should have their frames appended to the previous
@@ -75,7 +78,9 @@ where
 					let ape_header = read_ape_header(reader, false)?;
 
 					file.ape_tag = Some(crate::ape::tag::read::read_ape_tag_with_header(
-						reader, ape_header,
+						reader,
+						ape_header,
+						parse_options,
 					)?);
 
 					continue;
@@ -114,7 +119,7 @@ where
 					{
 						let reader = &mut &*id3v2_bytes;
 
-						let id3v2 = parse_id3v2(reader, header, parse_options.parsing_mode)?;
+						let id3v2 = parse_id3v2(reader, header, parse_options, None)?;
 
 						if let Some(existing_tag) = &mut file.id3v2_tag {
 							// https://github.com/Serial-ATA/lofty-rs/issues/87
@@ -147,7 +152,7 @@ where
 
 	reader.seek(SeekFrom::Current(-32))?;
 
-	match crate::ape::tag::read::read_ape_tag(reader, true)? {
+	match crate::ape::tag::read::read_ape_tag(reader, true, parse_options)? {
 		Some((tag, header)) => {
 			file.ape_tag = Some(tag);
 
@@ -180,8 +185,12 @@ where
 		let xing_header_location = first_frame_offset + u64::from(first_frame_header.data_start);
 		reader.seek(SeekFrom::Start(xing_header_location))?;
 
-		let mut xing_reader = [0; 32];
-		reader.read_exact(&mut xing_reader)?;
+		// Large enough for a Xing header with all optional fields, followed by a LAME extension
+		let mut xing_reader = Vec::with_capacity(XING_HEADER_MAX_LEN);
+		reader
+			.by_ref()
+			.take(XING_HEADER_MAX_LEN as u64)
+			.read_to_end(&mut xing_reader)?;
 
 		let xing_header = VbrHeader::read(&mut &xing_reader[..])?;
 
@@ -195,6 +204,7 @@ where
 			xing_header,
 			file_length,
 			parse_options.parsing_mode,
+			parse_options.properties_accuracy,
 		)?;
 	}
 
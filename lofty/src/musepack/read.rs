@@ -29,7 +29,7 @@ where
 	{
 		let reader = &mut &*content;
 
-		let id3v2 = parse_id3v2(reader, header, parse_options.parsing_mode)?;
+		let id3v2 = parse_id3v2(reader, header, parse_options, None)?;
 		file.id3v2_tag = Some(id3v2);
 
 		stream_length -= u64::from(header.full_tag_size());
@@ -51,7 +51,7 @@ where
 
 	reader.seek(SeekFrom::Current(-32))?;
 
-	if let Some((tag, header)) = crate::ape::tag::read::read_ape_tag(reader, true)? {
+	if let Some((tag, header)) = crate::ape::tag::read::read_ape_tag(reader, true, parse_options)? {
 		file.ape_tag = Some(tag);
 
 		// Seek back to the start of the tag
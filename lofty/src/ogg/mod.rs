@@ -3,11 +3,14 @@
 //! ## File notes
 //!
 //! The only supported tag format is [`VorbisComments`]
+//!
+//! Only the first logical stream of a file is read. For chained files, see [`OggStreams`].
 pub(crate) mod constants;
 pub(crate) mod opus;
 mod picture_storage;
 pub(crate) mod read;
 pub(crate) mod speex;
+mod stream;
 pub(crate) mod tag;
 pub(crate) mod vorbis;
 pub(crate) mod write;
@@ -26,10 +29,13 @@ pub use opus::OpusFile;
 pub use picture_storage::OggPictureStorage;
 pub use speex::properties::SpeexProperties;
 pub use speex::SpeexFile;
+pub use stream::{OggStream, OggStreams};
 pub use tag::VorbisComments;
 pub use vorbis::properties::VorbisProperties;
 pub use vorbis::VorbisFile;
 
+pub(crate) use stream::audio_payload_regions;
+
 fn verify_signature(content: &[u8], sig: &[u8]) -> Result<()> {
 	let sig_len = sig.len();
 
@@ -30,7 +30,7 @@ impl OpusFile {
 		R: Read + Seek,
 	{
 		let file_information =
-			super::read::read_from(reader, OPUSHEAD, OPUSTAGS, 2, parse_options.parsing_mode)?;
+			super::read::read_from(reader, OPUSHEAD, OPUSTAGS, 2, parse_options)?;
 
 		Ok(Self {
 			properties: if parse_options.read_properties {
@@ -1,6 +1,6 @@
 use super::tag::VorbisComments;
 use super::verify_signature;
-use crate::config::ParsingMode;
+use crate::config::{ParseOptions, ParsingMode};
 use crate::error::{ErrorKind, LoftyError, Result};
 use crate::macros::{decode_err, err, parse_mode_choice};
 use crate::picture::{MimeType, Picture, PictureInformation, PictureType};
@@ -18,13 +18,17 @@ pub type OGGTags = (Option<VorbisComments>, PageHeader, Packets);
 pub(crate) fn read_comments<R>(
 	data: &mut R,
 	mut len: u64,
-	parse_mode: ParsingMode,
+	parse_options: ParseOptions,
 ) -> Result<VorbisComments>
 where
 	R: Read,
 {
 	use crate::macros::try_vec;
 
+	let parse_mode = parse_options.parsing_mode;
+
+	parse_options.check_tag_size(len)?;
+
 	let vendor_len = data.read_u32::<LittleEndian>()?;
 	if u64::from(vendor_len) > len {
 		err!(SizeMismatch);
@@ -74,10 +78,25 @@ where
 
 	let number_of_items = data.read_u32::<LittleEndian>()?;
 
+	// Every item is prefixed with its length, so a (possibly malicious) item count can't be
+	// trusted to size the allocation on its own.
+	let max_items = len / 4;
+	if u64::from(number_of_items) > max_items {
+		log::warn!(
+			"Vorbis comments claim {number_of_items} items, but only {max_items} can fit in the \
+			 remaining {len} bytes"
+		);
+
+		if parse_mode == ParsingMode::Strict {
+			err!(SizeMismatch);
+		}
+	}
+
 	let mut tag = VorbisComments {
 		vendor,
-		items: Vec::with_capacity(number_of_items as usize),
+		items: Vec::with_capacity(std::cmp::min(u64::from(number_of_items), max_items) as usize),
 		pictures: Vec::new(),
+		..VorbisComments::default()
 	};
 
 	for _ in 0..number_of_items {
@@ -140,6 +159,7 @@ where
 							mime_type: Some(mime_type),
 							description: None,
 							data: Cow::from(picture_data),
+							location: None,
 						};
 
 						tag.pictures.push((picture, PictureInformation::default()))
@@ -189,7 +209,7 @@ pub(crate) fn read_from<T>(
 	header_sig: &[u8],
 	comment_sig: &[u8],
 	packets_to_read: isize,
-	parse_mode: ParsingMode,
+	parse_options: ParseOptions,
 ) -> Result<OGGTags>
 where
 	T: Read + Seek,
@@ -219,7 +239,7 @@ where
 	metadata_packet = &metadata_packet[comment_sig.len()..];
 
 	let reader = &mut metadata_packet;
-	let tag = read_comments(reader, reader.len() as u64, parse_mode)?;
+	let tag = read_comments(reader, reader.len() as u64, parse_options)?;
 
 	Ok((Some(tag), first_page_header, packets))
 }
@@ -28,8 +28,7 @@ impl SpeexFile {
 	where
 		R: Read + Seek,
 	{
-		let file_information =
-			super::read::read_from(reader, SPEEXHEADER, &[], 2, parse_options.parsing_mode)?;
+		let file_information = super::read::read_from(reader, SPEEXHEADER, &[], 2, parse_options)?;
 
 		Ok(Self {
 			properties: if parse_options.read_properties {
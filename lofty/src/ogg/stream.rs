@@ -0,0 +1,327 @@
+use super::constants::{OPUSHEAD, SPEEXHEADER, VORBIS_IDENT_HEAD};
+use super::read::read_comments;
+use super::tag::{VorbisComments, VorbisCommentsRef};
+use super::verify_signature;
+use super::write::{replace_comment_packet, OGGFormat};
+use crate::config::{ParseOptions, WriteOptions};
+use crate::error::{LoftyError, Result};
+use crate::file::{write_verified, FileType};
+use crate::macros::decode_err;
+use crate::util::io::{FileLike, Length, Truncate};
+
+use std::io::{Cursor, Read, Seek, SeekFrom, Write};
+
+use ogg_pager::{Packets, Page, PageHeader, CONTAINS_FIRST_PAGE_OF_BITSTREAM};
+
+/// A logical stream in an OGG file, see [`OggStreams`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OggStream {
+	pub(crate) serial: u32,
+	pub(crate) file_type: FileType,
+	pub(crate) vorbis_comments: VorbisComments,
+}
+
+impl OggStream {
+	/// The serial number of the stream
+	pub fn serial(&self) -> u32 {
+		self.serial
+	}
+
+	/// The codec of the stream
+	///
+	/// This will be one of [`FileType::Opus`], [`FileType::Speex`], or [`FileType::Vorbis`].
+	pub fn file_type(&self) -> FileType {
+		self.file_type
+	}
+
+	/// The stream's [`VorbisComments`]
+	pub fn vorbis_comments(&self) -> &VorbisComments {
+		&self.vorbis_comments
+	}
+
+	/// A mutable reference to the stream's [`VorbisComments`]
+	pub fn vorbis_comments_mut(&mut self) -> &mut VorbisComments {
+		&mut self.vorbis_comments
+	}
+}
+
+/// The logical streams of an OGG file
+///
+/// Most OGG files only contain a single logical stream, which is what [`OpusFile`], [`SpeexFile`],
+/// and [`VorbisFile`] read. Chained files, such as internet radio dumps, contain multiple streams
+/// one after another, each with its own comment header. `OggStreams` allows for reading and
+/// editing the comments of each one.
+///
+/// NOTE: Only chained streams are supported. Multiplexed streams, where the pages of multiple streams
+///       are interleaved (such as a video with an audio track), are not. Streams with unsupported
+///       codecs are skipped.
+///
+/// [`OpusFile`]: crate::ogg::OpusFile
+/// [`SpeexFile`]: crate::ogg::SpeexFile
+/// [`VorbisFile`]: crate::ogg::VorbisFile
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use lofty::config::{ParseOptions, WriteOptions};
+/// use lofty::ogg::OggStreams;
+/// use lofty::tag::Accessor;
+/// use std::fs::OpenOptions;
+///
+/// # fn main() -> lofty::error::Result<()> {
+/// let mut file = OpenOptions::new().read(true).write(true).open("radio.ogg")?;
+/// let mut streams = OggStreams::read_from(&mut file, ParseOptions::new())?;
+///
+/// for stream in streams.streams() {
+/// 	println!("{}: {:?}", stream.serial(), stream.vorbis_comments().title());
+/// }
+///
+/// // Change the title of the second song
+/// if let Some(stream) = streams.streams_mut().get_mut(1) {
+/// 	stream
+/// 		.vorbis_comments_mut()
+/// 		.set_title(String::from("Foo title"));
+/// }
+///
+/// streams.save_to(&mut file, WriteOptions::default())?;
+/// # Ok(()) }
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct OggStreams {
+	pub(crate) streams: Vec<OggStream>,
+}
+
+impl OggStreams {
+	/// Read the comments of every logical stream in a file
+	///
+	/// # Errors
+	///
+	/// * The file contains multiplexed streams
+	/// * A stream's header packets are missing or invalid
+	pub fn read_from<R>(reader: &mut R, parse_options: ParseOptions) -> Result<Self>
+	where
+		R: Read + Seek,
+	{
+		reader.rewind()?;
+
+		let mut streams = Vec::new();
+		while let Ok(page) = Page::read(reader) {
+			let header = page.header();
+			if header.header_type_flag() & CONTAINS_FIRST_PAGE_OF_BITSTREAM == 0 {
+				continue;
+			}
+
+			let serial = header.stream_serial;
+			let Some(file_type) = stream_file_type(page.content()) else {
+				log::debug!("OGG: Skipping stream {serial} with an unsupported codec");
+				continue;
+			};
+
+			let (format, header_packet_count) = OGGFormat::from_filetype(file_type);
+
+			reader.seek(SeekFrom::Start(header.start))?;
+			let (packets, _) = read_header_packets(reader, serial, header_packet_count)?;
+
+			let comment_packet = packets
+				.get(1)
+				.ok_or_else(|| decode_err!("OGG: Expected comment packet"))?;
+
+			let comment_signature = format.comment_signature().unwrap_or_default();
+			verify_signature(comment_packet, comment_signature)?;
+
+			let comments = &mut &comment_packet[comment_signature.len()..];
+			let vorbis_comments = read_comments(comments, comments.len() as u64, parse_options)?;
+
+			streams.push(OggStream {
+				serial,
+				file_type,
+				vorbis_comments,
+			});
+		}
+
+		Ok(Self { streams })
+	}
+
+	/// The logical streams, in the order they appear in the file
+	pub fn streams(&self) -> &[OggStream] {
+		&self.streams
+	}
+
+	/// A mutable reference to the logical streams
+	pub fn streams_mut(&mut self) -> &mut [OggStream] {
+		&mut self.streams
+	}
+
+	/// Write the comments of every stream back to the file
+	///
+	/// The file must have the same streams it was read from.
+	///
+	/// # Errors
+	///
+	/// * The streams don't match the file
+	/// * See [`VorbisComments::save_to`](crate::tag::TagExt::save_to)
+	pub fn save_to<F>(&self, file: &mut F, write_options: WriteOptions) -> Result<()>
+	where
+		F: FileLike,
+		LoftyError: From<<F as Truncate>::Error>,
+		LoftyError: From<<F as Length>::Error>,
+	{
+		write_verified(file, write_options, |file, write_options| {
+			file.rewind()?;
+
+			let mut file_content = Vec::new();
+			file.read_to_end(&mut file_content)?;
+
+			let mut reader = Cursor::new(&file_content[..]);
+			let mut new_content = Vec::with_capacity(file_content.len());
+
+			let mut streams = self.streams.iter();
+
+			// The remaining pages of a rewritten stream need their sequence numbers shifted
+			// by the difference in header page count
+			let mut current_serial = None;
+			let mut sequence_offset = 0_i64;
+
+			while let Ok(mut page) = Page::read(&mut reader) {
+				let header = page.header();
+				let serial = header.stream_serial;
+
+				if header.header_type_flag() & CONTAINS_FIRST_PAGE_OF_BITSTREAM != 0 {
+					current_serial = Some(serial);
+					sequence_offset = 0;
+
+					if let Some(file_type) = stream_file_type(page.content()) {
+						let Some(stream) = streams.next().filter(|stream| stream.serial == serial)
+						else {
+							decode_err!(@BAIL "OGG: Streams do not match the file");
+						};
+
+						let (format, header_packet_count) = OGGFormat::from_filetype(file_type);
+
+						reader.seek(SeekFrom::Start(header.start))?;
+						let (mut packets, old_page_count) =
+							read_header_packets(&mut reader, serial, header_packet_count)?;
+
+						let vorbis_comments = &stream.vorbis_comments;
+						let mut comments_ref = VorbisCommentsRef {
+							vendor: vorbis_comments.vendor.as_str(),
+							items: vorbis_comments
+								.items
+								.iter()
+								.map(|(k, v)| (k.as_str(), v.as_str())),
+							pictures: vorbis_comments.pictures.iter().map(|(p, i)| (p, *i)),
+						};
+
+						replace_comment_packet(
+							&mut packets,
+							&mut comments_ref,
+							format,
+							write_options,
+						)?;
+
+						let pages_written = packets.write_to(
+							&mut new_content,
+							serial,
+							0,
+							CONTAINS_FIRST_PAGE_OF_BITSTREAM,
+						)?;
+
+						sequence_offset = pages_written as i64 - i64::from(old_page_count);
+						continue;
+					}
+				}
+
+				if current_serial == Some(serial) && sequence_offset != 0 {
+					let header = page.header_mut();
+					header.sequence_number =
+						(i64::from(header.sequence_number) + sequence_offset) as u32;
+					page.gen_crc();
+				}
+
+				new_content.write_all(&page.as_bytes())?;
+			}
+
+			if streams.next().is_some() {
+				decode_err!(@BAIL "OGG: Streams do not match the file");
+			}
+
+			file.rewind()?;
+			file.truncate(0)?;
+			file.write_all(&new_content)?;
+
+			Ok(())
+		})
+	}
+}
+
+/// Get the offsets and sizes of the contents of every page, other than those holding header packets
+///
+/// The header pages are skipped for every logical stream that can be written to.
+pub(crate) fn audio_payload_regions<R>(reader: &mut R) -> Result<Vec<(u64, u64)>>
+where
+	R: Read + Seek,
+{
+	reader.rewind()?;
+
+	let mut regions = Vec::new();
+	while let Ok(header) = PageHeader::read(reader) {
+		let content_start = reader.stream_position()?;
+		let content_size = header.content_size() as u64;
+
+		if header.header_type_flag() & CONTAINS_FIRST_PAGE_OF_BITSTREAM != 0 {
+			let mut identification_packet = vec![0; content_size as usize];
+			reader.read_exact(&mut identification_packet)?;
+
+			if let Some(file_type) = stream_file_type(&identification_packet) {
+				let (_, header_packet_count) = OGGFormat::from_filetype(file_type);
+
+				reader.seek(SeekFrom::Start(header.start))?;
+				read_header_packets(reader, header.stream_serial, header_packet_count)?;
+				continue;
+			}
+		}
+
+		regions.push((content_start, content_size));
+		reader.seek(SeekFrom::Start(content_start + content_size))?;
+	}
+
+	Ok(regions)
+}
+
+fn stream_file_type(identification_packet: &[u8]) -> Option<FileType> {
+	if identification_packet.starts_with(VORBIS_IDENT_HEAD) {
+		Some(FileType::Vorbis)
+	} else if identification_packet.starts_with(OPUSHEAD) {
+		Some(FileType::Opus)
+	} else if identification_packet.starts_with(SPEEXHEADER) {
+		Some(FileType::Speex)
+	} else {
+		None
+	}
+}
+
+// Reads the header packets of the stream starting at the current position, returning them along with
+// the number of pages they span. The reader will be left at the start of the next page.
+fn read_header_packets<R>(reader: &mut R, serial: u32, count: isize) -> Result<(Packets, u32)>
+where
+	R: Read + Seek,
+{
+	let start = reader.stream_position()?;
+	let packets = Packets::read_count(reader, count)?;
+	let end = reader.stream_position()?;
+
+	reader.seek(SeekFrom::Start(start))?;
+
+	let mut page_count = 0;
+	while reader.stream_position()? < end {
+		let header = PageHeader::read(reader)?;
+		if header.stream_serial != serial {
+			decode_err!(@BAIL "OGG: Multiplexed streams are not supported");
+		}
+
+		reader.seek(SeekFrom::Current(header.content_size() as i64))?;
+		page_count += 1;
+	}
+
+	Ok((packets, page_count))
+}
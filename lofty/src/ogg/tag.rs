@@ -1,19 +1,21 @@
-use crate::config::WriteOptions;
+use crate::config::{ParseOptions, ParsingMode, WriteOptions};
 use crate::error::{LoftyError, Result};
-use crate::file::FileType;
+use crate::file::{write_verified, FileType};
 use crate::macros::err;
 use crate::ogg::picture_storage::OggPictureStorage;
 use crate::ogg::write::OGGFormat;
 use crate::picture::{Picture, PictureInformation};
 use crate::probe::Probe;
+use crate::tag::items::popm_item_to_text;
 use crate::tag::{
-	try_parse_year, Accessor, ItemKey, ItemValue, MergeTag, SplitTag, Tag, TagExt, TagItem, TagType,
+	try_parse_year, Accessor, ItemKey, ItemValue, KeyAliases, MergeTag, SplitTag, Tag, TagExt,
+	TagItem, TagType,
 };
 use crate::util::flag_item;
-use crate::util::io::{FileLike, Length, Truncate};
+use crate::util::io::{FileLike, Length, SeekStreamLen, Truncate};
 
 use std::borrow::Cow;
-use std::io::Write;
+use std::io::{Read, Seek, Write};
 use std::ops::Deref;
 
 use lofty_attr::tag;
@@ -58,7 +60,12 @@ macro_rules! impl_accessor {
 /// When converting [Picture]s, they will first be passed through [`PictureInformation::from_picture`].
 /// If the information is available, it will be used. Otherwise, the picture will be stored with zeroed out
 /// [`PictureInformation`].
-#[derive(Default, PartialEq, Eq, Debug, Clone)]
+///
+/// ## Key aliases
+///
+/// Some items have multiple common keys, such as `TRACKTOTAL` and `TOTALTRACKS`. These are handled
+/// by a [`KeyAliases`] table, see [`VorbisComments::key_aliases`].
+#[derive(PartialEq, Eq, Debug, Clone)]
 #[tag(
 	description = "Vorbis comments",
 	supported_formats(Flac, Opus, Speex, Vorbis)
@@ -70,8 +77,29 @@ pub struct VorbisComments {
 	pub(crate) items: Vec<(String, String)>,
 	/// A collection of all pictures
 	pub(crate) pictures: Vec<(Picture, PictureInformation)>,
+	pub(crate) key_aliases: KeyAliases,
+}
+
+impl Default for VorbisComments {
+	fn default() -> Self {
+		Self {
+			vendor: String::new(),
+			items: Vec::new(),
+			pictures: Vec::new(),
+			key_aliases: KeyAliases::from_static(&DEFAULT_KEY_ALIASES),
+		}
+	}
 }
 
+// (alias, canonical)
+const DEFAULT_KEY_ALIASES: [(&str, &str); 5] = [
+	("TOTALTRACKS", "TRACKTOTAL"),
+	("TOTALDISCS", "DISCTOTAL"),
+	("ENCODED-BY", "ENCODEDBY"),
+	("ENCODED_BY", "ENCODEDBY"),
+	("ENCODERSETTINGS", "ENCODING"),
+];
+
 impl VorbisComments {
 	/// Create a new empty `VorbisComments`
 	///
@@ -88,6 +116,42 @@ impl VorbisComments {
 		Self::default()
 	}
 
+	/// Read a tag previously written with [`TagExt::dump_to`]
+	///
+	/// The entire remainder of `reader` is expected to be the tag.
+	///
+	/// # Errors
+	///
+	/// * The vendor string or comment sizes exceed the remaining length of `reader`
+	/// * The tag is otherwise malformed, see [`ParsingMode`]
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use lofty::config::{ParsingMode, WriteOptions};
+	/// use lofty::ogg::VorbisComments;
+	/// use lofty::tag::{Accessor, TagExt};
+	/// use std::io::Cursor;
+	///
+	/// # fn main() -> lofty::error::Result<()> {
+	/// let mut tag = VorbisComments::new();
+	/// tag.set_title(String::from("Foo title"));
+	///
+	/// let mut dump = Vec::new();
+	/// tag.dump_to(&mut dump, WriteOptions::default())?;
+	///
+	/// let re_read = VorbisComments::read_from_dump(&mut Cursor::new(dump), ParsingMode::default())?;
+	/// assert_eq!(re_read.title().as_deref(), Some("Foo title"));
+	/// # Ok(()) }
+	/// ```
+	pub fn read_from_dump<R>(reader: &mut R, parse_mode: ParsingMode) -> Result<Self>
+	where
+		R: Read + Seek,
+	{
+		let len = reader.stream_len_hack()? - reader.stream_position()?;
+		super::read::read_comments(reader, len, ParseOptions::new().parsing_mode(parse_mode))
+	}
+
 	/// Returns the vendor string
 	///
 	/// ```rust
@@ -163,10 +227,42 @@ impl VorbisComments {
 		items.into_iter()
 	}
 
+	/// The [`KeyAliases`] used to look up items
+	///
+	/// By default, this treats the following keys as the same item:
+	///
+	/// * `TRACKTOTAL` and `TOTALTRACKS`
+	/// * `DISCTOTAL` and `TOTALDISCS`
+	/// * `ENCODEDBY`, `ENCODED-BY`, and `ENCODED_BY`
+	/// * `ENCODING` and `ENCODERSETTINGS`
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use lofty::ogg::VorbisComments;
+	///
+	/// let mut vorbis_comments = VorbisComments::default();
+	/// vorbis_comments.push(String::from("TOTALTRACKS"), String::from("10"));
+	///
+	/// assert_eq!(vorbis_comments.get("TRACKTOTAL"), Some("10"));
+	/// ```
+	pub fn key_aliases(&self) -> &KeyAliases {
+		&self.key_aliases
+	}
+
+	/// Replace the [`KeyAliases`] used to look up items
+	///
+	/// NOTE: Existing items are not renamed.
+	pub fn set_key_aliases(&mut self, key_aliases: KeyAliases) {
+		self.key_aliases = key_aliases;
+	}
+
 	/// Gets the first item with `key`
 	///
 	/// NOTE: There can be multiple items with the same key, this grabs whichever happens to be the first
 	///
+	/// Items stored with an alias of `key` are included, see [`VorbisComments::key_aliases`].
+	///
 	/// # Examples
 	///
 	/// ```rust
@@ -189,12 +285,14 @@ impl VorbisComments {
 
 		self.items
 			.iter()
-			.find(|(k, _)| k.eq_ignore_ascii_case(key))
+			.find(|(k, _)| self.key_aliases.matches(k, key))
 			.map(|(_, v)| v.as_str())
 	}
 
 	/// Gets all items with the key
 	///
+	/// Items stored with an alias of `key` are included, see [`VorbisComments::key_aliases`].
+	///
 	/// # Examples
 	///
 	/// ```rust
@@ -213,12 +311,13 @@ impl VorbisComments {
 	pub fn get_all<'a>(&'a self, key: &'a str) -> impl Iterator<Item = &'a str> + Clone + '_ {
 		self.items
 			.iter()
-			.filter_map(move |(k, v)| (k.eq_ignore_ascii_case(key)).then_some(v.as_str()))
+			.filter_map(move |(k, v)| (self.key_aliases.matches(k, key)).then_some(v.as_str()))
 	}
 
 	/// Inserts an item
 	///
-	/// This is the same as [`VorbisComments::push`], except it will remove any items with the same key.
+	/// This is the same as [`VorbisComments::push`], except it will remove any items with the same key,
+	/// including any of its aliases (see [`VorbisComments::key_aliases`]).
 	///
 	/// NOTE: This will do nothing if the key is invalid. This specification is available [here](https://xiph.org/vorbis/doc/v-comment.html#vectorformat).
 	///
@@ -237,16 +336,20 @@ impl VorbisComments {
 	/// assert_eq!(titles.next(), None);
 	/// ```
 	pub fn insert(&mut self, key: String, value: String) {
+		let key = self.canonical_key(key);
 		if !verify_key(&key) {
 			return;
 		}
 
-		self.items.retain(|(k, _)| !k.eq_ignore_ascii_case(&key));
+		let key_aliases = &self.key_aliases;
+		self.items.retain(|(k, _)| !key_aliases.matches(k, &key));
 		self.items.push((key, value))
 	}
 
 	/// Appends an item
 	///
+	/// If `key` is an alias, the item will be stored with the canonical key instead.
+	///
 	/// NOTE: This will do nothing if the key is invalid. This specification is available [here](https://xiph.org/vorbis/doc/v-comment.html#vectorformat).
 	///
 	/// # Examples
@@ -264,6 +367,7 @@ impl VorbisComments {
 	/// assert_eq!(titles.next(), Some("Title 2"));
 	/// ```
 	pub fn push(&mut self, key: String, value: String) {
+		let key = self.canonical_key(key);
 		if !verify_key(&key) {
 			return;
 		}
@@ -273,6 +377,8 @@ impl VorbisComments {
 
 	/// Removes all items with a key, returning an iterator
 	///
+	/// This includes items stored with any alias of the key.
+	///
 	/// # Examples
 	///
 	/// ```rust
@@ -292,7 +398,7 @@ impl VorbisComments {
 		let mut split_idx = 0_usize;
 
 		for read_idx in 0..self.items.len() {
-			if self.items[read_idx].0.eq_ignore_ascii_case(key) {
+			if self.key_aliases.matches(&self.items[read_idx].0, key) {
 				self.items.swap(split_idx, read_idx);
 				split_idx += 1;
 			}
@@ -300,6 +406,13 @@ impl VorbisComments {
 
 		self.items.drain(..split_idx).map(|(_, v)| v)
 	}
+
+	fn canonical_key(&self, key: String) -> String {
+		match self.key_aliases.canonical(&key) {
+			canonical if canonical == key => key,
+			canonical => canonical.to_owned(),
+		}
+	}
 }
 
 // A case-insensitive field name that may consist of ASCII 0x20 through 0x7D, 0x3D ('=') excluded.
@@ -321,11 +434,22 @@ impl OggPictureStorage for VorbisComments {
 
 impl Accessor for VorbisComments {
 	impl_accessor!(
-		artist  => "ARTIST";
-		title   => "TITLE";
-		album   => "ALBUM";
-		genre   => "GENRE";
-		comment => "COMMENT";
+		artist            => "ARTIST";
+		title             => "TITLE";
+		album             => "ALBUM";
+		genre             => "GENRE";
+		comment           => "COMMENT";
+		lyrics            => "LYRICS";
+		title_sort        => "TITLESORT";
+		artist_sort       => "ARTISTSORT";
+		album_title_sort  => "ALBUMSORT";
+		album_artist_sort => "ALBUMARTISTSORT";
+		encoder           => "ENCODER";
+		encoder_settings  => "ENCODING";
+		isrc              => "ISRC";
+		barcode           => "BARCODE";
+		catalog_number    => "CATALOGNUMBER";
+		label             => "LABEL";
 	);
 
 	fn track(&self) -> Option<u32> {
@@ -571,7 +695,8 @@ impl MergeTag for SplitTagRemainder {
 			merged.vendor = val;
 		}
 
-		for item in tag.items {
+		for mut item in tag.items {
+			popm_item_to_text(&mut item);
 			let item_key = item.item_key;
 			let item_value = item.item_value;
 
@@ -651,33 +776,40 @@ where
 		LoftyError: From<<F as Truncate>::Error>,
 		LoftyError: From<<F as Length>::Error>,
 	{
-		let probe = Probe::new(file).guess_file_type()?;
-		let f_ty = probe.file_type();
+		write_verified(file, write_options, |file, write_options| {
+			let probe = Probe::new(file).guess_file_type()?;
+			let f_ty = probe.file_type();
 
-		let file = probe.into_inner();
+			let file = probe.into_inner();
 
-		let file_type = match f_ty {
-			Some(ft) if VorbisComments::SUPPORTED_FORMATS.contains(&ft) => ft,
-			_ => err!(UnsupportedTag),
-		};
+			let file_type = match f_ty {
+				Some(ft) if VorbisComments::SUPPORTED_FORMATS.contains(&ft) => ft,
+				_ => err!(UnsupportedTag),
+			};
 
-		// FLAC has its own special writing needs :)
-		if file_type == FileType::Flac {
-			return crate::flac::write::write_to_inner(file, self, write_options);
-		}
+			// FLAC has its own special writing needs :)
+			if file_type == FileType::Flac {
+				return crate::flac::write::write_to_inner(file, self, write_options);
+			}
 
-		let (format, header_packet_count) = OGGFormat::from_filetype(file_type);
+			let (format, header_packet_count) = OGGFormat::from_filetype(file_type);
 
-		super::write::write(file, self, format, header_packet_count, write_options)
+			super::write::write(file, self, format, header_packet_count, write_options)
+		})
 	}
 
 	pub(crate) fn dump_to<W: Write>(
 		&mut self,
 		writer: &mut W,
-		_write_options: WriteOptions,
+		write_options: WriteOptions,
 	) -> Result<()> {
-		let metadata_packet =
-			super::write::create_metadata_packet(self, &[], self.vendor.as_bytes(), false)?;
+		let metadata_packet = super::write::create_metadata_packet(
+			self,
+			&[],
+			self.vendor.as_bytes(),
+			false,
+			write_options,
+		)?;
 		writer.write_all(&metadata_packet)?;
 		Ok(())
 	}
@@ -709,7 +841,7 @@ pub(crate) fn create_vorbis_comments_ref(
 
 #[cfg(test)]
 mod tests {
-	use crate::config::{ParsingMode, WriteOptions};
+	use crate::config::{ParseOptions, ParsingMode, WriteOptions};
 	use crate::ogg::{OggPictureStorage, VorbisComments};
 	use crate::prelude::*;
 	use crate::tag::{ItemValue, Tag, TagItem, TagType};
@@ -717,7 +849,12 @@ mod tests {
 	fn read_tag(tag: &[u8]) -> VorbisComments {
 		let mut reader = std::io::Cursor::new(tag);
 
-		crate::ogg::read::read_comments(&mut reader, tag.len() as u64, ParsingMode::Strict).unwrap()
+		crate::ogg::read::read_comments(
+			&mut reader,
+			tag.len() as u64,
+			ParseOptions::new().parsing_mode(ParsingMode::Strict),
+		)
+		.unwrap()
 	}
 
 	#[test]
@@ -894,4 +1031,83 @@ mod tests {
 			assert_eq!(Some("Cmin"), vorbis_comments.get("INITIALKEY"));
 		}
 	}
+
+	#[test]
+	fn oversized_item_count() {
+		// Empty vendor, u32::MAX items, and a single "A=B" item
+		let mut tag_bytes = vec![0, 0, 0, 0, 0xFF, 0xFF, 0xFF, 0xFF];
+		tag_bytes.extend(3_u32.to_le_bytes());
+		tag_bytes.extend(b"A=B");
+
+		let mut reader = std::io::Cursor::new(&tag_bytes);
+		assert!(crate::ogg::read::read_comments(
+			&mut reader,
+			tag_bytes.len() as u64,
+			ParseOptions::new().parsing_mode(ParsingMode::Strict)
+		)
+		.is_err());
+
+		// The item count shouldn't be trusted for the allocation, the read will fail
+		// once the data runs out
+		let mut reader = std::io::Cursor::new(&tag_bytes);
+		assert!(crate::ogg::read::read_comments(
+			&mut reader,
+			tag_bytes.len() as u64,
+			ParseOptions::new().parsing_mode(ParsingMode::BestAttempt)
+		)
+		.is_err());
+	}
+
+	#[test]
+	fn short_coverart() {
+		// A `COVERART` field with only 2 bytes of (base64 encoded) data
+		let item = b"COVERART=/9g=";
+
+		let mut tag_bytes = vec![0, 0, 0, 0, 1, 0, 0, 0];
+		tag_bytes.extend((item.len() as u32).to_le_bytes());
+		tag_bytes.extend(item);
+
+		let mut reader = std::io::Cursor::new(&tag_bytes);
+		let tag = crate::ogg::read::read_comments(
+			&mut reader,
+			tag_bytes.len() as u64,
+			ParseOptions::new().parsing_mode(ParsingMode::BestAttempt),
+		)
+		.unwrap();
+
+		assert_eq!(tag.pictures().len(), 1);
+		assert_eq!(tag.pictures()[0].0.data(), &[0xFF, 0xD8]);
+	}
+
+	#[test]
+	fn key_aliases() {
+		let mut vorbis_comments = VorbisComments::default();
+		vorbis_comments
+			.items
+			.push((String::from("totaltracks"), String::from("10")));
+
+		assert_eq!(vorbis_comments.track_total(), Some(10));
+		assert_eq!(vorbis_comments.get("TOTALTRACKS"), Some("10"));
+
+		// Replaces the aliased item, rather than adding another
+		vorbis_comments.insert(String::from("TRACKTOTAL"), String::from("12"));
+		assert_eq!(
+			vorbis_comments.items().collect::<Vec<_>>(),
+			vec![("TRACKTOTAL", "12")]
+		);
+
+		// Aliases are stored with the canonical key
+		vorbis_comments.push(String::from("ENCODED-BY"), String::from("Foo"));
+		vorbis_comments.push(String::from("ENCODED_BY"), String::from("Bar"));
+		assert_eq!(
+			vorbis_comments.get_all("EncodedBy").collect::<Vec<_>>(),
+			vec!["Foo", "Bar"]
+		);
+		assert!(vorbis_comments
+			.items()
+			.all(|(key, _)| key == "TRACKTOTAL" || key == "ENCODEDBY"));
+
+		assert_eq!(vorbis_comments.remove("encoded-by").count(), 2);
+		assert_eq!(vorbis_comments.items().len(), 1);
+	}
 }
@@ -14,6 +14,8 @@ use crate::util::io::{FileLike, Length, Truncate};
 use byteorder::{LittleEndian, ReadBytesExt, WriteBytesExt};
 use ogg_pager::{Packets, Page, PageHeader, CONTAINS_FIRST_PAGE_OF_BITSTREAM};
 
+const ENCODER_KEY: &str = "ENCODER";
+
 #[derive(PartialEq, Copy, Clone)]
 pub(crate) enum OGGFormat {
 	Opus,
@@ -79,7 +81,7 @@ pub(super) fn write<'a, F, II, IP>(
 	tag: &mut VorbisCommentsRef<'a, II, IP>,
 	format: OGGFormat,
 	header_packet_count: isize,
-	_write_options: WriteOptions,
+	write_options: WriteOptions,
 ) -> Result<()>
 where
 	F: FileLike,
@@ -102,6 +104,44 @@ where
 	let mut remaining_file_content = Vec::new();
 	file.read_to_end(&mut remaining_file_content)?;
 
+	replace_comment_packet(&mut packets, tag, format, write_options)?;
+
+	file.rewind()?;
+	file.truncate(0)?;
+
+	let pages_written =
+		packets.write_to(file, stream_serial, 0, CONTAINS_FIRST_PAGE_OF_BITSTREAM)? as u32;
+
+	// Correct all remaining page sequence numbers
+	let mut pages_reader = Cursor::new(&remaining_file_content[..]);
+	let mut idx = 0;
+	while let Ok(mut page) = Page::read(&mut pages_reader) {
+		// Chained files can have other logical streams following this one
+		let header = page.header_mut();
+		if header.stream_serial == stream_serial {
+			header.sequence_number = pages_written + idx;
+			page.gen_crc();
+
+			idx += 1;
+		}
+
+		file.write_all(&page.as_bytes())?;
+	}
+
+	Ok(())
+}
+
+// Replaces the comment packet (the second packet of the stream), retaining the original vendor string
+pub(super) fn replace_comment_packet<'a, II, IP>(
+	packets: &mut Packets,
+	tag: &mut VorbisCommentsRef<'a, II, IP>,
+	format: OGGFormat,
+	write_options: WriteOptions,
+) -> Result<()>
+where
+	II: Iterator<Item = (&'a str, &'a str)>,
+	IP: Iterator<Item = (&'a Picture, PictureInformation)>,
+{
 	let comment_packet = packets
 		.get(1)
 		.ok_or_else(|| decode_err!("OGG: Expected metadata packet"))?;
@@ -121,30 +161,17 @@ where
 	md_reader.read_exact(&mut vendor)?;
 
 	let add_framing_bit = format == OGGFormat::Vorbis;
-	let new_metadata_packet =
-		create_metadata_packet(tag, comment_signature, &vendor, add_framing_bit)?;
+	let new_metadata_packet = create_metadata_packet(
+		tag,
+		comment_signature,
+		&vendor,
+		add_framing_bit,
+		write_options,
+	)?;
 
 	// Replace the old comment packet
 	packets.set(1, new_metadata_packet);
 
-	file.rewind()?;
-	file.truncate(0)?;
-
-	let pages_written =
-		packets.write_to(file, stream_serial, 0, CONTAINS_FIRST_PAGE_OF_BITSTREAM)? as u32;
-
-	// Correct all remaining page sequence numbers
-	let mut pages_reader = Cursor::new(&remaining_file_content[..]);
-	let mut idx = 0;
-	while let Ok(mut page) = Page::read(&mut pages_reader) {
-		let header = page.header_mut();
-		header.sequence_number = pages_written + idx;
-		page.gen_crc();
-		file.write_all(&page.as_bytes())?;
-
-		idx += 1;
-	}
-
 	Ok(())
 }
 
@@ -153,6 +180,7 @@ pub(super) fn create_metadata_packet<'a, II, IP>(
 	comment_signature: &[u8],
 	vendor: &[u8],
 	add_framing_bit: bool,
+	write_options: WriteOptions,
 ) -> Result<Vec<u8>>
 where
 	II: Iterator<Item = (&'a str, &'a str)>,
@@ -169,7 +197,12 @@ where
 	new_comment_packet.write_u32::<LittleEndian>(0)?;
 
 	let mut count = 0;
-	create_comments(&mut new_comment_packet, &mut count, &mut tag.items)?;
+	create_comments(
+		&mut new_comment_packet,
+		&mut count,
+		&mut tag.items,
+		write_options.encoder_stamp_value(),
+	)?;
 	create_pictures(&mut new_comment_packet, &mut count, &mut tag.pictures)?;
 
 	// Seek back and write the item count
@@ -187,29 +220,52 @@ where
 	Ok(new_comment_packet.into_inner())
 }
 
+// Writes all non-empty items, appending an `ENCODER` item if one is not present (see `WriteOptions::encoder_stamp`)
 pub(crate) fn create_comments(
 	packet: &mut impl Write,
 	count: &mut u32,
 	items: &mut dyn Iterator<Item = (&str, &str)>,
+	encoder_stamp: Option<&str>,
 ) -> Result<()> {
+	let mut has_encoder = false;
+	let mut written = 0;
+
 	for (k, v) in items {
 		if v.is_empty() {
 			continue;
 		}
 
-		let comment = format!("{k}={v}");
-		let comment_bytes = comment.as_bytes();
+		has_encoder |= k.eq_ignore_ascii_case(ENCODER_KEY);
 
-		let Ok(bytes_len) = u32::try_from(comment_bytes.len()) else {
-			err!(TooMuchData);
-		};
+		create_comment(packet, k, v)?;
+		written += 1;
+	}
 
-		*count += 1;
+	// An empty tag is being removed, no need to stamp it
+	if let Some(encoder_stamp) = encoder_stamp {
+		if !has_encoder && written > 0 {
+			log::debug!("Stamping Vorbis Comments with encoder: {}", encoder_stamp);
 
-		packet.write_u32::<LittleEndian>(bytes_len)?;
-		packet.write_all(comment_bytes)?;
+			create_comment(packet, ENCODER_KEY, encoder_stamp)?;
+			written += 1;
+		}
 	}
 
+	*count += written;
+	Ok(())
+}
+
+fn create_comment(packet: &mut impl Write, key: &str, value: &str) -> Result<()> {
+	let comment = format!("{key}={value}");
+	let comment_bytes = comment.as_bytes();
+
+	let Ok(bytes_len) = u32::try_from(comment_bytes.len()) else {
+		err!(TooMuchData);
+	};
+
+	packet.write_u32::<LittleEndian>(bytes_len)?;
+	packet.write_all(comment_bytes)?;
+
 	Ok(())
 }
 
@@ -221,6 +277,8 @@ fn create_pictures(
 	const PICTURE_KEY: &str = "METADATA_BLOCK_PICTURE=";
 
 	for (pic, info) in pictures {
+		pic.check_loaded()?;
+
 		let picture = pic.as_flac_bytes(info, true);
 
 		let Ok(bytes_len) = u32::try_from(picture.len() + PICTURE_KEY.len()) else {
@@ -0,0 +1,135 @@
+use crate::error::Result;
+use crate::macros::decode_err;
+use crate::properties::FileProperties;
+
+use std::io::Read;
+use std::time::Duration;
+
+use byteorder::{LittleEndian, ReadBytesExt};
+
+/// An OptimFROG file's audio properties
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[non_exhaustive]
+pub struct OptimFrogProperties {
+	pub(crate) duration: Duration,
+	pub(crate) overall_bitrate: u32,
+	pub(crate) audio_bitrate: u32,
+	pub(crate) sample_rate: u32,
+	pub(crate) bit_depth: u8,
+	pub(crate) channels: u8,
+	pub(crate) total_samples: u64,
+}
+
+impl From<OptimFrogProperties> for FileProperties {
+	fn from(input: OptimFrogProperties) -> Self {
+		Self {
+			duration: input.duration,
+			overall_bitrate: Some(input.overall_bitrate),
+			audio_bitrate: Some(input.audio_bitrate),
+			sample_rate: Some(input.sample_rate),
+			bit_depth: Some(input.bit_depth),
+			channels: Some(input.channels),
+			channel_mask: None,
+		}
+	}
+}
+
+impl OptimFrogProperties {
+	/// Duration of the audio
+	pub fn duration(&self) -> Duration {
+		self.duration
+	}
+
+	/// Overall bitrate (kbps)
+	pub fn overall_bitrate(&self) -> u32 {
+		self.overall_bitrate
+	}
+
+	/// Audio bitrate (kbps)
+	pub fn audio_bitrate(&self) -> u32 {
+		self.audio_bitrate
+	}
+
+	/// Sample rate (Hz)
+	pub fn sample_rate(&self) -> u32 {
+		self.sample_rate
+	}
+
+	/// Bits per sample
+	pub fn bit_depth(&self) -> u8 {
+		self.bit_depth
+	}
+
+	/// Channel count
+	pub fn channels(&self) -> u8 {
+		self.channels
+	}
+
+	/// Total number of samples, across all channels
+	pub fn total_samples(&self) -> u64 {
+		self.total_samples
+	}
+}
+
+pub(super) fn read_properties<R>(
+	reader: &mut R,
+	file_length: u64,
+	stream_length: u64,
+) -> Result<OptimFrogProperties>
+where
+	R: Read,
+{
+	let mut signature = [0; 4];
+	reader.read_exact(&mut signature)?;
+
+	if &signature != b"OFR " {
+		decode_err!(@BAIL OptimFrog, "File missing \"OFR \" header");
+	}
+
+	let header_size = reader.read_u32::<LittleEndian>()?;
+	if header_size < 12 {
+		decode_err!(@BAIL OptimFrog, "File has an invalid header size");
+	}
+
+	// 48-bit sample count
+	let total_samples_low = reader.read_u32::<LittleEndian>()?;
+	let total_samples_high = reader.read_u16::<LittleEndian>()?;
+	let total_samples = (u64::from(total_samples_high) << 32) | u64::from(total_samples_low);
+
+	let sample_type = reader.read_u8()?;
+	let channel_config = reader.read_u8()?;
+	let sample_rate = reader.read_u32::<LittleEndian>()?;
+
+	let bit_depth = match sample_type {
+		0 | 1 => 8,
+		2 | 3 => 16,
+		4 | 5 => 24,
+		// Both integer and floating point
+		6..=10 => 32,
+		_ => decode_err!(@BAIL OptimFrog, "File has an unknown sample type"),
+	};
+
+	// Stored as `channels - 1`
+	let channels = channel_config.saturating_add(1);
+
+	let mut properties = OptimFrogProperties {
+		sample_rate,
+		bit_depth,
+		channels,
+		total_samples,
+		..OptimFrogProperties::default()
+	};
+
+	if sample_rate == 0 {
+		return Ok(properties);
+	}
+
+	let length = (total_samples / u64::from(channels)) * 1000 / u64::from(sample_rate);
+	if length > 0 {
+		properties.duration = Duration::from_millis(length);
+		properties.overall_bitrate = ((file_length * 8) / length) as u32;
+		properties.audio_bitrate = ((stream_length * 8) / length) as u32;
+	}
+
+	Ok(properties)
+}
@@ -0,0 +1,29 @@
+use super::properties::OptimFrogProperties;
+use super::OptimFrogFile;
+use crate::ape::tag::read::read_trailing_tags;
+use crate::config::ParseOptions;
+use crate::error::Result;
+
+use std::io::{Read, Seek, SeekFrom};
+
+pub(super) fn read_from<R>(reader: &mut R, parse_options: ParseOptions) -> Result<OptimFrogFile>
+where
+	R: Read + Seek,
+{
+	let current_pos = reader.stream_position()?;
+	let file_length = reader.seek(SeekFrom::End(0))? - current_pos;
+
+	let (id3v1_tag, ape_tag, tags_size) = read_trailing_tags(reader, parse_options)?;
+	let stream_length = file_length - tags_size;
+
+	Ok(OptimFrogFile {
+		id3v1_tag,
+		ape_tag,
+		properties: if parse_options.read_properties {
+			reader.seek(SeekFrom::Start(current_pos))?;
+			super::properties::read_properties(reader, file_length, stream_length)?
+		} else {
+			OptimFrogProperties::default()
+		},
+	})
+}
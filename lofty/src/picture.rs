@@ -2,7 +2,7 @@
 
 use crate::config::ParsingMode;
 use crate::error::{ErrorKind, LoftyError, Result};
-use crate::macros::err;
+use crate::macros::{err, try_vec};
 use crate::util::text::utf8_decode_str;
 
 use std::borrow::Cow;
@@ -39,6 +39,7 @@ pub const APE_PICTURE_TYPES: [&str; 21] = [
 
 /// MIME types for pictures.
 #[derive(Debug, Clone, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[non_exhaustive]
 pub enum MimeType {
 	/// PNG image
@@ -111,6 +112,7 @@ impl Display for MimeType {
 /// The picture type, according to ID3v2 APIC
 #[allow(missing_docs)]
 #[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[non_exhaustive]
 pub enum PictureType {
 	Other,
@@ -260,6 +262,7 @@ impl PictureType {
 /// This information is necessary for FLAC's `METADATA_BLOCK_PICTURE`.
 /// See [`Picture::as_flac_bytes`] for more information.
 #[derive(Debug, Copy, Clone, Eq, PartialEq, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct PictureInformation {
 	/// The picture's width in pixels
 	pub width: u32,
@@ -440,6 +443,7 @@ impl PictureInformation {
 
 /// Represents a picture.
 #[derive(Clone, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Picture {
 	/// The picture type according to ID3v2 APIC
 	pub(crate) pic_type: PictureType,
@@ -449,6 +453,18 @@ pub struct Picture {
 	pub(crate) description: Option<Cow<'static, str>>,
 	/// The binary data of the picture
 	pub(crate) data: Cow<'static, [u8]>,
+	/// Where the data is in the file, if it wasn't read, see [`Picture::load`]
+	#[cfg_attr(feature = "serde", serde(skip))]
+	pub(crate) location: Option<PictureLocation>,
+}
+
+/// The location of the data of a picture skipped with [`ParseOptions::read_pictures`]
+///
+/// [`ParseOptions::read_pictures`]: crate::config::ParseOptions::read_pictures
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub(crate) struct PictureLocation {
+	pub(crate) offset: u64,
+	pub(crate) len: u64,
 }
 
 impl Debug for Picture {
@@ -458,6 +474,7 @@ impl Debug for Picture {
 			.field("mime_type", &self.mime_type)
 			.field("description", &self.description)
 			.field("data", &format!("<{} bytes>", self.data.len()))
+			.field("location", &self.location)
 			.finish()
 	}
 }
@@ -495,6 +512,7 @@ impl Picture {
 			mime_type: Some(mime_type),
 			description: None,
 			data: data.into(),
+			location: None,
 		})
 	}
 
@@ -514,6 +532,7 @@ impl Picture {
 			mime_type,
 			description: description.map(Cow::Owned),
 			data: Cow::Owned(data),
+			location: None,
 		}
 	}
 
@@ -563,6 +582,186 @@ impl Picture {
 		self.data.into_owned()
 	}
 
+	/// Returns the size of the [`Picture`] data, in bytes
+	pub fn data_len(&self) -> usize {
+		self.data.len()
+	}
+
+	/// Whether the [`Picture`] data has been read
+	///
+	/// This is only `false` for pictures skipped with [`ParseOptions::read_pictures`], which have no data
+	/// until [`Picture::load`] is called.
+	///
+	/// [`ParseOptions::read_pictures`]: crate::config::ParseOptions::read_pictures
+	pub fn is_loaded(&self) -> bool {
+		self.location.is_none()
+	}
+
+	/// Read the data of a picture skipped with [`ParseOptions::read_pictures`]
+	///
+	/// Skipped pictures remember the offset of their data, the rest of the picture (type, mimetype,
+	/// and description) is read as usual. This does nothing if the picture is already loaded.
+	///
+	/// NOTE: `reader` must be the file the picture was read from, and it must not have been modified
+	///       since.
+	///
+	/// # Errors
+	///
+	/// * Unable to seek to, or read the data from `reader`
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use lofty::config::ParseOptions;
+	/// use lofty::file::TaggedFileExt;
+	/// use lofty::probe::Probe;
+	/// use std::fs::File;
+	///
+	/// # fn main() -> lofty::error::Result<()> {
+	/// # let path = "tests/files/assets/minimal/full_test.mp3";
+	/// let tagged_file = Probe::open(path)?
+	/// 	.options(ParseOptions::new().read_pictures(false))
+	/// 	.read()?;
+	///
+	/// if let Some(tag) = tagged_file.primary_tag() {
+	/// 	let mut file = File::open(path)?;
+	/// 	for picture in tag.pictures() {
+	/// 		let mut picture = picture.clone();
+	/// 		picture.load(&mut file)?;
+	/// 		assert!(picture.is_loaded());
+	/// 	}
+	/// }
+	/// # Ok(()) }
+	/// ```
+	pub fn load<R>(&mut self, reader: &mut R) -> Result<()>
+	where
+		R: Read + Seek,
+	{
+		let Some(location) = self.location else {
+			return Ok(());
+		};
+
+		reader.seek(SeekFrom::Start(location.offset))?;
+
+		let mut data = try_vec![0; location.len as usize];
+		reader.read_exact(&mut data)?;
+
+		self.data = Cow::Owned(data);
+		self.location = None;
+		Ok(())
+	}
+
+	/// Writing a picture that wasn't loaded would replace it with an empty one
+	pub(crate) fn check_loaded(&self) -> Result<()> {
+		if !self.is_loaded() {
+			err!(PictureNotLoaded);
+		}
+
+		Ok(())
+	}
+
+	/// Returns the width of the picture, in pixels
+	///
+	/// NOTE: This is only available for PNG and JPEG images, see [`PictureInformation::from_picture`].
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use lofty::picture::Picture;
+	///
+	/// # fn main() -> lofty::error::Result<()> {
+	/// # let mut file = std::fs::File::open("tests/picture/assets/jpeg_640x628.jpg")?;
+	/// let picture = Picture::from_reader(&mut file)?;
+	///
+	/// if let (Some(width), Some(height)) = (picture.width(), picture.height()) {
+	/// 	println!("The picture is {width}x{height}");
+	/// }
+	/// # Ok(()) }
+	/// ```
+	pub fn width(&self) -> Option<u32> {
+		self.information().map(|info| info.width)
+	}
+
+	/// Returns the height of the picture, in pixels
+	///
+	/// NOTE: This is only available for PNG and JPEG images, see [`PictureInformation::from_picture`].
+	pub fn height(&self) -> Option<u32> {
+		self.information().map(|info| info.height)
+	}
+
+	fn information(&self) -> Option<PictureInformation> {
+		PictureInformation::from_picture(self)
+			.ok()
+			.filter(|info| info.width != 0 && info.height != 0)
+	}
+
+	/// Convert the picture to another format
+	///
+	/// This is useful for formats that only support certain image formats, such as MP4,
+	/// which can only store JPEG, PNG, and BMP images.
+	///
+	/// The [`PictureType`] and description are retained. If the picture is already of the requested
+	/// [`MimeType`], it is returned unchanged.
+	///
+	/// This is only available with the `image` feature.
+	///
+	/// # Errors
+	///
+	/// * `mime_type` is [`MimeType::Unknown`] ([`ErrorKind::UnsupportedPicture`])
+	/// * The picture could not be decoded or encoded ([`ErrorKind::Image`])
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use lofty::picture::{MimeType, Picture};
+	///
+	/// # fn main() -> lofty::error::Result<()> {
+	/// # let mut file = std::fs::File::open("tests/picture/assets/jpeg_640x628.jpg")?;
+	/// let jpeg = Picture::from_reader(&mut file)?;
+	///
+	/// let png = jpeg.convert_to(MimeType::Png)?;
+	/// assert_eq!(png.mime_type(), Some(&MimeType::Png));
+	/// assert_eq!(png.width(), jpeg.width());
+	/// # Ok(()) }
+	/// ```
+	#[cfg(feature = "image")]
+	pub fn convert_to(&self, mime_type: MimeType) -> Result<Self> {
+		use crate::error::ImageError;
+
+		use image::{DynamicImage, ImageFormat};
+
+		let format = match mime_type {
+			MimeType::Png => ImageFormat::Png,
+			MimeType::Jpeg => ImageFormat::Jpeg,
+			MimeType::Tiff => ImageFormat::Tiff,
+			MimeType::Bmp => ImageFormat::Bmp,
+			MimeType::Gif => ImageFormat::Gif,
+			MimeType::Unknown(_) => err!(UnsupportedPicture),
+		};
+
+		if self.mime_type.as_ref() == Some(&mime_type) {
+			return Ok(self.clone());
+		}
+
+		let mut image = image::load_from_memory(&self.data).map_err(ImageError::new)?;
+
+		// JPEG has no alpha channel
+		if format == ImageFormat::Jpeg {
+			image = DynamicImage::ImageRgb8(image.to_rgb8());
+		}
+
+		let mut data = Cursor::new(Vec::new());
+		image.write_to(&mut data, format).map_err(ImageError::new)?;
+
+		Ok(Self {
+			pic_type: self.pic_type,
+			mime_type: Some(mime_type),
+			description: self.description.clone(),
+			data: Cow::Owned(data.into_inner()),
+			location: None,
+		})
+	}
+
 	/// Convert a [`Picture`] to a base64 encoded FLAC `METADATA_BLOCK_PICTURE` String
 	///
 	/// Use `encode` to convert the picture to a base64 encoded String ([RFC 4648 §4](http://www.faqs.org/rfcs/rfc4648.html))
@@ -629,18 +828,28 @@ impl Picture {
 			let data = BASE64
 				.decode(bytes)
 				.map_err(|_| LoftyError::new(ErrorKind::NotAPicture))?;
-			Self::from_flac_bytes_inner(&data, parse_mode)
+			Self::from_flac_bytes_inner(&data, parse_mode, None)
 		} else {
-			Self::from_flac_bytes_inner(bytes, parse_mode)
+			Self::from_flac_bytes_inner(bytes, parse_mode, None)
 		}
 	}
 
-	fn from_flac_bytes_inner(
+	/// Same as [`Picture::from_flac_bytes`], but only remembers where the data is, see [`Picture::load`]
+	///
+	/// `content_offset` is the offset of `content` (an unencoded FLAC block) in the file.
+	pub(crate) fn from_flac_bytes_unloaded(
 		content: &[u8],
+		content_offset: u64,
 		parse_mode: ParsingMode,
 	) -> Result<(Self, PictureInformation)> {
-		use crate::macros::try_vec;
+		Self::from_flac_bytes_inner(content, parse_mode, Some(content_offset))
+	}
 
+	fn from_flac_bytes_inner(
+		content: &[u8],
+		parse_mode: ParsingMode,
+		content_offset: Option<u64>,
+	) -> Result<(Self, PictureInformation)> {
 		let mut size = content.len();
 		let mut reader = Cursor::new(content);
 
@@ -692,35 +901,50 @@ impl Picture {
 		let data_len = reader.read_u32::<BigEndian>()? as usize;
 		size -= 20;
 
-		if data_len <= size {
-			let mut data = try_vec![0; data_len];
-
-			if let Ok(()) = reader.read_exact(&mut data) {
-				let mime_type;
-				if mime_type_str.is_empty() {
-					mime_type = None;
-				} else {
-					mime_type = Some(MimeType::from_str(mime_type_str));
-				}
-
-				return Ok((
-					Self {
-						pic_type: PictureType::from_u8(pic_ty as u8),
-						mime_type,
-						description,
-						data: Cow::from(data),
-					},
-					PictureInformation {
-						width,
-						height,
-						color_depth,
-						num_colors,
-					},
-				));
-			}
+		if data_len > size {
+			err!(NotAPicture);
 		}
 
-		err!(NotAPicture)
+		let (data, location) = match content_offset {
+			// Only remember where the data is, it's read later with `Picture::load`
+			Some(content_offset) => {
+				let location = PictureLocation {
+					offset: content_offset + reader.position(),
+					len: data_len as u64,
+				};
+
+				(Vec::new(), Some(location))
+			},
+			None => {
+				let mut data = try_vec![0; data_len];
+				reader.read_exact(&mut data)?;
+
+				(data, None)
+			},
+		};
+
+		let mime_type;
+		if mime_type_str.is_empty() {
+			mime_type = None;
+		} else {
+			mime_type = Some(MimeType::from_str(mime_type_str));
+		}
+
+		Ok((
+			Self {
+				pic_type: PictureType::from_u8(pic_ty as u8),
+				mime_type,
+				description,
+				data: Cow::from(data),
+				location,
+			},
+			PictureInformation {
+				width,
+				height,
+				color_depth,
+				num_colors,
+			},
+		))
 	}
 
 	/// Convert a [`Picture`] to an APE Cover Art byte vec:
@@ -790,10 +1014,15 @@ impl Picture {
 			mime_type: Some(mime_type),
 			description,
 			data,
+			location: None,
 		})
 	}
 
 	pub(crate) fn mimetype_from_bin(bytes: &[u8]) -> Result<MimeType> {
+		if bytes.len() < 8 {
+			err!(NotAPicture);
+		}
+
 		match bytes[..8] {
 			[0x89, b'P', b'N', b'G', 0x0D, 0x0A, 0x1A, 0x0A] => Ok(MimeType::Png),
 			[0xFF, 0xD8, ..] => Ok(MimeType::Jpeg),
@@ -811,4 +1040,5 @@ pub(crate) const TOMBSTONE_PICTURE: Picture = Picture {
 	mime_type: None,
 	description: None,
 	data: Cow::Owned(Vec::new()),
+	location: None,
 };
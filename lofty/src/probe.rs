@@ -3,6 +3,7 @@
 use crate::aac::AacFile;
 use crate::ape::ApeFile;
 use crate::config::{global_options, ParseOptions};
+use crate::dsf::DsfFile;
 use crate::error::Result;
 use crate::file::{AudioFile, FileType, FileTypeGuessResult, TaggedFile};
 use crate::flac::FlacFile;
@@ -16,6 +17,7 @@ use crate::musepack::MpcFile;
 use crate::ogg::opus::OpusFile;
 use crate::ogg::speex::SpeexFile;
 use crate::ogg::vorbis::VorbisFile;
+use crate::optimfrog::OptimFrogFile;
 use crate::resolve::custom_resolvers;
 use crate::wavpack::WavPackFile;
 
@@ -464,6 +466,7 @@ impl<R: Read + Seek> Probe<R> {
 				FileType::Aac => AacFile::read_from(reader, options)?.into(),
 				FileType::Aiff => AiffFile::read_from(reader, options)?.into(),
 				FileType::Ape => ApeFile::read_from(reader, options)?.into(),
+				FileType::Dsf => DsfFile::read_from(reader, options)?.into(),
 				FileType::Flac => FlacFile::read_from(reader, options)?.into(),
 				FileType::Mpeg => MpegFile::read_from(reader, options)?.into(),
 				FileType::Opus => OpusFile::read_from(reader, options)?.into(),
@@ -471,6 +474,7 @@ impl<R: Read + Seek> Probe<R> {
 				FileType::Wav => WavFile::read_from(reader, options)?.into(),
 				FileType::Mp4 => Mp4File::read_from(reader, options)?.into(),
 				FileType::Mpc => MpcFile::read_from(reader, options)?.into(),
+				FileType::OptimFrog => OptimFrogFile::read_from(reader, options)?.into(),
 				FileType::Speex => SpeexFile::read_from(reader, options)?.into(),
 				FileType::WavPack => WavPackFile::read_from(reader, options)?.into(),
 				FileType::Custom(c) => {
@@ -487,7 +491,13 @@ impl<R: Read + Seek> Probe<R> {
 	}
 }
 
-/// Read a [`TaggedFile`] from a [File]
+/// Read a [`TaggedFile`] from a reader
+///
+/// This accepts anything that implements [`Read`] and [`Seek`], such as a [`File`] or a [`Cursor`] over
+/// data that is already in memory, so files from other sources (such as over a network or from an archive)
+/// do not need to be written to disk first.
+///
+/// The [`FileType`] will be guessed from the content, see [`Probe::guess_file_type`].
 ///
 /// # Errors
 ///
@@ -509,13 +519,40 @@ impl<R: Read + Seek> Probe<R> {
 /// let parsed_file = read_from(&mut file)?;
 /// # Ok(()) }
 /// ```
-pub fn read_from(file: &mut File) -> Result<TaggedFile> {
-	Probe::new(BufReader::new(file)).guess_file_type()?.read()
+///
+/// Reading from memory:
+///
+/// ```rust
+/// use lofty::read_from;
+/// use std::io::Cursor;
+///
+/// # fn main() -> lofty::error::Result<()> {
+/// # let path = "tests/files/assets/minimal/full_test.mp3";
+/// let data: Vec<u8> = std::fs::read(path)?;
+///
+/// let parsed_file = read_from(&mut Cursor::new(data))?;
+/// # Ok(()) }
+/// ```
+pub fn read_from<R>(reader: &mut R) -> Result<TaggedFile>
+where
+	R: Read + Seek,
+{
+	Probe::new(BufReader::new(reader)).guess_file_type()?.read()
 }
 
 /// Read a [`TaggedFile`] from a path
 ///
-/// NOTE: This will determine the [`FileType`] from the extension
+/// NOTE: This will determine the [`FileType`] from the extension. For files that may have the wrong
+///       extension, use [`Probe::guess_file_type`] to determine the [`FileType`] from the content instead:
+///
+/// ```rust
+/// use lofty::probe::Probe;
+///
+/// # fn main() -> lofty::error::Result<()> {
+/// # let path = "tests/files/assets/minimal/full_test.mp3";
+/// let parsed_file = Probe::open(path)?.guess_file_type()?.read()?;
+/// # Ok(()) }
+/// ```
 ///
 /// # Errors
 ///
@@ -543,8 +580,8 @@ where
 
 #[cfg(test)]
 mod tests {
-	use crate::config::{GlobalOptions, ParseOptions};
-	use crate::file::FileType;
+	use crate::config::{GlobalOptions, ParseOptions, WriteOptions};
+	use crate::file::{FileType, TaggedFileExt};
 	use crate::probe::Probe;
 
 	use std::fs::File;
@@ -575,6 +612,20 @@ mod tests {
 		assert_eq!(probe.file_type(), Some(FileType::Mpeg));
 	}
 
+	#[test]
+	fn mislabeled_extension() {
+		let dir = tempfile::tempdir().unwrap();
+		let path = dir.path().join("full_test.wav");
+		std::fs::copy("tests/files/assets/minimal/full_test.mp3", &path).unwrap();
+
+		// The extension is wrong, so reading it as a WAV file will fail
+		assert!(crate::read_from_path(&path).is_err());
+
+		let probe = Probe::open(&path).unwrap().guess_file_type().unwrap();
+		assert_eq!(probe.file_type(), Some(FileType::Mpeg));
+		assert_eq!(probe.read().unwrap().file_type(), FileType::Mpeg);
+	}
+
 	#[test]
 	fn parse_options_allocation_limit() {
 		// In this test, we read a partial MP3 file that has an ID3v2 tag containing a frame outside
@@ -705,6 +756,11 @@ mod tests {
 		test_probe("tests/files/assets/minimal/full_test.ape", FileType::Ape);
 	}
 
+	#[test]
+	fn probe_dsf() {
+		test_probe("tests/files/assets/minimal/full_test.dsf", FileType::Dsf);
+	}
+
 	#[test]
 	fn probe_flac() {
 		test_probe("tests/files/assets/minimal/full_test.flac", FileType::Flac);
@@ -725,6 +781,14 @@ mod tests {
 		test_probe("tests/files/assets/junk.mp3", FileType::Mpeg);
 	}
 
+	#[test]
+	fn probe_optimfrog() {
+		test_probe(
+			"tests/files/assets/minimal/full_test.ofr",
+			FileType::OptimFrog,
+		);
+	}
+
 	#[test]
 	fn probe_vorbis() {
 		test_probe("tests/files/assets/minimal/full_test.ogg", FileType::Vorbis);
@@ -755,4 +819,83 @@ mod tests {
 			FileType::Wav,
 		);
 	}
+
+	#[test]
+	fn read_from_memory() {
+		for (path, file_type) in [
+			("tests/files/assets/minimal/full_test.flac", FileType::Flac),
+			("tests/files/assets/minimal/full_test.mp3", FileType::Mpeg),
+			("tests/files/assets/minimal/full_test.ogg", FileType::Vorbis),
+			(
+				"tests/files/assets/minimal/m4a_codec_aac.m4a",
+				FileType::Mp4,
+			),
+			(
+				"tests/files/assets/minimal/wav_format_pcm.wav",
+				FileType::Wav,
+			),
+		] {
+			let data = std::fs::read(path).unwrap();
+
+			let tagged_file = super::read_from(&mut std::io::Cursor::new(data)).unwrap();
+			assert_eq!(tagged_file.file_type(), file_type);
+			assert!(tagged_file.primary_tag().is_some());
+		}
+	}
+
+	#[test]
+	fn skip_pictures() {
+		use crate::picture::{MimeType, Picture, PictureType};
+		use crate::prelude::*;
+
+		use std::io::{Cursor, Seek};
+
+		let picture = Picture::new_unchecked(
+			PictureType::CoverFront,
+			Some(MimeType::Png),
+			None,
+			crate::tag::utils::test_utils::read_path("tests/picture/assets/png_640x628.png"),
+		);
+
+		for path in [
+			"tests/files/assets/minimal/full_test.flac",
+			"tests/files/assets/minimal/full_test.mp3",
+			"tests/files/assets/minimal/full_test.ogg",
+			"tests/files/assets/minimal/m4a_codec_aac.m4a",
+		] {
+			let mut file = Cursor::new(std::fs::read(path).unwrap());
+
+			let tagged_file = Probe::new(&mut file)
+				.guess_file_type()
+				.unwrap()
+				.read()
+				.unwrap();
+			let mut tag = tagged_file.primary_tag().unwrap().clone();
+			tag.set_title(String::from("Foo title"));
+			tag.push_picture(picture.clone());
+
+			file.rewind().unwrap();
+			tag.save_to(&mut file, WriteOptions::default()).unwrap();
+
+			for read_pictures in [true, false] {
+				file.rewind().unwrap();
+				let tagged_file = Probe::new(&mut file)
+					.options(ParseOptions::new().read_pictures(read_pictures))
+					.guess_file_type()
+					.unwrap()
+					.read()
+					.unwrap();
+
+				let tag = tagged_file.primary_tag().unwrap();
+				assert_eq!(tag.title().as_deref(), Some("Foo title"), "{path}");
+				assert_eq!(tag.picture_count(), 1, "{path}");
+
+				// Skipped pictures have no data until they're loaded
+				let mut read_picture = tag.pictures()[0].clone();
+				read_picture.load(&mut file).unwrap();
+				assert!(read_picture.is_loaded(), "{path}");
+				assert_eq!(read_picture.data(), picture.data(), "{path}");
+			}
+		}
+	}
 }
@@ -3,6 +3,7 @@ use std::time::Duration;
 
 /// Various *immutable* audio properties
 #[derive(Debug, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[non_exhaustive]
 pub struct FileProperties {
 	pub(crate) duration: Duration,
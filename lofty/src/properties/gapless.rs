@@ -0,0 +1,128 @@
+/// Information needed for gapless playback
+///
+/// Encoders add silence to the start (delay) and end (padding) of a stream, which must be trimmed
+/// by players to play consecutive tracks without a gap.
+///
+/// This is read from:
+///
+/// * MP3: The LAME extension of a Xing/Info header
+/// * MP4: The `----:com.apple.iTunes:iTunSMPB` atom
+///
+/// NOTE: For MP3, the encoder delay is as stored by LAME, and doesn't include the 529 samples of decoder delay.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct GaplessInfo {
+	/// The number of samples of silence at the start of the stream
+	pub encoder_delay: u32,
+	/// The number of samples of silence at the end of the stream
+	pub encoder_padding: u32,
+	/// The number of samples in the stream, excluding the delay and padding
+	pub total_samples: Option<u64>,
+}
+
+impl GaplessInfo {
+	/// Parse the contents of an `iTunSMPB` atom
+	///
+	/// Returns `None` if `value` is not a valid `iTunSMPB` value.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use lofty::properties::GaplessInfo;
+	///
+	/// let info = GaplessInfo::from_itunsmpb(
+	/// 	" 00000000 00000840 000001CA 00000000003F31F6 00000000 00000000 00000000 00000000 \
+	/// 	 00000000 00000000 00000000 00000000",
+	/// )
+	/// .unwrap();
+	///
+	/// assert_eq!(info.encoder_delay, 2112);
+	/// assert_eq!(info.encoder_padding, 458);
+	/// assert_eq!(info.total_samples, Some(4141558));
+	/// ```
+	pub fn from_itunsmpb(value: &str) -> Option<Self> {
+		let mut fields = value.split_ascii_whitespace().skip(1);
+
+		let encoder_delay = u32::from_str_radix(fields.next()?, 16).ok()?;
+		let encoder_padding = u32::from_str_radix(fields.next()?, 16).ok()?;
+		let total_samples = u64::from_str_radix(fields.next()?, 16).ok()?;
+
+		Some(Self {
+			encoder_delay,
+			encoder_padding,
+			total_samples: (total_samples > 0).then_some(total_samples),
+		})
+	}
+
+	/// Create the contents of an `iTunSMPB` atom
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use lofty::properties::GaplessInfo;
+	///
+	/// let info = GaplessInfo {
+	/// 	encoder_delay: 2112,
+	/// 	encoder_padding: 458,
+	/// 	total_samples: Some(4141558),
+	/// };
+	///
+	/// assert_eq!(GaplessInfo::from_itunsmpb(&info.to_itunsmpb()), Some(info));
+	/// ```
+	pub fn to_itunsmpb(&self) -> String {
+		let mut value = format!(
+			" 00000000 {:08X} {:08X} {:016X}",
+			self.encoder_delay,
+			self.encoder_padding,
+			self.total_samples.unwrap_or(0)
+		);
+
+		// The remaining fields are unused
+		value.push_str(&" 00000000".repeat(8));
+		value
+	}
+
+	// The LAME extension stores the delay and padding as two 12-bit integers
+	pub(crate) fn from_lame_bytes(bytes: [u8; 3]) -> Self {
+		let [a, b, c] = bytes.map(u32::from);
+
+		Self {
+			encoder_delay: (a << 4) | (b >> 4),
+			encoder_padding: ((b & 0x0F) << 8) | c,
+			total_samples: None,
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::GaplessInfo;
+
+	#[test]
+	fn itunsmpb() {
+		let info = GaplessInfo {
+			encoder_delay: 2112,
+			encoder_padding: 458,
+			total_samples: None,
+		};
+
+		let value = info.to_itunsmpb();
+		assert_eq!(
+			value,
+			" 00000000 00000840 000001CA 0000000000000000 00000000 00000000 00000000 00000000 \
+			 00000000 00000000 00000000 00000000"
+		);
+		assert_eq!(GaplessInfo::from_itunsmpb(&value), Some(info));
+
+		assert!(GaplessInfo::from_itunsmpb("").is_none());
+		assert!(GaplessInfo::from_itunsmpb(" 00000000 nothex 000001CA 0").is_none());
+	}
+
+	#[test]
+	fn lame_bytes() {
+		// 576 samples of delay, 1728 of padding
+		let info = GaplessInfo::from_lame_bytes([0x24, 0x06, 0xC0]);
+		assert_eq!(info.encoder_delay, 576);
+		assert_eq!(info.encoder_padding, 1728);
+	}
+}
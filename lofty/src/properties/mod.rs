@@ -6,9 +6,11 @@
 
 mod channel_mask;
 mod file_properties;
+mod gapless;
 
 #[cfg(test)]
 mod tests;
 
 pub use channel_mask::ChannelMask;
 pub use file_properties::FileProperties;
+pub use gapless::GaplessInfo;
@@ -1,6 +1,7 @@
 use crate::aac::{AACProperties, AacFile};
 use crate::ape::{ApeFile, ApeProperties};
 use crate::config::ParseOptions;
+use crate::dsf::{DsfFile, DsfProperties};
 use crate::file::AudioFile;
 use crate::flac::{FlacFile, FlacProperties};
 use crate::iff::aiff::{AiffFile, AiffProperties};
@@ -14,7 +15,8 @@ use crate::musepack::{MpcFile, MpcProperties};
 use crate::ogg::{
 	OpusFile, OpusProperties, SpeexFile, SpeexProperties, VorbisFile, VorbisProperties,
 };
-use crate::properties::ChannelMask;
+use crate::optimfrog::{OptimFrogFile, OptimFrogProperties};
+use crate::properties::{ChannelMask, GaplessInfo};
 use crate::wavpack::{WavPackFile, WavPackProperties};
 
 use std::fs::File;
@@ -57,6 +59,16 @@ const APE_PROPERTIES: ApeProperties = ApeProperties {
 	channels: 2,
 };
 
+const DSF_PROPERTIES: DsfProperties = DsfProperties {
+	duration: Duration::from_millis(100),
+	overall_bitrate: 5908,
+	audio_bitrate: 5644,
+	sample_rate: 2_822_400,
+	channels: 2,
+	channel_mask: Some(ChannelMask::stereo()),
+	sample_count: 282_240,
+};
+
 const FLAC_PROPERTIES: FlacProperties = FlacProperties {
 	duration: Duration::from_millis(1428),
 	overall_bitrate: 321,
@@ -80,6 +92,7 @@ const MP1_PROPERTIES: MpegProperties = MpegProperties {
 	sample_rate: 32000,
 	channels: 2,
 	emphasis: None,
+	gapless: None,
 };
 
 const MP2_PROPERTIES: MpegProperties = MpegProperties {
@@ -95,6 +108,7 @@ const MP2_PROPERTIES: MpegProperties = MpegProperties {
 	sample_rate: 48000,
 	channels: 2,
 	emphasis: None,
+	gapless: None,
 };
 
 const MP3_PROPERTIES: MpegProperties = MpegProperties {
@@ -110,6 +124,11 @@ const MP3_PROPERTIES: MpegProperties = MpegProperties {
 	sample_rate: 48000,
 	channels: 2,
 	emphasis: None,
+	gapless: Some(GaplessInfo {
+		encoder_delay: 576,
+		encoder_padding: 1150,
+		total_samples: Some(68546),
+	}),
 };
 
 const MP4_AAC_PROPERTIES: Mp4Properties = Mp4Properties {
@@ -122,6 +141,7 @@ const MP4_AAC_PROPERTIES: Mp4Properties = Mp4Properties {
 	bit_depth: None,
 	channels: 2,
 	drm_protected: false,
+	gapless: None,
 };
 
 const MP4_ALAC_PROPERTIES: Mp4Properties = Mp4Properties {
@@ -134,6 +154,7 @@ const MP4_ALAC_PROPERTIES: Mp4Properties = Mp4Properties {
 	bit_depth: Some(16),
 	channels: 2,
 	drm_protected: false,
+	gapless: None,
 };
 
 const MP4_ALS_PROPERTIES: Mp4Properties = Mp4Properties {
@@ -146,6 +167,7 @@ const MP4_ALS_PROPERTIES: Mp4Properties = Mp4Properties {
 	bit_depth: None,
 	channels: 2,
 	drm_protected: false,
+	gapless: None,
 };
 
 const MP4_FLAC_PROPERTIES: Mp4Properties = Mp4Properties {
@@ -158,6 +180,7 @@ const MP4_FLAC_PROPERTIES: Mp4Properties = Mp4Properties {
 	bit_depth: Some(16),
 	channels: 2,
 	drm_protected: false,
+	gapless: None,
 };
 
 // Properties verified with libmpcdec 1.2.2
@@ -224,6 +247,16 @@ const MPC_SV8_PROPERTIES: MpcSv8Properties = MpcSv8Properties {
 	}),
 };
 
+const OPTIMFROG_PROPERTIES: OptimFrogProperties = OptimFrogProperties {
+	duration: Duration::from_millis(1000),
+	overall_bitrate: 17,
+	audio_bitrate: 16,
+	sample_rate: 44100,
+	bit_depth: 16,
+	channels: 2,
+	total_samples: 88200,
+};
+
 const OPUS_PROPERTIES: OpusProperties = OpusProperties {
 	duration: Duration::from_millis(1428),
 	overall_bitrate: 120,
@@ -317,6 +350,14 @@ fn ape_properties() {
 	);
 }
 
+#[test]
+fn dsf_properties() {
+	assert_eq!(
+		get_properties::<DsfFile>("tests/files/assets/minimal/full_test.dsf"),
+		DSF_PROPERTIES
+	)
+}
+
 #[test]
 fn flac_properties() {
 	assert_eq!(
@@ -405,6 +446,14 @@ fn mpc_sv8_properties() {
 	)
 }
 
+#[test]
+fn optimfrog_properties() {
+	assert_eq!(
+		get_properties::<OptimFrogFile>("tests/files/assets/minimal/full_test.ofr"),
+		OPTIMFROG_PROPERTIES
+	)
+}
+
 #[test]
 fn opus_properties() {
 	assert_eq!(
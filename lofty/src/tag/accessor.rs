@@ -116,4 +116,10 @@ accessor_trait! {
 	[track ]<u32>,                  [track total]<u32>,
 	[disk  ]<u32>,                  [disk total ]<u32>,
 	[year  ]<u32>,                  [comment    ]<Cow<'_, str>, String>,
+	[encoder]<Cow<'_, str>, String>, [lyrics     ]<Cow<'_, str>, String>,
+	[title sort      ]<Cow<'_, str>, String>, [artist sort      ]<Cow<'_, str>, String>,
+	[album title sort]<Cow<'_, str>, String>, [album artist sort]<Cow<'_, str>, String>,
+	[encoder settings]<Cow<'_, str>, String>,
+	[isrc ]<Cow<'_, str>, String>, [barcode       ]<Cow<'_, str>, String>,
+	[label]<Cow<'_, str>, String>, [catalog number]<Cow<'_, str>, String>,
 }
@@ -0,0 +1,351 @@
+use super::{Accessor, ItemKey, ItemValue, Tag, TagExt, TagItem, TagType};
+use crate::error::{ErrorKind, LoftyError, Result};
+use crate::picture::Picture;
+
+/// A builder for [`Tag`]s
+///
+/// This allows for creating a tag in a single expression, rather than with many calls to the
+/// [`Accessor`] methods. The items are only validated against a [`TagType`] when the tag is built.
+///
+/// # Examples
+///
+/// ```rust
+/// use lofty::id3::v2::Id3v2Tag;
+/// use lofty::prelude::*;
+/// use lofty::tag::{TagBuilder, TagType};
+///
+/// # fn main() -> lofty::error::Result<()> {
+/// let tag = TagBuilder::new()
+/// 	.title("Foo title")
+/// 	.artist("Bar artist")
+/// 	.track(1)
+/// 	.build(TagType::Id3v2)?;
+///
+/// assert_eq!(tag.title().as_deref(), Some("Foo title"));
+///
+/// // Or build a concrete tag directly
+/// let id3v2 = TagBuilder::new()
+/// 	.title("Foo title")
+/// 	.build_into::<Id3v2Tag>()?;
+///
+/// assert_eq!(id3v2.title().as_deref(), Some("Foo title"));
+/// # Ok(()) }
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct TagBuilder {
+	items: Vec<TagItem>,
+	year: Option<u32>,
+	pictures: Vec<Picture>,
+}
+
+macro_rules! text_setters {
+	($($item_key:ident => $name:ident),+ $(,)?) => {
+		$(
+			#[doc = concat!("Set the ", stringify!($name))]
+			#[must_use]
+			pub fn $name(self, value: impl Into<String>) -> Self {
+				self.item(TagItem::new(ItemKey::$item_key, ItemValue::Text(value.into())))
+			}
+		)+
+	};
+}
+
+macro_rules! number_setters {
+	($($item_key:ident => $name:ident),+ $(,)?) => {
+		$(
+			#[doc = concat!("Set the ", stringify!($name))]
+			#[must_use]
+			pub fn $name(self, value: u32) -> Self {
+				self.item(TagItem::new(ItemKey::$item_key, ItemValue::Text(value.to_string())))
+			}
+		)+
+	};
+}
+
+impl TagBuilder {
+	/// Create a new, empty [`TagBuilder`]
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use lofty::tag::{TagBuilder, TagType};
+	///
+	/// let tag = TagBuilder::new().build(TagType::Id3v2)?;
+	/// assert!(tag.is_empty());
+	/// # Ok::<(), lofty::error::LoftyError>(())
+	/// ```
+	#[must_use]
+	pub fn new() -> Self {
+		Self::default()
+	}
+
+	text_setters!(
+		TrackTitle => title,
+		TrackArtist => artist,
+		AlbumTitle => album,
+		Genre => genre,
+		Comment => comment,
+	);
+
+	number_setters!(
+		TrackNumber => track,
+		TrackTotal => track_total,
+		DiscNumber => disk,
+		DiscTotal => disk_total,
+	);
+
+	/// Set the year
+	///
+	/// Depending on the [`TagType`], this will be stored as [`ItemKey::Year`] or [`ItemKey::RecordingDate`].
+	/// See [`Accessor::set_year`].
+	#[must_use]
+	pub fn year(mut self, year: u32) -> Self {
+		self.year = Some(year);
+		self
+	}
+
+	/// Set an item, replacing any with the same key
+	#[must_use]
+	pub fn item(mut self, item: TagItem) -> Self {
+		self.items.retain(|i| i.key() != item.key());
+		self.items.push(item);
+		self
+	}
+
+	/// Add a picture
+	#[must_use]
+	pub fn picture(mut self, picture: Picture) -> Self {
+		self.pictures.push(picture);
+		self
+	}
+
+	/// Add multiple pictures
+	#[must_use]
+	pub fn pictures(mut self, pictures: impl IntoIterator<Item = Picture>) -> Self {
+		self.pictures.extend(pictures);
+		self
+	}
+
+	/// Build a [`Tag`] of the given [`TagType`]
+	///
+	/// # Errors
+	///
+	/// * An item can't be stored in `tag_type` ([`ErrorKind::BadItem`])
+	/// * A number is too large for `tag_type`, such as a track number over 65535 in MP4 ([`ErrorKind::BadItem`])
+	/// * `tag_type` doesn't support pictures ([`ErrorKind::UnsupportedPicture`])
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use lofty::tag::{TagBuilder, TagType};
+	///
+	/// // MP4 stores track numbers as 16-bit integers
+	/// let result = TagBuilder::new().track(70_000).build(TagType::Mp4Ilst);
+	/// assert!(result.is_err());
+	/// ```
+	pub fn build(self, tag_type: TagType) -> Result<Tag> {
+		let mut tag = Tag::new(tag_type);
+
+		for item in self.items {
+			if !item.re_map(tag_type) {
+				return Err(bad_item(
+					item.item_key,
+					"Item cannot be stored in this tag type",
+				));
+			}
+
+			if let ItemValue::Text(ref text) = item.item_value {
+				verify_number(tag_type, &item.item_key, text)?;
+			}
+
+			tag.items.push(item);
+		}
+
+		if let Some(year) = self.year {
+			verify_year(year)?;
+
+			tag.set_year(year);
+			if tag.year() != Some(year) {
+				return Err(bad_item(
+					ItemKey::Year,
+					"Year cannot be stored in this tag type",
+				));
+			}
+		}
+
+		if !self.pictures.is_empty() {
+			if !matches!(
+				tag_type,
+				TagType::Id3v2 | TagType::Mp4Ilst | TagType::VorbisComments | TagType::Ape
+			) {
+				return Err(LoftyError::new(ErrorKind::UnsupportedPicture));
+			}
+
+			tag.pictures = self.pictures;
+		}
+
+		Ok(tag)
+	}
+
+	/// Build a concrete tag, such as an [`Id3v2Tag`](crate::id3::v2::Id3v2Tag)
+	///
+	/// # Errors
+	///
+	/// See [`TagBuilder::build`]
+	pub fn build_into<T>(self) -> Result<T>
+	where
+		T: TagExt + From<Tag> + Default,
+	{
+		let tag_type = T::default().tag_type();
+		self.build(tag_type).map(T::from)
+	}
+}
+
+fn bad_item(key: ItemKey, message: &'static str) -> LoftyError {
+	LoftyError::new(ErrorKind::BadItem(key, message))
+}
+
+fn verify_number(tag_type: TagType, key: &ItemKey, text: &str) -> Result<()> {
+	if max_number(tag_type, key).is_none() {
+		return Ok(());
+	}
+
+	match text.parse::<u32>() {
+		Ok(number) => verify_number_range(tag_type, key, number),
+		Err(_) => Err(bad_item(key.clone(), "Expected a number")),
+	}
+}
+
+// The largest number that can be stored for `key`, if the tag type stores it as an integer
+fn max_number(tag_type: TagType, key: &ItemKey) -> Option<u32> {
+	match (tag_type, key) {
+		(TagType::Id3v1, ItemKey::TrackNumber) => Some(u32::from(u8::MAX)),
+		(
+			TagType::Mp4Ilst,
+			ItemKey::TrackNumber | ItemKey::TrackTotal | ItemKey::DiscNumber | ItemKey::DiscTotal,
+		) => Some(u32::from(u16::MAX)),
+		_ => None,
+	}
+}
+
+/// Verify that `number` fits in the integer `tag_type` uses for `key`
+pub(crate) fn verify_number_range(tag_type: TagType, key: &ItemKey, number: u32) -> Result<()> {
+	match max_number(tag_type, key) {
+		Some(max) if number > max => Err(bad_item(
+			key.clone(),
+			"Number is too large for this tag type",
+		)),
+		_ => Ok(()),
+	}
+}
+
+/// Verify that `year` will be read back unchanged, as only the first 4 digits of a year are read
+pub(crate) fn verify_year(year: u32) -> Result<()> {
+	if year > 9999 {
+		return Err(bad_item(ItemKey::Year, "Year has more than 4 digits"));
+	}
+
+	Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+	use super::TagBuilder;
+	use crate::error::ErrorKind;
+	use crate::id3::v2::Id3v2Tag;
+	use crate::mp4::Ilst;
+	use crate::picture::{MimeType, Picture, PictureType};
+	use crate::prelude::*;
+	use crate::tag::{ItemKey, ItemValue, TagItem, TagType};
+
+	fn picture() -> Picture {
+		Picture::new_unchecked(
+			PictureType::CoverFront,
+			Some(MimeType::Png),
+			None,
+			vec![1; 10],
+		)
+	}
+
+	#[test]
+	fn build() {
+		let builder = TagBuilder::new()
+			.title("Foo title")
+			.artist("Bar artist")
+			.album("Baz album")
+			.genre("Rock")
+			.track(1)
+			.track_total(2)
+			.year(2024)
+			.picture(picture());
+
+		let tag = builder.clone().build(TagType::Id3v2).unwrap();
+		assert_eq!(tag.title().as_deref(), Some("Foo title"));
+		assert_eq!(tag.artist().as_deref(), Some("Bar artist"));
+		assert_eq!(tag.album().as_deref(), Some("Baz album"));
+		assert_eq!(tag.genre().as_deref(), Some("Rock"));
+		assert_eq!(tag.track(), Some(1));
+		assert_eq!(tag.track_total(), Some(2));
+		assert_eq!(tag.year(), Some(2024));
+		assert_eq!(tag.pictures(), &[picture()]);
+
+		let ilst = builder.build_into::<Ilst>().unwrap();
+		assert_eq!(ilst.title().as_deref(), Some("Foo title"));
+		assert_eq!(ilst.track(), Some(1));
+		assert_eq!(ilst.year(), Some(2024));
+		assert_eq!(ilst.pictures().unwrap().count(), 1);
+
+		// Later values replace earlier ones
+		let id3v2 = TagBuilder::new()
+			.title("Foo title")
+			.title("Bar title")
+			.build_into::<Id3v2Tag>()
+			.unwrap();
+		assert_eq!(id3v2.title().as_deref(), Some("Bar title"));
+	}
+
+	#[test]
+	fn validation() {
+		// Too large for MP4, fine for ID3v2
+		let builder = TagBuilder::new().track(70_000);
+		assert!(builder.clone().build(TagType::Id3v2).is_ok());
+
+		let err = builder.build(TagType::Mp4Ilst).unwrap_err();
+		assert!(matches!(
+			err.kind(),
+			ErrorKind::BadItem(ItemKey::TrackNumber, _)
+		));
+
+		// ID3v1 only has a single byte for the track number
+		assert!(TagBuilder::new().track(256).build(TagType::Id3v1).is_err());
+		assert!(TagBuilder::new().track(255).build(TagType::Id3v1).is_ok());
+
+		// Not a number
+		let item = TagItem::new(ItemKey::DiscNumber, ItemValue::Text(String::from("one")));
+		assert!(TagBuilder::new()
+			.item(item)
+			.build(TagType::Mp4Ilst)
+			.is_err());
+
+		// ID3v1 has no album artist
+		let item = TagItem::new(
+			ItemKey::AlbumArtist,
+			ItemValue::Text(String::from("Foo artist")),
+		);
+		let err = TagBuilder::new()
+			.item(item)
+			.build(TagType::Id3v1)
+			.unwrap_err();
+		assert!(matches!(
+			err.kind(),
+			ErrorKind::BadItem(ItemKey::AlbumArtist, _)
+		));
+
+		// No pictures in RIFF INFO
+		let err = TagBuilder::new()
+			.picture(picture())
+			.build(TagType::RiffInfo)
+			.unwrap_err();
+		assert!(matches!(err.kind(), ErrorKind::UnsupportedPicture));
+	}
+}
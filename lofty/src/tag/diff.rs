@@ -0,0 +1,305 @@
+use super::{ItemKey, Tag, TagItem};
+use crate::picture::{Picture, PictureType};
+
+use std::collections::hash_map::DefaultHasher;
+use std::hash::{Hash, Hasher};
+
+/// A change to the items of a single [`ItemKey`]
+///
+/// All items with the same key are compared together, in order.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ItemChange {
+	/// The key only exists in the new tag
+	Added {
+		/// The key of the items
+		key: ItemKey,
+		/// The items in the new tag
+		items: Vec<TagItem>,
+	},
+	/// The key only exists in the old tag
+	Removed {
+		/// The key of the items
+		key: ItemKey,
+		/// The items in the old tag
+		items: Vec<TagItem>,
+	},
+	/// The key exists in both tags, with different items
+	Changed {
+		/// The key of the items
+		key: ItemKey,
+		/// The items in the old tag
+		old: Vec<TagItem>,
+		/// The items in the new tag
+		new: Vec<TagItem>,
+	},
+}
+
+impl ItemChange {
+	/// The [`ItemKey`] that was changed
+	pub fn key(&self) -> &ItemKey {
+		match self {
+			ItemChange::Added { key, .. }
+			| ItemChange::Removed { key, .. }
+			| ItemChange::Changed { key, .. } => key,
+		}
+	}
+}
+
+/// A change to the pictures of a single [`PictureType`]
+///
+/// Rather than holding the pictures themselves, pictures are compared by a hash of their
+/// type, MIME type, description, and data. The hashes are only meaningful within a single process.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum PictureChange {
+	/// The picture type only exists in the new tag
+	Added {
+		/// The type of the pictures
+		pic_type: PictureType,
+		/// The hashes of the pictures in the new tag
+		hashes: Vec<u64>,
+	},
+	/// The picture type only exists in the old tag
+	Removed {
+		/// The type of the pictures
+		pic_type: PictureType,
+		/// The hashes of the pictures in the old tag
+		hashes: Vec<u64>,
+	},
+	/// The picture type exists in both tags, with different pictures
+	Changed {
+		/// The type of the pictures
+		pic_type: PictureType,
+		/// The hashes of the pictures in the old tag
+		old: Vec<u64>,
+		/// The hashes of the pictures in the new tag
+		new: Vec<u64>,
+	},
+}
+
+impl PictureChange {
+	/// The [`PictureType`] that was changed
+	pub fn pic_type(&self) -> PictureType {
+		match self {
+			PictureChange::Added { pic_type, .. }
+			| PictureChange::Removed { pic_type, .. }
+			| PictureChange::Changed { pic_type, .. } => *pic_type,
+		}
+	}
+}
+
+/// The differences between two [`Tag`]s
+///
+/// This is created with [`Tag::diff`].
+#[derive(Clone, Debug, PartialEq, Eq, Default)]
+pub struct TagDiff {
+	pub(crate) items: Vec<ItemChange>,
+	pub(crate) pictures: Vec<PictureChange>,
+}
+
+impl TagDiff {
+	/// Whether the tags are identical
+	///
+	/// NOTE: The [`TagType`](crate::tag::TagType)s of the tags are not compared.
+	pub fn is_empty(&self) -> bool {
+		self.items.is_empty() && self.pictures.is_empty()
+	}
+
+	/// The changed items, in order of their first appearance in the old, then new tag
+	pub fn items(&self) -> &[ItemChange] {
+		&self.items
+	}
+
+	/// The changed pictures, in order of their first appearance in the old, then new tag
+	pub fn pictures(&self) -> &[PictureChange] {
+		&self.pictures
+	}
+}
+
+pub(super) fn diff_tags(old: &Tag, new: &Tag) -> TagDiff {
+	let mut diff = TagDiff::default();
+
+	let keys = old.items.iter().chain(&new.items).map(TagItem::key);
+	for key in unique(keys) {
+		let old_items = items_with_key(old, key);
+		let new_items = items_with_key(new, key);
+
+		let change = match (old_items.is_empty(), new_items.is_empty()) {
+			(true, _) => ItemChange::Added {
+				key: key.clone(),
+				items: new_items,
+			},
+			(_, true) => ItemChange::Removed {
+				key: key.clone(),
+				items: old_items,
+			},
+			_ if old_items == new_items => continue,
+			_ => ItemChange::Changed {
+				key: key.clone(),
+				old: old_items,
+				new: new_items,
+			},
+		};
+
+		diff.items.push(change);
+	}
+
+	let pic_types = old
+		.pictures
+		.iter()
+		.chain(&new.pictures)
+		.map(Picture::pic_type);
+	for pic_type in unique(pic_types) {
+		let old_hashes = picture_hashes(old, pic_type);
+		let new_hashes = picture_hashes(new, pic_type);
+
+		let change = match (old_hashes.is_empty(), new_hashes.is_empty()) {
+			(true, _) => PictureChange::Added {
+				pic_type,
+				hashes: new_hashes,
+			},
+			(_, true) => PictureChange::Removed {
+				pic_type,
+				hashes: old_hashes,
+			},
+			_ if old_hashes == new_hashes => continue,
+			_ => PictureChange::Changed {
+				pic_type,
+				old: old_hashes,
+				new: new_hashes,
+			},
+		};
+
+		diff.pictures.push(change);
+	}
+
+	diff
+}
+
+pub(super) fn unique<T: PartialEq>(iter: impl Iterator<Item = T>) -> Vec<T> {
+	let mut ret = Vec::new();
+	for i in iter {
+		if !ret.contains(&i) {
+			ret.push(i);
+		}
+	}
+
+	ret
+}
+
+fn items_with_key(tag: &Tag, key: &ItemKey) -> Vec<TagItem> {
+	tag.items
+		.iter()
+		.filter(|item| item.key() == key)
+		.cloned()
+		.collect()
+}
+
+fn picture_hashes(tag: &Tag, pic_type: PictureType) -> Vec<u64> {
+	tag.pictures
+		.iter()
+		.filter(|picture| picture.pic_type() == pic_type)
+		.map(|picture| {
+			let mut hasher = DefaultHasher::new();
+			picture.hash(&mut hasher);
+			hasher.finish()
+		})
+		.collect()
+}
+
+#[cfg(test)]
+mod tests {
+	use super::{ItemChange, PictureChange};
+	use crate::picture::{MimeType, Picture, PictureType};
+	use crate::prelude::*;
+	use crate::tag::{ItemKey, ItemValue, Tag, TagItem, TagType};
+
+	fn picture(pic_type: PictureType, data: u8) -> Picture {
+		Picture::new_unchecked(pic_type, Some(MimeType::Png), None, vec![data; 10])
+	}
+
+	#[test]
+	fn diff() {
+		let mut old = Tag::new(TagType::Id3v2);
+		old.set_title(String::from("Foo title"));
+		old.set_artist(String::from("Foo artist"));
+		old.set_album(String::from("Foo album"));
+		old.push_picture(picture(PictureType::CoverFront, 1));
+		old.push_picture(picture(PictureType::CoverBack, 2));
+
+		assert!(old.diff(&old).is_empty());
+
+		// The tag type isn't compared
+		let mut new = old.clone();
+		new.re_map(TagType::VorbisComments);
+		assert!(old.diff(&new).is_empty());
+
+		new.set_title(String::from("Bar title"));
+		new.remove_album();
+		new.set_genre(String::from("Rock"));
+		new.remove_picture_type(PictureType::CoverBack);
+		new.push_picture(picture(PictureType::Artist, 3));
+
+		let diff = old.diff(&new);
+		assert_eq!(
+			diff.items(),
+			&[
+				ItemChange::Changed {
+					key: ItemKey::TrackTitle,
+					old: vec![TagItem::new(
+						ItemKey::TrackTitle,
+						ItemValue::Text(String::from("Foo title"))
+					)],
+					new: vec![TagItem::new(
+						ItemKey::TrackTitle,
+						ItemValue::Text(String::from("Bar title"))
+					)],
+				},
+				ItemChange::Removed {
+					key: ItemKey::AlbumTitle,
+					items: vec![TagItem::new(
+						ItemKey::AlbumTitle,
+						ItemValue::Text(String::from("Foo album"))
+					)],
+				},
+				ItemChange::Added {
+					key: ItemKey::Genre,
+					items: vec![TagItem::new(
+						ItemKey::Genre,
+						ItemValue::Text(String::from("Rock"))
+					)],
+				},
+			]
+		);
+
+		assert_eq!(diff.pictures().len(), 2);
+		assert!(matches!(
+			diff.pictures()[0],
+			PictureChange::Removed {
+				pic_type: PictureType::CoverBack,
+				..
+			}
+		));
+		assert!(matches!(
+			diff.pictures()[1],
+			PictureChange::Added {
+				pic_type: PictureType::Artist,
+				..
+			}
+		));
+
+		// Same type, different data
+		let mut new = old.clone();
+		new.remove_picture_type(PictureType::CoverFront);
+		new.push_picture(picture(PictureType::CoverFront, 4));
+
+		let diff = old.diff(&new);
+		assert!(diff.items().is_empty());
+		assert!(matches!(
+			diff.pictures(),
+			[PictureChange::Changed {
+				pic_type: PictureType::CoverFront,
+				..
+			}]
+		));
+	}
+}
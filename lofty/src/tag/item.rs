@@ -114,6 +114,7 @@ gen_map!(
 	"Compilation"                  => FlagCompilation,
 	"Media"                        => OriginalMediaType,
 	"EncodedBy"                    => EncodedBy,
+	"Encoder"                      => EncoderSoftware,
 	"REPLAYGAIN_ALBUM_GAIN"        => ReplayGainAlbumGain,
 	"REPLAYGAIN_ALBUM_PEAK"        => ReplayGainAlbumPeak,
 	"REPLAYGAIN_TRACK_GAIN"        => ReplayGainTrackGain,
@@ -132,7 +133,9 @@ gen_map!(
 	"MUSICBRAINZ_RELEASEGROUPID"   => MusicBrainzReleaseGroupId,
 	"MUSICBRAINZ_ARTISTID"         => MusicBrainzArtistId,
 	"MUSICBRAINZ_ALBUMARTISTID"    => MusicBrainzReleaseArtistId,
-	"MUSICBRAINZ_WORKID"           => MusicBrainzWorkId
+	"MUSICBRAINZ_WORKID"           => MusicBrainzWorkId,
+	"Acoustid_Id"                  => AcoustId,
+	"Acoustid_Fingerprint"         => AcoustIdFingerprint
 );
 
 gen_map!(
@@ -225,7 +228,9 @@ gen_map!(
 	"MusicBrainz Release Group Id" => MusicBrainzReleaseGroupId,
 	"MusicBrainz Artist Id"        => MusicBrainzArtistId,
 	"MusicBrainz Album Artist Id"  => MusicBrainzReleaseArtistId,
-	"MusicBrainz Work Id"          => MusicBrainzWorkId
+	"MusicBrainz Work Id"          => MusicBrainzWorkId,
+	"Acoustid Id"                  => AcoustId,
+	"Acoustid Fingerprint"         => AcoustIdFingerprint
 );
 
 gen_map!(
@@ -305,7 +310,9 @@ gen_map!(
 	"----:com.apple.iTunes:MusicBrainz Release Group Id" => MusicBrainzReleaseGroupId,
 	"----:com.apple.iTunes:MusicBrainz Artist Id"        => MusicBrainzArtistId,
 	"----:com.apple.iTunes:MusicBrainz Album Artist Id"  => MusicBrainzReleaseArtistId,
-	"----:com.apple.iTunes:MusicBrainz Work Id"          => MusicBrainzWorkId
+	"----:com.apple.iTunes:MusicBrainz Work Id"          => MusicBrainzWorkId,
+	"----:com.apple.iTunes:Acoustid Id"                  => AcoustId,
+	"----:com.apple.iTunes:Acoustid Fingerprint"         => AcoustIdFingerprint
 );
 
 gen_map!(
@@ -319,6 +326,8 @@ gen_map!(
 	"IPRO"          => Producer,
 	"IPRT" | "ITRK" => TrackNumber,
 	"IFRM"          => TrackTotal,
+	"DISC"          => DiscNumber,
+	"DTOT"          => DiscTotal,
 	"IRTD"          => Popularimeter,
 	"ICRD"          => RecordingDate,
 	"TLEN"          => Length,
@@ -405,7 +414,9 @@ gen_map!(
 	"MUSICBRAINZ_RELEASEGROUPID"              => MusicBrainzReleaseGroupId,
 	"MUSICBRAINZ_ARTISTID"                    => MusicBrainzArtistId,
 	"MUSICBRAINZ_ALBUMARTISTID"               => MusicBrainzReleaseArtistId,
-	"MUSICBRAINZ_WORKID"                      => MusicBrainzWorkId
+	"MUSICBRAINZ_WORKID"                      => MusicBrainzWorkId,
+	"ACOUSTID_ID"                             => AcoustId,
+	"ACOUSTID_FINGERPRINT"                    => AcoustIdFingerprint
 );
 
 macro_rules! gen_item_keys {
@@ -428,6 +439,7 @@ macro_rules! gen_item_keys {
 		#[allow(missing_docs)]
 		#[non_exhaustive]
 		/// A generic representation of a tag's key
+		#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 		pub enum ItemKey {
 			$(
 				$(#[$variant_meta])*
@@ -629,6 +641,23 @@ gen_item_keys!(
 		/// Reference: <https://picard-docs.musicbrainz.org/en/appendices/tag_mapping.html#musicbrainz-work-id>
 		MusicBrainzWorkId,
 
+		///////////////////////////////////////////////////////////////
+		// AcoustID
+
+		/// AcoustID
+		///
+		/// Textual representation of the UUID.
+		///
+		/// Reference: <https://picard-docs.musicbrainz.org/en/appendices/tag_mapping.html#acoustid>
+		AcoustId,
+
+		/// AcoustID Fingerprint
+		///
+		/// The compressed, base64 encoded Chromaprint fingerprint.
+		///
+		/// Reference: <https://picard-docs.musicbrainz.org/en/appendices/tag_mapping.html#acoustid-fingerprint>
+		AcoustIdFingerprint,
+
 		///////////////////////////////////////////////////////////////
 
 		// Flags
@@ -708,6 +737,7 @@ gen_item_keys!(
 
 /// Represents a tag item's value
 #[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum ItemValue {
 	/// Any UTF-8 encoded text
 	Text(String),
@@ -789,6 +819,7 @@ impl<'a> Into<ItemValueRef<'a>> for &'a ItemValue {
 
 /// Represents a tag item (key/value)
 #[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct TagItem {
 	pub(crate) lang: Lang,
 	pub(crate) description: String,
@@ -840,6 +871,22 @@ impl TagItem {
 		self.description = description;
 	}
 
+	/// Returns the language of the [`TagItem`]
+	///
+	/// NOTE: This will be [`UNKNOWN_LANGUAGE`] unless the item came from, or was set for, a format
+	///       that supports languages (such as ID3v2's `COMM` frame).
+	pub fn lang(&self) -> &Lang {
+		&self.lang
+	}
+
+	/// Returns the description of the [`TagItem`]
+	///
+	/// NOTE: This will be empty unless the item came from, or was set for, a format that supports
+	///       descriptions (such as ID3v2's `COMM` frame).
+	pub fn description(&self) -> &str {
+		&self.description
+	}
+
 	/// Returns a reference to the [`ItemKey`]
 	pub fn key(&self) -> &ItemKey {
 		&self.item_key
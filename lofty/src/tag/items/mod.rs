@@ -1,7 +1,15 @@
 //! Various generic representations of tag items
 
 mod lang;
+mod rating;
+mod replay_gain;
 mod timestamp;
 
 pub use lang::*;
+pub use replay_gain::ReplayGain;
 pub use timestamp::Timestamp;
+
+pub(crate) use rating::{
+	popm_from_rating, popm_item_to_text, rating_from_item_value, wmp_popm_counter,
+};
+pub(crate) use replay_gain::parse_replay_gain_value;
@@ -0,0 +1,111 @@
+use crate::id3::v2::{FrameFlags, PopularimeterFrame};
+use crate::tag::{ItemKey, ItemValue, TagItem};
+
+/// The email Windows Media Player uses for its `POPM` frames
+///
+/// Other applications (such as MediaMonkey) will read ratings stored under it.
+pub(crate) const POPM_EMAIL: &str = "Windows Media Player 9 Series";
+
+// The `POPM` ratings for 0-5 stars, following Windows Media Player
+const POPM_STARS: [u8; 6] = [0, 1, 64, 128, 196, 255];
+
+/// Convert a `POPM` rating (1-255, 0 is unknown) to a 0-100 rating
+///
+/// The value will be rounded to the nearest star (multiple of 20).
+pub(crate) fn rating_from_popm(popm_rating: u8) -> Option<u8> {
+	let stars = match popm_rating {
+		0 => return None,
+		1..=31 => 1,
+		32..=95 => 2,
+		96..=159 => 3,
+		160..=223 => 4,
+		224..=255 => 5,
+	};
+
+	Some(stars * 20)
+}
+
+/// Convert a 0-100 rating to a `POPM` rating
+///
+/// Any non-zero rating will be at least one star.
+pub(crate) fn rating_to_popm(rating: u8) -> u8 {
+	let rating = rating.min(100);
+	if rating == 0 {
+		return 0;
+	}
+
+	let stars = ((rating + 10) / 20).max(1);
+	POPM_STARS[usize::from(stars)]
+}
+
+/// Parse a 0-100 rating from either a text value or the contents of a `POPM` frame
+pub(crate) fn rating_from_item_value(value: &ItemValue) -> Option<u8> {
+	match value {
+		ItemValue::Text(text) => text.trim().parse::<u8>().ok().filter(|r| *r <= 100),
+		ItemValue::Binary(popm) => {
+			let popm = PopularimeterFrame::parse(&mut &popm[..], FrameFlags::default()).ok()?;
+			rating_from_popm(popm.rating)
+		},
+		ItemValue::Locator(_) => None,
+	}
+}
+
+/// Convert an item holding a `POPM` frame to a 0-100 text rating
+///
+/// Only ID3v2 can store `POPM` frames, every other format stores the rating as text. Any other item,
+/// or a `POPM` frame with an unknown rating, is left as-is.
+pub(crate) fn popm_item_to_text(item: &mut TagItem) {
+	if item.item_key != ItemKey::Popularimeter || !matches!(item.item_value, ItemValue::Binary(_)) {
+		return;
+	}
+
+	if let Some(rating) = rating_from_item_value(&item.item_value) {
+		item.item_value = ItemValue::Text(rating.to_string());
+	}
+}
+
+/// Get the play counter of an item holding the Windows Media Player `POPM` frame
+///
+/// Text ratings are converted to that frame when writing ID3v2, so they have a counter of 0. This
+/// returns `None` for `POPM` frames with any other email.
+pub(crate) fn wmp_popm_counter(value: &ItemValue) -> Option<u64> {
+	match value {
+		ItemValue::Text(_) => Some(0),
+		ItemValue::Binary(popm) => {
+			let popm = PopularimeterFrame::parse(&mut &popm[..], FrameFlags::default()).ok()?;
+			(popm.email == POPM_EMAIL).then_some(popm.counter)
+		},
+		ItemValue::Locator(_) => None,
+	}
+}
+
+/// Create a `POPM` frame from a 0-100 rating
+pub(crate) fn popm_from_rating(rating: u8) -> PopularimeterFrame<'static> {
+	PopularimeterFrame::new(String::from(POPM_EMAIL), rating_to_popm(rating), 0)
+}
+
+#[cfg(test)]
+mod tests {
+	use super::{rating_from_popm, rating_to_popm};
+
+	#[test]
+	fn popm_conversion() {
+		assert_eq!(rating_from_popm(0), None);
+
+		for (stars, popm) in [1, 64, 128, 196, 255].into_iter().enumerate() {
+			let rating = (stars as u8 + 1) * 20;
+			assert_eq!(rating_to_popm(rating), popm);
+			assert_eq!(rating_from_popm(popm), Some(rating));
+		}
+
+		// Rounded to the nearest star, never rounding a rating down to 0
+		assert_eq!(rating_to_popm(0), 0);
+		assert_eq!(rating_to_popm(5), 1);
+		assert_eq!(rating_to_popm(50), 128);
+		assert_eq!(rating_to_popm(200), 255);
+
+		// MediaMonkey half stars
+		assert_eq!(rating_from_popm(13), Some(20));
+		assert_eq!(rating_from_popm(186), Some(80));
+	}
+}
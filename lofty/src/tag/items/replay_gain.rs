@@ -0,0 +1,166 @@
+use crate::tag::ItemKey;
+
+/// ReplayGain information for a track and its album
+///
+/// Gains are in dB, and peaks are relative to full scale (`1.0`).
+///
+/// See [`Tag::replaygain`](crate::tag::Tag::replaygain) and [`Tag::set_replaygain`](crate::tag::Tag::set_replaygain)
+#[derive(Copy, Clone, Debug, PartialEq, Default)]
+pub struct ReplayGain {
+	/// The track gain, in dB
+	pub track_gain: Option<f32>,
+	/// The track peak
+	pub track_peak: Option<f32>,
+	/// The album gain, in dB
+	pub album_gain: Option<f32>,
+	/// The album peak
+	pub album_peak: Option<f32>,
+}
+
+impl ReplayGain {
+	/// Whether none of the values are set
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use lofty::tag::items::ReplayGain;
+	///
+	/// let mut replay_gain = ReplayGain::default();
+	/// assert!(replay_gain.is_empty());
+	///
+	/// replay_gain.track_gain = Some(-6.5);
+	/// assert!(!replay_gain.is_empty());
+	/// ```
+	pub fn is_empty(&self) -> bool {
+		self.track_gain.is_none()
+			&& self.track_peak.is_none()
+			&& self.album_gain.is_none()
+			&& self.album_peak.is_none()
+	}
+
+	pub(crate) fn fields(&self) -> [(ItemKey, Option<String>); 4] {
+		[
+			(
+				ItemKey::ReplayGainTrackGain,
+				self.track_gain.map(format_gain),
+			),
+			(
+				ItemKey::ReplayGainTrackPeak,
+				self.track_peak.map(format_peak),
+			),
+			(
+				ItemKey::ReplayGainAlbumGain,
+				self.album_gain.map(format_gain),
+			),
+			(
+				ItemKey::ReplayGainAlbumPeak,
+				self.album_peak.map(format_peak),
+			),
+		]
+	}
+}
+
+fn format_gain(gain: f32) -> String {
+	format!("{gain:.2} dB")
+}
+
+fn format_peak(peak: f32) -> String {
+	format!("{peak:.6}")
+}
+
+/// Parse a gain or peak value, ignoring any trailing "dB"
+pub(crate) fn parse_replay_gain_value(value: &str) -> Option<f32> {
+	let value = value.trim();
+	let value = value
+		.len()
+		.checked_sub(2)
+		.filter(|&pos| value.is_char_boundary(pos))
+		.filter(|&pos| value[pos..].eq_ignore_ascii_case("db"))
+		.map_or(value, |pos| &value[..pos]);
+
+	value.trim_end().parse().ok()
+}
+
+#[cfg(test)]
+mod tests {
+	use super::parse_replay_gain_value;
+	use crate::id3::v2::Id3v2Tag;
+	use crate::mp4::{AtomIdent, Ilst};
+	use crate::ogg::VorbisComments;
+	use crate::tag::items::ReplayGain;
+	use crate::tag::{Tag, TagType};
+
+	use std::borrow::Cow;
+
+	fn replay_gain() -> ReplayGain {
+		ReplayGain {
+			track_gain: Some(-6.5),
+			track_peak: Some(0.988_553),
+			album_gain: Some(-7.25),
+			album_peak: None,
+		}
+	}
+
+	#[test]
+	fn parse_values() {
+		assert_eq!(parse_replay_gain_value("-6.50 dB"), Some(-6.5));
+		assert_eq!(parse_replay_gain_value("+2.10dB"), Some(2.1));
+		assert_eq!(parse_replay_gain_value(" -1.00 DB "), Some(-1.0));
+		assert_eq!(parse_replay_gain_value("0.988553"), Some(0.988_553));
+		assert_eq!(parse_replay_gain_value("dB"), None);
+		assert_eq!(parse_replay_gain_value("foo"), None);
+	}
+
+	#[test]
+	fn tag_round_trip() {
+		let mut tag = Tag::new(TagType::VorbisComments);
+		tag.set_replaygain(replay_gain());
+
+		assert_eq!(tag.item_count(), 3);
+		assert_eq!(tag.replaygain(), replay_gain());
+
+		// Unset values are removed
+		tag.set_replaygain(ReplayGain {
+			track_gain: Some(1.0),
+			..ReplayGain::default()
+		});
+		assert_eq!(tag.item_count(), 1);
+
+		tag.set_replaygain(ReplayGain::default());
+		assert_eq!(tag.item_count(), 0);
+	}
+
+	#[test]
+	fn format_storage() {
+		let mut tag = Tag::new(TagType::Id3v2);
+		tag.set_replaygain(replay_gain());
+
+		let id3v2: Id3v2Tag = tag.clone().into();
+		assert_eq!(
+			id3v2.get_user_text("REPLAYGAIN_TRACK_GAIN"),
+			Some("-6.50 dB")
+		);
+		assert_eq!(
+			id3v2.get_user_text("REPLAYGAIN_TRACK_PEAK"),
+			Some("0.988553")
+		);
+
+		let vorbis_comments: VorbisComments = tag.clone().into();
+		assert_eq!(
+			vorbis_comments.get("REPLAYGAIN_ALBUM_GAIN"),
+			Some("-7.25 dB")
+		);
+
+		let ilst: Ilst = tag.into();
+		assert!(ilst
+			.get(&AtomIdent::Freeform {
+				mean: Cow::Borrowed("com.apple.iTunes"),
+				name: Cow::Borrowed("replaygain_track_gain"),
+			})
+			.is_some());
+
+		// And back
+		let tag: Tag = ilst.into();
+		assert_eq!(tag.replaygain(), replay_gain());
+	}
+}
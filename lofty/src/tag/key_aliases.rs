@@ -0,0 +1,121 @@
+use std::borrow::Cow;
+
+/// A table of alternative spellings for item keys
+///
+/// Some formats have multiple keys for the same item, such as `TRACKTOTAL` and `TOTALTRACKS` in
+/// [`VorbisComments`](crate::ogg::VorbisComments), or `IPRT` and `ITRK` in
+/// [`RiffInfoList`](crate::iff::wav::RiffInfoList). Tags that use a `KeyAliases` table will:
+///
+/// * Find an item by any of its spellings when reading
+/// * Replace or remove every spelling of an item
+/// * Store new items with the canonical spelling
+///
+/// All comparisons are case-insensitive.
+///
+/// # Examples
+///
+/// ```rust
+/// use lofty::ogg::VorbisComments;
+/// use lofty::tag::KeyAliases;
+///
+/// let mut aliases = KeyAliases::new();
+/// aliases.insert("ALBUM ARTIST", "ALBUMARTIST");
+///
+/// let mut vorbis_comments = VorbisComments::new();
+/// vorbis_comments.set_key_aliases(aliases);
+///
+/// vorbis_comments.insert(String::from("Album Artist"), String::from("Foo artist"));
+///
+/// // Stored with the canonical spelling, but can be found with either
+/// assert_eq!(vorbis_comments.items().next(), Some(("ALBUMARTIST", "Foo artist")));
+/// assert_eq!(vorbis_comments.get("album artist"), Some("Foo artist"));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct KeyAliases {
+	// (alias, canonical)
+	aliases: Vec<(Cow<'static, str>, Cow<'static, str>)>,
+}
+
+impl KeyAliases {
+	/// Create a new empty `KeyAliases`
+	///
+	/// NOTE: Tags have their own default tables, see [`VorbisComments::key_aliases`] and
+	///       [`RiffInfoList::key_aliases`].
+	///
+	/// [`VorbisComments::key_aliases`]: crate::ogg::VorbisComments::key_aliases
+	/// [`RiffInfoList::key_aliases`]: crate::iff::wav::RiffInfoList::key_aliases
+	pub fn new() -> Self {
+		Self::default()
+	}
+
+	pub(crate) fn from_static(aliases: &'static [(&'static str, &'static str)]) -> Self {
+		Self {
+			aliases: aliases
+				.iter()
+				.map(|(alias, canonical)| (Cow::Borrowed(*alias), Cow::Borrowed(*canonical)))
+				.collect(),
+		}
+	}
+
+	/// Make `alias` an alternative spelling of `canonical`
+	///
+	/// This will replace any existing entry for `alias`.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use lofty::tag::KeyAliases;
+	///
+	/// let mut aliases = KeyAliases::new();
+	/// aliases.insert("TOTALTRACKS", "TRACKTOTAL");
+	///
+	/// assert_eq!(aliases.canonical("totaltracks"), "TRACKTOTAL");
+	/// ```
+	pub fn insert(
+		&mut self,
+		alias: impl Into<Cow<'static, str>>,
+		canonical: impl Into<Cow<'static, str>>,
+	) {
+		let alias = alias.into();
+
+		self.remove(&alias);
+		self.aliases.push((alias, canonical.into()));
+	}
+
+	/// Remove `alias`, returning its canonical spelling if it exists
+	pub fn remove(&mut self, alias: &str) -> Option<Cow<'static, str>> {
+		let pos = self
+			.aliases
+			.iter()
+			.position(|(a, _)| a.eq_ignore_ascii_case(alias))?;
+
+		Some(self.aliases.remove(pos).1)
+	}
+
+	/// Get the canonical spelling of `key`
+	///
+	/// If `key` isn't an alias, it is returned as-is.
+	pub fn canonical<'a>(&'a self, key: &'a str) -> &'a str {
+		self.aliases
+			.iter()
+			.find(|(alias, _)| alias.eq_ignore_ascii_case(key))
+			.map_or(key, |(_, canonical)| canonical.as_ref())
+	}
+
+	/// Whether `a` and `b` are spellings of the same key
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use lofty::tag::KeyAliases;
+	///
+	/// let mut aliases = KeyAliases::new();
+	/// aliases.insert("ITRK", "IPRT");
+	///
+	/// assert!(aliases.matches("itrk", "IPRT"));
+	/// assert!(!aliases.matches("ITRK", "IFRM"));
+	/// ```
+	pub fn matches(&self, a: &str, b: &str) -> bool {
+		self.canonical(a).eq_ignore_ascii_case(self.canonical(b))
+	}
+}
@@ -0,0 +1,349 @@
+use super::diff::unique;
+use super::{ItemKey, ItemValue, Tag, TagItem};
+use crate::picture::{Picture, PictureType};
+
+/// How to resolve items or pictures that exist in both tags, see [`MergePolicy`]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Default)]
+#[non_exhaustive]
+pub enum MergeStrategy {
+	/// Keep the values of the tag being merged into
+	#[default]
+	PreferSelf,
+	/// Replace the values with those of the other tag
+	PreferOther,
+	/// Keep the values of both tags, skipping any of the other tag's values that already exist
+	///
+	/// This can be used to combine artist lists.
+	Combine,
+	/// Keep the values of whichever tag has more data
+	///
+	/// For items, this is the total length of their values. For pictures, this is the total size
+	/// of their data, keeping the larger picture.
+	PreferLarger,
+}
+
+/// Controls how [`Tag::merge`] resolves conflicts
+///
+/// A conflict occurs when both tags have items with the same [`ItemKey`], or pictures with the same
+/// [`PictureType`]. Items and pictures that only exist in the other tag are always added.
+///
+/// # Examples
+///
+/// ```rust
+/// use lofty::tag::{ItemKey, MergePolicy, MergeStrategy};
+///
+/// // Keep existing items, but combine the artists and take any larger cover art
+/// let policy = MergePolicy::new()
+/// 	.item(ItemKey::TrackArtist, MergeStrategy::Combine)
+/// 	.pictures(MergeStrategy::PreferLarger);
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq, Default)]
+pub struct MergePolicy {
+	pub(crate) items: MergeStrategy,
+	pub(crate) item_overrides: Vec<(ItemKey, MergeStrategy)>,
+	pub(crate) pictures: MergeStrategy,
+}
+
+impl MergePolicy {
+	/// Creates a new `MergePolicy`, alias for `Default` implementation
+	///
+	/// This will prefer the existing items and pictures for all conflicts.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use lofty::tag::MergePolicy;
+	///
+	/// let policy = MergePolicy::new();
+	/// ```
+	pub fn new() -> Self {
+		Self::default()
+	}
+
+	/// Set the strategy for all items without their own strategy
+	///
+	/// See also: [`MergePolicy::item`]
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use lofty::tag::{MergePolicy, MergeStrategy};
+	///
+	/// // The other tag is more trustworthy
+	/// let policy = MergePolicy::new().items(MergeStrategy::PreferOther);
+	/// ```
+	pub fn items(mut self, strategy: MergeStrategy) -> Self {
+		self.items = strategy;
+		self
+	}
+
+	/// Set the strategy for the items of a single [`ItemKey`]
+	///
+	/// This will replace any existing strategy for `key`.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use lofty::tag::{ItemKey, MergePolicy, MergeStrategy};
+	///
+	/// // Take everything from the other tag, except for the hand-edited comments
+	/// let policy = MergePolicy::new()
+	/// 	.items(MergeStrategy::PreferOther)
+	/// 	.item(ItemKey::Comment, MergeStrategy::PreferSelf);
+	/// ```
+	pub fn item(mut self, key: ItemKey, strategy: MergeStrategy) -> Self {
+		self.item_overrides.retain(|(k, _)| *k != key);
+		self.item_overrides.push((key, strategy));
+		self
+	}
+
+	/// Set the strategy for pictures
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use lofty::tag::{MergePolicy, MergeStrategy};
+	///
+	/// // Replace low resolution cover art
+	/// let policy = MergePolicy::new().pictures(MergeStrategy::PreferLarger);
+	/// ```
+	pub fn pictures(mut self, strategy: MergeStrategy) -> Self {
+		self.pictures = strategy;
+		self
+	}
+
+	fn item_strategy(&self, key: &ItemKey) -> MergeStrategy {
+		self.item_overrides
+			.iter()
+			.find(|(k, _)| k == key)
+			.map_or(self.items, |(_, strategy)| *strategy)
+	}
+}
+
+pub(super) fn merge_tags(tag: &mut Tag, other: &Tag, policy: &MergePolicy) {
+	for key in unique(other.items.iter().map(TagItem::key)) {
+		let other_items = other.items.iter().filter(|item| item.key() == key);
+		if !tag.items.iter().any(|item| item.key() == key) {
+			push_items(tag, other_items.cloned());
+			continue;
+		}
+
+		match policy.item_strategy(key) {
+			MergeStrategy::PreferSelf => {},
+			MergeStrategy::PreferOther => {
+				tag.remove_key(key);
+				push_items(tag, other_items.cloned());
+			},
+			MergeStrategy::Combine => {
+				let new_items = other_items
+					.filter(|item| {
+						!tag.items
+							.iter()
+							.any(|i| i.key() == key && i.value() == item.value())
+					})
+					.cloned()
+					.collect::<Vec<_>>();
+
+				push_items(tag, new_items);
+			},
+			MergeStrategy::PreferLarger => {
+				let existing_len: usize = tag
+					.items
+					.iter()
+					.filter(|item| item.key() == key)
+					.map(|item| value_len(item.value()))
+					.sum();
+				let other_len: usize = other_items
+					.clone()
+					.map(|item| value_len(item.value()))
+					.sum();
+
+				if other_len > existing_len {
+					tag.remove_key(key);
+					push_items(tag, other_items.cloned());
+				}
+			},
+		}
+	}
+
+	for pic_type in unique(other.pictures.iter().map(Picture::pic_type)) {
+		let other_pictures = other
+			.pictures
+			.iter()
+			.filter(|picture| picture.pic_type() == pic_type);
+		if !tag.pictures.iter().any(|p| p.pic_type() == pic_type) {
+			tag.pictures.extend(other_pictures.cloned());
+			continue;
+		}
+
+		match policy.pictures {
+			MergeStrategy::PreferSelf => {},
+			MergeStrategy::PreferOther => {
+				tag.remove_picture_type(pic_type);
+				tag.pictures.extend(other_pictures.cloned());
+			},
+			MergeStrategy::Combine => {
+				let new_pictures = other_pictures
+					.filter(|picture| !tag.pictures.contains(picture))
+					.cloned()
+					.collect::<Vec<_>>();
+
+				tag.pictures.extend(new_pictures);
+			},
+			MergeStrategy::PreferLarger => {
+				let existing_len = pictures_len(tag.pictures.iter(), pic_type);
+				let other_len = pictures_len(other_pictures.clone(), pic_type);
+
+				if other_len > existing_len {
+					tag.remove_picture_type(pic_type);
+					tag.pictures.extend(other_pictures.cloned());
+				}
+			},
+		}
+	}
+}
+
+// Items that can't be stored in the tag's type are skipped
+fn push_items(tag: &mut Tag, items: impl IntoIterator<Item = TagItem>) {
+	for item in items {
+		tag.push(item);
+	}
+}
+
+fn value_len(value: &ItemValue) -> usize {
+	match value {
+		ItemValue::Text(text) | ItemValue::Locator(text) => text.len(),
+		ItemValue::Binary(data) => data.len(),
+	}
+}
+
+fn pictures_len<'a>(pictures: impl Iterator<Item = &'a Picture>, pic_type: PictureType) -> usize {
+	pictures
+		.filter(|picture| picture.pic_type() == pic_type)
+		.map(Picture::data_len)
+		.sum()
+}
+
+#[cfg(test)]
+mod tests {
+	use super::{MergePolicy, MergeStrategy};
+	use crate::picture::{MimeType, Picture, PictureType};
+	use crate::prelude::*;
+	use crate::tag::{ItemKey, ItemValue, Tag, TagItem, TagType};
+
+	fn picture(data_len: usize) -> Picture {
+		Picture::new_unchecked(
+			PictureType::CoverFront,
+			Some(MimeType::Png),
+			None,
+			vec![0; data_len],
+		)
+	}
+
+	fn tags() -> (Tag, Tag) {
+		let mut tag = Tag::new(TagType::Id3v2);
+		tag.set_title(String::from("Foo title"));
+		tag.set_artist(String::from("Foo artist"));
+		tag.set_comment(String::from("Hand-edited comment"));
+		tag.push_picture(picture(10));
+
+		let mut other = Tag::new(TagType::VorbisComments);
+		other.set_title(String::from("Bar title"));
+		other.push(TagItem::new(
+			ItemKey::TrackArtist,
+			ItemValue::Text(String::from("Foo artist")),
+		));
+		other.push(TagItem::new(
+			ItemKey::TrackArtist,
+			ItemValue::Text(String::from("Bar artist")),
+		));
+		other.set_album(String::from("Bar album"));
+		other.set_comment(String::from("Comment"));
+		other.push_picture(picture(20));
+
+		(tag, other)
+	}
+
+	#[test]
+	fn prefer_self() {
+		let (mut tag, other) = tags();
+		tag.merge(&other, &MergePolicy::new());
+
+		// Only the missing album is added
+		assert_eq!(tag.title().as_deref(), Some("Foo title"));
+		assert_eq!(tag.get_strings(&ItemKey::TrackArtist).count(), 1);
+		assert_eq!(tag.album().as_deref(), Some("Bar album"));
+		assert_eq!(tag.comment().as_deref(), Some("Hand-edited comment"));
+
+		assert_eq!(tag.pictures().len(), 1);
+		assert_eq!(tag.pictures()[0].data_len(), 10);
+
+		// The tag type is unchanged
+		assert_eq!(tag.tag_type(), TagType::Id3v2);
+	}
+
+	#[test]
+	fn prefer_other() {
+		let (mut tag, other) = tags();
+		let policy = MergePolicy::new()
+			.items(MergeStrategy::PreferOther)
+			.item(ItemKey::Comment, MergeStrategy::PreferSelf)
+			.pictures(MergeStrategy::PreferOther);
+		tag.merge(&other, &policy);
+
+		assert_eq!(tag.title().as_deref(), Some("Bar title"));
+		assert_eq!(
+			tag.get_strings(&ItemKey::TrackArtist).collect::<Vec<_>>(),
+			&["Foo artist", "Bar artist"]
+		);
+		assert_eq!(tag.comment().as_deref(), Some("Hand-edited comment"));
+
+		assert_eq!(tag.pictures().len(), 1);
+		assert_eq!(tag.pictures()[0].data_len(), 20);
+	}
+
+	#[test]
+	fn combine() {
+		let (mut tag, other) = tags();
+		let policy = MergePolicy::new()
+			.item(ItemKey::TrackArtist, MergeStrategy::Combine)
+			.pictures(MergeStrategy::Combine);
+		tag.merge(&other, &policy);
+
+		// The duplicate artist is skipped
+		assert_eq!(
+			tag.get_strings(&ItemKey::TrackArtist).collect::<Vec<_>>(),
+			&["Foo artist", "Bar artist"]
+		);
+		assert_eq!(tag.title().as_deref(), Some("Foo title"));
+
+		assert_eq!(tag.pictures().len(), 2);
+
+		// Merging again changes nothing
+		let merged = tag.clone();
+		tag.merge(&other, &policy);
+		assert!(tag.diff(&merged).is_empty());
+	}
+
+	#[test]
+	fn prefer_larger() {
+		let (mut tag, other) = tags();
+		let policy = MergePolicy::new()
+			.items(MergeStrategy::PreferLarger)
+			.pictures(MergeStrategy::PreferLarger);
+		tag.merge(&other, &policy);
+
+		// "Foo artist" vs "Foo artist" + "Bar artist"
+		assert_eq!(tag.get_strings(&ItemKey::TrackArtist).count(), 2);
+		assert_eq!(tag.comment().as_deref(), Some("Hand-edited comment"));
+
+		assert_eq!(tag.pictures().len(), 1);
+		assert_eq!(tag.pictures()[0].data_len(), 20);
+
+		// A smaller picture doesn't replace a larger one
+		let mut smaller = Tag::new(TagType::Id3v2);
+		smaller.push_picture(picture(5));
+		tag.merge(&smaller, &policy);
+		assert_eq!(tag.pictures()[0].data_len(), 20);
+	}
+}
@@ -1,9 +1,13 @@
 //! Utilities for generic tag handling
 
 mod accessor;
+mod builder;
 pub(crate) mod companion_tag;
+mod diff;
 pub(crate) mod item;
 pub mod items;
+mod key_aliases;
+mod merge;
 mod split_merge_tag;
 mod tag_ext;
 mod tag_type;
@@ -11,9 +15,12 @@ pub(crate) mod utils;
 
 use crate::config::WriteOptions;
 use crate::error::{LoftyError, Result};
+use crate::file::write_verified;
 use crate::macros::err;
 use crate::picture::{Picture, PictureType};
 use crate::probe::Probe;
+use crate::tag::items::{Lang, ReplayGain, Timestamp};
+use crate::util::flag_item;
 use crate::util::io::{FileLike, Length, Truncate};
 
 use std::borrow::Cow;
@@ -22,11 +29,17 @@ use std::path::Path;
 
 // Exports
 pub use accessor::Accessor;
+pub use builder::TagBuilder;
+pub use diff::{ItemChange, PictureChange, TagDiff};
 pub use item::{ItemKey, ItemValue, TagItem};
+pub use key_aliases::KeyAliases;
+pub use merge::{MergePolicy, MergeStrategy};
 pub use split_merge_tag::{MergeTag, SplitTag};
 pub use tag_ext::TagExt;
 pub use tag_type::TagType;
 
+pub(crate) use builder::{verify_number_range, verify_year};
+
 macro_rules! impl_accessor {
 	($($item_key:ident => $name:tt),+) => {
 		paste::paste! {
@@ -108,10 +121,12 @@ macro_rules! impl_accessor {
 /// let id3v2_tag: Id3v2Tag = tag.into();
 /// ```
 #[derive(Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Tag {
 	tag_type: TagType,
 	pub(crate) pictures: Vec<Picture>,
 	pub(crate) items: Vec<TagItem>,
+	#[cfg_attr(feature = "serde", serde(skip))]
 	pub(crate) companion_tag: Option<companion_tag::CompanionTag>,
 }
 
@@ -131,11 +146,20 @@ pub(crate) fn try_parse_year(input: &str) -> Option<u32> {
 
 impl Accessor for Tag {
 	impl_accessor!(
-		TrackArtist => artist,
-		TrackTitle  => title,
-		AlbumTitle  => album,
-		Genre       => genre,
-		Comment     => comment
+		TrackArtist          => artist,
+		TrackTitle           => title,
+		AlbumTitle           => album,
+		Genre                => genre,
+		Comment              => comment,
+		Lyrics               => lyrics,
+		TrackTitleSortOrder  => title_sort,
+		TrackArtistSortOrder => artist_sort,
+		AlbumTitleSortOrder  => album_title_sort,
+		AlbumArtistSortOrder => album_artist_sort,
+		EncoderSettings      => encoder_settings,
+		Isrc                 => isrc,
+		Barcode              => barcode,
+		CatalogNumber        => catalog_number
 	);
 
 	fn track(&self) -> Option<u32> {
@@ -219,6 +243,45 @@ impl Accessor for Tag {
 		self.remove_key(&ItemKey::Year);
 		self.remove_key(&ItemKey::RecordingDate);
 	}
+
+	fn encoder(&self) -> Option<Cow<'_, str>> {
+		self.get_string(&ItemKey::EncoderSoftware)
+			.map(Cow::Borrowed)
+	}
+
+	fn set_encoder(&mut self, value: String) {
+		if value.is_empty() {
+			self.remove_encoder();
+			return;
+		}
+
+		self.insert_text(ItemKey::EncoderSoftware, value);
+	}
+
+	fn remove_encoder(&mut self) {
+		self.remove_key(&ItemKey::EncoderSoftware);
+	}
+
+	fn label(&self) -> Option<Cow<'_, str>> {
+		self.get_string(&ItemKey::Label)
+			.map_or_else(|| self.get_string(&ItemKey::Publisher), Some)
+			.map(Cow::Borrowed)
+	}
+
+	fn set_label(&mut self, value: String) {
+		if value.is_empty() {
+			self.remove_label();
+			return;
+		}
+
+		self.remove_key(&ItemKey::Publisher);
+		self.insert_text(ItemKey::Label, value);
+	}
+
+	fn remove_label(&mut self) {
+		self.remove_key(&ItemKey::Label);
+		self.remove_key(&ItemKey::Publisher);
+	}
 }
 
 impl Tag {
@@ -262,7 +325,11 @@ impl Tag {
 		}
 
 		self.retain(|i| i.re_map(tag_type));
-		self.tag_type = tag_type
+		self.tag_type = tag_type;
+
+		if tag_type != TagType::Id3v2 {
+			self.items.iter_mut().for_each(items::popm_item_to_text);
+		}
 	}
 
 	/// Check if the tag contains any format-specific items
@@ -348,8 +415,12 @@ impl Tag {
 	/// NOTE: This **will** verify an [`ItemKey`] mapping exists for the target [`TagType`]
 	///
 	/// This will return `true` if the item was inserted.
-	pub fn insert(&mut self, item: TagItem) -> bool {
+	pub fn insert(&mut self, mut item: TagItem) -> bool {
 		if item.re_map(self.tag_type) {
+			if self.tag_type != TagType::Id3v2 {
+				items::popm_item_to_text(&mut item);
+			}
+
 			self.insert_unchecked(item);
 			return true;
 		}
@@ -380,8 +451,12 @@ impl Tag {
 	/// the first available item will be used.
 	///
 	/// This will return `true` if the item was pushed.
-	pub fn push(&mut self, item: TagItem) -> bool {
+	pub fn push(&mut self, mut item: TagItem) -> bool {
 		if item.re_map(self.tag_type) {
+			if self.tag_type != TagType::Id3v2 {
+				items::popm_item_to_text(&mut item);
+			}
+
 			self.items.push(item);
 			return true;
 		}
@@ -483,6 +558,546 @@ impl Tag {
 		self.items.retain(|item| !item.value().is_empty());
 	}
 
+	/// Get the [`ReplayGain`] information
+	///
+	/// Any values that are missing or fail to parse will be `None`.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use lofty::tag::items::ReplayGain;
+	/// use lofty::tag::{ItemKey, Tag, TagType};
+	///
+	/// let mut tag = Tag::new(TagType::VorbisComments);
+	/// tag.insert_text(ItemKey::ReplayGainTrackGain, String::from("-6.50 dB"));
+	///
+	/// let replay_gain = tag.replaygain();
+	/// assert_eq!(replay_gain.track_gain, Some(-6.5));
+	/// assert_eq!(replay_gain.album_gain, None);
+	/// ```
+	pub fn replaygain(&self) -> ReplayGain {
+		let value = |key| {
+			self.get_string(&key)
+				.and_then(items::parse_replay_gain_value)
+		};
+
+		ReplayGain {
+			track_gain: value(ItemKey::ReplayGainTrackGain),
+			track_peak: value(ItemKey::ReplayGainTrackPeak),
+			album_gain: value(ItemKey::ReplayGainAlbumGain),
+			album_peak: value(ItemKey::ReplayGainAlbumPeak),
+		}
+	}
+
+	/// Set the [`ReplayGain`] information
+	///
+	/// Gains are stored as `"-6.50 dB"`, and peaks as `"0.988553"`. Any `None` values will remove
+	/// the existing item.
+	///
+	/// The items are mapped to the appropriate storage for each format:
+	///
+	/// * ID3v2: `TXXX` frames (`REPLAYGAIN_TRACK_GAIN`, ...)
+	/// * Vorbis Comments and APE: `REPLAYGAIN_TRACK_GAIN`, ...
+	/// * MP4: `----:com.apple.iTunes:replaygain_track_gain`, ...
+	///
+	/// NOTE: If the [`TagType`] has no mapping for ReplayGain, nothing will be stored.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use lofty::tag::items::ReplayGain;
+	/// use lofty::tag::{ItemKey, Tag, TagType};
+	///
+	/// let mut tag = Tag::new(TagType::Id3v2);
+	/// tag.set_replaygain(ReplayGain {
+	/// 	track_gain: Some(-6.5),
+	/// 	track_peak: Some(0.988553),
+	/// 	..ReplayGain::default()
+	/// });
+	///
+	/// assert_eq!(
+	/// 	tag.get_string(&ItemKey::ReplayGainTrackGain),
+	/// 	Some("-6.50 dB")
+	/// );
+	/// assert_eq!(
+	/// 	tag.get_string(&ItemKey::ReplayGainTrackPeak),
+	/// 	Some("0.988553")
+	/// );
+	/// ```
+	pub fn set_replaygain(&mut self, replay_gain: ReplayGain) {
+		for (key, value) in replay_gain.fields() {
+			match value {
+				Some(value) => {
+					self.insert_text(key, value);
+				},
+				None => self.remove_key(&key),
+			}
+		}
+	}
+
+	/// Remove all ReplayGain information
+	pub fn remove_replaygain(&mut self) {
+		self.set_replaygain(ReplayGain::default());
+	}
+
+	/// Get the rating, on a scale of 0-100
+	///
+	/// Ratings stored in ID3v2 `POPM` frames (1-255) will be rounded to the nearest star, following the
+	/// Windows Media Player convention. For example, a 4 star rating (`196`) will be `80`. If there are
+	/// multiple `POPM` frames, the one with the Windows Media Player email is preferred.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use lofty::tag::{ItemKey, Tag, TagType};
+	///
+	/// let mut tag = Tag::new(TagType::VorbisComments);
+	/// tag.insert_text(ItemKey::Popularimeter, String::from("80"));
+	///
+	/// assert_eq!(tag.rating(), Some(80));
+	/// ```
+	pub fn rating(&self) -> Option<u8> {
+		let item = self
+			.get_items(&ItemKey::Popularimeter)
+			.find(|item| items::wmp_popm_counter(item.value()).is_some())
+			.or_else(|| self.get(&ItemKey::Popularimeter))?;
+
+		items::rating_from_item_value(item.value())
+	}
+
+	/// Set the rating, on a scale of 0-100
+	///
+	/// Ratings above 100 will be clamped. The rating is mapped to the appropriate storage for each format:
+	///
+	/// * ID3v2: A `POPM` frame with the Windows Media Player email, rounded to the nearest star. `POPM` frames
+	///   with other emails are left untouched, and the play counter of an existing frame is kept.
+	/// * Vorbis Comments: `RATING`
+	/// * MP4: `rate`
+	/// * RIFF INFO: `IRTD`
+	///
+	/// NOTE: If the [`TagType`] has no mapping for ratings, nothing will be stored.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use lofty::tag::{Tag, TagType};
+	///
+	/// let mut tag = Tag::new(TagType::Id3v2);
+	///
+	/// // 3.5 stars, will be rounded to 4 stars
+	/// tag.set_rating(70);
+	/// assert_eq!(tag.rating(), Some(80));
+	/// ```
+	pub fn set_rating(&mut self, rating: u8) {
+		let rating = rating.min(100);
+
+		if self.tag_type != TagType::Id3v2 {
+			self.insert(TagItem::new(
+				ItemKey::Popularimeter,
+				ItemValue::Text(rating.to_string()),
+			));
+			return;
+		}
+
+		// ID3v2 tags can have a `POPM` frame per email, so only ours is replaced
+		let mut popm = items::popm_from_rating(rating);
+		for item in &mut self.items {
+			if item.item_key != ItemKey::Popularimeter {
+				continue;
+			}
+
+			if let Some(counter) = items::wmp_popm_counter(&item.item_value) {
+				popm.counter = counter;
+				item.item_value = ItemValue::Binary(popm.as_bytes());
+				return;
+			}
+		}
+
+		self.items.push(TagItem::new(
+			ItemKey::Popularimeter,
+			ItemValue::Binary(popm.as_bytes()),
+		));
+	}
+
+	/// Remove the rating
+	pub fn remove_rating(&mut self) {
+		self.remove_key(&ItemKey::Popularimeter);
+	}
+
+	/// Get the BPM, rounded to the nearest integer
+	///
+	/// This will check [`ItemKey::IntegerBpm`], falling back to [`ItemKey::Bpm`].
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use lofty::tag::{ItemKey, Tag, TagType};
+	///
+	/// let mut tag = Tag::new(TagType::VorbisComments);
+	/// tag.insert_text(ItemKey::Bpm, String::from("127.6"));
+	///
+	/// assert_eq!(tag.bpm(), Some(128));
+	/// ```
+	pub fn bpm(&self) -> Option<u32> {
+		let bpm = self
+			.get_string(&ItemKey::IntegerBpm)
+			.or_else(|| self.get_string(&ItemKey::Bpm))?
+			.trim();
+
+		if let Ok(bpm) = bpm.parse::<u32>() {
+			return Some(bpm);
+		}
+
+		let bpm = bpm.parse::<f64>().ok()?.round();
+		if !(0.0..=f64::from(u32::MAX)).contains(&bpm) {
+			return None;
+		}
+
+		Some(bpm as u32)
+	}
+
+	/// Set the BPM
+	///
+	/// This will use [`ItemKey::IntegerBpm`] (ID3v2 `TBPM`, MP4 `tmpo`) if the [`TagType`] supports it,
+	/// otherwise [`ItemKey::Bpm`] (Vorbis Comments `BPM`, ...).
+	///
+	/// NOTE: MP4 `tmpo` atoms are 16-bit, so values above [`i16::MAX`] will be clamped when converting to an [`Ilst`](crate::mp4::Ilst).
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use lofty::tag::{ItemKey, Tag, TagType};
+	///
+	/// let mut tag = Tag::new(TagType::Id3v2);
+	/// tag.set_bpm(128);
+	///
+	/// assert_eq!(tag.get_string(&ItemKey::IntegerBpm), Some("128"));
+	/// ```
+	pub fn set_bpm(&mut self, bpm: u32) {
+		self.remove_bpm();
+
+		if !self.insert_text(ItemKey::IntegerBpm, bpm.to_string()) {
+			self.insert_text(ItemKey::Bpm, bpm.to_string());
+		}
+	}
+
+	/// Remove the BPM
+	pub fn remove_bpm(&mut self) {
+		self.remove_key(&ItemKey::IntegerBpm);
+		self.remove_key(&ItemKey::Bpm);
+	}
+
+	/// Get the initial key of the track, such as `"Am"`
+	pub fn initial_key(&self) -> Option<&str> {
+		self.get_string(&ItemKey::InitialKey)
+	}
+
+	/// Set the initial key of the track
+	///
+	/// This is stored as-is, no notation is enforced.
+	pub fn set_initial_key(&mut self, key: String) {
+		self.insert_text(ItemKey::InitialKey, key);
+	}
+
+	/// Remove the initial key of the track
+	pub fn remove_initial_key(&mut self) {
+		self.remove_key(&ItemKey::InitialKey);
+	}
+
+	/// Whether the track is a part of a compilation
+	///
+	/// This will return `None` if the flag isn't set, or if it has an invalid value.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use lofty::tag::{Tag, TagType};
+	///
+	/// let mut tag = Tag::new(TagType::Mp4Ilst);
+	/// assert_eq!(tag.compilation(), None);
+	///
+	/// tag.set_compilation(true);
+	/// assert_eq!(tag.compilation(), Some(true));
+	/// ```
+	pub fn compilation(&self) -> Option<bool> {
+		flag_item(self.get_string(&ItemKey::FlagCompilation)?)
+	}
+
+	/// Set whether the track is a part of a compilation
+	///
+	/// This is stored as `"1"` or `"0"`, and will be converted to the appropriate type when
+	/// converting to a concrete tag (such as a boolean `cpil` atom in MP4).
+	pub fn set_compilation(&mut self, compilation: bool) {
+		self.insert_text(ItemKey::FlagCompilation, u8::from(compilation).to_string());
+	}
+
+	/// Remove the compilation flag
+	pub fn remove_compilation(&mut self) {
+		self.remove_key(&ItemKey::FlagCompilation);
+	}
+
+	/// Get the composer
+	pub fn composer(&self) -> Option<&str> {
+		self.get_string(&ItemKey::Composer)
+	}
+
+	/// Set the composer
+	pub fn set_composer(&mut self, composer: String) {
+		self.insert_text(ItemKey::Composer, composer);
+	}
+
+	/// Remove the composer
+	pub fn remove_composer(&mut self) {
+		self.remove_key(&ItemKey::Composer);
+	}
+
+	/// Get the conductor
+	pub fn conductor(&self) -> Option<&str> {
+		self.get_string(&ItemKey::Conductor)
+	}
+
+	/// Set the conductor
+	pub fn set_conductor(&mut self, conductor: String) {
+		self.insert_text(ItemKey::Conductor, conductor);
+	}
+
+	/// Remove the conductor
+	pub fn remove_conductor(&mut self) {
+		self.remove_key(&ItemKey::Conductor);
+	}
+
+	/// Get the remixer
+	///
+	/// NOTE: In ID3v2, this is stored in `TPE4`, which is also used for arrangers and other modifiers
+	pub fn remixer(&self) -> Option<&str> {
+		self.get_string(&ItemKey::Remixer)
+	}
+
+	/// Set the remixer
+	pub fn set_remixer(&mut self, remixer: String) {
+		self.insert_text(ItemKey::Remixer, remixer);
+	}
+
+	/// Remove the remixer
+	pub fn remove_remixer(&mut self) {
+		self.remove_key(&ItemKey::Remixer);
+	}
+
+	/// Get the MusicBrainz Recording ID
+	///
+	/// NOTE: In ID3v2, this is stored in a `UFID` frame owned by `http://musicbrainz.org`
+	pub fn musicbrainz_recording_id(&self) -> Option<&str> {
+		self.get_string(&ItemKey::MusicBrainzRecordingId)
+	}
+
+	/// Set the MusicBrainz Recording ID
+	pub fn set_musicbrainz_recording_id(&mut self, id: String) {
+		self.insert_text(ItemKey::MusicBrainzRecordingId, id);
+	}
+
+	/// Remove the MusicBrainz Recording ID
+	pub fn remove_musicbrainz_recording_id(&mut self) {
+		self.remove_key(&ItemKey::MusicBrainzRecordingId);
+	}
+
+	/// Get the MusicBrainz Track ID
+	pub fn musicbrainz_track_id(&self) -> Option<&str> {
+		self.get_string(&ItemKey::MusicBrainzTrackId)
+	}
+
+	/// Set the MusicBrainz Track ID
+	pub fn set_musicbrainz_track_id(&mut self, id: String) {
+		self.insert_text(ItemKey::MusicBrainzTrackId, id);
+	}
+
+	/// Remove the MusicBrainz Track ID
+	pub fn remove_musicbrainz_track_id(&mut self) {
+		self.remove_key(&ItemKey::MusicBrainzTrackId);
+	}
+
+	/// Get the MusicBrainz Release ID
+	pub fn musicbrainz_release_id(&self) -> Option<&str> {
+		self.get_string(&ItemKey::MusicBrainzReleaseId)
+	}
+
+	/// Set the MusicBrainz Release ID
+	pub fn set_musicbrainz_release_id(&mut self, id: String) {
+		self.insert_text(ItemKey::MusicBrainzReleaseId, id);
+	}
+
+	/// Remove the MusicBrainz Release ID
+	pub fn remove_musicbrainz_release_id(&mut self) {
+		self.remove_key(&ItemKey::MusicBrainzReleaseId);
+	}
+
+	/// Get the MusicBrainz Artist ID
+	pub fn musicbrainz_artist_id(&self) -> Option<&str> {
+		self.get_string(&ItemKey::MusicBrainzArtistId)
+	}
+
+	/// Set the MusicBrainz Artist ID
+	pub fn set_musicbrainz_artist_id(&mut self, id: String) {
+		self.insert_text(ItemKey::MusicBrainzArtistId, id);
+	}
+
+	/// Remove the MusicBrainz Artist ID
+	pub fn remove_musicbrainz_artist_id(&mut self) {
+		self.remove_key(&ItemKey::MusicBrainzArtistId);
+	}
+
+	/// Get the AcoustID
+	pub fn acoustid(&self) -> Option<&str> {
+		self.get_string(&ItemKey::AcoustId)
+	}
+
+	/// Set the AcoustID
+	pub fn set_acoustid(&mut self, id: String) {
+		self.insert_text(ItemKey::AcoustId, id);
+	}
+
+	/// Remove the AcoustID
+	pub fn remove_acoustid(&mut self) {
+		self.remove_key(&ItemKey::AcoustId);
+	}
+
+	/// Returns all [`ItemKey::Comment`] items
+	///
+	/// Unlike [`Accessor::comment`], this includes every comment, along with its language and
+	/// description (see [`TagItem::lang`] and [`TagItem::description`]).
+	///
+	/// NOTE: Only ID3v2 (`COMM` frames) stores the language and description. Comments from other
+	///       formats will have [`UNKNOWN_LANGUAGE`](crate::tag::items::UNKNOWN_LANGUAGE) and an empty description.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use lofty::tag::{Tag, TagType};
+	///
+	/// let mut tag = Tag::new(TagType::Id3v2);
+	/// tag.add_comment(*b"eng", String::new(), String::from("Foo comment"));
+	/// tag.add_comment(
+	/// 	*b"deu",
+	/// 	String::from("Bar description"),
+	/// 	String::from("Bar comment"),
+	/// );
+	///
+	/// let comment = tag.comments().find(|c| c.lang() == b"deu").unwrap();
+	/// assert_eq!(comment.description(), "Bar description");
+	/// assert_eq!(comment.value().text(), Some("Bar comment"));
+	/// ```
+	pub fn comments(&self) -> impl Iterator<Item = &TagItem> + Clone {
+		self.items
+			.iter()
+			.filter(|item| item.key() == &ItemKey::Comment)
+	}
+
+	/// Add a comment, keeping any existing comments
+	///
+	/// This will return `true` if the comment was added. See [`Tag::push`]
+	pub fn add_comment(&mut self, lang: Lang, description: String, text: String) -> bool {
+		let mut item = TagItem::new(ItemKey::Comment, ItemValue::Text(text));
+		item.set_lang(lang);
+		item.set_description(description);
+
+		self.push(item)
+	}
+
+	/// Remove all comments matching the predicate
+	///
+	/// Items that aren't comments are not passed to `f`.
+	pub fn remove_comments_matching<F>(&mut self, mut f: F)
+	where
+		F: FnMut(&TagItem) -> bool,
+	{
+		self.items
+			.retain(|item| item.key() != &ItemKey::Comment || !f(item));
+	}
+
+	/// Get the recording date as a [`Timestamp`]
+	///
+	/// This will check [`ItemKey::RecordingDate`], falling back to [`ItemKey::Year`]. If the date
+	/// is not a valid ISO 8601 timestamp (such as `2024/05/06`), only the year will be used.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use lofty::tag::items::Timestamp;
+	/// use lofty::tag::{ItemKey, Tag, TagType};
+	///
+	/// let mut tag = Tag::new(TagType::Mp4Ilst);
+	/// tag.insert_text(ItemKey::RecordingDate, String::from("2024-05-06T07:08:00Z"));
+	///
+	/// let date = tag.date().unwrap();
+	/// assert_eq!(date.year, 2024);
+	/// assert_eq!(date.month, Some(5));
+	/// assert_eq!(date.day, Some(6));
+	/// ```
+	pub fn date(&self) -> Option<Timestamp> {
+		let date = self
+			.get_string(&ItemKey::RecordingDate)
+			.or_else(|| self.get_string(&ItemKey::Year))?
+			.trim();
+
+		if let Ok(timestamp) = date.parse::<Timestamp>() {
+			return Some(timestamp);
+		}
+
+		let year = u16::try_from(try_parse_year(date)?).ok()?;
+		Some(Timestamp {
+			year,
+			..Timestamp::default()
+		})
+	}
+
+	/// Set the recording date
+	///
+	/// The date is stored as an ISO 8601 timestamp (`2024-05-06T07:08`) in [`ItemKey::RecordingDate`],
+	/// which maps to:
+	///
+	/// * ID3v2: `TDRC` (or `TYER`, `TDAT`, and `TIME` when writing ID3v2.3)
+	/// * Vorbis Comments: `DATE`
+	/// * MP4: `©day`
+	/// * RIFF INFO: `ICRD`
+	///
+	/// If the [`TagType`] has no mapping for [`ItemKey::RecordingDate`], [`ItemKey::Year`] will be used instead.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use lofty::tag::items::Timestamp;
+	/// use lofty::tag::{ItemKey, Tag, TagType};
+	///
+	/// let mut tag = Tag::new(TagType::VorbisComments);
+	/// tag.set_date(Timestamp {
+	/// 	year: 2024,
+	/// 	month: Some(5),
+	/// 	day: Some(6),
+	/// 	..Timestamp::default()
+	/// });
+	///
+	/// assert_eq!(tag.get_string(&ItemKey::RecordingDate), Some("2024-05-06"));
+	/// ```
+	pub fn set_date(&mut self, date: Timestamp) {
+		if ItemKey::RecordingDate
+			.map_key(self.tag_type, false)
+			.is_some()
+		{
+			self.remove_key(&ItemKey::Year);
+			self.insert_text(ItemKey::RecordingDate, date.to_string());
+		} else {
+			self.remove_key(&ItemKey::RecordingDate);
+			self.insert_text(ItemKey::Year, date.to_string());
+		}
+	}
+
+	/// Remove the recording date
+	///
+	/// This will remove both [`ItemKey::RecordingDate`] and [`ItemKey::Year`].
+	pub fn remove_date(&mut self) {
+		self.remove_key(&ItemKey::RecordingDate);
+		self.remove_key(&ItemKey::Year);
+	}
+
 	/// Returns the stored [`Picture`]s as a slice
 	pub fn pictures(&self) -> &[Picture] {
 		&self.pictures
@@ -589,6 +1204,73 @@ impl Tag {
 	pub fn remove_picture(&mut self, index: usize) -> Picture {
 		self.pictures.remove(index)
 	}
+
+	/// Compare this tag to `other`, treating `other` as the newer tag
+	///
+	/// This can be used to preview the changes that will be made to a file before saving, or to
+	/// verify that items survive a conversion between tag types.
+	///
+	/// See [`TagDiff`] for details.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use lofty::tag::{Accessor, ItemChange, ItemKey, Tag, TagType};
+	///
+	/// let mut tag = Tag::new(TagType::Id3v2);
+	/// tag.set_title(String::from("Foo title"));
+	///
+	/// let mut new_tag = tag.clone();
+	/// new_tag.set_artist(String::from("Foo artist"));
+	///
+	/// let diff = tag.diff(&new_tag);
+	/// assert_eq!(diff.items().len(), 1);
+	/// assert!(matches!(
+	/// 	&diff.items()[0],
+	/// 	ItemChange::Added { key: ItemKey::TrackArtist, .. }
+	/// ));
+	/// ```
+	pub fn diff(&self, other: &Tag) -> TagDiff {
+		diff::diff_tags(self, other)
+	}
+
+	/// Merge the items and pictures of `other` into this tag
+	///
+	/// Items and pictures that only exist in `other` are always added, while conflicts are resolved
+	/// according to `policy`. Items that can't be stored in this tag's [`TagType`] are skipped.
+	///
+	/// Since a `Tag` can be converted to and from any concrete tag type, this can be used to merge
+	/// tags of any type.
+	///
+	/// See [`MergePolicy`] for details.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use lofty::tag::{Accessor, MergePolicy, MergeStrategy, Tag, TagType};
+	///
+	/// // A tag with hand-edited items
+	/// let mut tag = Tag::new(TagType::Id3v2);
+	/// tag.set_title(String::from("Foo title (Live)"));
+	///
+	/// // A tag from an online source
+	/// let mut online_tag = Tag::new(TagType::VorbisComments);
+	/// online_tag.set_title(String::from("Foo title"));
+	/// online_tag.set_album(String::from("Foo album"));
+	///
+	/// // The existing title is kept, and the missing album is added
+	/// tag.merge(&online_tag, &MergePolicy::new());
+	/// assert_eq!(tag.title().as_deref(), Some("Foo title (Live)"));
+	/// assert_eq!(tag.album().as_deref(), Some("Foo album"));
+	///
+	/// // Or the online source can take priority
+	/// let policy = MergePolicy::new().items(MergeStrategy::PreferOther);
+	/// tag.merge(&online_tag, &policy);
+	/// assert_eq!(tag.title().as_deref(), Some("Foo title"));
+	/// ```
+	pub fn merge(&mut self, other: &Tag, policy: &MergePolicy) {
+		merge::merge_tags(self, other, policy)
+	}
 }
 
 impl TagExt for Tag {
@@ -628,18 +1310,20 @@ impl TagExt for Tag {
 		LoftyError: From<<F as Truncate>::Error>,
 		LoftyError: From<<F as Length>::Error>,
 	{
-		let probe = Probe::new(file).guess_file_type()?;
-
-		match probe.file_type() {
-			Some(file_type) => {
-				if file_type.supports_tag_type(self.tag_type()) {
-					utils::write_tag(self, probe.into_inner(), file_type, write_options)
-				} else {
-					err!(UnsupportedTag);
-				}
-			},
-			None => err!(UnknownFormat),
-		}
+		write_verified(file, write_options, |file, write_options| {
+			let probe = Probe::new(file).guess_file_type()?;
+
+			match probe.file_type() {
+				Some(file_type) => {
+					if file_type.supports_tag_type(self.tag_type()) {
+						utils::write_tag(self, probe.into_inner(), file_type, write_options)
+					} else {
+						err!(UnsupportedTag);
+					}
+				},
+				None => err!(UnknownFormat),
+			}
+		})
 	}
 
 	fn dump_to<W: Write>(&self, writer: &mut W, write_options: WriteOptions) -> Result<()> {
@@ -699,8 +1383,12 @@ impl MergeTag for SplitTagRemainder {
 mod tests {
 	use super::try_parse_year;
 	use crate::config::WriteOptions;
+	use crate::id3::v2::Id3v2Tag;
+	use crate::mp4::Ilst;
+	use crate::ogg::VorbisComments;
 	use crate::picture::{Picture, PictureType};
 	use crate::prelude::*;
+	use crate::tag::items::Timestamp;
 	use crate::tag::utils::test_utils::read_path;
 	use crate::tag::{Tag, TagType};
 
@@ -811,4 +1499,413 @@ mod tests {
 		assert!(try_parse_year("19").is_none());
 		assert!(try_parse_year("1").is_none());
 	}
+
+	#[test]
+	fn date() {
+		let timestamp = Timestamp {
+			year: 2024,
+			month: Some(5),
+			day: Some(6),
+			hour: Some(7),
+			minute: Some(8),
+			second: None,
+		};
+
+		let mut tag = Tag::new(TagType::Id3v2);
+		tag.set_date(timestamp);
+		assert_eq!(tag.date(), Some(timestamp));
+
+		// The date should survive a round trip through the concrete tag types
+		let id3v2: Id3v2Tag = tag.clone().into();
+		let tag: Tag = id3v2.into();
+		assert_eq!(tag.date(), Some(timestamp));
+
+		let ilst: Ilst = tag.clone().into();
+		let tag: Tag = ilst.into();
+		assert_eq!(tag.date(), Some(timestamp));
+
+		// APE has no recording date, so `Year` is used
+		let mut tag = Tag::new(TagType::Ape);
+		tag.set_date(timestamp);
+		assert_eq!(tag.get_string(&ItemKey::Year), Some("2024-05-06T07:08"));
+		assert_eq!(tag.date(), Some(timestamp));
+
+		// Fall back to the year for non-ISO 8601 dates
+		let mut tag = Tag::new(TagType::VorbisComments);
+		tag.insert_text(ItemKey::RecordingDate, String::from("2024/05/06"));
+		assert_eq!(
+			tag.date(),
+			Some(Timestamp {
+				year: 2024,
+				..Timestamp::default()
+			})
+		);
+
+		tag.remove_date();
+		assert!(tag.date().is_none());
+	}
+
+	#[test]
+	fn rating() {
+		use crate::id3::v2::{Frame, PopularimeterFrame};
+		use crate::iff::wav::RiffInfoList;
+		use crate::mp4::{AtomData, AtomIdent, Ilst};
+		use crate::ogg::VorbisComments;
+
+		let mut vorbis_comments = VorbisComments::default();
+		vorbis_comments.push(String::from("RATING"), String::from("80"));
+
+		let tag: Tag = vorbis_comments.into();
+		assert_eq!(tag.rating(), Some(80));
+
+		// Sync the rating to an ID3v2 tag
+		let mut id3v2_tag = Tag::new(TagType::Id3v2);
+		id3v2_tag.set_rating(tag.rating().unwrap());
+
+		let id3v2: Id3v2Tag = id3v2_tag.into();
+		let Some(Frame::Popularimeter(popm)) = id3v2.into_iter().next() else {
+			panic!("Expected a POPM frame");
+		};
+		assert_eq!(popm.email, "Windows Media Player 9 Series");
+		assert_eq!(popm.rating, 196);
+
+		// Existing POPM frames are available through `Tag`
+		let mut id3v2 = Id3v2Tag::new();
+		id3v2.insert(Frame::Popularimeter(PopularimeterFrame::new(
+			String::from("foo@bar.com"),
+			255,
+			10,
+		)));
+
+		let mut tag: Tag = id3v2.into();
+		assert_eq!(tag.rating(), Some(100));
+
+		let id3v2: Id3v2Tag = tag.clone().into();
+		let Some(Frame::Popularimeter(popm)) = id3v2.into_iter().next() else {
+			panic!("Expected a POPM frame");
+		};
+		assert_eq!(popm.email, "foo@bar.com");
+		assert_eq!(popm.counter, 10);
+
+		// Setting the rating keeps the other `POPM` frames
+		tag.set_rating(60);
+		assert_eq!(tag.rating(), Some(60));
+		assert_eq!(tag.get_items(&ItemKey::Popularimeter).count(), 2);
+
+		// And replaces our own, keeping its play counter
+		let mut id3v2: Id3v2Tag = tag.clone().into();
+		id3v2.insert(Frame::Popularimeter(PopularimeterFrame::new(
+			String::from("Windows Media Player 9 Series"),
+			64,
+			5,
+		)));
+
+		let mut tag: Tag = id3v2.into();
+		tag.set_rating(100);
+		assert_eq!(tag.rating(), Some(100));
+		assert_eq!(tag.get_items(&ItemKey::Popularimeter).count(), 2);
+
+		let id3v2: Id3v2Tag = tag.clone().into();
+		let counters = id3v2
+			.into_iter()
+			.filter_map(|frame| match frame {
+				Frame::Popularimeter(popm) => Some((popm.email, popm.rating, popm.counter)),
+				_ => None,
+			})
+			.collect::<Vec<_>>();
+		assert!(counters.contains(&(String::from("foo@bar.com"), 255, 10)));
+		assert!(counters.contains(&(String::from("Windows Media Player 9 Series"), 255, 5)));
+
+		tag.remove_rating();
+		assert!(tag.rating().is_none());
+
+		// Text ratings are converted when writing ID3v2
+		let mut tag = Tag::new(TagType::Id3v2);
+		tag.insert_text(ItemKey::Popularimeter, String::from("40"));
+
+		let id3v2: Id3v2Tag = tag.into();
+		let Some(Frame::Popularimeter(popm)) = (&id3v2).into_iter().next() else {
+			panic!("Expected a POPM frame");
+		};
+		assert_eq!(popm.rating, 64);
+
+		// And `POPM` frames are converted to text ratings for every other format
+		let tag: Tag = id3v2.into();
+
+		let vorbis_comments: VorbisComments = tag.clone().into();
+		assert_eq!(vorbis_comments.get("RATING"), Some("40"));
+
+		let ilst: Ilst = tag.clone().into();
+		assert_eq!(
+			ilst.get(&AtomIdent::Fourcc(*b"rate"))
+				.and_then(|atom| atom.data().next()),
+			Some(&AtomData::UTF8(String::from("40")))
+		);
+
+		let riff_info: RiffInfoList = tag.clone().into();
+		assert_eq!(riff_info.get("IRTD"), Some("40"));
+
+		let mut tag = tag;
+		tag.re_map(TagType::VorbisComments);
+		assert_eq!(tag.get_string(&ItemKey::Popularimeter), Some("40"));
+	}
+
+	#[test]
+	fn extended_fields() {
+		use crate::mp4::{AtomData, AtomIdent};
+
+		// ID3v2
+		let mut tag = Tag::new(TagType::Id3v2);
+		tag.set_bpm(128);
+		tag.set_initial_key(String::from("Am"));
+		tag.set_compilation(true);
+		tag.set_composer(String::from("Foo composer"));
+		tag.set_conductor(String::from("Bar conductor"));
+		tag.set_remixer(String::from("Baz remixer"));
+
+		let id3v2: Id3v2Tag = tag.into();
+		let tag: Tag = id3v2.into();
+		assert_eq!(tag.bpm(), Some(128));
+		assert_eq!(tag.initial_key(), Some("Am"));
+		assert_eq!(tag.compilation(), Some(true));
+		assert_eq!(tag.composer(), Some("Foo composer"));
+		assert_eq!(tag.conductor(), Some("Bar conductor"));
+		assert_eq!(tag.remixer(), Some("Baz remixer"));
+
+		// MP4, BPM is stored as an integer
+		let mut tag = Tag::new(TagType::Mp4Ilst);
+		tag.set_bpm(128);
+		tag.set_initial_key(String::from("Am"));
+		tag.set_compilation(true);
+		tag.set_composer(String::from("Foo composer"));
+
+		let ilst: Ilst = tag.into();
+		assert_eq!(
+			ilst.get(&AtomIdent::Fourcc(*b"tmpo"))
+				.unwrap()
+				.data()
+				.next()
+				.unwrap(),
+			&AtomData::Unknown {
+				code: 21,
+				data: vec![0, 128]
+			}
+		);
+
+		let tag: Tag = ilst.into();
+		assert_eq!(tag.bpm(), Some(128));
+		assert_eq!(tag.initial_key(), Some("Am"));
+		assert_eq!(tag.compilation(), Some(true));
+		assert_eq!(tag.composer(), Some("Foo composer"));
+
+		// `tmpo` can't store values above `i16::MAX`
+		let mut tag = Tag::new(TagType::Mp4Ilst);
+		tag.set_bpm(40_000);
+
+		let ilst: Ilst = tag.into();
+		let tag: Tag = ilst.into();
+		assert_eq!(tag.bpm(), Some(32767));
+
+		// Vorbis Comments have no integer BPM
+		let mut tag = Tag::new(TagType::VorbisComments);
+		tag.set_bpm(128);
+		tag.set_compilation(true);
+		tag.set_conductor(String::from("Bar conductor"));
+		tag.set_remixer(String::from("Baz remixer"));
+		assert_eq!(tag.get_string(&ItemKey::Bpm), Some("128"));
+
+		let vorbis_comments: VorbisComments = tag.into();
+		assert_eq!(vorbis_comments.get("BPM"), Some("128"));
+
+		let mut tag: Tag = vorbis_comments.into();
+		assert_eq!(tag.bpm(), Some(128));
+		assert_eq!(tag.compilation(), Some(true));
+		assert_eq!(tag.conductor(), Some("Bar conductor"));
+		assert_eq!(tag.remixer(), Some("Baz remixer"));
+
+		tag.remove_bpm();
+		tag.remove_compilation();
+		assert!(tag.bpm().is_none());
+		assert!(tag.compilation().is_none());
+	}
+
+	#[test]
+	fn identifiers() {
+		const RECORDING_ID: &str = "189002e7-3285-4e2e-92a3-7f6c30d407a2";
+		const RELEASE_ID: &str = "e6a9f7b4-3a7e-4b6f-8a42-5d0f9c1d2b11";
+		const ACOUSTID: &str = "0f9a8b7c-6d5e-4f3a-2b1c-0d9e8f7a6b5c";
+
+		// ID3v2, stored in `TXXX` frames
+		let mut tag = Tag::new(TagType::Id3v2);
+		tag.set_musicbrainz_release_id(String::from(RELEASE_ID));
+		tag.set_acoustid(String::from(ACOUSTID));
+
+		let id3v2: Id3v2Tag = tag.into();
+		assert_eq!(id3v2.get_user_text("Acoustid Id"), Some(ACOUSTID));
+		assert_eq!(
+			id3v2.get_user_text("MusicBrainz Album Id"),
+			Some(RELEASE_ID)
+		);
+
+		let tag: Tag = id3v2.into();
+		assert_eq!(tag.musicbrainz_release_id(), Some(RELEASE_ID));
+		assert_eq!(tag.acoustid(), Some(ACOUSTID));
+
+		// MP4
+		let mut tag = Tag::new(TagType::Mp4Ilst);
+		tag.set_musicbrainz_recording_id(String::from(RECORDING_ID));
+		tag.set_musicbrainz_artist_id(String::from("2c4f5f8e-6c7d-4e1a-9f3b-0a1b2c3d4e5f"));
+
+		let ilst: Ilst = tag.into();
+		let tag: Tag = ilst.into();
+		assert_eq!(tag.musicbrainz_recording_id(), Some(RECORDING_ID));
+		assert_eq!(
+			tag.musicbrainz_artist_id(),
+			Some("2c4f5f8e-6c7d-4e1a-9f3b-0a1b2c3d4e5f")
+		);
+
+		// Vorbis Comments, the recording ID is stored as `MUSICBRAINZ_TRACKID`
+		let mut tag = Tag::new(TagType::VorbisComments);
+		tag.set_musicbrainz_recording_id(String::from(RECORDING_ID));
+		tag.set_musicbrainz_track_id(String::from("8d1fa7e5-bd4d-4b0e-9b2c-4f0d5a1a2d5b"));
+		tag.set_acoustid(String::from(ACOUSTID));
+
+		let vorbis_comments: VorbisComments = tag.into();
+		assert_eq!(vorbis_comments.get("ACOUSTID_ID"), Some(ACOUSTID));
+		assert_eq!(
+			vorbis_comments.get("MUSICBRAINZ_TRACKID"),
+			Some(RECORDING_ID)
+		);
+
+		let mut tag: Tag = vorbis_comments.into();
+		assert_eq!(tag.musicbrainz_recording_id(), Some(RECORDING_ID));
+		assert_eq!(
+			tag.musicbrainz_track_id(),
+			Some("8d1fa7e5-bd4d-4b0e-9b2c-4f0d5a1a2d5b")
+		);
+		assert_eq!(tag.acoustid(), Some(ACOUSTID));
+
+		tag.remove_acoustid();
+		tag.remove_musicbrainz_recording_id();
+		assert!(tag.acoustid().is_none());
+		assert!(tag.musicbrainz_recording_id().is_none());
+	}
+
+	#[test]
+	#[cfg(feature = "serde")]
+	fn serde_round_trip() {
+		use crate::picture::MimeType;
+		use crate::tag::{ItemValue, TagItem};
+
+		let mut tag = Tag::new(TagType::Id3v2);
+		tag.set_title(String::from("Foo title"));
+		tag.insert_unchecked(TagItem::new(
+			ItemKey::Unknown(String::from("FOO")),
+			ItemValue::Binary(vec![1, 2, 3]),
+		));
+		tag.push_picture(Picture::new_unchecked(
+			PictureType::CoverFront,
+			Some(MimeType::Png),
+			Some(String::from("Foo description")),
+			vec![1; 10],
+		));
+
+		let json = serde_json::to_string(&tag).unwrap();
+		let deserialized: Tag = serde_json::from_str(&json).unwrap();
+
+		assert_eq!(deserialized.tag_type(), TagType::Id3v2);
+		assert!(deserialized.items().eq(tag.items()));
+		assert_eq!(deserialized.pictures(), tag.pictures());
+	}
+
+	#[test]
+	fn comments() {
+		let mut tag = Tag::new(TagType::Id3v2);
+		assert!(tag.add_comment(*b"eng", String::new(), String::from("Foo comment")));
+		assert!(tag.add_comment(
+			*b"deu",
+			String::from("Bar description"),
+			String::from("Bar comment")
+		));
+		assert_eq!(tag.comments().count(), 2);
+
+		// The language and description survive a round trip through `COMM` frames
+		let id3v2: Id3v2Tag = tag.into();
+		assert_eq!(
+			(&id3v2)
+				.into_iter()
+				.filter(|frame| frame.id_str() == "COMM")
+				.count(),
+			2
+		);
+
+		let mut tag: Tag = id3v2.into();
+		let comment = tag.comments().find(|c| c.lang() == b"deu").unwrap();
+		assert_eq!(comment.description(), "Bar description");
+		assert_eq!(comment.value().text(), Some("Bar comment"));
+
+		tag.remove_comments_matching(|c| c.lang() == b"deu");
+
+		let comments = tag.comments().collect::<Vec<_>>();
+		assert_eq!(comments.len(), 1);
+		assert_eq!(comments[0].lang(), b"eng");
+		assert_eq!(comments[0].value().text(), Some("Foo comment"));
+	}
+
+	#[test]
+	fn sort_order_conversions() {
+		let mut id3v2 = Id3v2Tag::new();
+		id3v2.set_title_sort(String::from("Foo title"));
+		id3v2.set_artist_sort(String::from("Bar artist"));
+		id3v2.set_album_title_sort(String::from("Baz album"));
+		id3v2.set_album_artist_sort(String::from("Qux album artist"));
+
+		let tag: Tag = id3v2.into();
+		assert_eq!(tag.title_sort().as_deref(), Some("Foo title"));
+		assert_eq!(tag.artist_sort().as_deref(), Some("Bar artist"));
+		assert_eq!(tag.album_title_sort().as_deref(), Some("Baz album"));
+		assert_eq!(tag.album_artist_sort().as_deref(), Some("Qux album artist"));
+
+		let ilst: Ilst = tag.clone().into();
+		assert_eq!(ilst.title_sort().as_deref(), Some("Foo title"));
+		assert_eq!(ilst.artist_sort().as_deref(), Some("Bar artist"));
+		assert_eq!(ilst.album_title_sort().as_deref(), Some("Baz album"));
+		assert_eq!(
+			ilst.album_artist_sort().as_deref(),
+			Some("Qux album artist")
+		);
+
+		let vorbis_comments: VorbisComments = tag.into();
+		assert_eq!(vorbis_comments.get("TITLESORT"), Some("Foo title"));
+		assert_eq!(vorbis_comments.get("ARTISTSORT"), Some("Bar artist"));
+		assert_eq!(vorbis_comments.get("ALBUMSORT"), Some("Baz album"));
+		assert_eq!(
+			vorbis_comments.get("ALBUMARTISTSORT"),
+			Some("Qux album artist")
+		);
+
+		// And back again
+		let id3v2: Id3v2Tag = Tag::from(vorbis_comments).into();
+		assert_eq!(id3v2.title_sort().as_deref(), Some("Foo title"));
+		assert_eq!(
+			id3v2.album_artist_sort().as_deref(),
+			Some("Qux album artist")
+		);
+	}
+
+	#[test]
+	fn tags_are_send_and_sync() {
+		fn assert_send_sync<T: Send + Sync + 'static>() {}
+
+		// Tags are owned, and can be sent across threads or kept after the file is dropped
+		assert_send_sync::<Tag>();
+		assert_send_sync::<crate::file::TaggedFile>();
+		assert_send_sync::<crate::ape::ApeTag>();
+		assert_send_sync::<crate::id3::v1::Id3v1Tag>();
+		assert_send_sync::<Id3v2Tag>();
+		assert_send_sync::<crate::iff::aiff::AiffTextChunks>();
+		assert_send_sync::<crate::iff::wav::RiffInfoList>();
+		assert_send_sync::<Ilst>();
+		assert_send_sync::<crate::ogg::VorbisComments>();
+	}
 }
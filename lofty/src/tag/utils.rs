@@ -4,7 +4,7 @@ use crate::file::FileType;
 use crate::macros::err;
 use crate::tag::{Tag, TagType};
 use crate::util::io::{FileLike, Length, Truncate};
-use crate::{aac, ape, flac, iff, mpeg, musepack, wavpack};
+use crate::{aac, ape, dsf, flac, iff, mpeg, musepack, optimfrog, wavpack};
 
 use crate::id3::v1::tag::Id3v1TagRef;
 use crate::id3::v2::tag::Id3v2TagRef;
@@ -13,9 +13,9 @@ use crate::mp4::Ilst;
 use crate::ogg::tag::{create_vorbis_comments_ref, VorbisCommentsRef};
 use ape::tag::ApeTagRef;
 use iff::aiff::tag::AiffTextChunksRef;
-use iff::wav::tag::RIFFInfoListRef;
+use iff::wav::RiffInfoList;
 
-use std::io::Write;
+use std::io::{Seek, Write};
 
 #[allow(unreachable_patterns)]
 pub(crate) fn write_tag<F>(
@@ -33,18 +33,44 @@ where
 		FileType::Aac => aac::write::write_to(file, tag, write_options),
 		FileType::Aiff => iff::aiff::write::write_to(file, tag, write_options),
 		FileType::Ape => ape::write::write_to(file, tag, write_options),
+		FileType::Dsf => dsf::write::write_to(file, tag, write_options),
 		FileType::Flac => flac::write::write_to(file, tag, write_options),
 		FileType::Opus | FileType::Speex | FileType::Vorbis => {
 			crate::ogg::write::write_to(file, tag, file_type, write_options)
 		},
 		FileType::Mpc => musepack::write::write_to(file, tag, write_options),
-		FileType::Mpeg => mpeg::write::write_to(file, tag, write_options),
+		FileType::Mpeg => {
+			mpeg::write::write_to(file, tag, write_options)?;
+
+			if write_options.sync_id3v1 && tag.tag_type() == TagType::Id3v2 {
+				let id3v1: Id3v1TagRef<'_> = tag.into();
+				crate::id3::v1::write::write_synced_id3v1(file, &id3v1, write_options)?;
+			}
+
+			Ok(())
+		},
+		FileType::OptimFrog => optimfrog::write::write_to(file, tag, write_options),
 		FileType::Mp4 => crate::mp4::ilst::write::write_to(
 			file,
 			&mut Into::<Ilst>::into(tag.clone()).as_ref(),
 			write_options,
 		),
-		FileType::Wav => iff::wav::write::write_to(file, tag, write_options),
+		FileType::Wav => {
+			iff::wav::write::write_to(file, tag, write_options)?;
+
+			// WAV files can hold both an ID3v2 chunk and a RIFF INFO list, only keep the one written
+			if write_options.remove_others {
+				let other = match tag.tag_type() {
+					TagType::Id3v2 => TagType::RiffInfo,
+					_ => TagType::Id3v2,
+				};
+
+				file.rewind()?;
+				iff::wav::write::write_to(file, &Tag::new(other), write_options)?;
+			}
+
+			Ok(())
+		},
 		FileType::WavPack => wavpack::write::write_to(file, tag, write_options),
 		_ => err!(UnsupportedTag),
 	}
@@ -81,10 +107,9 @@ pub(crate) fn dump_tag<W: Write>(
 			}
 			.dump_to(writer, write_options)
 		},
-		TagType::RiffInfo => RIFFInfoListRef {
-			items: iff::wav::tag::tagitems_into_riff(tag.items()),
-		}
-		.dump_to(writer, write_options),
+		TagType::RiffInfo => Into::<RiffInfoList>::into(tag)
+			.as_ref()
+			.dump_to(writer, write_options),
 		TagType::AiffText => {
 			use crate::tag::item::ItemKey;
 
@@ -0,0 +1,231 @@
+//! Utilities for testing tag round trips
+//!
+//! This module is only available with the `test_utils` feature.
+//!
+//! [`TagGenerator`] creates random, but valid, [`Tag`]s from a seed, which can then be written to and
+//! read back from a file with [`round_trip`]. This can be used to verify that no information is lost
+//! when writing a tag, for example in a custom tag implementation or an unusual file.
+//!
+//! All of this can be done in memory, as any [`FileLike`] can be used, such as a `Cursor<Vec<u8>>`.
+//!
+//! # Examples
+//!
+//! ```rust,no_run
+//! use lofty::tag::TagType;
+//! use lofty::test_utils::{assert_round_trip, TagGenerator};
+//! use std::io::Cursor;
+//!
+//! # fn main() -> lofty::error::Result<()> {
+//! let mp3 = std::fs::read("foo.mp3")?;
+//!
+//! let mut generator = TagGenerator::new(0);
+//! for _ in 0..100 {
+//! 	let tag = generator.generate(TagType::Id3v2);
+//! 	assert_round_trip(&tag, &mut Cursor::new(mp3.clone()));
+//! }
+//! # Ok(()) }
+//! ```
+
+use crate::config::{ParseOptions, WriteOptions};
+use crate::error::{LoftyError, Result};
+use crate::file::TaggedFileExt;
+use crate::id3::v1::GENRES;
+use crate::probe::Probe;
+use crate::tag::{ItemKey, ItemValue, Tag, TagExt, TagType};
+use crate::util::io::{FileLike, Length, Truncate};
+
+use std::io::Seek;
+
+const GENERATED_KEYS: [ItemKey; 9] = [
+	ItemKey::TrackTitle,
+	ItemKey::TrackArtist,
+	ItemKey::AlbumTitle,
+	ItemKey::AlbumArtist,
+	ItemKey::Composer,
+	ItemKey::Comment,
+	ItemKey::Genre,
+	ItemKey::TrackNumber,
+	ItemKey::DiscNumber,
+];
+
+const TEXT_CHARACTERS: &[u8] = b"abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ0123456789";
+
+// Long enough for the items to fit in an ID3v1 tag
+const MAX_TEXT_LENGTH: u64 = 20;
+
+/// Generates random tags
+///
+/// The same seed will always produce the same sequence of tags.
+///
+/// Only a small set of common items are generated, and their values are kept short and simple,
+/// so the tags can be stored losslessly in every format that supports the items.
+#[derive(Debug, Clone)]
+pub struct TagGenerator {
+	state: u64,
+}
+
+impl TagGenerator {
+	/// Create a new `TagGenerator` from a seed
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use lofty::tag::TagType;
+	/// use lofty::test_utils::TagGenerator;
+	///
+	/// let tag = TagGenerator::new(0).generate(TagType::Id3v2);
+	/// let same_tag = TagGenerator::new(0).generate(TagType::Id3v2);
+	///
+	/// assert_eq!(tag.items().count(), same_tag.items().count());
+	/// ```
+	pub fn new(seed: u64) -> Self {
+		Self { state: seed }
+	}
+
+	/// Generate a random tag of type `tag_type`
+	///
+	/// Items that can't be represented in `tag_type` will not be included.
+	pub fn generate(&mut self, tag_type: TagType) -> Tag {
+		let mut tag = Tag::new(tag_type);
+
+		for key in GENERATED_KEYS {
+			if self.next() % 2 == 0 {
+				continue;
+			}
+
+			let value = match key {
+				ItemKey::TrackNumber | ItemKey::DiscNumber => (self.next() % 99 + 1).to_string(),
+				ItemKey::Genre => {
+					let index = (self.next() % GENRES.len() as u64) as usize;
+					GENRES[index].to_string()
+				},
+				_ => self.text(),
+			};
+
+			tag.insert_text(key, value);
+		}
+
+		tag
+	}
+
+	fn text(&mut self) -> String {
+		let len = self.next() % MAX_TEXT_LENGTH + 1;
+
+		(0..len)
+			.map(|_| {
+				let index = (self.next() % TEXT_CHARACTERS.len() as u64) as usize;
+				char::from(TEXT_CHARACTERS[index])
+			})
+			.collect()
+	}
+
+	// SplitMix64
+	fn next(&mut self) -> u64 {
+		self.state = self.state.wrapping_add(0x9E37_79B9_7F4A_7C15);
+
+		let mut z = self.state;
+		z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+		z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+		z ^ (z >> 31)
+	}
+}
+
+/// Write `tag` to `file`, and read it back
+///
+/// If the tag is empty, it will be removed from the file, and the returned tag will be empty.
+///
+/// NOTE: Only the tag is read back, audio properties are not read.
+///
+/// # Errors
+///
+/// * `file` is not a supported format
+/// * `tag` cannot be written to `file`
+/// * See [`TagExt::save_to`] and [`Probe::read`]
+pub fn round_trip<F>(tag: &Tag, file: &mut F) -> Result<Tag>
+where
+	F: FileLike,
+	LoftyError: From<<F as Truncate>::Error>,
+	LoftyError: From<<F as Length>::Error>,
+{
+	file.rewind()?;
+	tag.save_to(file, WriteOptions::default())?;
+	file.rewind()?;
+
+	let tagged_file = Probe::new(file)
+		.options(ParseOptions::new().read_properties(false))
+		.guess_file_type()?
+		.read()?;
+
+	Ok(tagged_file
+		.tag(tag.tag_type())
+		.cloned()
+		.unwrap_or_else(|| Tag::new(tag.tag_type())))
+}
+
+/// Assert that every text item in `tag` survives a [`round_trip`] through `file`
+///
+/// The re-read tag is allowed to contain additional items, as some formats will add items on write.
+///
+/// # Panics
+///
+/// * [`round_trip`] fails
+/// * An item from `tag` is missing or differs in the re-read tag
+#[track_caller]
+pub fn assert_round_trip<F>(tag: &Tag, file: &mut F)
+where
+	F: FileLike,
+	LoftyError: From<<F as Truncate>::Error>,
+	LoftyError: From<<F as Length>::Error>,
+{
+	let read_tag = match round_trip(tag, file) {
+		Ok(read_tag) => read_tag,
+		Err(e) => panic!("Failed to round trip {:?} tag: {e}", tag.tag_type()),
+	};
+
+	for item in tag.items() {
+		let ItemValue::Text(value) = item.value() else {
+			continue;
+		};
+
+		let key = item.key();
+		assert!(
+			read_tag
+				.get_strings(key)
+				.any(|read_value| read_value == value),
+			"{:?} tag: expected {key:?} to be {value:?}, found {:?}",
+			tag.tag_type(),
+			read_tag.get_strings(key).collect::<Vec<_>>()
+		);
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::{assert_round_trip, TagGenerator};
+	use crate::tag::TagType;
+
+	use std::io::Cursor;
+
+	#[test]
+	fn generated_tags_round_trip() {
+		let formats = [
+			("full_test.mp3", TagType::Id3v2),
+			("full_test.mp3", TagType::Ape),
+			("full_test.mp3", TagType::Id3v1),
+			("full_test.flac", TagType::VorbisComments),
+			("m4a_codec_aac.m4a", TagType::Mp4Ilst),
+			("wav_format_pcm.wav", TagType::RiffInfo),
+			("full_test.aiff", TagType::AiffText),
+		];
+
+		for (file_name, tag_type) in formats {
+			let content = std::fs::read(format!("tests/files/assets/minimal/{file_name}")).unwrap();
+
+			let mut generator = TagGenerator::new(0);
+			for _ in 0..25 {
+				let tag = generator.generate(tag_type);
+				assert_round_trip(&tag, &mut Cursor::new(content.clone()));
+			}
+		}
+	}
+}
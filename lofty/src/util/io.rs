@@ -5,6 +5,8 @@ use crate::error::LoftyError;
 use std::collections::VecDeque;
 use std::fs::File;
 use std::io::{Cursor, Read, Seek, Write};
+#[cfg(any(feature = "batch", feature = "tokio"))]
+use std::path::{Path, PathBuf};
 
 // TODO: https://github.com/rust-lang/rust/issues/59359
 pub(crate) trait SeekStreamLen: Seek {
@@ -22,6 +24,26 @@ pub(crate) trait SeekStreamLen: Seek {
 
 impl<T> SeekStreamLen for T where T: Seek {}
 
+/// The path of a hidden temporary file next to `path`, used to replace it atomically
+///
+/// Every call returns a new path, so writes to the same file (or a file with the same name) never
+/// share a temporary file.
+#[cfg(any(feature = "batch", feature = "tokio"))]
+pub(crate) fn temp_path(path: &Path) -> PathBuf {
+	use std::sync::atomic::{AtomicUsize, Ordering};
+
+	static COUNTER: AtomicUsize = AtomicUsize::new(0);
+
+	let mut temp_name = std::ffi::OsString::from(".");
+	temp_name.push(path.file_name().unwrap_or_default());
+	temp_name.push(format!(
+		".{}-{}.lofty-tmp",
+		std::process::id(),
+		COUNTER.fetch_add(1, Ordering::Relaxed)
+	));
+	path.with_file_name(temp_name)
+}
+
 /// Provides a method to truncate an object to the specified length
 ///
 /// This is one component of the [`FileLike`] trait, which is used to provide implementors access to any
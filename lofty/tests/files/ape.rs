@@ -1,5 +1,6 @@
 use crate::{set_artist, temp_file, verify_artist};
-use lofty::config::ParseOptions;
+use lofty::ape::ApeFile;
+use lofty::config::{ParseOptions, WriteOptions};
 use lofty::file::FileType;
 use lofty::prelude::*;
 use lofty::probe::Probe;
@@ -62,6 +63,49 @@ fn write() {
 	crate::set_artist!(tagged_file, tag_mut, TagType::Id3v1, "Baz artist", 1 => file, "Bar artist");
 }
 
+#[test]
+fn encoder_stamp() {
+	let mut file = temp_file!("tests/files/assets/minimal/full_test.ape");
+
+	let mut ape_file =
+		ApeFile::read_from(&mut file, ParseOptions::new().read_properties(false)).unwrap();
+
+	let ape = ape_file.ape_mut().unwrap();
+	assert!(ape.encoder().is_none());
+
+	// A custom stamp is unaffected by `stamp_encoder`
+	file.rewind().unwrap();
+	ape.save_to(
+		&mut file,
+		WriteOptions::new()
+			.encoder_stamp("Foo encoder")
+			.stamp_encoder(false),
+	)
+	.unwrap();
+
+	file.rewind().unwrap();
+	let mut ape_file =
+		ApeFile::read_from(&mut file, ParseOptions::new().read_properties(false)).unwrap();
+
+	let ape = ape_file.ape_mut().unwrap();
+	assert_eq!(ape.encoder().as_deref(), Some("Foo encoder"));
+
+	ape.remove_encoder();
+
+	file.rewind().unwrap();
+	ape.save_to(&mut file, WriteOptions::new().stamp_encoder(true))
+		.unwrap();
+
+	file.rewind().unwrap();
+	let ape_file =
+		ApeFile::read_from(&mut file, ParseOptions::new().read_properties(false)).unwrap();
+
+	assert_eq!(
+		ape_file.ape().unwrap().encoder().as_deref(),
+		Some(WriteOptions::LOFTY_ENCODER_STAMP)
+	);
+}
+
 #[test]
 fn remove_ape() {
 	crate::remove_tag!("tests/files/assets/minimal/full_test.ape", TagType::Ape);
@@ -0,0 +1,99 @@
+use crate::{set_artist, temp_file, verify_artist};
+use lofty::config::ParseOptions;
+use lofty::file::FileType;
+use lofty::prelude::*;
+use lofty::probe::Probe;
+use lofty::tag::TagType;
+
+use std::io::{Seek, Write};
+
+#[test]
+fn read() {
+	// Here we have a DSF file with an ID3v2 tag
+	let file = Probe::open("tests/files/assets/minimal/full_test.dsf")
+		.unwrap()
+		.options(ParseOptions::new().read_properties(false))
+		.read()
+		.unwrap();
+
+	assert_eq!(file.file_type(), FileType::Dsf);
+
+	crate::verify_artist!(file, primary_tag, "Foo artist", 1);
+}
+
+#[test]
+fn write() {
+	let mut file = temp_file!("tests/files/assets/minimal/full_test.dsf");
+
+	let mut tagged_file = Probe::new(&mut file)
+		.options(ParseOptions::new().read_properties(false))
+		.guess_file_type()
+		.unwrap()
+		.read()
+		.unwrap();
+
+	assert_eq!(tagged_file.file_type(), FileType::Dsf);
+
+	crate::set_artist!(tagged_file, primary_tag_mut, "Foo artist", 1 => file, "Bar artist");
+
+	// Now reread the file
+	file.rewind().unwrap();
+	let mut tagged_file = Probe::new(&mut file)
+		.options(ParseOptions::new().read_properties(false))
+		.guess_file_type()
+		.unwrap()
+		.read()
+		.unwrap();
+
+	crate::set_artist!(tagged_file, primary_tag_mut, "Bar artist", 1 => file, "Foo artist");
+}
+
+#[test]
+fn remove_id3v2() {
+	crate::remove_tag!("tests/files/assets/minimal/full_test.dsf", TagType::Id3v2);
+}
+
+#[test]
+fn bad_metadata_pointer() {
+	use lofty::config::{ParsingMode, WriteOptions};
+	use lofty::id3::v2::Id3v2Tag;
+
+	use std::io::Cursor;
+
+	let mut contents = std::fs::read("tests/files/assets/minimal/full_test.dsf").unwrap();
+	let tag_offset = u64::from_le_bytes(contents[20..28].try_into().unwrap()) as usize;
+
+	// Point the metadata pointer at the "fmt " chunk
+	contents[20..28].copy_from_slice(&28_u64.to_le_bytes());
+
+	let mut file = Cursor::new(contents.clone());
+	assert!(Probe::new(&mut file)
+		.options(ParseOptions::new().parsing_mode(ParsingMode::Strict))
+		.guess_file_type()
+		.unwrap()
+		.read()
+		.is_err());
+
+	// Outside of strict mode, the tag is skipped
+	file.rewind().unwrap();
+	let tagged_file = Probe::new(&mut file)
+		.guess_file_type()
+		.unwrap()
+		.read()
+		.unwrap();
+	assert!(tagged_file.tag(TagType::Id3v2).is_none());
+
+	// Writing appends the tag, rather than truncating the file at the pointer
+	let mut tag = Id3v2Tag::new();
+	tag.set_artist(String::from("Foo artist"));
+
+	file.rewind().unwrap();
+	tag.save_to(&mut file, WriteOptions::default()).unwrap();
+
+	let new_contents = file.into_inner();
+	assert_eq!(&new_contents[28..tag_offset], &contents[28..tag_offset]);
+	assert_eq!(
+		u64::from_le_bytes(new_contents[20..28].try_into().unwrap()),
+		contents.len() as u64
+	);
+}
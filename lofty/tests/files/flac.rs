@@ -1,9 +1,10 @@
-use lofty::config::{ParseOptions, ParsingMode};
+use crate::temp_file;
+use lofty::config::{ParseOptions, ParsingMode, WriteOptions};
 use lofty::flac::FlacFile;
 use lofty::prelude::*;
 
 use std::fs::File;
-use std::io::Seek;
+use std::io::{Read, Seek, Write};
 
 #[test]
 fn multiple_vorbis_comments() {
@@ -30,3 +31,63 @@ fn multiple_vorbis_comments() {
 		Some("Artist 2")
 	);
 }
+
+#[test]
+fn write_reuses_padding() {
+	// This file has 8171 bytes of padding, more than enough for our changes
+	let original = std::fs::read("tests/files/assets/minimal/full_test.flac").unwrap();
+	let mut file = temp_file!("tests/files/assets/minimal/full_test.flac");
+
+	let mut f = FlacFile::read_from(&mut file, ParseOptions::new().read_properties(false)).unwrap();
+	f.vorbis_comments_mut()
+		.unwrap()
+		.set_title(String::from("Foo title"));
+
+	file.rewind().unwrap();
+	f.save_to(&mut file, WriteOptions::default()).unwrap();
+
+	file.rewind().unwrap();
+	let mut written = Vec::new();
+	file.read_to_end(&mut written).unwrap();
+
+	// The padding should have shrunk, leaving the audio data untouched
+	assert_eq!(written.len(), original.len());
+	assert_eq!(written[8263..], original[8263..]);
+
+	file.rewind().unwrap();
+	let f = FlacFile::read_from(&mut file, ParseOptions::new().read_properties(false)).unwrap();
+	assert_eq!(
+		f.vorbis_comments().unwrap().title().as_deref(),
+		Some("Foo title")
+	);
+}
+
+#[test]
+fn encoder_stamp() {
+	let mut file = temp_file!("tests/files/assets/minimal/full_test.flac");
+
+	let mut flac_file =
+		FlacFile::read_from(&mut file, ParseOptions::new().read_properties(false)).unwrap();
+
+	let vorbis_comments = flac_file.vorbis_comments_mut().unwrap();
+	vorbis_comments.remove_encoder();
+	vorbis_comments.set_encoder_settings(String::from("--best"));
+
+	file.rewind().unwrap();
+	vorbis_comments
+		.save_to(&mut file, WriteOptions::new().encoder_stamp("Foo encoder"))
+		.unwrap();
+
+	file.rewind().unwrap();
+	let flac_file =
+		FlacFile::read_from(&mut file, ParseOptions::new().read_properties(false)).unwrap();
+
+	let vorbis_comments = flac_file.vorbis_comments().unwrap();
+	assert_eq!(vorbis_comments.encoder().as_deref(), Some("Foo encoder"));
+	assert_eq!(vorbis_comments.get("ENCODER"), Some("Foo encoder"));
+	assert_eq!(
+		vorbis_comments.encoder_settings().as_deref(),
+		Some("--best")
+	);
+	assert_eq!(vorbis_comments.get("ENCODERSETTINGS"), Some("--best"));
+}
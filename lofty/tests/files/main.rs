@@ -1,11 +1,13 @@
 mod aac;
 mod aiff;
 mod ape;
+mod dsf;
 mod flac;
 mod mp4;
 mod mpc;
 mod mpeg;
 mod ogg;
+mod optimfrog;
 pub(crate) mod util;
 mod wav;
 mod wavpack;
@@ -1,11 +1,13 @@
 use crate::{set_artist, temp_file, verify_artist};
-use lofty::config::ParseOptions;
+use lofty::config::{ParseOptions, WriteOptions};
 use lofty::file::FileType;
+use lofty::mp4::{AtomIdent, Mp4File};
 use lofty::prelude::*;
 use lofty::probe::Probe;
+use lofty::properties::GaplessInfo;
 use lofty::tag::TagType;
 
-use std::io::{Seek, Write};
+use std::io::{Cursor, Seek, Write};
 
 #[test]
 fn read() {
@@ -58,3 +60,105 @@ fn remove() {
 		TagType::Mp4Ilst
 	);
 }
+
+#[test]
+fn encoder() {
+	let mut file = temp_file!("tests/files/assets/minimal/m4a_codec_aac.m4a");
+
+	let mut mp4_file =
+		Mp4File::read_from(&mut file, ParseOptions::new().read_properties(false)).unwrap();
+
+	let ilst = mp4_file.ilst_mut().unwrap();
+	assert!(ilst.encoder().is_none());
+
+	ilst.set_encoder(String::from("Foo encoder"));
+
+	file.rewind().unwrap();
+	ilst.save_to(&mut file, WriteOptions::default()).unwrap();
+
+	// Now reread the file
+	file.rewind().unwrap();
+	let mut mp4_file =
+		Mp4File::read_from(&mut file, ParseOptions::new().read_properties(false)).unwrap();
+
+	let ilst = mp4_file.ilst_mut().unwrap();
+	assert_eq!(ilst.encoder().as_deref(), Some("Foo encoder"));
+
+	// The stamp should never overwrite an existing value
+	file.rewind().unwrap();
+	ilst.save_to(&mut file, WriteOptions::new().encoder_stamp("Bar encoder"))
+		.unwrap();
+
+	file.rewind().unwrap();
+	let mut mp4_file =
+		Mp4File::read_from(&mut file, ParseOptions::new().read_properties(false)).unwrap();
+
+	let ilst = mp4_file.ilst_mut().unwrap();
+	assert_eq!(ilst.encoder().as_deref(), Some("Foo encoder"));
+
+	// An empty value removes the atom, allowing the stamp to be written
+	ilst.set_encoder(String::new());
+	assert!(ilst.get(&AtomIdent::Fourcc(*b"\xa9too")).is_none());
+
+	file.rewind().unwrap();
+	ilst.save_to(&mut file, WriteOptions::new().encoder_stamp("Bar encoder"))
+		.unwrap();
+
+	file.rewind().unwrap();
+	let mp4_file =
+		Mp4File::read_from(&mut file, ParseOptions::new().read_properties(false)).unwrap();
+
+	assert_eq!(
+		mp4_file.ilst().unwrap().encoder().as_deref(),
+		Some("Bar encoder")
+	);
+}
+
+#[test]
+fn read_from_memory() {
+	// No path is needed, any `Read + Seek` source will do
+	let file_bytes = std::fs::read("tests/files/assets/minimal/m4a_codec_aac.m4a").unwrap();
+
+	let mp4_file = Mp4File::read_from(
+		&mut Cursor::new(file_bytes),
+		ParseOptions::new().read_properties(false),
+	)
+	.unwrap();
+
+	assert_eq!(
+		mp4_file.ilst().unwrap().artist().as_deref(),
+		Some("Foo artist")
+	);
+}
+
+#[test]
+fn gapless_info() {
+	let mut file = temp_file!("tests/files/assets/minimal/m4a_codec_aac.m4a");
+
+	let mut mp4_file = Mp4File::read_from(&mut file, ParseOptions::new()).unwrap();
+	assert!(mp4_file.properties().gapless_info().is_none());
+
+	let gapless_info = GaplessInfo {
+		encoder_delay: 1024,
+		encoder_padding: 448,
+		total_samples: Some(69_120),
+	};
+
+	let ilst = mp4_file.ilst_mut().unwrap();
+	ilst.set_gapless_info(gapless_info);
+
+	file.rewind().unwrap();
+	ilst.save_to(&mut file, WriteOptions::default()).unwrap();
+
+	// Now reread the file
+	file.rewind().unwrap();
+	let mp4_file = Mp4File::read_from(&mut file, ParseOptions::new()).unwrap();
+
+	assert_eq!(mp4_file.properties().gapless_info(), Some(gapless_info));
+	assert!(mp4_file
+		.ilst()
+		.unwrap()
+		.freeform("com.apple.iTunes", "iTunSMPB")
+		.unwrap()
+		.starts_with(" 00000000 00000400 000001C0 0000000000010E00"));
+}
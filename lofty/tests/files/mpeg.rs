@@ -169,6 +169,65 @@ fn save_to_id3v2() {
 	assert!(tag.disk_total().is_none());
 }
 
+#[test]
+fn save_to_id3v2_sync_id3v1() {
+	let mut file = temp_file!("tests/files/assets/minimal/full_test.mp3");
+
+	let mut tag = Tag::new(TagType::Id3v2);
+	tag.set_title("A title that is much too long to fit in an ID3v1 tag".to_string());
+	tag.set_artist("Foo artist".to_string());
+
+	tag.save_to(&mut file, WriteOptions::new().sync_id3v1(true))
+		.unwrap();
+
+	file.rewind().unwrap();
+	let mpeg_file =
+		MpegFile::read_from(&mut file, ParseOptions::new().read_properties(false)).unwrap();
+
+	let id3v1 = mpeg_file.id3v1().unwrap();
+	assert_eq!(
+		id3v1.title.as_deref(),
+		Some("A title that is much too long ")
+	);
+	assert_eq!(id3v1.artist.as_deref(), Some("Foo artist"));
+	assert!(id3v1.album.is_none());
+
+	// The same goes for a concrete `Id3v2Tag`
+	let mut id3v2 = Id3v2Tag::new();
+	id3v2.set_artist("Bar artist".to_string());
+
+	file.rewind().unwrap();
+	id3v2
+		.save_to(&mut file, WriteOptions::new().sync_id3v1(true))
+		.unwrap();
+
+	file.rewind().unwrap();
+	let mut mpeg_file =
+		MpegFile::read_from(&mut file, ParseOptions::new().read_properties(false)).unwrap();
+
+	let id3v1 = mpeg_file.id3v1().unwrap();
+	assert!(id3v1.title.is_none());
+	assert_eq!(id3v1.artist.as_deref(), Some("Bar artist"));
+
+	// Saving the entire file shouldn't overwrite the synced tag with the stale ID3v1 tag
+	mpeg_file
+		.id3v2_mut()
+		.unwrap()
+		.set_artist("Baz artist".to_string());
+
+	file.rewind().unwrap();
+	mpeg_file
+		.save_to(&mut file, WriteOptions::new().sync_id3v1(true))
+		.unwrap();
+
+	file.rewind().unwrap();
+	let mpeg_file =
+		MpegFile::read_from(&mut file, ParseOptions::new().read_properties(false)).unwrap();
+
+	let id3v1 = mpeg_file.id3v1().unwrap();
+	assert_eq!(id3v1.artist.as_deref(), Some("Baz artist"));
+}
+
 #[test]
 fn save_number_of_track_and_disk_to_id3v2() {
 	let mut file = temp_file!("tests/files/assets/minimal/full_test.mp3");
@@ -346,3 +405,44 @@ fn read_and_write_tpil_frame() {
 
 	assert_eq!(key_value_pairs, content.key_value_pairs);
 }
+
+#[test]
+fn encoder_stamp() {
+	let mut file = temp_file!("tests/files/assets/minimal/full_test.mp3");
+
+	let mut id3v2 = Id3v2Tag::default();
+	id3v2.set_title(String::from("Foo title"));
+
+	id3v2
+		.save_to(&mut file, WriteOptions::new().stamp_encoder(true))
+		.unwrap();
+
+	file.rewind().unwrap();
+	let mpeg_file =
+		MpegFile::read_from(&mut file, ParseOptions::new().read_properties(false)).unwrap();
+
+	let id3v2 = mpeg_file.id3v2().unwrap();
+	assert_eq!(
+		id3v2.encoder().as_deref(),
+		Some(WriteOptions::LOFTY_ENCODER_STAMP)
+	);
+	assert!(WriteOptions::LOFTY_ENCODER_STAMP.starts_with("lofty "));
+
+	// The stamp should never overwrite an existing value
+	let mut id3v2 = id3v2.clone();
+	id3v2.set_encoder(String::from("Foo encoder"));
+
+	file.rewind().unwrap();
+	id3v2
+		.save_to(&mut file, WriteOptions::new().stamp_encoder(true))
+		.unwrap();
+
+	file.rewind().unwrap();
+	let mpeg_file =
+		MpegFile::read_from(&mut file, ParseOptions::new().read_properties(false)).unwrap();
+
+	assert_eq!(
+		mpeg_file.id3v2().unwrap().encoder().as_deref(),
+		Some("Foo encoder")
+	);
+}
@@ -186,3 +186,57 @@ fn flac_try_write_non_empty_id3v2() {
 		)
 		.is_err());
 }
+
+#[test]
+fn chained_streams() {
+	use lofty::ogg::{OggStreams, VorbisFile};
+
+	let mut content = std::fs::read("tests/files/assets/minimal/full_test.ogg").unwrap();
+	content.extend(std::fs::read("tests/files/assets/minimal/full_test.opus").unwrap());
+
+	let mut file = std::io::Cursor::new(content);
+
+	let mut streams = OggStreams::read_from(&mut file, ParseOptions::new()).unwrap();
+	assert_eq!(streams.streams().len(), 2);
+	assert_eq!(streams.streams()[0].file_type(), FileType::Vorbis);
+	assert_eq!(streams.streams()[1].file_type(), FileType::Opus);
+
+	// Large enough to span multiple pages
+	let long_artist = "Bar artist".repeat(10_000);
+	streams.streams_mut()[1]
+		.vorbis_comments_mut()
+		.set_artist(long_artist.clone());
+	streams.save_to(&mut file, WriteOptions::default()).unwrap();
+
+	let streams = OggStreams::read_from(&mut file, ParseOptions::new()).unwrap();
+	assert_eq!(
+		streams.streams()[0].vorbis_comments().artist().as_deref(),
+		Some("Foo artist")
+	);
+	assert_eq!(
+		streams.streams()[1].vorbis_comments().artist().as_deref(),
+		Some(long_artist.as_str())
+	);
+
+	// Writing to the first stream shouldn't touch the second
+	file.rewind().unwrap();
+	let mut vorbis_file =
+		VorbisFile::read_from(&mut file, ParseOptions::new().read_properties(false)).unwrap();
+	vorbis_file
+		.vorbis_comments_mut()
+		.set_artist(String::from("Baz artist"));
+	vorbis_file
+		.save_to(&mut file, WriteOptions::default())
+		.unwrap();
+
+	let streams = OggStreams::read_from(&mut file, ParseOptions::new()).unwrap();
+	assert_eq!(streams.streams().len(), 2);
+	assert_eq!(
+		streams.streams()[0].vorbis_comments().artist().as_deref(),
+		Some("Baz artist")
+	);
+	assert_eq!(
+		streams.streams()[1].vorbis_comments().artist().as_deref(),
+		Some(long_artist.as_str())
+	);
+}
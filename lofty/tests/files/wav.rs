@@ -1,11 +1,13 @@
 use crate::{set_artist, temp_file, verify_artist};
-use lofty::config::ParseOptions;
+use lofty::config::{ParseOptions, WriteOptions};
 use lofty::file::FileType;
+use lofty::iff::wav::{RiffInfoList, WavFile};
 use lofty::prelude::*;
 use lofty::probe::Probe;
-use lofty::tag::TagType;
+use lofty::tag::{Tag, TagType};
 
-use std::io::{Seek, Write};
+use std::io::{Read, Seek, Write};
+use std::time::Duration;
 
 #[test]
 fn read() {
@@ -85,3 +87,310 @@ fn issue_174_divide_by_zero() {
 
 	assert_eq!(file.file_type(), FileType::Wav);
 }
+
+#[test]
+fn encoder() {
+	let mut file = temp_file!("tests/files/assets/minimal/wav_format_pcm.wav");
+
+	let mut wav_file =
+		WavFile::read_from(&mut file, ParseOptions::new().read_properties(false)).unwrap();
+
+	let riff_info = wav_file.riff_info_mut().unwrap();
+	assert!(riff_info.encoder().is_none());
+
+	riff_info.set_encoder(String::from("Foo encoder"));
+
+	file.rewind().unwrap();
+	riff_info
+		.save_to(&mut file, WriteOptions::default())
+		.unwrap();
+
+	// Now reread the file
+	file.rewind().unwrap();
+	let mut wav_file =
+		WavFile::read_from(&mut file, ParseOptions::new().read_properties(false)).unwrap();
+
+	let riff_info = wav_file.riff_info_mut().unwrap();
+	assert_eq!(riff_info.encoder().as_deref(), Some("Foo encoder"));
+	assert_eq!(riff_info.get("ISFT"), Some("Foo encoder"));
+
+	// The stamp should never overwrite an existing value
+	file.rewind().unwrap();
+	riff_info
+		.save_to(&mut file, WriteOptions::new().encoder_stamp("Bar encoder"))
+		.unwrap();
+
+	file.rewind().unwrap();
+	let mut wav_file =
+		WavFile::read_from(&mut file, ParseOptions::new().read_properties(false)).unwrap();
+
+	let riff_info = wav_file.riff_info_mut().unwrap();
+	assert_eq!(riff_info.encoder().as_deref(), Some("Foo encoder"));
+
+	// An empty value removes the chunk, allowing the stamp to be written
+	riff_info.set_encoder(String::new());
+	assert!(riff_info.get("ISFT").is_none());
+
+	file.rewind().unwrap();
+	riff_info
+		.save_to(&mut file, WriteOptions::new().encoder_stamp("Bar encoder"))
+		.unwrap();
+
+	file.rewind().unwrap();
+	let wav_file =
+		WavFile::read_from(&mut file, ParseOptions::new().read_properties(false)).unwrap();
+
+	assert_eq!(
+		wav_file.riff_info().unwrap().encoder().as_deref(),
+		Some("Bar encoder")
+	);
+}
+
+#[test]
+fn odd_length_data_chunk() {
+	// The `data` chunk is 8001 bytes, followed by a pad byte and a RIFF INFO chunk
+	const PAD_BYTE_POS: usize = 44 + 8001;
+
+	let mut file = temp_file!("tests/files/assets/odd_length_data_chunk.wav");
+
+	let mut wav_file = WavFile::read_from(&mut file, ParseOptions::new()).unwrap();
+
+	// 8001 samples @ 8kHz, the pad byte should not be counted
+	assert_eq!(
+		wav_file.properties().duration(),
+		Duration::from_millis(1000)
+	);
+	assert_eq!(wav_file.properties().bit_depth(), 8);
+
+	let riff_info = wav_file.riff_info_mut().unwrap();
+	assert_eq!(riff_info.artist().as_deref(), Some("Foo artist"));
+
+	riff_info.set_artist(String::from("Bar artist"));
+
+	file.rewind().unwrap();
+	riff_info
+		.save_to(&mut file, WriteOptions::default())
+		.unwrap();
+
+	// The pad byte must be preserved
+	file.rewind().unwrap();
+	let mut file_bytes = Vec::new();
+	file.read_to_end(&mut file_bytes).unwrap();
+
+	assert_eq!(file_bytes[PAD_BYTE_POS], 0);
+	assert_eq!(&file_bytes[PAD_BYTE_POS + 1..PAD_BYTE_POS + 5], b"LIST");
+
+	file.rewind().unwrap();
+	let wav_file = WavFile::read_from(&mut file, ParseOptions::new()).unwrap();
+
+	assert_eq!(
+		wav_file.riff_info().unwrap().artist().as_deref(),
+		Some("Bar artist")
+	);
+}
+
+#[test]
+fn odd_length_data_chunk_missing_pad_byte() {
+	// Same as above, with the pad byte and RIFF INFO chunk stripped
+	const PAD_BYTE_POS: usize = 44 + 8001;
+
+	let mut file_bytes = std::fs::read("tests/files/assets/odd_length_data_chunk.wav").unwrap();
+	file_bytes.truncate(PAD_BYTE_POS);
+
+	let riff_size = (file_bytes.len() - 8) as u32;
+	let _ = file_bytes.splice(4..8, riff_size.to_le_bytes());
+
+	let mut file = tempfile::tempfile().unwrap();
+	file.write_all(&file_bytes).unwrap();
+	file.rewind().unwrap();
+
+	let wav_file = WavFile::read_from(&mut file, ParseOptions::new()).unwrap();
+	assert_eq!(
+		wav_file.properties().duration(),
+		Duration::from_millis(1000)
+	);
+	assert!(wav_file.riff_info().is_none());
+
+	let mut riff_info = RiffInfoList::new();
+	riff_info.set_artist(String::from("Foo artist"));
+
+	file.rewind().unwrap();
+	riff_info
+		.save_to(&mut file, WriteOptions::default())
+		.unwrap();
+
+	// The writer should have added the missing pad byte
+	file.rewind().unwrap();
+	let mut file_bytes = Vec::new();
+	file.read_to_end(&mut file_bytes).unwrap();
+
+	assert_eq!(file_bytes[PAD_BYTE_POS], 0);
+	assert_eq!(&file_bytes[PAD_BYTE_POS + 1..PAD_BYTE_POS + 5], b"LIST");
+
+	file.rewind().unwrap();
+	let wav_file = WavFile::read_from(&mut file, ParseOptions::new()).unwrap();
+
+	assert_eq!(
+		wav_file.riff_info().unwrap().artist().as_deref(),
+		Some("Foo artist")
+	);
+}
+
+#[test]
+fn bad_chunk_size() {
+	use lofty::error::ErrorKind;
+
+	let mut file_bytes = std::fs::read("tests/files/assets/minimal/wav_format_pcm.wav").unwrap();
+	let list_offset = file_bytes.len() as u64;
+
+	// A LIST chunk too small to hold its own type
+	file_bytes.extend(b"LIST");
+	file_bytes.extend(2_u32.to_le_bytes());
+	file_bytes.extend(b"INFO");
+
+	let riff_size = (file_bytes.len() - 8) as u32;
+	let _ = file_bytes.splice(4..8, riff_size.to_le_bytes());
+
+	let err =
+		WavFile::read_from(&mut std::io::Cursor::new(file_bytes), ParseOptions::new()).unwrap_err();
+	match err.kind() {
+		ErrorKind::BadChunkSize { chunk, offset } => {
+			assert_eq!(chunk, "LIST");
+			assert_eq!(*offset, list_offset);
+		},
+		_ => panic!("Expected `ErrorKind::BadChunkSize`, got: {err:?}"),
+	}
+}
+
+#[test]
+fn max_tag_size() {
+	use lofty::error::ErrorKind;
+
+	let file_bytes = std::fs::read("tests/files/assets/minimal/wav_format_pcm.wav").unwrap();
+
+	// Both the ID3v2 and RIFF INFO chunks are larger than 16 bytes
+	let err = WavFile::read_from(
+		&mut std::io::Cursor::new(&file_bytes),
+		ParseOptions::new().max_tag_size(16),
+	)
+	.unwrap_err();
+	assert!(matches!(err.kind(), ErrorKind::TooMuchData));
+
+	// The tags are well within the default limit
+	let wav_file =
+		WavFile::read_from(&mut std::io::Cursor::new(&file_bytes), ParseOptions::new()).unwrap();
+	assert!(wav_file.id3v2().is_some());
+	assert!(wav_file.riff_info().is_some());
+}
+
+#[test]
+fn preserve_unknown_riff_info_chunks() {
+	// The offsets of the `ID3 ` and `LIST` chunks in the original file
+	const ID3_CHUNK_POS: usize = 274_228;
+	const INFO_LIST_POS: usize = 275_292;
+
+	let mut file_bytes = std::fs::read("tests/files/assets/minimal/wav_format_pcm.wav").unwrap();
+
+	// Add a vendor chunk with an odd length to the end of the RIFF INFO list
+	file_bytes.extend(b"vndr\x03\0\0\0\x01\x02\x03\0");
+	let info_list_size = (file_bytes.len() - INFO_LIST_POS - 8) as u32;
+	let _ = file_bytes.splice(
+		INFO_LIST_POS + 4..INFO_LIST_POS + 8,
+		info_list_size.to_le_bytes(),
+	);
+
+	// Add an unrelated `LIST` chunk before the RIFF INFO list
+	let adtl_list = b"LIST\x10\0\0\0adtllabl\x04\0\0\0\x01\0\0\0";
+	let _ = file_bytes.splice(ID3_CHUNK_POS..ID3_CHUNK_POS, adtl_list.iter().copied());
+
+	let riff_size = (file_bytes.len() - 8) as u32;
+	let _ = file_bytes.splice(4..8, riff_size.to_le_bytes());
+
+	let mut file = tempfile::tempfile().unwrap();
+	file.write_all(&file_bytes).unwrap();
+	file.rewind().unwrap();
+
+	let wav_file = WavFile::read_from(&mut file, ParseOptions::new()).unwrap();
+	let mut riff_info = wav_file.riff_info().unwrap().clone();
+	assert_eq!(
+		riff_info.unknown_chunks().collect::<Vec<_>>(),
+		vec![(b"vndr", &[1_u8, 2, 3][..])]
+	);
+
+	riff_info.set_title(String::from("Foo title"));
+
+	file.rewind().unwrap();
+	riff_info
+		.save_to(&mut file, WriteOptions::default())
+		.unwrap();
+
+	file.rewind().unwrap();
+	let mut file_bytes = Vec::new();
+	file.read_to_end(&mut file_bytes).unwrap();
+
+	// The existing list should have been replaced, rather than a new one being appended
+	assert_eq!(file_bytes.windows(4).filter(|w| w == b"INFO").count(), 1);
+	assert_eq!(
+		&file_bytes[ID3_CHUNK_POS..ID3_CHUNK_POS + adtl_list.len()],
+		adtl_list
+	);
+
+	file.rewind().unwrap();
+	let wav_file = WavFile::read_from(&mut file, ParseOptions::new()).unwrap();
+	let riff_info = wav_file.riff_info().unwrap();
+
+	assert_eq!(riff_info.title().as_deref(), Some("Foo title"));
+	assert_eq!(riff_info.artist().as_deref(), Some("Bar artist"));
+	assert_eq!(
+		riff_info.unknown_chunks().collect::<Vec<_>>(),
+		vec![(b"vndr", &[1_u8, 2, 3][..])]
+	);
+}
+
+#[test]
+fn choose_tag_representation() {
+	let mut file = temp_file!("tests/files/assets/minimal/wav_format_pcm.wav");
+
+	// Only keep the ID3v2 chunk, using the lowercase chunk name
+	let mut tag = Tag::new(TagType::Id3v2);
+	tag.set_artist(String::from("Foo artist"));
+	tag.save_to(
+		&mut file,
+		WriteOptions::new()
+			.remove_others(true)
+			.uppercase_id3v2_chunk(false),
+	)
+	.unwrap();
+
+	file.rewind().unwrap();
+	let mut bytes = Vec::new();
+	file.read_to_end(&mut bytes).unwrap();
+	assert!(bytes.windows(4).any(|w| w == b"id3 "));
+	assert!(!bytes.windows(4).any(|w| w == b"INFO"));
+
+	file.rewind().unwrap();
+	let wav_file =
+		WavFile::read_from(&mut file, ParseOptions::new().read_properties(false)).unwrap();
+	assert!(wav_file.riff_info().is_none());
+	assert_eq!(
+		wav_file.id3v2().unwrap().artist().as_deref(),
+		Some("Foo artist")
+	);
+
+	// Now switch to RIFF INFO
+	let mut tag = Tag::new(TagType::RiffInfo);
+	tag.set_artist(String::from("Bar artist"));
+
+	file.rewind().unwrap();
+	tag.save_to(&mut file, WriteOptions::new().remove_others(true))
+		.unwrap();
+
+	file.rewind().unwrap();
+	let wav_file =
+		WavFile::read_from(&mut file, ParseOptions::new().read_properties(false)).unwrap();
+	assert!(wav_file.id3v2().is_none());
+	assert_eq!(
+		wav_file.riff_info().unwrap().artist().as_deref(),
+		Some("Bar artist")
+	);
+}
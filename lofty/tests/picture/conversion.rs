@@ -0,0 +1,58 @@
+use lofty::picture::Picture;
+
+use std::fs::File;
+
+fn read_picture(path: &str) -> Picture {
+	let mut f = File::open(path).unwrap();
+	Picture::from_reader(&mut f).unwrap()
+}
+
+#[test]
+fn dimensions() {
+	let png = read_picture("tests/picture/assets/png_640x628.png");
+	assert_eq!(png.width(), Some(640));
+	assert_eq!(png.height(), Some(628));
+	assert_eq!(png.data_len(), png.data().len());
+
+	let jpeg = read_picture("tests/picture/assets/jpeg_640x628.jpg");
+	assert_eq!(jpeg.width(), Some(640));
+	assert_eq!(jpeg.height(), Some(628));
+
+	// Only PNG and JPEG are supported
+	let bmp = read_picture("tests/picture/assets/bmp_640x628.bmp");
+	assert_eq!(bmp.width(), None);
+	assert_eq!(bmp.height(), None);
+}
+
+#[test]
+#[cfg(feature = "image")]
+fn convert() {
+	use lofty::picture::MimeType;
+
+	for path in [
+		"tests/picture/assets/bmp_640x628.bmp",
+		"tests/picture/assets/gif_640x628.gif",
+		"tests/picture/assets/tiff_640x628.tiff",
+	] {
+		let picture = read_picture(path);
+
+		for mime_type in [MimeType::Png, MimeType::Jpeg] {
+			let converted = picture.convert_to(mime_type.clone()).unwrap();
+			assert_eq!(converted.mime_type(), Some(&mime_type));
+			assert_eq!(converted.pic_type(), picture.pic_type());
+			assert_eq!(converted.width(), Some(640));
+			assert_eq!(converted.height(), Some(628));
+
+			// The converted data should be detected as the new type
+			let reread = Picture::from_reader(&mut converted.data()).unwrap();
+			assert_eq!(reread.mime_type(), Some(&mime_type));
+		}
+	}
+
+	let png = read_picture("tests/picture/assets/png_640x628.png");
+	assert_eq!(png.convert_to(MimeType::Png).unwrap(), png);
+	assert!(png
+		.convert_to(MimeType::Unknown(String::from("image/webp")))
+		.is_err());
+}
+
@@ -1,3 +1,4 @@
+mod conversion;
 mod format_parsers;
 mod from_reader;
 mod information;
@@ -9,9 +9,9 @@ use quote::quote;
 pub(crate) fn opt_internal_file_type(
 	struct_name: String,
 ) -> Option<(proc_macro2::TokenStream, bool)> {
-	const LOFTY_FILE_TYPES: [&str; 12] = [
-		"Aac", "Aiff", "Ape", "Flac", "Mpeg", "Mp4", "Mpc", "Opus", "Vorbis", "Speex", "Wav",
-		"WavPack",
+	const LOFTY_FILE_TYPES: [&str; 14] = [
+		"Aac", "Aiff", "Ape", "Dsf", "Flac", "Mpeg", "Mp4", "Mpc", "OptimFrog", "Opus", "Vorbis",
+		"Speex", "Wav", "WavPack",
 	];
 
 	const ID3V2_STRIPPABLE: [&str; 2] = ["Flac", "Ape"];
@@ -70,10 +70,9 @@ pub(crate) fn init_write_lookup(
 	}
 
 	insert!(map, RiffInfo, {
-		lofty::iff::wav::tag::RIFFInfoListRef::new(lofty::iff::wav::tag::tagitems_into_riff(
-			tag.items(),
-		))
-		.write_to(file, write_options)
+		Into::<lofty::iff::wav::RiffInfoList>::into(tag)
+			.as_ref()
+			.write_to(file, write_options)
 	});
 
 	insert!(map, AiffText, {
@@ -0,0 +1,364 @@
+//! A minimal C API for [Lofty](https://crates.io/crates/lofty)
+//!
+//! Files are read into an opaque [`LoftyFile`] handle, which can be queried and modified through the
+//! primary tag of the file, and then written back to disk. All strings passed in and out of
+//! these functions are NUL-terminated UTF-8, with the exception of paths, which are passed to the OS
+//! as-is on Unix.
+//!
+//! A C header can be generated with [cbindgen](https://github.com/mozilla/cbindgen), using the
+//! `cbindgen.toml` at the root of the crate:
+//!
+//! ```sh
+//! cbindgen --config cbindgen.toml --crate lofty_ffi --output lofty.h
+//! ```
+//!
+//! # Examples
+//!
+//! ```c
+//! LoftyFile *file = lofty_read_path("foo.mp3");
+//! if (file == NULL) {
+//! 	return;
+//! }
+//!
+//! char *title = lofty_get(file, LOFTY_FIELD_TITLE);
+//! if (title != NULL) {
+//! 	printf("Title: %s\n", title);
+//! 	lofty_string_free(title);
+//! }
+//!
+//! lofty_set(file, LOFTY_FIELD_ARTIST, "Foo artist");
+//! lofty_write(file, "foo.mp3");
+//! lofty_free(file);
+//! ```
+
+use std::ffi::{c_char, CStr, CString};
+use std::path::Path;
+
+use lofty::config::WriteOptions;
+use lofty::file::{AudioFile, TaggedFile, TaggedFileExt};
+use lofty::tag::{ItemKey, Tag};
+
+/// An opaque handle to a file, created with [`lofty_read_path`]
+///
+/// This must be freed with [`lofty_free`].
+pub struct LoftyFile(TaggedFile);
+
+/// The fields accessible with [`lofty_get`] and [`lofty_set`]
+///
+/// These are passed to the functions as integers, any value outside of this enum is rejected.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LoftyField {
+	/// The title of the track ([`ItemKey::TrackTitle`])
+	Title,
+	/// The artist of the track ([`ItemKey::TrackArtist`])
+	Artist,
+	/// The title of the album ([`ItemKey::AlbumTitle`])
+	Album,
+	/// The artist of the album ([`ItemKey::AlbumArtist`])
+	AlbumArtist,
+	/// The genre ([`ItemKey::Genre`])
+	Genre,
+	/// The first comment ([`ItemKey::Comment`])
+	Comment,
+	/// The recording date ([`ItemKey::RecordingDate`])
+	RecordingDate,
+	/// The track number ([`ItemKey::TrackNumber`])
+	TrackNumber,
+	/// The total number of tracks ([`ItemKey::TrackTotal`])
+	TrackTotal,
+	/// The disc number ([`ItemKey::DiscNumber`])
+	DiscNumber,
+	/// The total number of discs ([`ItemKey::DiscTotal`])
+	DiscTotal,
+}
+
+impl LoftyField {
+	// In discriminant order
+	const ALL: [LoftyField; 11] = [
+		LoftyField::Title,
+		LoftyField::Artist,
+		LoftyField::Album,
+		LoftyField::AlbumArtist,
+		LoftyField::Genre,
+		LoftyField::Comment,
+		LoftyField::RecordingDate,
+		LoftyField::TrackNumber,
+		LoftyField::TrackTotal,
+		LoftyField::DiscNumber,
+		LoftyField::DiscTotal,
+	];
+
+	fn from_u32(value: u32) -> Option<Self> {
+		Self::ALL.get(value as usize).copied()
+	}
+
+	fn item_key(self) -> ItemKey {
+		match self {
+			LoftyField::Title => ItemKey::TrackTitle,
+			LoftyField::Artist => ItemKey::TrackArtist,
+			LoftyField::Album => ItemKey::AlbumTitle,
+			LoftyField::AlbumArtist => ItemKey::AlbumArtist,
+			LoftyField::Genre => ItemKey::Genre,
+			LoftyField::Comment => ItemKey::Comment,
+			LoftyField::RecordingDate => ItemKey::RecordingDate,
+			LoftyField::TrackNumber => ItemKey::TrackNumber,
+			LoftyField::TrackTotal => ItemKey::TrackTotal,
+			LoftyField::DiscNumber => ItemKey::DiscNumber,
+			LoftyField::DiscTotal => ItemKey::DiscTotal,
+		}
+	}
+}
+
+unsafe fn str_from_ptr<'a>(ptr: *const c_char) -> Option<&'a str> {
+	if ptr.is_null() {
+		return None;
+	}
+
+	CStr::from_ptr(ptr).to_str().ok()
+}
+
+#[cfg(unix)]
+unsafe fn path_from_ptr<'a>(ptr: *const c_char) -> Option<&'a Path> {
+	use std::ffi::OsStr;
+	use std::os::unix::ffi::OsStrExt;
+
+	if ptr.is_null() {
+		return None;
+	}
+
+	Some(Path::new(OsStr::from_bytes(CStr::from_ptr(ptr).to_bytes())))
+}
+
+#[cfg(not(unix))]
+unsafe fn path_from_ptr<'a>(ptr: *const c_char) -> Option<&'a Path> {
+	str_from_ptr(ptr).map(Path::new)
+}
+
+/// Read the file at `path`
+///
+/// Returns `NULL` if the file could not be read.
+///
+/// # Safety
+///
+/// `path` must be `NULL` or a valid NUL-terminated string.
+#[no_mangle]
+pub unsafe extern "C" fn lofty_read_path(path: *const c_char) -> *mut LoftyFile {
+	let Some(path) = path_from_ptr(path) else {
+		return std::ptr::null_mut();
+	};
+
+	match lofty::read_from_path(path) {
+		Ok(tagged_file) => Box::into_raw(Box::new(LoftyFile(tagged_file))),
+		Err(e) => {
+			log::error!("C API: Failed to read \"{}\": {e}", path.display());
+			std::ptr::null_mut()
+		},
+	}
+}
+
+/// Free a file created with [`lofty_read_path`]
+///
+/// # Safety
+///
+/// `file` must be `NULL` or a handle created with [`lofty_read_path`] that has not already been freed.
+#[no_mangle]
+pub unsafe extern "C" fn lofty_free(file: *mut LoftyFile) {
+	if !file.is_null() {
+		drop(Box::from_raw(file));
+	}
+}
+
+/// Get the value of `field` from the primary tag of `file`
+///
+/// `field` is a [`LoftyField`].
+///
+/// Returns `NULL` if `field` is invalid, the file has no primary tag, or the field is not set. The
+/// returned string must be freed with [`lofty_string_free`].
+///
+/// # Safety
+///
+/// `file` must be `NULL` or a valid handle created with [`lofty_read_path`].
+#[no_mangle]
+pub unsafe extern "C" fn lofty_get(file: *const LoftyFile, field: u32) -> *mut c_char {
+	let (Some(LoftyFile(tagged_file)), Some(field)) = (file.as_ref(), LoftyField::from_u32(field))
+	else {
+		return std::ptr::null_mut();
+	};
+
+	tagged_file
+		.primary_tag()
+		.and_then(|tag| tag.get_string(&field.item_key()))
+		.and_then(|value| CString::new(value).ok())
+		.map_or(std::ptr::null_mut(), CString::into_raw)
+}
+
+/// Set the value of `field` in the primary tag of `file`
+///
+/// `field` is a [`LoftyField`]. If `value` is `NULL`, the field will be removed. If the file has no
+/// primary tag, one will be created.
+///
+/// Returns `false` if `field` is invalid, `value` is not valid UTF-8, or the field cannot be stored
+/// in the primary tag.
+///
+/// NOTE: This does not write to the file, see [`lofty_write`].
+///
+/// # Safety
+///
+/// * `file` must be `NULL` or a valid handle created with [`lofty_read_path`].
+/// * `value` must be `NULL` or a valid NUL-terminated string.
+#[no_mangle]
+pub unsafe extern "C" fn lofty_set(file: *mut LoftyFile, field: u32, value: *const c_char) -> bool {
+	let (Some(LoftyFile(tagged_file)), Some(field)) = (file.as_mut(), LoftyField::from_u32(field))
+	else {
+		return false;
+	};
+
+	let key = field.item_key();
+
+	if value.is_null() {
+		if let Some(tag) = tagged_file.primary_tag_mut() {
+			tag.remove_key(&key);
+		}
+
+		return true;
+	}
+
+	let Some(value) = str_from_ptr(value) else {
+		return false;
+	};
+
+	if tagged_file.primary_tag().is_none() {
+		tagged_file.insert_tag(Tag::new(tagged_file.primary_tag_type()));
+	}
+
+	match tagged_file.primary_tag_mut() {
+		Some(tag) => tag.insert_text(key, value.to_owned()),
+		None => false,
+	}
+}
+
+/// Write the tags of `file` to the file at `path`
+///
+/// Returns `false` if the tags could not be written.
+///
+/// # Safety
+///
+/// * `file` must be `NULL` or a valid handle created with [`lofty_read_path`].
+/// * `path` must be `NULL` or a valid NUL-terminated string.
+#[no_mangle]
+pub unsafe extern "C" fn lofty_write(file: *const LoftyFile, path: *const c_char) -> bool {
+	let (Some(LoftyFile(tagged_file)), Some(path)) = (file.as_ref(), path_from_ptr(path)) else {
+		return false;
+	};
+
+	match tagged_file.save_to_path(path, WriteOptions::default()) {
+		Ok(()) => true,
+		Err(e) => {
+			log::error!("C API: Failed to write \"{}\": {e}", path.display());
+			false
+		},
+	}
+}
+
+/// Get the duration of `file` in milliseconds
+///
+/// Returns 0 if the duration is unknown.
+///
+/// # Safety
+///
+/// `file` must be `NULL` or a valid handle created with [`lofty_read_path`].
+#[no_mangle]
+pub unsafe extern "C" fn lofty_duration_ms(file: *const LoftyFile) -> u64 {
+	file.as_ref().map_or(0, |LoftyFile(tagged_file)| {
+		tagged_file.properties().duration().as_millis() as u64
+	})
+}
+
+/// Free a string returned by [`lofty_get`]
+///
+/// # Safety
+///
+/// `string` must be `NULL` or a string returned by [`lofty_get`] that has not already been freed.
+#[no_mangle]
+pub unsafe extern "C" fn lofty_string_free(string: *mut c_char) {
+	if !string.is_null() {
+		drop(CString::from_raw(string));
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::{
+		lofty_duration_ms, lofty_free, lofty_get, lofty_read_path, lofty_set, lofty_string_free,
+		lofty_write, LoftyField,
+	};
+
+	use std::ffi::{CStr, CString};
+	use std::io::Write;
+
+	#[test]
+	fn read_modify_write() {
+		let mut temp_file = tempfile::NamedTempFile::new().unwrap();
+		temp_file
+			.write_all(&std::fs::read("../lofty/tests/files/assets/minimal/full_test.mp3").unwrap())
+			.unwrap();
+
+		let path = CString::new(temp_file.path().to_str().unwrap()).unwrap();
+
+		unsafe {
+			let file = lofty_read_path(path.as_ptr());
+			assert!(!file.is_null());
+			assert!(lofty_duration_ms(file) > 0);
+
+			let title = CString::new("Foo title").unwrap();
+			assert!(lofty_set(file, LoftyField::Title as u32, title.as_ptr()));
+			assert!(lofty_set(file, LoftyField::Artist as u32, std::ptr::null()));
+			assert!(lofty_write(file, path.as_ptr()));
+			lofty_free(file);
+
+			let file = lofty_read_path(path.as_ptr());
+			assert!(!file.is_null());
+
+			let value = lofty_get(file, LoftyField::Title as u32);
+			assert_eq!(CStr::from_ptr(value).to_str(), Ok("Foo title"));
+			lofty_string_free(value);
+
+			assert!(lofty_get(file, LoftyField::Artist as u32).is_null());
+
+			// Out of range fields are rejected
+			assert!(lofty_get(file, 1000).is_null());
+			assert!(!lofty_set(file, 1000, title.as_ptr()));
+			lofty_free(file);
+		}
+
+		assert!(unsafe { lofty_read_path(std::ptr::null()) }.is_null());
+	}
+
+	#[test]
+	#[cfg(unix)]
+	fn non_utf8_path() {
+		use std::ffi::OsStr;
+		use std::os::unix::ffi::OsStrExt;
+
+		let dir = tempfile::tempdir().unwrap();
+		let file_name = OsStr::from_bytes(b"foo\xFF.mp3");
+		std::fs::copy(
+			"../lofty/tests/files/assets/minimal/full_test.mp3",
+			dir.path().join(file_name),
+		)
+		.unwrap();
+
+		let mut path = dir.path().as_os_str().as_bytes().to_vec();
+		path.push(b'/');
+		path.extend_from_slice(file_name.as_bytes());
+		let path = CString::new(path).unwrap();
+
+		unsafe {
+			let file = lofty_read_path(path.as_ptr());
+			assert!(!file.is_null());
+			assert!(lofty_write(file, path.as_ptr()));
+			lofty_free(file);
+		}
+	}
+}